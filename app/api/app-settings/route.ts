@@ -0,0 +1,48 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { exportAppSettings, importAppSettings, validateAppSettings } from '@/app/lib/appSettings';
+
+// GET: App-wide settings (library history, ffmpeg templates) as a JSON export
+export async function GET() {
+  try {
+    return NextResponse.json({ success: true, settings: exportAppSettings() });
+  } catch (error) {
+    console.error('Error exporting settings:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to export settings' },
+      { status: 500 }
+    );
+  }
+}
+
+// POST: Merge an export from another machine; existing entries are kept
+// Body: the exported settings object
+export async function POST(request: NextRequest) {
+  let data: unknown;
+  try {
+    data = await request.json();
+  } catch {
+    return NextResponse.json(
+      { success: false, error: 'Settings file is not valid JSON' },
+      { status: 400 }
+    );
+  }
+
+  const validation = validateAppSettings(data);
+  if ('error' in validation) {
+    return NextResponse.json(
+      { success: false, error: validation.error },
+      { status: 400 }
+    );
+  }
+
+  try {
+    const { added, skipped, templatesImported, presetsImported } = importAppSettings(validation.settings);
+    return NextResponse.json({ success: true, added, skipped, templatesImported, presetsImported });
+  } catch (error) {
+    console.error('Error importing settings:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to import settings' },
+      { status: 500 }
+    );
+  }
+}
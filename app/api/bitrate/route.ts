@@ -0,0 +1,54 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized } from '@/app/lib/db';
+import {
+  DEFAULT_BITRATE_THRESHOLDS,
+  getBitrateThresholds,
+  isValidBitrateThresholds,
+  MAX_BITRATE_THRESHOLD,
+  setBitrateThresholds,
+} from '@/app/lib/bitrate';
+
+// GET: Bit rate thresholds (Mbps per resolution class) for the open library
+export async function GET() {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  return NextResponse.json({
+    success: true,
+    thresholds: getBitrateThresholds(),
+    defaults: DEFAULT_BITRATE_THRESHOLDS,
+  });
+}
+
+// PUT: Change the thresholds
+// Body: { thresholds: { sd, '720p', '1080p', '4k' } }
+export async function PUT(request: NextRequest) {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  try {
+    const { thresholds } = await request.json();
+    if (!isValidBitrateThresholds(thresholds)) {
+      return NextResponse.json(
+        { success: false, error: `Thresholds must be positive numbers up to ${MAX_BITRATE_THRESHOLD} Mbps` },
+        { status: 400 }
+      );
+    }
+    setBitrateThresholds(thresholds);
+    return NextResponse.json({ success: true, thresholds: getBitrateThresholds() });
+  } catch (error) {
+    console.error('Error saving bitrate thresholds:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to save bitrate thresholds' },
+      { status: 500 }
+    );
+  }
+}
@@ -1,17 +1,39 @@
-import { NextResponse } from 'next/server';
+import { NextRequest, NextResponse } from 'next/server';
 import fs from 'fs/promises';
 import path from 'path';
-import { getCurrentRootPath } from '@/app/lib/db';
+import { getCurrentRootPath, isDatabaseInitialized } from '@/app/lib/db';
+import { clearAssetVersions } from '@/app/lib/assetCache';
+import { clearLibraryCacheCategory, getStoredLibraryCacheSize } from '@/app/lib/storage';
+import { CacheClearScope } from '@/app/lib/types';
 
-export async function POST() {
+const SCOPES: CacheClearScope[] = ['thumbnails', 'sprites', 'proxies', 'all'];
+
+// POST: Delete cached data. Body: { scope?: CacheClearScope } ('all' when omitted, which
+// removes .vcb-data including the database)
+export async function POST(request: NextRequest) {
   try {
     const rootPath = getCurrentRootPath();
     if (!rootPath) {
       return NextResponse.json({ error: 'No root path set' }, { status: 400 });
     }
 
+    const body = await request.json().catch(() => ({}));
+    const scope: CacheClearScope = body.scope ?? 'all';
+    if (!SCOPES.includes(scope)) {
+      return NextResponse.json({ error: 'Unknown cache scope' }, { status: 400 });
+    }
+
+    if (scope !== 'all') {
+      if (!isDatabaseInitialized()) {
+        return NextResponse.json({ error: 'No video library loaded' }, { status: 400 });
+      }
+      const freed = await clearLibraryCacheCategory(rootPath, scope);
+      return NextResponse.json({ success: true, freed, cacheSize: getStoredLibraryCacheSize() });
+    }
+
     const vcbDataPath = path.join(rootPath, '.vcb-data');
     await fs.rm(vcbDataPath, { recursive: true, force: true });
+    clearAssetVersions();
 
     return NextResponse.json({ success: true, message: 'Cache cleared successfully' });
   } catch (error) {
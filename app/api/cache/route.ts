@@ -0,0 +1,26 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getCurrentRootPath, isDatabaseInitialized } from '@/app/lib/db';
+import { getStoredLibraryCacheSize, refreshLibraryCacheSize } from '@/app/lib/storage';
+
+// GET: Library cache size on disk (?refresh=true recomputes)
+export async function GET(request: NextRequest) {
+  try {
+    const rootPath = getCurrentRootPath();
+    if (!rootPath || !isDatabaseInitialized()) {
+      return NextResponse.json({ success: true, cacheSize: null });
+    }
+
+    const { searchParams } = new URL(request.url);
+    const refresh = searchParams.get('refresh') === 'true';
+
+    const cacheSize = (!refresh && getStoredLibraryCacheSize()) || await refreshLibraryCacheSize(rootPath);
+
+    return NextResponse.json({ success: true, cacheSize });
+  } catch (error) {
+    console.error('Error computing cache size:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to compute cache size' },
+      { status: 500 }
+    );
+  }
+}
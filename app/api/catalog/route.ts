@@ -0,0 +1,54 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized } from '@/app/lib/db';
+import { getCatalogApiPort, isCatalogApiEnabled, isValidCatalogApiPort, setCatalogApiEnabled, setCatalogApiPort } from '@/app/lib/catalogApi';
+import { getCatalogServerStatus, syncCatalogServer } from '@/app/lib/catalogServer';
+
+// The configured port, and whether the server is listening on it
+function getCatalogApiState() {
+  const { running, error } = getCatalogServerStatus();
+  return {
+    success: true,
+    enabled: isCatalogApiEnabled(),
+    port: isDatabaseInitialized() ? getCatalogApiPort() : null,
+    running,
+    error,
+  };
+}
+
+// GET: Local catalog API setting and server status (also starts/stops it to match the open library)
+export async function GET() {
+  await syncCatalogServer();
+  return NextResponse.json(getCatalogApiState());
+}
+
+// POST: Enable or disable the local catalog API for the open library, or change its port
+export async function POST(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const body = await request.json();
+    if (body.port !== undefined && !isValidCatalogApiPort(body.port)) {
+      return NextResponse.json(
+        { success: false, error: 'Port must be a whole number from 1024 to 65535' },
+        { status: 400 }
+      );
+    }
+
+    if (body.enabled !== undefined) setCatalogApiEnabled(body.enabled === true);
+    if (body.port !== undefined) setCatalogApiPort(body.port);
+    await syncCatalogServer();
+
+    return NextResponse.json(getCatalogApiState());
+  } catch (error) {
+    console.error('Error updating catalog API setting:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to update setting' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,40 @@
+import { NextResponse } from 'next/server';
+import fs from 'fs/promises';
+import os from 'os';
+import path from 'path';
+import { backupDatabase, getCurrentRootPath, isDatabaseInitialized } from '@/app/lib/db';
+
+// GET: Download a consistent snapshot of the open library's catalog.db, safe to take while
+// scans or the watcher are writing
+export async function GET() {
+  const rootPath = getCurrentRootPath();
+  if (!isDatabaseInitialized() || !rootPath) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  const tempDir = await fs.mkdtemp(path.join(os.tmpdir(), 'vcb-backup-'));
+  try {
+    const backupPath = path.join(tempDir, 'catalog.db');
+    await backupDatabase(backupPath);
+    const data = await fs.readFile(backupPath);
+    const fileName = `${path.basename(rootPath) || 'library'}-catalog-${new Date().toISOString().slice(0, 10)}.db`;
+
+    return new NextResponse(new Uint8Array(data), {
+      headers: {
+        'Content-Type': 'application/vnd.sqlite3',
+        'Content-Disposition': `attachment; filename="${fileName.replace(/"/g, '')}"`,
+      },
+    });
+  } catch (error) {
+    console.error('Error backing up database:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to back up database' },
+      { status: 500 }
+    );
+  } finally {
+    await fs.rm(tempDir, { recursive: true, force: true }).catch(() => {});
+  }
+}
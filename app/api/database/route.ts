@@ -0,0 +1,45 @@
+import { NextResponse } from 'next/server';
+import { getCurrentRootPath, getDatabaseStats, isDatabaseInitialized, vacuumDatabase } from '@/app/lib/db';
+import { refreshLibraryCacheSize } from '@/app/lib/storage';
+
+// GET: Size of the open library's catalog.db and whether compacting would help
+export async function GET() {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json({ success: true, stats: null });
+  }
+
+  try {
+    return NextResponse.json({ success: true, stats: getDatabaseStats() });
+  } catch (error) {
+    console.error('Error reading database stats:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to read database stats' },
+      { status: 500 }
+    );
+  }
+}
+
+// POST: Compact the database (VACUUM) and return the sizes before and after
+export async function POST() {
+  const rootPath = getCurrentRootPath();
+  if (!isDatabaseInitialized() || !rootPath) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  try {
+    const { before, after } = vacuumDatabase();
+    await refreshLibraryCacheSize(rootPath).catch((error) => {
+      console.error('Failed to compute cache size:', error);
+    });
+    return NextResponse.json({ success: true, before, after, stats: getDatabaseStats() });
+  } catch (error) {
+    console.error('Error compacting database:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to compact database. Is a scan running?' },
+      { status: 500 }
+    );
+  }
+}
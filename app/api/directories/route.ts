@@ -0,0 +1,48 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getDirectoryStats, isDatabaseInitialized } from '@/app/lib/db';
+import { getCollapsedDirectories, setDirectoryCollapsed } from '@/app/lib/directories';
+
+// GET: Cached per-folder totals (from the last scan) and which folders are collapsed
+export async function GET() {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  return NextResponse.json({
+    success: true,
+    directories: getDirectoryStats(),
+    collapsed: getCollapsedDirectories(),
+  });
+}
+
+// PUT: Remember a folder as collapsed or expanded
+// Body: { directory: string (relative to the library root), collapsed: boolean }
+export async function PUT(request: NextRequest) {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  try {
+    const { directory, collapsed } = await request.json();
+    if (typeof directory !== 'string' || typeof collapsed !== 'boolean') {
+      return NextResponse.json(
+        { success: false, error: 'directory and collapsed are required' },
+        { status: 400 }
+      );
+    }
+    setDirectoryCollapsed(directory, collapsed);
+    return NextResponse.json({ success: true, collapsed: getCollapsedDirectories() });
+  } catch (error) {
+    console.error('Error saving folder state:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to save folder state' },
+      { status: 500 }
+    );
+  }
+}
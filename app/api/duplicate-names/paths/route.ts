@@ -0,0 +1,31 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized, getPathsWithFileName } from '@/app/lib/db';
+
+// GET: Paths of every video sharing a file name (?name=C0001.MP4), for the card's ≈N tooltip
+export async function GET(request: NextRequest) {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  const { searchParams } = new URL(request.url);
+  const name = searchParams.get('name');
+  if (!name) {
+    return NextResponse.json(
+      { success: false, error: 'name is required' },
+      { status: 400 }
+    );
+  }
+
+  try {
+    return NextResponse.json({ success: true, paths: getPathsWithFileName(name) });
+  } catch (error) {
+    console.error('Error fetching paths with a shared name:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to fetch paths' },
+      { status: 500 }
+    );
+  }
+}
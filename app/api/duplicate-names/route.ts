@@ -0,0 +1,31 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized } from '@/app/lib/db';
+import { isCopyWithFolderEnabled, setCopyWithFolderEnabled } from '@/app/lib/duplicateNames';
+
+// GET: Whether file names shared by several videos are copied with their parent folder
+export async function GET() {
+  return NextResponse.json({ success: true, copyWithFolder: isCopyWithFolderEnabled() });
+}
+
+// POST: Turn copying shared names with their folder on or off for the open library
+export async function POST(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const body = await request.json();
+    setCopyWithFolderEnabled(body.copyWithFolder === true);
+
+    return NextResponse.json({ success: true, copyWithFolder: isCopyWithFolderEnabled() });
+  } catch (error) {
+    console.error('Error updating duplicate name setting:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to update setting' },
+      { status: 500 }
+    );
+  }
+}
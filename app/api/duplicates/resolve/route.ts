@@ -0,0 +1,42 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized, getCurrentRootPath } from '@/app/lib/db';
+import { removeDuplicates } from '@/app/lib/duplicates';
+import { refreshLibraryCacheSize } from '@/app/lib/storage';
+
+// POST: Remove copies from the catalog, optionally moving their files to the trash
+// Body: { removeIds: string[], trash: boolean }
+export async function POST(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const body = await request.json();
+    const removeIds: unknown = body.removeIds;
+    if (!Array.isArray(removeIds) || removeIds.length === 0 || !removeIds.every((id) => typeof id === 'string')) {
+      return NextResponse.json(
+        { success: false, error: 'removeIds must be a non-empty list of video IDs' },
+        { status: 400 }
+      );
+    }
+
+    const { removed, errors } = await removeDuplicates(removeIds, body.trash === true);
+
+    // Proxies were deleted, so the cache size changed
+    const rootPath = getCurrentRootPath();
+    if (rootPath) {
+      await refreshLibraryCacheSize(rootPath).catch((error) => console.error('Failed to compute cache size:', error));
+    }
+
+    return NextResponse.json({ success: true, removed, errors });
+  } catch (error) {
+    console.error('Error removing duplicates:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to remove duplicates' },
+      { status: 500 }
+    );
+  }
+}
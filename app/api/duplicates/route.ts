@@ -0,0 +1,24 @@
+import { NextResponse } from 'next/server';
+import { isDatabaseInitialized } from '@/app/lib/db';
+import { findDuplicates } from '@/app/lib/duplicates';
+
+// GET: Duplicate groups in the open library, largest reclaimable space first
+export async function GET() {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const report = await findDuplicates();
+    return NextResponse.json({ success: true, ...report });
+  } catch (error) {
+    console.error('Error finding duplicates:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to find duplicates' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,61 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized } from '@/app/lib/db';
+import { addExclusion, getExclusions, removeExclusion, setExclusions } from '@/app/lib/exclusions';
+
+// GET: Excluded folder patterns for the open library
+export async function GET() {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  return NextResponse.json({ success: true, patterns: getExclusions() });
+}
+
+// Shared by the write methods; invalid patterns are rejected with the reason
+async function updateExclusions(request: NextRequest, update: (body: Record<string, unknown>) => string[]) {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  let body: Record<string, unknown>;
+  try {
+    body = await request.json();
+  } catch {
+    return NextResponse.json(
+      { success: false, error: 'Invalid request body' },
+      { status: 400 }
+    );
+  }
+
+  try {
+    return NextResponse.json({ success: true, patterns: update(body) });
+  } catch (error) {
+    return NextResponse.json(
+      { success: false, error: error instanceof Error ? error.message : 'Failed to save exclusions' },
+      { status: 400 }
+    );
+  }
+}
+
+// PUT: Replace the list ({ patterns }), as edited one per line in the settings panel
+export async function PUT(request: NextRequest) {
+  return updateExclusions(request, (body) => setExclusions(
+    Array.isArray(body.patterns) ? body.patterns.filter((p): p is string => typeof p === 'string') : []
+  ));
+}
+
+// POST: Add one pattern ({ pattern })
+export async function POST(request: NextRequest) {
+  return updateExclusions(request, (body) => addExclusion(typeof body.pattern === 'string' ? body.pattern : ''));
+}
+
+// DELETE: Remove one pattern ({ pattern })
+export async function DELETE(request: NextRequest) {
+  return updateExclusions(request, (body) => removeExclusion(typeof body.pattern === 'string' ? body.pattern : ''));
+}
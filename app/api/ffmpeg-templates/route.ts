@@ -0,0 +1,60 @@
+import { NextRequest, NextResponse } from 'next/server';
+import {
+  getCommandTemplates,
+  getShellKind,
+  resetCommandTemplates,
+  setCommandTemplates,
+  validateCommandTemplates,
+} from '@/app/lib/commandTemplates';
+
+// GET: ffmpeg command templates and the shell to quote for
+export async function GET() {
+  return NextResponse.json({ success: true, templates: getCommandTemplates(), shell: getShellKind() });
+}
+
+// PUT: Replace the templates
+// Body: { templates: [{ label, template }] }
+export async function PUT(request: NextRequest) {
+  let body;
+  try {
+    body = await request.json();
+  } catch {
+    return NextResponse.json(
+      { success: false, error: 'Invalid request body' },
+      { status: 400 }
+    );
+  }
+
+  const validation = validateCommandTemplates(body?.templates);
+  if ('error' in validation) {
+    return NextResponse.json(
+      { success: false, error: validation.error },
+      { status: 400 }
+    );
+  }
+
+  try {
+    setCommandTemplates(validation.templates);
+    return NextResponse.json({ success: true, templates: validation.templates });
+  } catch (error) {
+    console.error('Error saving ffmpeg templates:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to save templates' },
+      { status: 500 }
+    );
+  }
+}
+
+// DELETE: Restore the built-in templates
+export async function DELETE() {
+  try {
+    resetCommandTemplates();
+    return NextResponse.json({ success: true, templates: getCommandTemplates() });
+  } catch (error) {
+    console.error('Error resetting ffmpeg templates:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to reset templates' },
+      { status: 500 }
+    );
+  }
+}
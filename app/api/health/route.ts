@@ -0,0 +1,20 @@
+import { NextResponse } from 'next/server';
+import { isDatabaseInitialized } from '@/app/lib/db';
+import { computeLibraryHealth } from '@/app/lib/health';
+
+// GET: Health score of the open library with its components
+export async function GET() {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json({ success: true, health: null });
+  }
+
+  try {
+    return NextResponse.json({ success: true, health: await computeLibraryHealth() });
+  } catch (error) {
+    console.error('Error computing library health:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to compute library health' },
+      { status: 500 }
+    );
+  }
+}
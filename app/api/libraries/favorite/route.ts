@@ -0,0 +1,47 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getSelectionByVideoId, upsertSelection } from '@/app/lib/db';
+import { isKnownLibrary, withLibraryDatabase } from '@/app/lib/globalSearch';
+
+// POST: Set a video's favorite flag in the library it belongs to (not necessarily the open one)
+// Body: { library, videoId, isFavorite }
+export async function POST(request: NextRequest) {
+  try {
+    const body = await request.json();
+    const { library, videoId, isFavorite } = body;
+
+    if (!library || !videoId) {
+      return NextResponse.json(
+        { success: false, error: 'library and videoId are required' },
+        { status: 400 }
+      );
+    }
+
+    if (!isKnownLibrary(library)) {
+      return NextResponse.json(
+        { success: false, error: 'Unknown library' },
+        { status: 404 }
+      );
+    }
+
+    // Keep existing notes; only the favorite flag changes
+    const selection = withLibraryDatabase(library, (db) => {
+      const existing = getSelectionByVideoId(videoId, db);
+      return upsertSelection(videoId, !!isFavorite, existing?.notes ?? '', db);
+    }, true);
+
+    if (!selection) {
+      return NextResponse.json(
+        { success: false, error: 'Library is offline' },
+        { status: 404 }
+      );
+    }
+
+    return NextResponse.json({ success: true, selection });
+  } catch (error) {
+    console.error('Error updating favorite:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to update favorite' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,36 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { validatePath } from '@/app/lib/scanner';
+import { relinkLibrary } from '@/app/lib/relink';
+
+// POST: Relink a moved library to its new root
+// Body: { oldPath, newPath }
+export async function POST(request: NextRequest) {
+  try {
+    const body = await request.json();
+    const { oldPath, newPath } = body;
+
+    if (!oldPath || !newPath) {
+      return NextResponse.json(
+        { success: false, error: 'oldPath and newPath are required' },
+        { status: 400 }
+      );
+    }
+
+    const validation = await validatePath(newPath);
+    if (!validation.valid) {
+      return NextResponse.json(
+        { success: false, error: validation.error },
+        { status: 400 }
+      );
+    }
+
+    const result = await relinkLibrary(oldPath, newPath);
+    return NextResponse.json({ success: true, path: newPath, ...result });
+  } catch (error) {
+    console.error('Error relinking library:', error);
+    return NextResponse.json(
+      { success: false, error: error instanceof Error ? error.message : 'Failed to relink library' },
+      { status: 500 }
+    );
+  }
+}
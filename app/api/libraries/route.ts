@@ -0,0 +1,16 @@
+import { NextResponse } from 'next/server';
+import fs from 'fs/promises';
+import { loadLibraryHistory } from '@/app/lib/libraryHistory';
+
+// GET: Recently opened libraries, flagging ones whose folder is gone (moved or unmounted).
+// The folders are checked in parallel and asynchronously, since an offline network
+// drive can take seconds to answer.
+export async function GET() {
+  const history = await loadLibraryHistory();
+  const libraries = await Promise.all(history.map(async (entry) => ({
+    ...entry,
+    exists: await fs.access(entry.path).then(() => true, () => false),
+  })));
+
+  return NextResponse.json({ success: true, libraries });
+}
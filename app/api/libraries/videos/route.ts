@@ -0,0 +1,33 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getLibraryVideos, isKnownLibrary } from '@/app/lib/globalSearch';
+
+// GET: Every video in one known library, for the all-libraries view.
+// The client fetches libraries in parallel so a slow volume doesn't hold up the rest.
+// Params: library
+export async function GET(request: NextRequest) {
+  try {
+    const { searchParams } = new URL(request.url);
+    const library = searchParams.get('library') || '';
+
+    // Only read libraries this machine has opened
+    if (!isKnownLibrary(library)) {
+      return NextResponse.json(
+        { success: false, error: 'Unknown library' },
+        { status: 404 }
+      );
+    }
+
+    const videos = getLibraryVideos(library);
+    if (!videos) {
+      return NextResponse.json({ success: true, online: false, videos: [] });
+    }
+
+    return NextResponse.json({ success: true, online: true, videos });
+  } catch (error) {
+    console.error('Error loading library videos:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to load library' },
+      { status: 500 }
+    );
+  }
+}
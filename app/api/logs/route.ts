@@ -0,0 +1,25 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getLogPath, readRecentLogs } from '@/app/lib/logger';
+
+const DEFAULT_LIMIT = 500;
+
+// GET: Tail of today's log file and its path (for attaching to bug reports)
+// Params: limit
+export async function GET(request: NextRequest) {
+  try {
+    const { searchParams } = new URL(request.url);
+    const limit = parseInt(searchParams.get('limit') || '', 10);
+
+    return NextResponse.json({
+      success: true,
+      path: getLogPath(),
+      entries: readRecentLogs(Number.isFinite(limit) && limit > 0 ? limit : DEFAULT_LIMIT),
+    });
+  } catch (error) {
+    console.error('Error reading logs:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to read logs' },
+      { status: 500 }
+    );
+  }
+}
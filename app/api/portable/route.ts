@@ -0,0 +1,33 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized, isPortableLibrary, setPortableLibrary } from '@/app/lib/db';
+
+// GET: Whether the open library stores paths relative to its root
+export async function GET() {
+  return NextResponse.json({
+    success: true,
+    enabled: isDatabaseInitialized() && isPortableLibrary(),
+  });
+}
+
+// POST: Switch the open library to relative (portable) or absolute paths, converting it in place
+export async function POST(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const body = await request.json();
+    const converted = setPortableLibrary(body.enabled === true);
+
+    return NextResponse.json({ success: true, enabled: isPortableLibrary(), converted });
+  } catch (error) {
+    console.error('Error converting library paths:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to convert library paths' },
+      { status: 500 }
+    );
+  }
+}
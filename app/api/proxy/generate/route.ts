@@ -1,6 +1,7 @@
 import { NextRequest, NextResponse } from 'next/server';
-import { getVideoById, addToProxyQueue, getNextQueuedJob, updateProxyJobStatus, updateVideoProxy, getAllVideos, isDatabaseInitialized, getCurrentRootPath } from '@/app/lib/db';
+import { getVideoById, addToProxyQueue, getNextQueuedJob, updateProxyJobStatus, updateVideoProxy, getReadableVideos, isDatabaseInitialized, getCurrentRootPath } from '@/app/lib/db';
 import { generateAllProxyAssets } from '@/app/lib/ffmpeg';
+import { refreshLibraryCacheSize } from '@/app/lib/storage';
 
 // Track if proxy generation is running
 let isGenerating = false;
@@ -61,22 +62,28 @@ async function processQueue() {
               (proxyProgress * 0.8);
 
             updateProxyJobStatus(job!.id, 'processing', Math.round(overallProgress));
-          }
+          },
+          video.posterTime
         );
 
         // Update video with proxy paths
-        updateVideoProxy(video.id, result.proxyPath, result.spritePath, result.thumbnailPath);
+        updateVideoProxy(video.id, result.proxyPath, result.spritePath, result.thumbnailPath, result.spriteConfig);
 
         // Mark job as complete
         updateProxyJobStatus(job.id, 'complete', 100);
       } catch (error) {
-        console.error(`Error generating proxy for ${video.fileName}:`, error);
+        console.warn(`Error generating proxy for ${video.filePath}:`, error);
         updateProxyJobStatus(job.id, 'error', 0, String(error));
       }
 
       // Get next job
       job = getNextQueuedJob();
     }
+
+    // Proxies dominate cache size, so refresh it once the queue drains
+    await refreshLibraryCacheSize(rootPath).catch((error) => {
+      console.error('Failed to compute cache size:', error);
+    });
   } finally {
     isGenerating = false;
   }
@@ -97,13 +104,15 @@ export async function POST(request: NextRequest) {
     const { videoIds, all } = body;
 
     let idsToQueue: string[] = [];
+    let skippedOnlineOnly = 0;
 
     if (all) {
-      // Queue all videos without proxies
-      const allVideos = getAllVideos();
-      idsToQueue = allVideos
+      // Queue all videos without proxies, except online-only ones the transcode would download
+      const { videos, onlineOnly } = getReadableVideos();
+      idsToQueue = videos
         .filter((v) => !v.hasProxy)
         .map((v) => v.id);
+      skippedOnlineOnly = onlineOnly;
     } else if (videoIds && Array.isArray(videoIds)) {
       idsToQueue = videoIds;
     } else {
@@ -129,6 +138,7 @@ export async function POST(request: NextRequest) {
     return NextResponse.json({
       success: true,
       queued,
+      skippedOnlineOnly,
       message: `Added ${queued} videos to proxy generation queue`,
     });
   } catch (error) {
@@ -0,0 +1,32 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized } from '@/app/lib/db';
+import { restoreVideos } from '@/app/lib/removed';
+
+// POST: Put videos from Recently Removed back into the catalog, selections intact
+// Body: { videoIds: string[] }
+export async function POST(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { videoIds } = await request.json();
+    if (!Array.isArray(videoIds) || videoIds.length === 0 || !videoIds.every((id) => typeof id === 'string')) {
+      return NextResponse.json(
+        { success: false, error: 'videoIds must be a non-empty list of video IDs' },
+        { status: 400 }
+      );
+    }
+
+    return NextResponse.json({ success: true, restored: restoreVideos(videoIds) });
+  } catch (error) {
+    console.error('Error restoring videos:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to restore videos' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,84 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getRemovedVideos, getSelectionByVideoId, isDatabaseInitialized, REMOVED_RETENTION_DAYS } from '@/app/lib/db';
+import { purgeVideos, removeVideos } from '@/app/lib/removed';
+import { getAssetVersion } from '@/app/lib/assetCache';
+import { VideoWithSelection } from '@/app/lib/types';
+
+function isVideoIdList(value: unknown): value is string[] {
+  return Array.isArray(value) && value.length > 0 && value.every((id) => typeof id === 'string');
+}
+
+// GET: Videos in Recently Removed, most recently removed first, with their selections
+export async function GET() {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  const videos: VideoWithSelection[] = getRemovedVideos().map((video) => ({
+    ...video,
+    selection: getSelectionByVideoId(video.id) || undefined,
+    thumbnailVersion: getAssetVersion(video.thumbnailPath),
+  }));
+  return NextResponse.json({ success: true, videos, retentionDays: REMOVED_RETENTION_DAYS });
+}
+
+// POST: Remove videos from the catalog into Recently Removed
+// Body: { videoIds: string[] }
+export async function POST(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { videoIds } = await request.json();
+    if (!isVideoIdList(videoIds)) {
+      return NextResponse.json(
+        { success: false, error: 'videoIds must be a non-empty list of video IDs' },
+        { status: 400 }
+      );
+    }
+
+    return NextResponse.json({ success: true, removed: removeVideos(videoIds) });
+  } catch (error) {
+    console.error('Error removing videos:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to remove videos' },
+      { status: 500 }
+    );
+  }
+}
+
+// DELETE: Purge videos in Recently Removed for good, with their selections and generated files
+// Body: { videoIds: string[] }
+export async function DELETE(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { videoIds } = await request.json();
+    if (!isVideoIdList(videoIds)) {
+      return NextResponse.json(
+        { success: false, error: 'videoIds must be a non-empty list of video IDs' },
+        { status: 400 }
+      );
+    }
+
+    return NextResponse.json({ success: true, purged: await purgeVideos(videoIds) });
+  } catch (error) {
+    console.error('Error purging removed videos:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to delete videos' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,28 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getScanHistory, isDatabaseInitialized } from '@/app/lib/db';
+
+// Scans listed in the history panel by default
+const DEFAULT_HISTORY_LIMIT = 20;
+
+// GET: Recent scans of the open library with per-folder counts and errors
+export async function GET(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { searchParams } = new URL(request.url);
+    const limit = parseInt(searchParams.get('limit') || String(DEFAULT_HISTORY_LIMIT), 10) || DEFAULT_HISTORY_LIMIT;
+
+    return NextResponse.json({ success: true, scans: getScanHistory(limit) });
+  } catch (error) {
+    console.error('Error fetching scan history:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to fetch scan history' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,38 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized } from '@/app/lib/db';
+import { getMeasureLoudness, setMeasureLoudness } from '@/app/lib/scanner';
+
+// GET: Whether the open library's scans measure loudness
+export async function GET() {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  return NextResponse.json({ success: true, enabled: getMeasureLoudness() });
+}
+
+// PUT: Turn loudness measurement on or off. Applies from the next scan.
+// Body: { enabled: boolean }
+export async function PUT(request: NextRequest) {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  try {
+    const { enabled } = await request.json();
+    setMeasureLoudness(enabled === true);
+    return NextResponse.json({ success: true, enabled: getMeasureLoudness() });
+  } catch (error) {
+    console.error('Error saving loudness setting:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to save loudness setting' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,45 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getCurrentRootPath, isDatabaseInitialized } from '@/app/lib/db';
+import { getMinFileSize, setMinFileSize } from '@/app/lib/scanner';
+
+// GET: Smallest video file (bytes) the open library's scans index
+export async function GET() {
+  const rootPath = getCurrentRootPath();
+  if (!isDatabaseInitialized() || !rootPath) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  return NextResponse.json({ success: true, bytes: getMinFileSize(rootPath) });
+}
+
+// PUT: Change the minimum size; 0 indexes every file. Applies from the next scan.
+// Body: { bytes: number }
+export async function PUT(request: NextRequest) {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  try {
+    const bytes = Number((await request.json()).bytes);
+    if (!Number.isFinite(bytes) || bytes < 0) {
+      return NextResponse.json(
+        { success: false, error: 'Minimum size must be zero or a positive number of bytes' },
+        { status: 400 }
+      );
+    }
+    setMinFileSize(bytes);
+    return NextResponse.json({ success: true, bytes: Math.round(bytes) });
+  } catch (error) {
+    console.error('Error saving minimum file size:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to save minimum file size' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,44 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized } from '@/app/lib/db';
+import { getCloudPlaceholderMode, setCloudPlaceholderMode } from '@/app/lib/cloudPlaceholders';
+
+// GET: What the open library's scans do with online-only cloud files
+export async function GET() {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  return NextResponse.json({ success: true, mode: getCloudPlaceholderMode() });
+}
+
+// PUT: Skip online-only files or catalog them from the name. Applies from the next scan.
+// Body: { mode: 'skip' | 'catalog' }
+export async function PUT(request: NextRequest) {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  try {
+    const { mode } = await request.json();
+    if (mode !== 'skip' && mode !== 'catalog') {
+      return NextResponse.json(
+        { success: false, error: 'Mode must be skip or catalog' },
+        { status: 400 }
+      );
+    }
+    setCloudPlaceholderMode(mode);
+    return NextResponse.json({ success: true, mode: getCloudPlaceholderMode() });
+  } catch (error) {
+    console.error('Error saving online-only file setting:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to save online-only file setting' },
+      { status: 500 }
+    );
+  }
+}
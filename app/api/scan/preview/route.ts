@@ -0,0 +1,44 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { previewScan, validatePath } from '@/app/lib/scanner';
+import { startTask } from '@/app/lib/tasks';
+import path from 'path';
+
+// POST: Dry-run scan of a folder (counts only, nothing is indexed)
+// Body: { path }
+export async function POST(request: NextRequest) {
+  try {
+    const { path: dirPath } = await request.json();
+
+    if (!dirPath) {
+      return NextResponse.json(
+        { success: false, error: 'Path is required' },
+        { status: 400 }
+      );
+    }
+
+    const validation = await validatePath(dirPath);
+    if (!validation.valid) {
+      return NextResponse.json(
+        { success: false, error: validation.error },
+        { status: 400 }
+      );
+    }
+
+    // Shows (and can be cancelled) in the task popover while the request is open
+    const task = startTask('scan', `Previewing ${path.basename(dirPath) || dirPath}`);
+    try {
+      const preview = await previewScan(dirPath, task.setProgress, task.isCancelled);
+      task.finish('complete');
+      return NextResponse.json({ success: true, preview });
+    } catch (error) {
+      task.finish('error', error instanceof Error ? error.message : String(error));
+      throw error;
+    }
+  } catch (error) {
+    console.error('Scan preview error:', error);
+    return NextResponse.json(
+      { success: false, error: error instanceof Error ? error.message : 'Failed to preview scan' },
+      { status: 500 }
+    );
+  }
+}
@@ -1,6 +1,8 @@
 import { NextRequest, NextResponse } from 'next/server';
 import { scanAndProcessDirectory, validatePath, ScanProgressCallback } from '@/app/lib/scanner';
-import { getScanStatus, initDatabase, isDatabaseInitialized, getCurrentRootPath } from '@/app/lib/db';
+import { getScanStatus, getInterruptedScan, closeInterruptedScans, initDatabase, isDatabaseInitialized, getCurrentRootPath } from '@/app/lib/db';
+import { startTask } from '@/app/lib/tasks';
+import path from 'path';
 
 // Rolling status messages for UI
 const ROLLING_MESSAGES = [
@@ -19,11 +21,17 @@ let activeScan: {
   totalVideos: number;
   videosProcessed: number;
   videosSkipped: number;
+  videosTooSmall: number; // Under the library's minimum file size, not indexed
+  totalBytes: number;
+  bytesProcessed: number;
+  videosOnlineOnly: number; // Online-only cloud files, not downloaded
   currentFile: string;
   message: string;
   messageIndex: number;
   lastMessageChange: number;
   rootPath: string;
+  folder: string | null; // Set when only a folder inside the library is scanned
+  errors: string[];
 } | null = null;
 
 // Rotate message every 3 seconds
@@ -39,11 +47,11 @@ function getRotatingMessage(): string {
   return ROLLING_MESSAGES[activeScan.messageIndex];
 }
 
-// POST: Start a new directory scan
+// POST: Start a new directory scan, or with folder, rescan just that folder of the library
 export async function POST(request: NextRequest) {
   try {
     const body = await request.json();
-    const { path: dirPath } = body;
+    const { path: dirPath, folder } = body;
 
     if (!dirPath) {
       return NextResponse.json(
@@ -61,6 +69,25 @@ export async function POST(request: NextRequest) {
       );
     }
 
+    // A folder may be given relative to the library
+    const scanPath = folder ? path.resolve(dirPath, folder) : dirPath;
+    if (folder) {
+      const relative = path.relative(dirPath, scanPath);
+      if (relative === '..' || relative.startsWith(`..${path.sep}`) || path.isAbsolute(relative)) {
+        return NextResponse.json(
+          { success: false, error: 'Folder must be inside the library' },
+          { status: 400 }
+        );
+      }
+      const folderValidation = await validatePath(scanPath);
+      if (!folderValidation.valid) {
+        return NextResponse.json(
+          { success: false, error: folderValidation.error },
+          { status: 400 }
+        );
+      }
+    }
+
     // Check if a scan is already in progress
     if (activeScan && (activeScan.status === 'scanning' || activeScan.status === 'counting')) {
       return NextResponse.json(
@@ -80,29 +107,48 @@ export async function POST(request: NextRequest) {
       totalVideos: 0,
       videosProcessed: 0,
       videosSkipped: 0,
+      videosTooSmall: 0,
+      totalBytes: 0,
+      bytesProcessed: 0,
+      videosOnlineOnly: 0,
       currentFile: '',
       message: ROLLING_MESSAGES[0],
       messageIndex: 0,
       lastMessageChange: Date.now(),
       rootPath: dirPath,
+      folder: scanPath !== dirPath ? scanPath : null,
+      errors: [],
     };
 
+    // Register with the task manager so the scan shows (and can be cancelled) in the task popover
+    const task = startTask('scan', `Scanning ${path.basename(scanPath) || scanPath}`);
+
     // Progress callback
     const onProgress: ScanProgressCallback = (data) => {
+      task.setProgress(
+        data.phase === 'counting' ? 0 : data.processed + data.skipped,
+        data.phase === 'counting' ? 0 : data.totalVideos,
+        data.currentFile
+      );
       if (activeScan) {
         activeScan.status = data.phase === 'counting' ? 'counting' : 'scanning';
         activeScan.phase = data.phase === 'counting' ? 'count' : 'metadata';
         activeScan.totalVideos = data.totalVideos;
         activeScan.videosProcessed = data.processed;
         activeScan.videosSkipped = data.skipped;
+        activeScan.videosTooSmall = data.tooSmall;
+        activeScan.totalBytes = data.totalBytes;
+        activeScan.bytesProcessed = data.bytesProcessed;
+        activeScan.videosOnlineOnly = data.placeholders;
         activeScan.currentFile = data.currentFile;
         activeScan.message = getRotatingMessage();
       }
     };
 
     // Run scan asynchronously
-    scanAndProcessDirectory(dirPath, onProgress)
-      .then(({ scanId, videosFound, videosProcessed, videosSkipped }) => {
+    scanAndProcessDirectory(dirPath, onProgress, task.isCancelled, scanPath)
+      .then(({ scanId, videosFound, videosProcessed, videosSkipped, videosTooSmall, videosOnlineOnly, errors }) => {
+        task.finish('complete');
         if (activeScan) {
           activeScan.id = scanId;
           activeScan.status = 'complete';
@@ -110,12 +156,17 @@ export async function POST(request: NextRequest) {
           activeScan.totalVideos = videosFound;
           activeScan.videosProcessed = videosProcessed;
           activeScan.videosSkipped = videosSkipped;
+          activeScan.videosTooSmall = videosTooSmall;
+          activeScan.videosOnlineOnly = videosOnlineOnly;
+          activeScan.errors = errors;
+          const where = activeScan.folder ? ` in ${path.basename(activeScan.folder)}` : '';
           activeScan.message = videosSkipped > 0
-            ? `Scan complete! ${videosSkipped} videos were already indexed.`
-            : `Scan complete! Found ${videosFound} videos.`;
+            ? `Scan complete! ${videosSkipped} videos${where} were already indexed.`
+            : `Scan complete! Found ${videosFound} videos${where}.`;
         }
       })
       .catch((error) => {
+        task.finish('error', error.message);
         if (activeScan) {
           activeScan.status = 'error';
           activeScan.message = `Error: ${error.message}`;
@@ -127,6 +178,7 @@ export async function POST(request: NextRequest) {
       success: true,
       message: 'Scan started',
       path: dirPath,
+      folder: activeScan.folder,
     });
   } catch (error) {
     console.error('Scan error:', error);
@@ -166,17 +218,40 @@ export async function GET(request: NextRequest) {
       totalVideos: activeScan.totalVideos,
       videosProcessed: activeScan.videosProcessed,
       videosSkipped: activeScan.videosSkipped,
+      videosTooSmall: activeScan.videosTooSmall,
+      totalBytes: activeScan.totalBytes,
+      bytesProcessed: activeScan.bytesProcessed,
+      videosOnlineOnly: activeScan.videosOnlineOnly,
       currentFile: activeScan.currentFile,
       message: activeScan.message,
       rootPath: activeScan.rootPath,
+      folder: activeScan.folder,
+      errors: activeScan.errors,
     });
   }
 
-  // Return idle status with last used directory info
+  // Return idle status with last used directory info, and any scan that died mid-way
   const lastRootPath = getCurrentRootPath();
+  const interrupted = isDatabaseInitialized() ? getInterruptedScan() : null;
   return NextResponse.json({
     success: true,
     status: 'idle',
     lastDirectory: lastRootPath,
+    interruptedScan: interrupted
+      ? { startedAt: interrupted.startedAt, videosFound: interrupted.videosFound, folder: interrupted.scanPath }
+      : null,
   });
 }
+
+// DELETE: Dismiss an interrupted scan without resuming it
+export async function DELETE(request: NextRequest) {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  closeInterruptedScans();
+  return NextResponse.json({ success: true });
+}
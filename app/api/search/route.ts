@@ -0,0 +1,23 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { searchAllLibraries } from '@/app/lib/globalSearch';
+
+const DEFAULT_LIMIT = 100;
+
+// GET: Search file names across every library opened on this machine
+// Params: q, limit
+export async function GET(request: NextRequest) {
+  try {
+    const { searchParams } = new URL(request.url);
+    const query = searchParams.get('q') || '';
+    const limit = parseInt(searchParams.get('limit') || '', 10);
+
+    const results = searchAllLibraries(query, Number.isFinite(limit) && limit > 0 ? limit : DEFAULT_LIMIT);
+    return NextResponse.json({ success: true, results });
+  } catch (error) {
+    console.error('Global search error:', error);
+    return NextResponse.json(
+      { success: false, error: 'Search failed' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,45 @@
+import { NextRequest, NextResponse } from 'next/server';
+import fs from 'fs';
+import { isKnownLibrary, withLibraryDatabase } from '@/app/lib/globalSearch';
+import { resolveVideoPaths } from '@/app/lib/db';
+import { VideoRow, rowToVideo } from '@/app/lib/types';
+
+// GET: Thumbnail for a global search result (the library may not be the open one)
+// Params: library, id
+export async function GET(request: NextRequest) {
+  try {
+    const { searchParams } = new URL(request.url);
+    const library = searchParams.get('library') || '';
+    const id = searchParams.get('id') || '';
+
+    // Only serve from libraries this machine has opened
+    if (!isKnownLibrary(library)) {
+      return NextResponse.json(
+        { success: false, error: 'Unknown library' },
+        { status: 404 }
+      );
+    }
+
+    const video = withLibraryDatabase(library, (db) => {
+      const row = db.prepare('SELECT * FROM videos WHERE id = ?').get(id) as VideoRow | undefined;
+      return row ? resolveVideoPaths(rowToVideo(row), library) : null;
+    });
+
+    if (!video?.thumbnailPath || !fs.existsSync(video.thumbnailPath)) {
+      return NextResponse.redirect(new URL('/placeholder-video.svg', request.url));
+    }
+
+    return new NextResponse(fs.readFileSync(video.thumbnailPath), {
+      headers: {
+        'Content-Type': 'image/jpeg',
+        'Cache-Control': 'public, max-age=3600',
+      },
+    });
+  } catch (error) {
+    console.error('Error serving search thumbnail:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to serve thumbnail' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,142 @@
+import { NextRequest, NextResponse } from 'next/server';
+import {
+  isDatabaseInitialized,
+  getSelectionSets,
+  renameSelectionSet,
+  deleteSelectionSet,
+  resolveSelectionSet,
+  addToSelectionSet,
+  getVideoById,
+} from '@/app/lib/db';
+
+// GET: A selection set's videos that still exist, and how many have been removed
+export async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  const { id } = await params;
+  const resolved = resolveSelectionSet(id);
+  if (!resolved) {
+    return NextResponse.json(
+      { success: false, error: 'Selection set not found' },
+      { status: 404 }
+    );
+  }
+
+  return NextResponse.json({ success: true, ...resolved });
+}
+
+// POST: Add videos to a selection set (dropped from the grid)
+// Body: { videoIds: string[] }
+export async function POST(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { id } = await params;
+    const { videoIds } = await request.json();
+    if (!Array.isArray(videoIds)) {
+      return NextResponse.json(
+        { success: false, error: 'videoIds is required' },
+        { status: 400 }
+      );
+    }
+
+    // Unknown IDs are skipped rather than failing the whole request
+    const added = addToSelectionSet(
+      id,
+      videoIds.filter((videoId) => typeof videoId === 'string' && getVideoById(videoId))
+    );
+    if (added === null) {
+      return NextResponse.json(
+        { success: false, error: 'Selection set not found' },
+        { status: 404 }
+      );
+    }
+
+    return NextResponse.json({ success: true, added, sets: getSelectionSets() });
+  } catch (error) {
+    console.error('Error adding to selection set:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to add to selection set' },
+      { status: 500 }
+    );
+  }
+}
+
+// PATCH: Rename a selection set
+export async function PATCH(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { id } = await params;
+    const body = await request.json();
+    const name = typeof body.name === 'string' ? body.name.trim() : '';
+    if (!name) {
+      return NextResponse.json(
+        { success: false, error: 'Name is required' },
+        { status: 400 }
+      );
+    }
+
+    if (!renameSelectionSet(id, name)) {
+      return NextResponse.json(
+        { success: false, error: 'Selection set not found' },
+        { status: 404 }
+      );
+    }
+
+    return NextResponse.json({ success: true, sets: getSelectionSets() });
+  } catch (error) {
+    console.error('Error renaming selection set:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to rename selection set' },
+      { status: 500 }
+    );
+  }
+}
+
+// DELETE: Remove a selection set (the videos themselves are untouched)
+export async function DELETE(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  const { id } = await params;
+  if (!deleteSelectionSet(id)) {
+    return NextResponse.json(
+      { success: false, error: 'Selection set not found' },
+      { status: 404 }
+    );
+  }
+
+  return NextResponse.json({ success: true, sets: getSelectionSets() });
+}
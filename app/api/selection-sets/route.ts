@@ -0,0 +1,54 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized, getSelectionSets, createSelectionSet } from '@/app/lib/db';
+
+// GET: Saved selection sets for the open library
+export async function GET() {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  return NextResponse.json({ success: true, sets: getSelectionSets() });
+}
+
+// POST: Save the given video ids as a named selection set
+// Body: { name: string, videoIds: string[] }
+export async function POST(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const body = await request.json();
+    const name = typeof body.name === 'string' ? body.name.trim() : '';
+    if (!name) {
+      return NextResponse.json(
+        { success: false, error: 'Name is required' },
+        { status: 400 }
+      );
+    }
+    const videoIds = Array.isArray(body.videoIds)
+      ? body.videoIds.filter((id: unknown): id is string => typeof id === 'string')
+      : [];
+    if (videoIds.length === 0) {
+      return NextResponse.json(
+        { success: false, error: 'Nothing is selected' },
+        { status: 400 }
+      );
+    }
+
+    const set = createSelectionSet(name, videoIds);
+    return NextResponse.json({ success: true, set, sets: getSelectionSets() });
+  } catch (error) {
+    console.error('Error saving selection set:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to save selection set' },
+      { status: 500 }
+    );
+  }
+}
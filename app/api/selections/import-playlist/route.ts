@@ -0,0 +1,76 @@
+import { NextRequest, NextResponse } from 'next/server';
+import fs from 'fs';
+import path from 'path';
+import { fileURLToPath } from 'url';
+import { isDatabaseInitialized, getVideoByPath, getSelectionByVideoId, upsertSelection } from '@/app/lib/db';
+import { parseM3u } from '@/app/lib/playlist';
+
+// Resolve a playlist entry (absolute, relative, or file:// URI) to a filesystem path
+function resolveEntry(entry: string, playlistDir: string): string | null {
+  if (entry.startsWith('file://')) {
+    try {
+      return fileURLToPath(entry);
+    } catch {
+      return null;
+    }
+  }
+  // Network URLs can't match library files
+  if (/^[a-z][a-z0-9+.-]*:\/\//i.test(entry)) return null;
+  return path.resolve(playlistDir, entry);
+}
+
+// POST: Mark every video listed in an M3U/M3U8 playlist as a favorite
+export async function POST(request: NextRequest) {
+  try {
+    // Check if database is initialized
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const body = await request.json();
+    const { path: playlistPath } = body;
+
+    if (!playlistPath || !fs.existsSync(playlistPath)) {
+      return NextResponse.json(
+        { success: false, error: 'Playlist file not found' },
+        { status: 400 }
+      );
+    }
+
+    const entries = parseM3u(fs.readFileSync(playlistPath, 'utf-8'));
+    const playlistDir = path.dirname(playlistPath);
+
+    let favorited = 0;
+    let alreadyFavorite = 0;
+    let notFound = 0;
+
+    for (const entry of entries) {
+      const filePath = resolveEntry(entry, playlistDir);
+      const video = filePath ? getVideoByPath(filePath) : null;
+      if (!video) {
+        notFound++;
+        continue;
+      }
+
+      const selection = getSelectionByVideoId(video.id);
+      if (selection?.isFavorite) {
+        alreadyFavorite++;
+        continue;
+      }
+
+      upsertSelection(video.id, true, selection?.notes ?? '');
+      favorited++;
+    }
+
+    return NextResponse.json({ success: true, favorited, alreadyFavorite, notFound });
+  } catch (error) {
+    console.error('Error importing playlist:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to import playlist' },
+      { status: 500 }
+    );
+  }
+}
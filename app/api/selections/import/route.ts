@@ -0,0 +1,45 @@
+import { NextRequest, NextResponse } from 'next/server';
+import fs from 'fs';
+import { isDatabaseInitialized, getDatabasePath, getCurrentRootPath } from '@/app/lib/db';
+import { importSelectionsFromCatalog } from '@/app/lib/catalogImport';
+
+// POST: Import favorites and notes from another catalog.db
+export async function POST(request: NextRequest) {
+  try {
+    // Check if database is initialized
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const body = await request.json();
+    const { path: catalogPath } = body;
+
+    if (!catalogPath || !fs.existsSync(catalogPath)) {
+      return NextResponse.json(
+        { success: false, error: 'Catalog file not found' },
+        { status: 400 }
+      );
+    }
+
+    const rootPath = getCurrentRootPath();
+    if (rootPath && getDatabasePath(rootPath) === catalogPath) {
+      return NextResponse.json(
+        { success: false, error: 'Cannot import a catalog into itself' },
+        { status: 400 }
+      );
+    }
+
+    const result = importSelectionsFromCatalog(catalogPath);
+
+    return NextResponse.json({ success: true, ...result });
+  } catch (error) {
+    console.error('Error importing catalog:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to import catalog. Is it a valid catalog.db?' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,43 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { setVideosColorLabel, getVideoById, isDatabaseInitialized } from '@/app/lib/db';
+import { COLOR_LABELS, ColorLabel } from '@/app/lib/types';
+
+// POST: Set or clear the color label of one or more videos
+// Body: { videoIds: string[], label: ColorLabel | null }
+export async function POST(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { videoIds, label } = await request.json();
+    if (!Array.isArray(videoIds)) {
+      return NextResponse.json(
+        { success: false, error: 'videoIds is required' },
+        { status: 400 }
+      );
+    }
+    if (label !== null && !COLOR_LABELS.includes(label)) {
+      return NextResponse.json(
+        { success: false, error: 'Unknown color label' },
+        { status: 400 }
+      );
+    }
+
+    // Unknown IDs are skipped rather than failing the whole request
+    const selections = setVideosColorLabel(
+      videoIds.filter((id) => typeof id === 'string' && getVideoById(id)),
+      label as ColorLabel | null
+    );
+    return NextResponse.json({ success: true, selections });
+  } catch (error) {
+    console.error('Error updating color labels:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to update color labels' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,43 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { setVideosRating, getVideoById, isDatabaseInitialized } from '@/app/lib/db';
+import { MAX_RATING } from '@/app/lib/types';
+
+// POST: Set or clear the star rating of one or more videos
+// Body: { videoIds: string[], rating: 1-5 | null }
+export async function POST(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { videoIds, rating } = await request.json();
+    if (!Array.isArray(videoIds)) {
+      return NextResponse.json(
+        { success: false, error: 'videoIds is required' },
+        { status: 400 }
+      );
+    }
+    if (rating !== null && !(Number.isInteger(rating) && rating >= 1 && rating <= MAX_RATING)) {
+      return NextResponse.json(
+        { success: false, error: `Rating must be a whole number from 1 to ${MAX_RATING}` },
+        { status: 400 }
+      );
+    }
+
+    // Unknown IDs are skipped rather than failing the whole request
+    const selections = setVideosRating(
+      videoIds.filter((id) => typeof id === 'string' && getVideoById(id)),
+      rating
+    );
+    return NextResponse.json({ success: true, selections });
+  } catch (error) {
+    console.error('Error updating ratings:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to update ratings' },
+      { status: 500 }
+    );
+  }
+}
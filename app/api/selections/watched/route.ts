@@ -0,0 +1,56 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { setVideoWatched, setVideosWatched, getVideoById, isDatabaseInitialized } from '@/app/lib/db';
+
+// POST: Mark a video watched or unwatched
+// Body: { videoId, watched } or { videoIds, watched } for a batch
+export async function POST(request: NextRequest) {
+  try {
+    // Check if database is initialized
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const body = await request.json();
+    const { videoId, videoIds, watched } = body;
+
+    // Batch: unknown IDs are skipped rather than failing the whole request
+    if (Array.isArray(videoIds)) {
+      const selections = setVideosWatched(
+        videoIds.filter((id) => typeof id === 'string' && getVideoById(id)),
+        watched === true
+      );
+      return NextResponse.json({ success: true, selections });
+    }
+
+    if (!videoId) {
+      return NextResponse.json(
+        { success: false, error: 'videoId or videoIds is required' },
+        { status: 400 }
+      );
+    }
+
+    // Verify video exists
+    if (!getVideoById(videoId)) {
+      return NextResponse.json(
+        { success: false, error: 'Video not found' },
+        { status: 404 }
+      );
+    }
+
+    const selection = setVideoWatched(videoId, watched === true);
+
+    return NextResponse.json({
+      success: true,
+      selection,
+    });
+  } catch (error) {
+    console.error('Error updating watched state:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to update watched state' },
+      { status: 500 }
+    );
+  }
+}
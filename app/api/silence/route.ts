@@ -0,0 +1,78 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getVideoById, isDatabaseInitialized } from '@/app/lib/db';
+import { getSilenceThreshold, setSilenceThreshold, startSilenceBatch } from '@/app/lib/silence';
+import { Video } from '@/app/lib/types';
+
+// GET: Silence warning threshold (seconds) for the open library
+export async function GET() {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  return NextResponse.json({ success: true, threshold: getSilenceThreshold() });
+}
+
+// PUT: Change the threshold
+// Body: { threshold: number }
+export async function PUT(request: NextRequest) {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  try {
+    const threshold = Number((await request.json()).threshold);
+    if (!Number.isFinite(threshold) || threshold <= 0) {
+      return NextResponse.json(
+        { success: false, error: 'Threshold must be a positive number of seconds' },
+        { status: 400 }
+      );
+    }
+    setSilenceThreshold(threshold);
+    return NextResponse.json({ success: true, threshold });
+  } catch (error) {
+    console.error('Error saving silence threshold:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to save threshold' },
+      { status: 500 }
+    );
+  }
+}
+
+// POST: Check clips for silence as a background task (already checked ones are skipped)
+// Body: { videoIds: string[] }
+export async function POST(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { videoIds } = await request.json();
+    if (!Array.isArray(videoIds) || videoIds.length === 0) {
+      return NextResponse.json(
+        { success: false, error: 'No videos specified' },
+        { status: 400 }
+      );
+    }
+
+    const videos = videoIds
+      .map((id: unknown) => (typeof id === 'string' ? getVideoById(id) : null))
+      .filter((video: Video | null): video is Video => video !== null);
+
+    return NextResponse.json({ success: true, taskId: startSilenceBatch(videos) });
+  } catch (error) {
+    console.error('Error starting silence detection:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to start silence detection' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,66 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized } from '@/app/lib/db';
+import { getSmartViews, renameSmartView, deleteSmartView } from '@/app/lib/smartViews';
+
+// PATCH: Rename a smart view
+export async function PATCH(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { id } = await params;
+    const body = await request.json();
+    const name = typeof body.name === 'string' ? body.name.trim() : '';
+    if (!name) {
+      return NextResponse.json(
+        { success: false, error: 'Name is required' },
+        { status: 400 }
+      );
+    }
+
+    if (!renameSmartView(id, name)) {
+      return NextResponse.json(
+        { success: false, error: 'Smart view not found' },
+        { status: 404 }
+      );
+    }
+
+    return NextResponse.json({ success: true, views: getSmartViews() });
+  } catch (error) {
+    console.error('Error renaming smart view:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to rename smart view' },
+      { status: 500 }
+    );
+  }
+}
+
+// DELETE: Remove a smart view
+export async function DELETE(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  const { id } = await params;
+  if (!deleteSmartView(id)) {
+    return NextResponse.json(
+      { success: false, error: 'Smart view not found' },
+      { status: 404 }
+    );
+  }
+
+  return NextResponse.json({ success: true, views: getSmartViews() });
+}
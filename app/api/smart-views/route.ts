@@ -0,0 +1,45 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized } from '@/app/lib/db';
+import { getSmartViews, createSmartView, parseSmartViewCriteria } from '@/app/lib/smartViews';
+
+// GET: Saved smart views for the open library
+export async function GET() {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  return NextResponse.json({ success: true, views: getSmartViews() });
+}
+
+// POST: Save the current search, favorites filter and sort as a named view
+export async function POST(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const body = await request.json();
+    const name = typeof body.name === 'string' ? body.name.trim() : '';
+    if (!name) {
+      return NextResponse.json(
+        { success: false, error: 'Name is required' },
+        { status: 400 }
+      );
+    }
+
+    const view = createSmartView(name, parseSmartViewCriteria(body.criteria || {}));
+    return NextResponse.json({ success: true, view, views: getSmartViews() });
+  } catch (error) {
+    console.error('Error saving smart view:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to save smart view' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,76 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized } from '@/app/lib/db';
+import {
+  DEFAULT_SPRITE_DENSITY,
+  getSpriteDensity,
+  isValidSpriteDensity,
+  setSpriteDensity,
+  SPRITE_LIMITS,
+  startSpriteRegeneration,
+} from '@/app/lib/sprites';
+
+// GET: Sprite sheet density for the open library (null for the duration-based layout)
+export async function GET() {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  return NextResponse.json({
+    success: true,
+    density: getSpriteDensity(),
+    defaults: DEFAULT_SPRITE_DENSITY,
+    limits: SPRITE_LIMITS,
+  });
+}
+
+// PUT: Change the density; existing sprites keep theirs until regenerated
+// Body: { density: { tileWidth, columns, targetTiles } | null }
+export async function PUT(request: NextRequest) {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  try {
+    const { density } = await request.json();
+    if (density !== null && !isValidSpriteDensity(density)) {
+      return NextResponse.json(
+        { success: false, error: 'Tile width, columns and tile count must be whole numbers within the allowed range' },
+        { status: 400 }
+      );
+    }
+    setSpriteDensity(density);
+    return NextResponse.json({ success: true, density: getSpriteDensity() });
+  } catch (error) {
+    console.error('Error saving sprite density:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to save sprite density' },
+      { status: 500 }
+    );
+  }
+}
+
+// POST: Regenerate every sprite with the current density as a background task
+export async function POST() {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    return NextResponse.json({ success: true, taskId: startSpriteRegeneration() });
+  } catch (error) {
+    console.error('Error starting sprite regeneration:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to start sprite regeneration' },
+      { status: 500 }
+    );
+  }
+}
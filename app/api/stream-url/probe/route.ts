@@ -0,0 +1,31 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getVideoMetadata, isNetworkStreamUrl } from '@/app/lib/ffmpeg';
+
+// GET: Probe a network stream (?url=); live streams report a duration of 0
+export async function GET(request: NextRequest) {
+  try {
+    const { searchParams } = new URL(request.url);
+    const url = searchParams.get('url');
+
+    if (!url || !isNetworkStreamUrl(url)) {
+      return NextResponse.json(
+        { success: false, error: 'A valid rtsp, rtmp, or http(s) stream URL is required' },
+        { status: 400 }
+      );
+    }
+
+    const metadata = await getVideoMetadata(url);
+
+    return NextResponse.json({
+      success: true,
+      metadata,
+      isLive: metadata.duration <= 0,
+    });
+  } catch (error) {
+    console.error('Error probing network stream:', error);
+    return NextResponse.json(
+      { success: false, error: 'Could not open stream. Check the URL and that the source is reachable.' },
+      { status: 502 }
+    );
+  }
+}
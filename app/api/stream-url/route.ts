@@ -0,0 +1,41 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isNetworkStreamUrl, openNetworkStream } from '@/app/lib/ffmpeg';
+
+// GET: Play a network stream (?url=) as fragmented MP4
+export async function GET(request: NextRequest) {
+  try {
+    const { searchParams } = new URL(request.url);
+    const url = searchParams.get('url');
+
+    if (!url || !isNetworkStreamUrl(url)) {
+      return NextResponse.json(
+        { success: false, error: 'A valid rtsp, rtmp, or http(s) stream URL is required' },
+        { status: 400 }
+      );
+    }
+
+    const ffmpeg = openNetworkStream(url);
+
+    // Stop transcoding as soon as the player goes away
+    request.signal.addEventListener('abort', () => {
+      ffmpeg.kill('SIGKILL');
+    });
+
+    ffmpeg.stderr?.on('data', (data) => {
+      console.error(`Network stream ${url}: ${data.toString()}`);
+    });
+
+    return new NextResponse(ffmpeg.stdout as unknown as ReadableStream, {
+      headers: {
+        'Content-Type': 'video/mp4',
+        'Cache-Control': 'no-store',
+      },
+    });
+  } catch (error) {
+    console.error('Error opening network stream:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to open network stream' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,19 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { cancelTask } from '@/app/lib/tasks';
+
+// POST: Request cancellation of a running task
+export async function POST(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  const { id } = await params;
+
+  if (!cancelTask(id)) {
+    return NextResponse.json(
+      { success: false, error: 'Task not found or already finished' },
+      { status: 404 }
+    );
+  }
+
+  return NextResponse.json({ success: true });
+}
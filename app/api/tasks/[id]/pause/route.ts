@@ -0,0 +1,26 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { pauseTask } from '@/app/lib/tasks';
+
+// POST: Pause ({ paused: true }) or resume a running task that supports it
+export async function POST(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  const { id } = await params;
+
+  let paused: boolean;
+  try {
+    paused = (await request.json()).paused !== false;
+  } catch {
+    paused = true;
+  }
+
+  if (!pauseTask(id, paused)) {
+    return NextResponse.json(
+      { success: false, error: 'Task not found, finished or not pausable' },
+      { status: 404 }
+    );
+  }
+
+  return NextResponse.json({ success: true });
+}
@@ -0,0 +1,20 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getTask } from '@/app/lib/tasks';
+
+// GET: One task's progress, for views that follow a single task
+export async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  const { id } = await params;
+  const task = getTask(id);
+
+  if (!task) {
+    return NextResponse.json(
+      { success: false, error: 'Task not found' },
+      { status: 404 }
+    );
+  }
+
+  return NextResponse.json({ success: true, task });
+}
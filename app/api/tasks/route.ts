@@ -0,0 +1,7 @@
+import { NextResponse } from 'next/server';
+import { getTasks } from '@/app/lib/tasks';
+
+// GET: Running and recently finished background tasks
+export async function GET() {
+  return NextResponse.json({ success: true, tasks: getTasks() });
+}
@@ -0,0 +1,62 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getCurrentRootPath, getVideoById, isDatabaseInitialized } from '@/app/lib/db';
+import { getThumbnailStatuses, getThumbnailStatusVersion, startThumbnailTask } from '@/app/lib/thumbnailStatus';
+import { Video } from '@/app/lib/types';
+
+// GET: Thumbnails pending, generating or failed in the open library
+// Params: since (version from the last response; unchanged state returns no statuses)
+export async function GET(request: NextRequest) {
+  const rootPath = getCurrentRootPath();
+  if (!isDatabaseInitialized() || !rootPath) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  const since = request.nextUrl.searchParams.get('since');
+  if (since !== null && Number(since) === getThumbnailStatusVersion()) {
+    return NextResponse.json({ success: true, version: Number(since), unchanged: true });
+  }
+
+  return NextResponse.json({ success: true, ...getThumbnailStatuses(rootPath) });
+}
+
+// POST: Generate thumbnails again as a background task (a card's retry, or a batch)
+// Body: { videoIds: string[] }
+export async function POST(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { videoIds } = await request.json();
+    if (!Array.isArray(videoIds) || videoIds.length === 0) {
+      return NextResponse.json(
+        { success: false, error: 'No videos specified' },
+        { status: 400 }
+      );
+    }
+
+    const videos = videoIds
+      .map((id: unknown) => (typeof id === 'string' ? getVideoById(id) : null))
+      .filter((video: Video | null): video is Video => video !== null && video.duration > 0);
+    if (videos.length === 0) {
+      return NextResponse.json(
+        { success: false, error: 'No videos with a known duration' },
+        { status: 400 }
+      );
+    }
+
+    return NextResponse.json({ success: true, taskId: startThumbnailTask(videos) });
+  } catch (error) {
+    console.error('Error starting thumbnail generation:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to start thumbnail generation' },
+      { status: 500 }
+    );
+  }
+}
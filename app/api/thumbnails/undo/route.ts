@@ -0,0 +1,35 @@
+import { NextResponse } from 'next/server';
+import { getCurrentRootPath, isDatabaseInitialized } from '@/app/lib/db';
+import { getUndoableThumbnailCount, undoThumbnailRegeneration } from '@/app/lib/thumbnailBackups';
+
+// GET: How many thumbnails regenerated this session can be restored
+export async function GET() {
+  const rootPath = getCurrentRootPath();
+  if (!isDatabaseInitialized() || !rootPath) {
+    return NextResponse.json({ success: true, count: 0 });
+  }
+
+  return NextResponse.json({ success: true, count: getUndoableThumbnailCount(rootPath) });
+}
+
+// POST: Restore the previous thumbnails (and poster times) in the open library
+export async function POST() {
+  try {
+    const rootPath = getCurrentRootPath();
+    if (!isDatabaseInitialized() || !rootPath) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const restored = await undoThumbnailRegeneration(rootPath);
+    return NextResponse.json({ success: true, restored });
+  } catch (error) {
+    console.error('Error undoing thumbnail regeneration:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to restore thumbnails' },
+      { status: 500 }
+    );
+  }
+}
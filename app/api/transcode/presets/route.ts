@@ -0,0 +1,59 @@
+import { NextRequest, NextResponse } from 'next/server';
+import {
+  getTranscodePresets,
+  resetTranscodePresets,
+  setTranscodePresets,
+  validateTranscodePresets,
+} from '@/app/lib/transcodePresets';
+
+// GET: Batch transcode presets
+export async function GET() {
+  return NextResponse.json({ success: true, presets: getTranscodePresets() });
+}
+
+// PUT: Replace the presets
+// Body: { presets: [{ label, args, extension }] }
+export async function PUT(request: NextRequest) {
+  let body;
+  try {
+    body = await request.json();
+  } catch {
+    return NextResponse.json(
+      { success: false, error: 'Invalid request body' },
+      { status: 400 }
+    );
+  }
+
+  const validation = validateTranscodePresets(body?.presets);
+  if ('error' in validation) {
+    return NextResponse.json(
+      { success: false, error: validation.error },
+      { status: 400 }
+    );
+  }
+
+  try {
+    setTranscodePresets(validation.presets);
+    return NextResponse.json({ success: true, presets: validation.presets });
+  } catch (error) {
+    console.error('Error saving transcode presets:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to save presets' },
+      { status: 500 }
+    );
+  }
+}
+
+// DELETE: Restore the built-in presets
+export async function DELETE() {
+  try {
+    resetTranscodePresets();
+    return NextResponse.json({ success: true, presets: getTranscodePresets() });
+  } catch (error) {
+    console.error('Error resetting transcode presets:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to reset presets' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,83 @@
+import { NextRequest, NextResponse } from 'next/server';
+import fs from 'fs/promises';
+import { constants } from 'fs';
+import path from 'path';
+import { getCurrentRootPath, getVideoById, isDatabaseInitialized } from '@/app/lib/db';
+import { getTranscodePresets } from '@/app/lib/transcodePresets';
+import { startTranscodeBatch } from '@/app/lib/transcode';
+import { Video } from '@/app/lib/types';
+
+// POST: Queue a batch transcode; progress is reported as a background task
+// Body: { videoIds: string[], preset: number (index), destination: string, addToCatalog?: boolean }
+export async function POST(request: NextRequest) {
+  try {
+    const rootPath = getCurrentRootPath();
+    if (!isDatabaseInitialized() || !rootPath) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const body = await request.json();
+    const { videoIds, preset: presetIndex, destination, addToCatalog } = body;
+
+    if (!Array.isArray(videoIds) || videoIds.length === 0) {
+      return NextResponse.json(
+        { success: false, error: 'No videos specified' },
+        { status: 400 }
+      );
+    }
+
+    const preset = getTranscodePresets()[Number(presetIndex)];
+    if (!preset) {
+      return NextResponse.json(
+        { success: false, error: 'Unknown preset' },
+        { status: 400 }
+      );
+    }
+
+    if (typeof destination !== 'string' || !path.isAbsolute(destination.trim())) {
+      return NextResponse.json(
+        { success: false, error: 'Destination must be an absolute folder path' },
+        { status: 400 }
+      );
+    }
+    const destinationPath = path.resolve(destination.trim());
+    try {
+      if (!(await fs.stat(destinationPath)).isDirectory()) throw new Error('Not a folder');
+      await fs.access(destinationPath, constants.W_OK);
+    } catch {
+      return NextResponse.json(
+        { success: false, error: 'Destination folder does not exist or is not writable' },
+        { status: 400 }
+      );
+    }
+
+    const videos = videoIds
+      .map((id: unknown) => (typeof id === 'string' ? getVideoById(id) : null))
+      .filter((video: Video | null): video is Video => video !== null);
+    if (videos.length === 0) {
+      return NextResponse.json(
+        { success: false, error: 'Videos not found' },
+        { status: 404 }
+      );
+    }
+
+    const taskId = startTranscodeBatch({
+      videos,
+      preset,
+      destination: destinationPath,
+      rootPath,
+      addToCatalog: addToCatalog === true,
+    });
+
+    return NextResponse.json({ success: true, taskId, queued: videos.length });
+  } catch (error) {
+    console.error('Error starting transcode batch:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to start transcode' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,53 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getCurrentRootPath, getVideoById, isDatabaseInitialized, setVideoCorrupt } from '@/app/lib/db';
+import { processSingleVideo } from '@/app/lib/scanner';
+import { updateVerifyEntry } from '@/app/lib/verify';
+
+// POST: Act on one file from the verify report
+// Body: { videoId, action: 'rescan' | 'corrupt' | 'clear' }
+// rescan re-indexes the file as it is now (new fingerprint, metadata and thumbnails)
+export async function POST(request: NextRequest) {
+  try {
+    const rootPath = getCurrentRootPath();
+    if (!isDatabaseInitialized() || !rootPath) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { videoId, action } = await request.json();
+    const video = typeof videoId === 'string' ? getVideoById(videoId) : null;
+    if (!video) {
+      return NextResponse.json(
+        { success: false, error: 'Video not found' },
+        { status: 404 }
+      );
+    }
+
+    switch (action) {
+      case 'rescan':
+        await processSingleVideo(video.filePath, rootPath);
+        updateVerifyEntry(video.id, null);
+        break;
+      case 'corrupt':
+      case 'clear':
+        setVideoCorrupt(video.id, action === 'corrupt');
+        updateVerifyEntry(video.id, { isCorrupt: action === 'corrupt' });
+        break;
+      default:
+        return NextResponse.json(
+          { success: false, error: 'action must be rescan, corrupt or clear' },
+          { status: 400 }
+        );
+    }
+
+    return NextResponse.json({ success: true });
+  } catch (error) {
+    console.error('Error resolving verify entry:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to update video' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,44 @@
+import { NextResponse } from 'next/server';
+import { isDatabaseInitialized } from '@/app/lib/db';
+import { getVerifyReport, getVerifyTaskId, startVerify } from '@/app/lib/verify';
+
+// GET: Progress and results of the open library's last verify
+export async function GET() {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  const taskId = getVerifyTaskId();
+  return NextResponse.json({ success: true, running: taskId !== null, taskId, report: getVerifyReport() });
+}
+
+// POST: Re-fingerprint every catalogued file in the background (cancel via the task popover)
+export async function POST() {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    if (getVerifyTaskId() !== null) {
+      return NextResponse.json(
+        { success: false, error: 'Verification is already running' },
+        { status: 409 }
+      );
+    }
+
+    const taskId = startVerify();
+    return NextResponse.json({ success: true, taskId });
+  } catch (error) {
+    console.error('Error starting verify:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to start verification' },
+      { status: 500 }
+    );
+  }
+}
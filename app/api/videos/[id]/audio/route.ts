@@ -0,0 +1,86 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getVideoById, getCurrentRootPath, isDatabaseInitialized } from '@/app/lib/db';
+import { extractAudioTrack } from '@/app/lib/ffmpeg';
+import fs from 'fs';
+
+// GET: Stream one audio track (?track=, 0-based) as AAC with range support
+export async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  try {
+    // Check if database is initialized
+    const rootPath = getCurrentRootPath();
+    if (!isDatabaseInitialized() || !rootPath) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { id } = await params;
+    const { searchParams } = new URL(request.url);
+    const track = parseInt(searchParams.get('track') || '0', 10);
+
+    const video = getVideoById(id);
+    if (!video) {
+      return NextResponse.json(
+        { success: false, error: 'Video not found' },
+        { status: 404 }
+      );
+    }
+
+    if (!Number.isInteger(track) || track < 0 || track >= (video.audioTrackCount ?? 1)) {
+      return NextResponse.json(
+        { success: false, error: 'Audio track not found' },
+        { status: 404 }
+      );
+    }
+
+    if (!fs.existsSync(video.filePath)) {
+      return NextResponse.json(
+        { success: false, error: 'Video file not found' },
+        { status: 404 }
+      );
+    }
+
+    const filePath = await extractAudioTrack(video.id, video.filePath, rootPath, track);
+    const fileSize = fs.statSync(filePath).size;
+    const range = request.headers.get('range');
+
+    if (range) {
+      // Handle range request for seeking
+      const parts = range.replace(/bytes=/, '').split('-');
+      const start = parseInt(parts[0], 10);
+      const end = parts[1] ? parseInt(parts[1], 10) : fileSize - 1;
+
+      const fileStream = fs.createReadStream(filePath, { start, end });
+
+      return new NextResponse(fileStream as unknown as ReadableStream, {
+        status: 206,
+        headers: {
+          'Content-Range': `bytes ${start}-${end}/${fileSize}`,
+          'Accept-Ranges': 'bytes',
+          'Content-Length': String(end - start + 1),
+          'Content-Type': 'audio/mp4',
+        },
+      });
+    }
+
+    const fileStream = fs.createReadStream(filePath);
+
+    return new NextResponse(fileStream as unknown as ReadableStream, {
+      headers: {
+        'Content-Length': String(fileSize),
+        'Content-Type': 'audio/mp4',
+        'Accept-Ranges': 'bytes',
+      },
+    });
+  } catch (error) {
+    console.error('Error streaming audio track:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to stream audio track' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,38 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getVideoById, isDatabaseInitialized } from '@/app/lib/db';
+import { getChapters } from '@/app/lib/ffmpeg';
+
+// GET: Chapter markers from the original file's container (read on demand, not catalogued)
+export async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  try {
+    // Check if database is initialized
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { id } = await params;
+    const video = getVideoById(id);
+    if (!video) {
+      return NextResponse.json(
+        { success: false, error: 'Video not found' },
+        { status: 404 }
+      );
+    }
+
+    const chapters = await getChapters(video.filePath);
+
+    return NextResponse.json({ success: true, chapters });
+  } catch (error) {
+    console.error('Error reading chapters:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to read chapters' },
+      { status: 500 }
+    );
+  }
+}
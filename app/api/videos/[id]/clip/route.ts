@@ -0,0 +1,58 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getCurrentRootPath, getVideoById, isDatabaseInitialized } from '@/app/lib/db';
+import { startClipExport, validateClipOptions } from '@/app/lib/clipExport';
+import { ClipExportOptions } from '@/app/lib/types';
+
+// POST: Export the range between start and end (seconds) as a new file next to the source
+// Body: { start: number, end: number, options: ClipExportOptions }
+export async function POST(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  try {
+    const rootPath = getCurrentRootPath();
+    if (!isDatabaseInitialized() || !rootPath) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { id } = await params;
+    const video = getVideoById(id);
+    if (!video) {
+      return NextResponse.json(
+        { success: false, error: 'Video not found' },
+        { status: 404 }
+      );
+    }
+
+    const body = await request.json();
+    const start = Number(body.start);
+    const end = Number(body.end);
+    if (!Number.isFinite(start) || !Number.isFinite(end) || start < 0 || end <= start) {
+      return NextResponse.json(
+        { success: false, error: 'B must come after A' },
+        { status: 400 }
+      );
+    }
+
+    const options = body.options as ClipExportOptions;
+    const invalid = options ? validateClipOptions(options) : 'Export options are required';
+    if (invalid) {
+      return NextResponse.json(
+        { success: false, error: invalid },
+        { status: 400 }
+      );
+    }
+
+    const { taskId, outputName } = await startClipExport(video, start, end, options, rootPath);
+    return NextResponse.json({ success: true, taskId, outputName });
+  } catch (error) {
+    console.error('Error starting clip export:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to start clip export' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,73 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getVideoById, isDatabaseInitialized } from '@/app/lib/db';
+import { extractFrame } from '@/app/lib/ffmpeg';
+import fs from 'fs';
+
+// Bounds for ?width=
+const MIN_FRAME_WIDTH = 160;
+const MAX_FRAME_WIDTH = 1920;
+
+// GET: Decode a single frame at ?time= (seconds) as JPEG, ?width= pixels wide (default 640).
+// ?keyframe=1 returns the nearest keyframe at or after the time instead, for fast scrubbing.
+
+export async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  try {
+    // Check if database is initialized
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { id } = await params;
+    const { searchParams } = new URL(request.url);
+
+    const video = getVideoById(id);
+    if (!video) {
+      return NextResponse.json(
+        { success: false, error: 'Video not found' },
+        { status: 404 }
+      );
+    }
+
+    if (!fs.existsSync(video.filePath)) {
+      return NextResponse.json(
+        { success: false, error: 'Video file not found' },
+        { status: 404 }
+      );
+    }
+
+    // Default to 10% into the video
+    const requestedTime = parseFloat(searchParams.get('time') || '');
+    const time = Number.isFinite(requestedTime)
+      ? Math.max(0, Math.min(requestedTime, video.duration))
+      : video.duration * 0.1;
+
+    const requestedWidth = parseInt(searchParams.get('width') || '', 10);
+    const width = Number.isFinite(requestedWidth)
+      ? Math.max(MIN_FRAME_WIDTH, Math.min(requestedWidth, MAX_FRAME_WIDTH))
+      : undefined;
+
+    // Past the last keyframe there is nothing to return, so fall back to an exact decode
+    const frame = searchParams.get('keyframe') === '1'
+      ? await extractFrame(video.filePath, time, width, true).catch(() => extractFrame(video.filePath, time, width))
+      : await extractFrame(video.filePath, time, width);
+
+    return new NextResponse(new Uint8Array(frame), {
+      headers: {
+        'Content-Type': 'image/jpeg',
+        'Cache-Control': 'no-store',
+      },
+    });
+  } catch (error) {
+    console.error('Error extracting frame:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to extract frame' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,105 @@
+import { NextRequest, NextResponse } from 'next/server';
+import {
+  getVideoById,
+  getSelectionByVideoId,
+  updateVideoPosterTime,
+  updateVideoThumbnail,
+  isDatabaseInitialized,
+  getCurrentRootPath,
+} from '@/app/lib/db';
+import { generateThumbnailOnly } from '@/app/lib/ffmpeg';
+import { getAssetVersion } from '@/app/lib/assetCache';
+import { backupThumbnail, restoreThumbnail } from '@/app/lib/thumbnailBackups';
+
+// Regenerate the thumbnail at the given time (null = automatic) and return the updated video
+async function applyPosterTime(id: string, posterTime: number | null) {
+  const rootPath = getCurrentRootPath();
+  const video = getVideoById(id);
+  if (!rootPath || !video) {
+    return NextResponse.json(
+      { success: false, error: 'Video not found' },
+      { status: 404 }
+    );
+  }
+
+  // Keep the previous thumbnail so this session's changes can be undone
+  const backedUp = await backupThumbnail(video.id, rootPath, video.thumbnailPath, video.posterTime);
+  let thumbnailPath: string;
+  try {
+    thumbnailPath = await generateThumbnailOnly(video.id, video.filePath, rootPath, video.duration, posterTime);
+  } catch (error) {
+    if (backedUp) await restoreThumbnail(video.id);
+    throw error;
+  }
+  updateVideoThumbnail(video.id, thumbnailPath);
+  updateVideoPosterTime(video.id, posterTime);
+
+  const updated = getVideoById(id)!;
+  return NextResponse.json({
+    success: true,
+    video: {
+      ...updated,
+      selection: getSelectionByVideoId(id) || undefined,
+      thumbnailVersion: getAssetVersion(updated.thumbnailPath),
+      spriteVersion: getAssetVersion(updated.spritePath),
+    },
+  });
+}
+
+// POST: Use the frame at `time` (seconds) as the video's thumbnail
+export async function POST(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { id } = await params;
+    const body = await request.json();
+    const time = Number(body.time);
+
+    if (!Number.isFinite(time) || time < 0) {
+      return NextResponse.json(
+        { success: false, error: 'A valid time is required' },
+        { status: 400 }
+      );
+    }
+
+    return await applyPosterTime(id, time);
+  } catch (error) {
+    console.error('Error setting poster frame:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to set poster frame' },
+      { status: 500 }
+    );
+  }
+}
+
+// DELETE: Reset the thumbnail to the automatic frame
+export async function DELETE(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { id } = await params;
+    return await applyPosterTime(id, null);
+  } catch (error) {
+    console.error('Error resetting poster frame:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to reset poster frame' },
+      { status: 500 }
+    );
+  }
+}
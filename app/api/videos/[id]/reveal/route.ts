@@ -0,0 +1,54 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getVideoById, isDatabaseInitialized } from '@/app/lib/db';
+import { spawn } from 'child_process';
+import fs from 'fs';
+
+// File manager command for each platform (this app runs on the machine holding the files)
+function getOpenCommand(): string {
+  switch (process.platform) {
+    case 'darwin':
+      return 'open';
+    case 'win32':
+      return 'explorer';
+    default:
+      return 'xdg-open';
+  }
+}
+
+// POST: Open the folder containing the video (and its subtitles) in the file manager
+export async function POST(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  try {
+    // Check if database is initialized
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { id } = await params;
+
+    const video = getVideoById(id);
+    if (!video || !fs.existsSync(video.directory)) {
+      return NextResponse.json(
+        { success: false, error: 'Folder not found' },
+        { status: 404 }
+      );
+    }
+
+    const child = spawn(getOpenCommand(), [video.directory], { detached: true, stdio: 'ignore' });
+    child.on('error', (error) => console.error('Failed to open folder:', error));
+    child.unref();
+
+    return NextResponse.json({ success: true });
+  } catch (error) {
+    console.error('Error opening folder:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to open folder' },
+      { status: 500 }
+    );
+  }
+}
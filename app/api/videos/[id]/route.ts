@@ -1,5 +1,6 @@
 import { NextRequest, NextResponse } from 'next/server';
 import { getVideoById, getSelectionByVideoId, isDatabaseInitialized } from '@/app/lib/db';
+import { getAssetVersion } from '@/app/lib/assetCache';
 
 // GET: Get single video details
 export async function GET(
@@ -32,6 +33,8 @@ export async function GET(
       video: {
         ...video,
         selection: selection || undefined,
+        thumbnailVersion: getAssetVersion(video.thumbnailPath),
+        spriteVersion: getAssetVersion(video.spritePath),
       },
     });
   } catch (error) {
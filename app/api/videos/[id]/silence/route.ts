@@ -0,0 +1,56 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getSilenceReport, getVideoById, isDatabaseInitialized } from '@/app/lib/db';
+import { analyzeSilence, getSilenceThreshold } from '@/app/lib/silence';
+
+// GET: Stored silence report (null if not analyzed, or the file changed since) and the threshold
+export async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  if (!isDatabaseInitialized()) {
+    return NextResponse.json(
+      { success: false, error: 'No video library loaded' },
+      { status: 400 }
+    );
+  }
+
+  const { id } = await params;
+  return NextResponse.json({
+    success: true,
+    report: getSilenceReport(id),
+    threshold: getSilenceThreshold(),
+  });
+}
+
+// POST: Analyze this clip now (returns the stored report if the file is unchanged)
+export async function POST(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { id } = await params;
+    const video = getVideoById(id);
+    if (!video) {
+      return NextResponse.json(
+        { success: false, error: 'Video not found' },
+        { status: 404 }
+      );
+    }
+
+    const report = await analyzeSilence(video);
+    return NextResponse.json({ success: true, report, threshold: getSilenceThreshold() });
+  } catch (error) {
+    console.error('Error detecting silence:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to detect silence' },
+      { status: 500 }
+    );
+  }
+}
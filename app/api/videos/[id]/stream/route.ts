@@ -3,6 +3,18 @@ import { getVideoById, isDatabaseInitialized } from '@/app/lib/db';
 import fs from 'fs';
 import path from 'path';
 
+// Content types for non-MP4 containers
+const CONTENT_TYPES: Record<string, string> = {
+  '.mov': 'video/quicktime',
+  '.webm': 'video/webm',
+  '.mkv': 'video/x-matroska',
+  '.avi': 'video/x-msvideo',
+  '.mts': 'video/mp2t',
+  '.m2ts': 'video/mp2t',
+  '.3gp': 'video/3gpp',
+  '.mxf': 'application/mxf',
+};
+
 // GET: Stream video file with range support
 export async function GET(
   request: NextRequest,
@@ -51,7 +63,7 @@ export async function GET(
 
     // Determine content type
     const ext = path.extname(filePath).toLowerCase();
-    const contentType = ext === '.mov' ? 'video/quicktime' : 'video/mp4';
+    const contentType = CONTENT_TYPES[ext] || 'video/mp4';
 
     if (range) {
       // Handle range request for video seeking
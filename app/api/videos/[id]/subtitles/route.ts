@@ -0,0 +1,63 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getVideoById, isDatabaseInitialized } from '@/app/lib/db';
+import { convertSubtitlesToVtt } from '@/app/lib/ffmpeg';
+import { cuesToVtt, decodeSubtitleFile, parseSrt } from '@/app/lib/subtitles';
+import fs from 'fs';
+import path from 'path';
+
+// GET: One of the video's subtitle files (?index=, 0-based) as WebVTT
+export async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  try {
+    // Check if database is initialized
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { id } = await params;
+    const { searchParams } = new URL(request.url);
+    const index = parseInt(searchParams.get('index') || '0', 10);
+
+    const video = getVideoById(id);
+    if (!video) {
+      return NextResponse.json(
+        { success: false, error: 'Video not found' },
+        { status: 404 }
+      );
+    }
+
+    const fileName = video.subtitleFiles[index];
+    const filePath = fileName ? path.join(video.directory, fileName) : null;
+    if (!filePath || !fs.existsSync(filePath)) {
+      return NextResponse.json(
+        { success: false, error: 'Subtitle file not found' },
+        { status: 404 }
+      );
+    }
+
+    // .srt is simple enough to convert here; .ass styling needs FFmpeg
+    const extension = path.extname(filePath).toLowerCase();
+    const vtt = extension === '.vtt'
+      ? fs.readFileSync(filePath, 'utf-8')
+      : extension === '.srt'
+        ? cuesToVtt(parseSrt(decodeSubtitleFile(fs.readFileSync(filePath))))
+        : await convertSubtitlesToVtt(filePath);
+
+    return new NextResponse(vtt, {
+      headers: {
+        'Content-Type': 'text/vtt; charset=utf-8',
+      },
+    });
+  } catch (error) {
+    console.error('Error serving subtitles:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to load subtitles' },
+      { status: 500 }
+    );
+  }
+}
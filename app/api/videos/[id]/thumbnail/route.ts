@@ -1,5 +1,6 @@
 import { NextRequest, NextResponse } from 'next/server';
 import { getVideoById, isDatabaseInitialized } from '@/app/lib/db';
+import { takeWarmThumbnail } from '@/app/lib/assetCache';
 import fs from 'fs';
 
 // GET: Serve video thumbnail
@@ -26,7 +27,9 @@ export async function GET(
       );
     }
 
-    if (!video.thumbnailPath || !fs.existsSync(video.thumbnailPath)) {
+    const warmed = video.thumbnailPath ? takeWarmThumbnail(video.thumbnailPath) : null;
+
+    if (!warmed && (!video.thumbnailPath || !fs.existsSync(video.thumbnailPath))) {
       // Return a placeholder SVG instead of 404
       const placeholderSvg = `<svg xmlns="http://www.w3.org/2000/svg" width="384" height="216" viewBox="0 0 384 216">
         <rect fill="#1a1a1a" width="384" height="216"/>
@@ -44,7 +47,7 @@ export async function GET(
       });
     }
 
-    const fileBuffer = fs.readFileSync(video.thumbnailPath);
+    const fileBuffer = warmed ?? fs.readFileSync(video.thumbnailPath!);
 
     return new NextResponse(fileBuffer, {
       headers: {
@@ -0,0 +1,44 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { getCurrentRootPath, getVideoById, isDatabaseInitialized } from '@/app/lib/db';
+import { getWaveform, WAVEFORM_BUCKETS } from '@/app/lib/waveform';
+
+// GET: Audio waveform as one peak byte (0-255) per bucket, streamed while it's decoded
+export async function GET(
+  request: NextRequest,
+  { params }: { params: Promise<{ id: string }> }
+) {
+  try {
+    const rootPath = getCurrentRootPath();
+    if (!isDatabaseInitialized() || !rootPath) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const { id } = await params;
+    const video = getVideoById(id);
+    if (!video) {
+      return NextResponse.json(
+        { success: false, error: 'Video not found' },
+        { status: 404 }
+      );
+    }
+
+    const waveform = await getWaveform(video, rootPath);
+
+    return new NextResponse(waveform, {
+      headers: {
+        'Content-Type': 'application/octet-stream',
+        'Cache-Control': 'no-store',
+        'X-Waveform-Buckets': String(WAVEFORM_BUCKETS),
+      },
+    });
+  } catch (error) {
+    console.error('Error generating waveform:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to generate waveform' },
+      { status: 500 }
+    );
+  }
+}
@@ -1,5 +1,8 @@
 import { NextRequest, NextResponse } from 'next/server';
-import { getAllVideos, getVideosByDirectory, getSelectionByVideoId, isDatabaseInitialized, initDatabase } from '@/app/lib/db';
+import { searchVideos, getSelectionByVideoId, isDatabaseInitialized, initDatabase, getLongestSilences, getDuplicateFileNames } from '@/app/lib/db';
+import { getDisambiguatedName, isCopyWithFolderEnabled } from '@/app/lib/duplicateNames';
+import { getSilenceThreshold } from '@/app/lib/silence';
+import { getAssetVersion, warmThumbnailCache } from '@/app/lib/assetCache';
 import { SortOption, VideoWithSelection } from '@/app/lib/types';
 
 // GET: List videos with optional filtering and sorting
@@ -24,29 +27,41 @@ export async function GET(request: NextRequest) {
       });
     }
 
-    // Get videos
-    let videos = directory
-      ? getVideosByDirectory(directory, sortBy)
-      : getAllVideos(sortBy);
+    // Get videos (directory and favorites are filtered in SQL)
+    const { videos } = searchVideos({
+      directory: directory || undefined,
+      isFavorite: favoritesOnly ? true : undefined,
+      sort: sortBy,
+    });
+
+    // Read the first screens' thumbnails ahead of the grid requesting them
+    warmThumbnailCache(videos.map((video) => video.thumbnailPath));
+
+    const longestSilences = getLongestSilences();
+    const silenceThreshold = getSilenceThreshold();
+    const duplicateFileNames = getDuplicateFileNames();
+    const copyWithFolder = isCopyWithFolderEnabled();
 
     // Add selection data to each video
     const videosWithSelections: VideoWithSelection[] = videos.map((video) => {
       const selection = getSelectionByVideoId(video.id);
+      const longestSilence = longestSilences.get(video.id) ?? 0;
+      const sameNameCount = duplicateFileNames.get(video.fileName);
       return {
         ...video,
         selection: selection || undefined,
+        thumbnailVersion: getAssetVersion(video.thumbnailPath),
+        spriteVersion: getAssetVersion(video.spritePath),
+        silenceWarning: longestSilence >= silenceThreshold ? longestSilence : undefined,
+        sameNameCount,
+        copyName: sameNameCount && copyWithFolder ? getDisambiguatedName(video.filePath) : undefined,
       };
     });
 
-    // Filter to favorites only if requested
-    const filteredVideos = favoritesOnly
-      ? videosWithSelections.filter((v) => v.selection?.isFavorite)
-      : videosWithSelections;
-
     return NextResponse.json({
       success: true,
-      videos: filteredVideos,
-      total: filteredVideos.length,
+      videos: videosWithSelections,
+      total: videosWithSelections.length,
     });
   } catch (error) {
     console.error('Error fetching videos:', error);
@@ -0,0 +1,32 @@
+import { NextRequest, NextResponse } from 'next/server';
+import { isDatabaseInitialized } from '@/app/lib/db';
+import { getWatcherStatus, setWatchEnabled, syncWatcher } from '@/app/lib/watcher';
+
+// GET: Folder watcher status (also starts/stops it to match the open library)
+export async function GET() {
+  syncWatcher();
+  return NextResponse.json({ success: true, ...getWatcherStatus() });
+}
+
+// POST: Enable or disable watching the open library folder
+export async function POST(request: NextRequest) {
+  try {
+    if (!isDatabaseInitialized()) {
+      return NextResponse.json(
+        { success: false, error: 'No video library loaded' },
+        { status: 400 }
+      );
+    }
+
+    const body = await request.json();
+    setWatchEnabled(body.enabled === true);
+
+    return NextResponse.json({ success: true, ...getWatcherStatus() });
+  } catch (error) {
+    console.error('Error updating watch setting:', error);
+    return NextResponse.json(
+      { success: false, error: 'Failed to update setting' },
+      { status: 500 }
+    );
+  }
+}
@@ -0,0 +1,286 @@
+'use client';
+
+import { useState, useEffect, useMemo, useCallback } from 'react';
+import { ShowToast } from './Toast';
+import { SORT_OPTIONS } from './SortControls';
+import { GlobalSearchResult, LibraryVideo, RecentLibrary, SortOption } from '@/app/lib/types';
+import { compareVideos, formatDuration, formatFileSize, getBaseName, matchesSearch } from '@/app/lib/utils';
+
+interface AllLibrariesViewProps {
+  onOpenVideo: (result: GlobalSearchResult) => void; // Switches to the video's library
+  onClose: () => void;
+  onToast: ShowToast;
+}
+
+type LibraryStatus = 'loading' | 'online' | 'offline' | 'error';
+
+interface LibraryState {
+  path: string;
+  name: string;
+  status: LibraryStatus;
+  videoCount: number;
+}
+
+// Cards rendered at once; the merged grid isn't virtualized
+const MAX_RENDERED = 500;
+
+const STATUS_CLASSES: Record<LibraryStatus, string> = {
+  loading: 'bg-card-border text-muted animate-pulse',
+  online: 'bg-card-border text-foreground',
+  offline: 'bg-card-border text-muted line-through',
+  error: 'bg-error/20 text-error',
+};
+
+// Read-mostly grid of every known library's videos. Each library loads on its own,
+// so cards appear as catalogs are read and an unmounted drive only greys out its chip.
+export default function AllLibrariesView({ onOpenVideo, onClose, onToast }: AllLibrariesViewProps) {
+  const [libraries, setLibraries] = useState<LibraryState[]>([]);
+  const [videos, setVideos] = useState<LibraryVideo[]>([]);
+  const [query, setQuery] = useState('');
+  const [sortBy, setSortBy] = useState<SortOption>('date-desc');
+  const [favoritesOnly, setFavoritesOnly] = useState(false);
+
+  useEffect(() => {
+    let cancelled = false;
+
+    const setStatus = (path: string, status: LibraryStatus, videoCount: number = 0) => {
+      setLibraries((prev) => prev.map((library) =>
+        library.path === path ? { ...library, status, videoCount } : library
+      ));
+    };
+
+    const loadLibrary = async (path: string) => {
+      try {
+        const res = await fetch(`/api/libraries/videos?library=${encodeURIComponent(path)}`);
+        const data = await res.json();
+        if (cancelled) return;
+
+        if (!data.success) {
+          setStatus(path, 'error');
+        } else if (!data.online) {
+          setStatus(path, 'offline');
+        } else {
+          setVideos((prev) => [...prev, ...data.videos]);
+          setStatus(path, 'online', data.videos.length);
+        }
+      } catch (error) {
+        if (!cancelled) setStatus(path, 'error');
+        console.error(`Error loading library ${path}:`, error);
+      }
+    };
+
+    const loadAll = async () => {
+      try {
+        const res = await fetch('/api/libraries');
+        const data = await res.json();
+        if (cancelled || !data.success) return;
+
+        const known = data.libraries as RecentLibrary[];
+        setLibraries(known.map((library) => ({
+          path: library.path,
+          name: getBaseName(library.path) || library.path,
+          status: library.exists ? 'loading' : 'offline',
+          videoCount: 0,
+        })));
+
+        // Not awaited together: each library shows up as soon as it's read
+        known.filter((library) => library.exists).forEach((library) => loadLibrary(library.path));
+      } catch (error) {
+        console.error('Error fetching libraries:', error);
+      }
+    };
+
+    loadAll();
+    return () => {
+      cancelled = true;
+    };
+  }, []);
+
+  // Handle escape key to close
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        onClose();
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [onClose]);
+
+  const visibleVideos = useMemo(
+    () => videos
+      .filter((v) => matchesSearch(v, query, 'name') && (!favoritesOnly || !!v.selection?.isFavorite))
+      .sort((a, b) => compareVideos(a, b, sortBy)),
+    [videos, query, favoritesOnly, sortBy]
+  );
+
+  // Favorites are written to the video's own library, not the open one
+  const handleToggleFavorite = useCallback(async (video: LibraryVideo) => {
+    try {
+      const res = await fetch('/api/libraries/favorite', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({
+          library: video.libraryPath,
+          videoId: video.id,
+          isFavorite: !video.selection?.isFavorite,
+        }),
+      });
+      const data = await res.json();
+
+      if (data.success) {
+        setVideos((prev) => prev.map((v) =>
+          v.libraryPath === video.libraryPath && v.id === video.id
+            ? { ...v, selection: data.selection }
+            : v
+        ));
+      } else {
+        onToast(data.error || 'Failed to update favorite', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to update favorite', 'error');
+      console.error('Error updating favorite:', error);
+    }
+  }, [onToast]);
+
+  const handleOpen = useCallback((video: LibraryVideo) => {
+    if (video.isMissing) {
+      onToast(`${video.fileName} is not available. Is its drive mounted?`, 'error');
+      return;
+    }
+    onOpenVideo({ libraryPath: video.libraryPath, libraryName: video.libraryName, relevance: 0, video });
+  }, [onOpenVideo, onToast]);
+
+  const isLoading = libraries.some((library) => library.status === 'loading');
+
+  return (
+    <div className="fixed inset-0 z-50 flex flex-col bg-background">
+      <div className="flex items-center gap-3 p-4 border-b border-card-border">
+        <h2 className="text-xl font-semibold mr-2">All libraries</h2>
+        <input
+          type="text"
+          value={query}
+          onChange={(e) => setQuery(e.target.value)}
+          placeholder="Search file names..."
+          className="flex-1 max-w-md px-3 py-2 bg-card border border-card-border rounded-lg text-sm focus:outline-none focus:ring-2 focus:ring-accent"
+        />
+        <select
+          value={sortBy}
+          onChange={(e) => setSortBy(e.target.value as SortOption)}
+          className="px-3 py-2 bg-card border border-card-border rounded-lg text-sm"
+        >
+          {SORT_OPTIONS.map((option) => (
+            <option key={option.value} value={option.value}>{option.label}</option>
+          ))}
+        </select>
+        <button
+          onClick={() => setFavoritesOnly(!favoritesOnly)}
+          className={`px-4 py-2 text-sm rounded-lg transition-colors ${
+            favoritesOnly ? 'bg-accent text-white' : 'bg-card-border text-muted hover:text-foreground'
+          }`}
+        >
+          ★ Favorites
+        </button>
+        {isLoading && (
+          <div className="w-4 h-4 border-2 border-muted/40 border-t-accent rounded-full animate-spin" />
+        )}
+        <button
+          onClick={onClose}
+          className="ml-auto w-10 h-10 bg-black/50 hover:bg-black/70 rounded-full flex items-center justify-center transition-colors"
+        >
+          <svg className="w-6 h-6" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+            <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M6 18L18 6M6 6l12 12" />
+          </svg>
+        </button>
+      </div>
+
+      {/* Per-library load state */}
+      <div className="flex flex-wrap gap-2 px-4 py-3 border-b border-card-border">
+        {libraries.map((library) => (
+          <span
+            key={library.path}
+            className={`px-2 py-1 rounded text-xs ${STATUS_CLASSES[library.status]}`}
+            title={library.path}
+          >
+            {library.name}
+            {library.status === 'online' && ` · ${library.videoCount}`}
+            {library.status === 'offline' && ' · offline'}
+            {library.status === 'error' && ' · failed to load'}
+          </span>
+        ))}
+      </div>
+
+      <div className="flex-1 overflow-auto p-4">
+        {!isLoading && visibleVideos.length === 0 && (
+          <p className="text-sm text-muted">No videos match.</p>
+        )}
+        {visibleVideos.length > MAX_RENDERED && (
+          <p className="text-sm text-muted mb-4">
+            Showing {MAX_RENDERED} of {visibleVideos.length} videos. Search to narrow it down.
+          </p>
+        )}
+
+        <div className="grid grid-cols-2 md:grid-cols-4 lg:grid-cols-5 gap-4">
+          {visibleVideos.slice(0, MAX_RENDERED).map((video) => (
+            <div
+              key={`${video.libraryPath}:${video.id}`}
+              onClick={() => handleOpen(video)}
+              className={`
+                rounded-lg overflow-hidden bg-card border border-card-border transition-colors
+                ${video.isMissing ? 'opacity-50 cursor-not-allowed' : 'cursor-pointer hover:border-accent'}
+              `}
+              title={video.isMissing ? 'File not found' : video.filePath}
+            >
+              <div
+                className={`relative aspect-video bg-black bg-cover bg-center ${video.isMissing ? 'grayscale' : ''}`}
+                style={{
+                  backgroundImage: `url("/api/search/thumbnail?library=${encodeURIComponent(video.libraryPath)}&id=${encodeURIComponent(video.id)}")`,
+                }}
+              >
+                <span
+                  className="absolute top-2 left-2 px-2 py-0.5 rounded text-xs max-w-[60%] truncate bg-black/70 text-white"
+                  title={video.libraryPath}
+                >
+                  {video.libraryName}
+                </span>
+                <button
+                  onClick={(e) => {
+                    e.stopPropagation();
+                    handleToggleFavorite(video);
+                  }}
+                  className={`
+                    absolute top-2 right-2 w-7 h-7 rounded-full flex items-center justify-center text-sm
+                    ${video.selection?.isFavorite
+                      ? 'bg-yellow-500 text-black'
+                      : 'bg-black/50 text-white/70 hover:bg-black/70 hover:text-white'
+                    }
+                  `}
+                  title={video.selection?.isFavorite ? 'Remove from Favorites' : 'Add to Favorites'}
+                >
+                  ★
+                </button>
+                {video.isMissing ? (
+                  <span className="absolute bottom-2 left-2 bg-error/80 text-white px-1.5 py-0.5 rounded text-xs">
+                    Missing
+                  </span>
+                ) : video.selection?.watchedAt && (
+                  <span className="absolute bottom-2 left-2 bg-success text-white px-1.5 py-0.5 rounded text-xs">
+                    ✓
+                  </span>
+                )}
+                <span className="absolute bottom-2 right-2 bg-black/70 px-1.5 py-0.5 rounded text-xs">
+                  {formatDuration(video.duration)}
+                </span>
+              </div>
+              <div className="p-2">
+                <p className="text-sm truncate" title={video.fileName}>{video.fileName}</p>
+                <p className="text-xs text-muted">{formatFileSize(video.fileSize)}</p>
+              </div>
+            </div>
+          ))}
+        </div>
+      </div>
+    </div>
+  );
+}
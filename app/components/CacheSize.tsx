@@ -0,0 +1,48 @@
+'use client';
+
+import { useState, useEffect } from 'react';
+import { CacheSizeBreakdown } from '@/app/lib/types';
+import { formatFileSize } from '@/app/lib/utils';
+
+interface CacheSizeProps {
+  refreshKey: number | string; // Refetch when this changes (e.g. video count)
+}
+
+export default function CacheSize({ refreshKey }: CacheSizeProps) {
+  const [cacheSize, setCacheSize] = useState<CacheSizeBreakdown | null>(null);
+
+  useEffect(() => {
+    const fetchCacheSize = async () => {
+      try {
+        const res = await fetch('/api/cache');
+        const data = await res.json();
+        if (data.success) {
+          setCacheSize(data.cacheSize);
+        }
+      } catch (error) {
+        console.error('Error fetching cache size:', error);
+      }
+    };
+
+    fetchCacheSize();
+  }, [refreshKey]);
+
+  if (!cacheSize) return null;
+
+  const breakdown = [
+    `Database: ${formatFileSize(cacheSize.database)}`,
+    `Thumbnails: ${formatFileSize(cacheSize.thumbnails)}`,
+    `Sprites: ${formatFileSize(cacheSize.sprites)}`,
+    `Proxies: ${formatFileSize(cacheSize.proxies)}`,
+    ...(cacheSize.other > 0 ? [`Other: ${formatFileSize(cacheSize.other)}`] : []),
+  ].join('\n');
+
+  return (
+    <>
+      <span>•</span>
+      <span className="text-sm text-muted cursor-help" title={breakdown}>
+        Cache: {formatFileSize(cacheSize.total)}
+      </span>
+    </>
+  );
+}
@@ -0,0 +1,113 @@
+'use client';
+
+import { useState, useRef, useEffect } from 'react';
+import { ShowToast } from './Toast';
+
+interface CatalogImportProps {
+  onImported: () => void;
+  onToast: ShowToast;
+}
+
+export default function CatalogImport({ onImported, onToast }: CatalogImportProps) {
+  const [showMenu, setShowMenu] = useState(false);
+  const [catalogPath, setCatalogPath] = useState('');
+  const [isImporting, setIsImporting] = useState(false);
+  const menuRef = useRef<HTMLDivElement>(null);
+
+  // Close menu when clicking outside
+  useEffect(() => {
+    const handleClickOutside = (e: MouseEvent) => {
+      if (menuRef.current && !menuRef.current.contains(e.target as Node)) {
+        setShowMenu(false);
+      }
+    };
+    if (showMenu) {
+      document.addEventListener('mousedown', handleClickOutside);
+    }
+    return () => document.removeEventListener('mousedown', handleClickOutside);
+  }, [showMenu]);
+
+  const handleImport = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!catalogPath.trim()) return;
+
+    // Playlists mark their entries as favorites; anything else is treated as a catalog.db
+    const isPlaylist = /\.m3u8?$/i.test(catalogPath.trim());
+
+    setIsImporting(true);
+    try {
+      const res = await fetch(isPlaylist ? '/api/selections/import-playlist' : '/api/selections/import', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ path: catalogPath.trim() }),
+      });
+      const data = await res.json();
+
+      if (data.success) {
+        onToast(isPlaylist
+          ? `Marked ${data.favorited} favorite${data.favorited !== 1 ? 's' : ''} (${data.alreadyFavorite} already, ${data.notFound} not in library)`
+          : `Imported ${data.updated} selection${data.updated !== 1 ? 's' : ''} (${data.matched} matched, ${data.unmatched} unmatched)`);
+        setShowMenu(false);
+        onImported();
+      } else {
+        onToast(data.error || 'Import failed', 'error');
+      }
+    } catch (error) {
+      console.error('Error importing catalog:', error);
+      onToast('Import failed', 'error');
+    } finally {
+      setIsImporting(false);
+    }
+  };
+
+  return (
+    <div ref={menuRef} className="relative">
+      <button
+        onClick={() => setShowMenu(!showMenu)}
+        className="
+          px-3 py-1.5 text-sm rounded-lg transition-colors
+          bg-card border border-card-border text-muted
+          hover:text-foreground
+          flex items-center gap-2
+        "
+      >
+        <svg className="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+          <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M4 16v1a3 3 0 003 3h10a3 3 0 003-3v-1m-4-8l-4-4m0 0L8 8m4-4v12" />
+        </svg>
+        Import
+      </button>
+
+      {showMenu && (
+        <form
+          onSubmit={handleImport}
+          className="absolute left-0 top-full mt-2 bg-card border border-card-border rounded-lg shadow-xl p-4 z-50 min-w-[360px]"
+        >
+          <p className="text-sm text-foreground mb-3">
+            Copy favorites and notes from an existing catalog.db (matched by content, then path, then name and size), or mark every video in an .m3u/.m3u8 playlist as a favorite.
+          </p>
+          <input
+            type="text"
+            value={catalogPath}
+            onChange={(e) => setCatalogPath(e.target.value)}
+            placeholder="/path/to/catalog.db or playlist.m3u8"
+            className="
+              w-full px-3 py-2 mb-3 bg-background border border-card-border rounded-lg
+              text-sm text-foreground placeholder:text-muted
+              focus:outline-none focus:ring-2 focus:ring-accent focus:border-transparent
+            "
+            autoFocus
+          />
+          <div className="flex justify-end">
+            <button
+              type="submit"
+              disabled={!catalogPath.trim() || isImporting}
+              className="px-3 py-1.5 text-sm rounded-lg bg-accent hover:bg-accent-hover text-white disabled:opacity-50"
+            >
+              {isImporting ? 'Importing...' : 'Import'}
+            </button>
+          </div>
+        </form>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,252 @@
+'use client';
+
+import { useState, useEffect, useCallback } from 'react';
+import { ClipAudioCodec, ClipContainer, ClipExportOptions, ClipVideoCodec, TaskInfo, Video } from '@/app/lib/types';
+import { formatTimecode, DEFAULT_FRAME_RATE } from '@/app/lib/utils';
+
+interface ClipExportModalProps {
+  video: Video;
+  start: number; // A marker (seconds)
+  end: number; // B marker
+  onClose: () => void;
+}
+
+const POLL_MS = 500;
+
+const CONTAINERS: ClipContainer[] = ['mp4', 'mov', 'webm'];
+const VIDEO_CODECS: { value: ClipVideoCodec; label: string }[] = [
+  { value: 'copy', label: 'Copy (fast)' },
+  { value: 'h264', label: 'H.264' },
+  { value: 'hevc', label: 'H.265 / HEVC' },
+];
+const AUDIO_CODECS: { value: ClipAudioCodec; label: string }[] = [
+  { value: 'copy', label: 'Copy' },
+  { value: 'aac', label: 'AAC' },
+];
+
+const DEFAULT_OPTIONS: ClipExportOptions = { container: 'mp4', videoCodec: 'copy', audioCodec: 'copy', crf: 20 };
+
+// Export the A–B range as a new file next to the source, following the export task's progress
+export default function ClipExportModal({ video, start, end, onClose }: ClipExportModalProps) {
+  const [options, setOptions] = useState<ClipExportOptions>(DEFAULT_OPTIONS);
+  const [outputName, setOutputName] = useState<string | null>(null);
+  const [task, setTask] = useState<TaskInfo | null>(null);
+  const [taskId, setTaskId] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [isStarting, setIsStarting] = useState(false);
+
+  // Escape closes this modal only, not the player underneath (capture runs before its listener)
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        e.stopPropagation();
+        onClose();
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown, true);
+    return () => window.removeEventListener('keydown', handleKeyDown, true);
+  }, [onClose]);
+
+  // Poll the export task until it finishes; closing the modal leaves it running
+  useEffect(() => {
+    if (!taskId) return;
+
+    let finished = false;
+    const poll = async () => {
+      try {
+        const res = await fetch(`/api/tasks/${encodeURIComponent(taskId)}`);
+        const data = await res.json();
+        if (!data.success) return;
+        setTask(data.task);
+        finished = data.task.status !== 'running';
+        if (finished) clearInterval(interval);
+      } catch (err) {
+        console.error('Error polling clip export:', err);
+      }
+    };
+    const interval = setInterval(() => {
+      if (!finished) poll();
+    }, POLL_MS);
+    poll();
+    return () => clearInterval(interval);
+  }, [taskId]);
+
+  const handleExport = useCallback(async () => {
+    setIsStarting(true);
+    setError(null);
+    try {
+      const res = await fetch(`/api/videos/${video.id}/clip`, {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ start, end, options }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        setTaskId(data.taskId);
+        setOutputName(data.outputName);
+      } else {
+        setError(data.error || 'Failed to start export');
+      }
+    } catch (err) {
+      setError('Failed to start export');
+      console.error('Error starting clip export:', err);
+    } finally {
+      setIsStarting(false);
+    }
+  }, [video.id, start, end, options]);
+
+  const handleCancel = useCallback(async () => {
+    if (!taskId) return;
+    try {
+      await fetch(`/api/tasks/${encodeURIComponent(taskId)}/cancel`, { method: 'POST' });
+    } catch (err) {
+      console.error('Error cancelling clip export:', err);
+    }
+  }, [taskId]);
+
+  const update = (change: Partial<ClipExportOptions>) => {
+    setOptions((current) => {
+      const next = { ...current, ...change };
+      // WebM can only copy streams
+      if (next.container === 'webm') return { ...next, videoCodec: 'copy', audioCodec: 'copy' };
+      return next;
+    });
+  };
+
+  const frameRate = video.frameRate ?? DEFAULT_FRAME_RATE;
+  const isRunning = task?.status === 'running' || (taskId !== null && !task);
+  const reEncoding = options.videoCodec !== 'copy';
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/70"
+      onClick={(e) => {
+        e.stopPropagation();
+        onClose();
+      }}
+    >
+      <div
+        className="relative w-full max-w-md bg-card rounded-xl overflow-hidden shadow-2xl"
+        onClick={(e) => e.stopPropagation()}
+      >
+        <div className="p-6 border-b border-card-border">
+          <h2 className="text-xl font-semibold">Export clip</h2>
+          <p className="text-xs text-muted font-mono mt-1">
+            {formatTimecode(start, frameRate)} → {formatTimecode(end, frameRate)} ({(end - start).toFixed(1)}s)
+          </p>
+        </div>
+
+        <div className="p-6 space-y-4">
+          {!taskId ? (
+            <>
+              <label className="flex items-center justify-between text-sm">
+                Container
+                <select
+                  value={options.container}
+                  onChange={(e) => update({ container: e.target.value as ClipContainer })}
+                  className="px-2 py-1 bg-background border border-card-border rounded text-sm"
+                >
+                  {CONTAINERS.map((container) => (
+                    <option key={container} value={container}>.{container}</option>
+                  ))}
+                </select>
+              </label>
+              <label className="flex items-center justify-between text-sm">
+                Video
+                <select
+                  value={options.videoCodec}
+                  onChange={(e) => update({ videoCodec: e.target.value as ClipVideoCodec })}
+                  disabled={options.container === 'webm'}
+                  className="px-2 py-1 bg-background border border-card-border rounded text-sm disabled:opacity-50"
+                >
+                  {VIDEO_CODECS.map(({ value, label }) => (
+                    <option key={value} value={value}>{label}</option>
+                  ))}
+                </select>
+              </label>
+              <label className="flex items-center justify-between text-sm">
+                Audio
+                <select
+                  value={options.audioCodec}
+                  onChange={(e) => update({ audioCodec: e.target.value as ClipAudioCodec })}
+                  disabled={options.container === 'webm'}
+                  className="px-2 py-1 bg-background border border-card-border rounded text-sm disabled:opacity-50"
+                >
+                  {AUDIO_CODECS.map(({ value, label }) => (
+                    <option key={value} value={value}>{label}</option>
+                  ))}
+                </select>
+              </label>
+              {reEncoding && (
+                <label className="flex items-center justify-between text-sm">
+                  Quality (CRF, lower is better)
+                  <input
+                    type="number"
+                    min={0}
+                    max={51}
+                    value={options.crf}
+                    onChange={(e) => update({ crf: Math.round(Number(e.target.value)) })}
+                    className="w-16 px-2 py-1 bg-background border border-card-border rounded text-sm text-right tabular-nums"
+                  />
+                </label>
+              )}
+              <p className="text-xs text-muted">
+                {reEncoding
+                  ? 'Re-encoding cuts exactly at A but takes a while.'
+                  : 'Copying is fast, but the clip starts on the keyframe at or before A.'}
+                {' '}The file is saved next to the original and added to the catalog.
+              </p>
+              {error && <p className="text-xs text-error">{error}</p>}
+            </>
+          ) : (
+            <div className="space-y-2">
+              <p className="text-sm truncate" title={outputName ?? undefined}>{outputName}</p>
+              <div className="h-2 bg-card-border rounded-full overflow-hidden">
+                <div
+                  className={`h-full transition-all ${task?.status === 'error' ? 'bg-error' : task?.status === 'complete' ? 'bg-success' : 'bg-accent'}`}
+                  style={{ width: `${task?.done ?? 0}%` }}
+                />
+              </div>
+              <p className="text-xs text-muted">
+                {task?.status === 'complete' && 'Done'}
+                {task?.status === 'cancelled' && 'Cancelled'}
+                {isRunning && `${task?.done ?? 0}%`}
+              </p>
+              {task?.status === 'error' && (
+                <pre className="text-xs text-error whitespace-pre-wrap max-h-40 overflow-auto">{task.error}</pre>
+              )}
+            </div>
+          )}
+        </div>
+
+        <div className="flex justify-end gap-3 p-6 border-t border-card-border">
+          {isRunning ? (
+            <button
+              onClick={handleCancel}
+              className="px-4 py-2 text-sm rounded-lg bg-card-border text-muted hover:text-foreground"
+            >
+              Cancel export
+            </button>
+          ) : (
+            <button
+              onClick={onClose}
+              className="px-4 py-2 text-sm rounded-lg bg-card-border text-muted hover:text-foreground"
+            >
+              {taskId ? 'Close' : 'Cancel'}
+            </button>
+          )}
+          {!taskId && (
+            <button
+              onClick={handleExport}
+              disabled={isStarting}
+              className="px-4 py-2 text-sm rounded-lg bg-accent text-white hover:bg-accent-hover disabled:opacity-50"
+            >
+              {isStarting ? 'Starting…' : 'Export'}
+            </button>
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
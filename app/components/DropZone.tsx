@@ -4,13 +4,17 @@ import { useState, useCallback, useRef } from 'react';
 
 interface DropZoneProps {
   onDirectorySelected: (path: string) => void;
+  onPreview: (path: string) => void; // Dry-run scan
+  onStreamUrlSelected: (url: string) => void;
   currentPath: string | null;
   isScanning: boolean;
+  isPreviewing: boolean;
 }
 
-export default function DropZone({ onDirectorySelected, currentPath, isScanning }: DropZoneProps) {
+export default function DropZone({ onDirectorySelected, onPreview, onStreamUrlSelected, currentPath, isScanning, isPreviewing }: DropZoneProps) {
   const [isDragging, setIsDragging] = useState(false);
   const [manualPath, setManualPath] = useState(currentPath || '');
+  const [streamUrl, setStreamUrl] = useState('');
   const inputRef = useRef<HTMLInputElement>(null);
 
   const handleDragOver = useCallback((e: React.DragEvent) => {
@@ -64,6 +68,13 @@ export default function DropZone({ onDirectorySelected, currentPath, isScanning
     }
   }, [manualPath, isScanning, onDirectorySelected]);
 
+  const handleStreamSubmit = useCallback((e: React.FormEvent) => {
+    e.preventDefault();
+    if (streamUrl.trim()) {
+      onStreamUrlSelected(streamUrl.trim());
+    }
+  }, [streamUrl, onStreamUrlSelected]);
+
   return (
     <div className="w-full">
       <div
@@ -130,6 +141,45 @@ export default function DropZone({ onDirectorySelected, currentPath, isScanning
               >
                 {isScanning ? 'Scanning...' : 'Scan'}
               </button>
+              <button
+                type="button"
+                onClick={() => onPreview(manualPath.trim())}
+                disabled={!manualPath.trim() || isScanning || isPreviewing}
+                className="
+                  px-4 py-3 bg-card-border hover:bg-muted/30 text-foreground font-medium rounded-lg
+                  transition-colors disabled:opacity-50 disabled:cursor-not-allowed
+                "
+                title="Count new, cached and excluded files without indexing anything"
+              >
+                {isPreviewing ? 'Previewing...' : 'Preview scan'}
+              </button>
+            </div>
+          </form>
+
+          {/* Network stream (RTSP/HLS/DASH) */}
+          <form onSubmit={handleStreamSubmit} className="max-w-xl mx-auto mt-3">
+            <div className="flex gap-3">
+              <input
+                type="text"
+                value={streamUrl}
+                onChange={(e) => setStreamUrl(e.target.value)}
+                placeholder="or enter a stream URL (rtsp://, https://.../index.m3u8)"
+                className="
+                  flex-1 px-4 py-3 bg-background border border-card-border rounded-lg
+                  text-foreground placeholder:text-muted
+                  focus:outline-none focus:ring-2 focus:ring-accent focus:border-transparent
+                "
+              />
+              <button
+                type="submit"
+                disabled={!streamUrl.trim()}
+                className="
+                  px-6 py-3 bg-card-border hover:bg-muted/30 text-foreground font-medium rounded-lg
+                  transition-colors disabled:opacity-50 disabled:cursor-not-allowed
+                "
+              >
+                Open
+              </button>
             </div>
           </form>
 
@@ -0,0 +1,218 @@
+'use client';
+
+import { useState, useEffect, useCallback } from 'react';
+import { ShowToast } from './Toast';
+import { DuplicateGroup, DuplicateReport } from '@/app/lib/types';
+import { formatDuration, formatFileSize, getResolutionLabel } from '@/app/lib/utils';
+
+interface DuplicatesViewProps {
+  onClose: () => void;
+  onCatalogChanged: () => void; // Refresh the main grid after copies are removed
+  onToast: ShowToast;
+}
+
+export default function DuplicatesView({ onClose, onCatalogChanged, onToast }: DuplicatesViewProps) {
+  const [report, setReport] = useState<DuplicateReport | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [keepIds, setKeepIds] = useState<Record<string, string>>({}); // Group key -> video ID to keep
+  const [busyGroup, setBusyGroup] = useState<string | null>(null);
+
+  const fetchDuplicates = useCallback(async () => {
+    try {
+      const res = await fetch('/api/duplicates');
+      const data = await res.json();
+      if (data.success) {
+        setReport(data);
+        setError(null);
+      } else {
+        setError(data.error || 'Failed to find duplicates');
+      }
+    } catch (err) {
+      setError('Failed to find duplicates');
+      console.error('Error fetching duplicates:', err);
+    }
+  }, []);
+
+  useEffect(() => {
+    fetchDuplicates();
+  }, [fetchDuplicates]);
+
+  // Handle escape key to close
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        onClose();
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [onClose]);
+
+  const handleResolve = async (group: DuplicateGroup, trash: boolean) => {
+    const keepId = keepIds[group.key] ?? group.videos[0].id;
+    const removeIds = group.videos.filter((v) => v.id !== keepId).map((v) => v.id);
+
+    if (trash && !window.confirm(`Move ${removeIds.length} file${removeIds.length !== 1 ? 's' : ''} to the trash?`)) {
+      return;
+    }
+
+    setBusyGroup(group.key);
+    try {
+      const res = await fetch('/api/duplicates/resolve', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ removeIds, trash }),
+      });
+      const data = await res.json();
+
+      if (!data.success) {
+        onToast(data.error || 'Failed to remove duplicates', 'error');
+      } else if (data.errors.length > 0) {
+        onToast(`Removed ${data.removed}, ${data.errors.length} failed: ${data.errors[0]}`, 'error');
+      } else {
+        onToast(trash ? `Moved ${data.removed} to trash` : `Moved ${data.removed} to Recently Removed`, 'success');
+      }
+
+      await fetchDuplicates();
+      onCatalogChanged();
+    } catch (err) {
+      onToast('Failed to remove duplicates', 'error');
+      console.error('Error removing duplicates:', err);
+    } finally {
+      setBusyGroup(null);
+    }
+  };
+
+  const renderGroup = (group: DuplicateGroup) => {
+    const keepId = keepIds[group.key] ?? group.videos[0].id;
+    const isBusy = busyGroup === group.key;
+
+    return (
+      <div key={group.key} className="bg-background border border-card-border rounded-lg p-4">
+        <div className="flex items-center justify-between mb-3">
+          <span className="text-sm text-muted">
+            {group.videos.length} copies · {formatFileSize(group.wastedBytes)} reclaimable
+          </span>
+          <div className="flex gap-2">
+            <button
+              onClick={() => handleResolve(group, false)}
+              disabled={isBusy}
+              className="px-3 py-1.5 text-xs rounded-lg bg-card border border-card-border text-muted hover:text-foreground disabled:opacity-50"
+            >
+              Remove others from catalog
+            </button>
+            <button
+              onClick={() => handleResolve(group, true)}
+              disabled={isBusy}
+              className="px-3 py-1.5 text-xs rounded-lg bg-red-500/20 text-red-400 hover:bg-red-500/30 disabled:opacity-50"
+            >
+              Move others to trash
+            </button>
+          </div>
+        </div>
+
+        <div className="grid grid-cols-2 lg:grid-cols-4 gap-3">
+          {group.videos.map((video) => (
+            <label
+              key={video.id}
+              className={`
+                block rounded-lg border overflow-hidden cursor-pointer transition-colors
+                ${keepId === video.id ? 'border-accent ring-1 ring-accent' : 'border-card-border'}
+              `}
+            >
+              <div
+                className="aspect-video bg-black bg-cover bg-center"
+                style={video.thumbnailPath
+                  ? { backgroundImage: `url(/api/videos/${video.id}/thumbnail?v=${video.thumbnailVersion ?? 0})` }
+                  : undefined}
+              />
+              <div className="p-2 space-y-1 text-xs">
+                <div className="flex items-center gap-2">
+                  <input
+                    type="radio"
+                    name={`keep-${group.key}`}
+                    checked={keepId === video.id}
+                    onChange={() => setKeepIds((prev) => ({ ...prev, [group.key]: video.id }))}
+                  />
+                  <span className="font-medium">{keepId === video.id ? 'Keep' : 'Remove'}</span>
+                </div>
+                <p className="font-mono text-muted break-all" title={video.filePath}>{video.filePath}</p>
+                <p className="text-muted">
+                  {formatFileSize(video.fileSize)} · {new Date(video.createdAt).toLocaleDateString()}
+                  {' · '}
+                  {getResolutionLabel(video.width, video.height) ?? 'unknown'} · {formatDuration(video.duration)}
+                </p>
+              </div>
+            </label>
+          ))}
+        </div>
+      </div>
+    );
+  };
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/90"
+      onClick={onClose}
+    >
+      <div
+        className="relative w-full max-w-6xl h-[90vh] bg-card rounded-xl overflow-hidden shadow-2xl flex flex-col"
+        onClick={(e) => e.stopPropagation()}
+      >
+        {/* Summary header */}
+        <div className="flex items-center justify-between p-6 border-b border-card-border">
+          <div>
+            <h2 className="text-xl font-semibold">Duplicates</h2>
+            {report && (
+              <p className="text-sm text-muted">
+                {report.exact.length} duplicate group{report.exact.length !== 1 ? 's' : ''}
+                {' · '}
+                {formatFileSize(report.reclaimableBytes)} reclaimable
+                {report.skippedOnlineOnly > 0 && ` · ${report.skippedOnlineOnly} online-only skipped`}
+              </p>
+            )}
+          </div>
+          <button
+            onClick={onClose}
+            className="w-10 h-10 bg-black/50 hover:bg-black/70 rounded-full flex items-center justify-center transition-colors"
+          >
+            <svg className="w-6 h-6" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+              <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M6 18L18 6M6 6l12 12" />
+            </svg>
+          </button>
+        </div>
+
+        <div className="flex-1 overflow-auto p-6 space-y-6">
+          {error && <p className="text-error text-sm">{error}</p>}
+
+          {!report && !error && (
+            <div className="flex items-center gap-3 text-sm text-muted">
+              <div className="w-5 h-5 border-2 border-muted/40 border-t-accent rounded-full animate-spin" />
+              Comparing files...
+            </div>
+          )}
+
+          {report && report.exact.length === 0 && report.possible.length === 0 && (
+            <p className="text-sm text-muted">No duplicates found.</p>
+          )}
+
+          {report && report.exact.length > 0 && (
+            <div className="space-y-4">{report.exact.map(renderGroup)}</div>
+          )}
+
+          {/* Same duration and resolution, different content */}
+          {report && report.possible.length > 0 && (
+            <div>
+              <h3 className="text-sm font-semibold mb-1">Possible duplicates</h3>
+              <p className="text-xs text-muted mb-3">
+                Same duration and resolution but different file contents (e.g. re-encodes). Check before removing.
+              </p>
+              <div className="space-y-4">{report.possible.map(renderGroup)}</div>
+            </div>
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,262 @@
+'use client';
+
+import { useState, useEffect, useCallback } from 'react';
+import { Video } from '@/app/lib/types';
+import { buildFfmpegCommand, templateUsesTimes, CommandTemplate, ShellKind } from '@/app/lib/ffmpegCommand';
+import { formatTimecode, DEFAULT_FRAME_RATE } from '@/app/lib/utils';
+
+interface FfmpegCommandModalProps {
+  video: Video;
+  initialStart: number;
+  initialEnd: number;
+  getCurrentTime?: () => number; // Set when opened from the player, to take in/out points from it
+  onClose: () => void;
+}
+
+// Pick a template, fill in the clip's path (and in/out points) and copy the command
+export default function FfmpegCommandModal({ video, initialStart, initialEnd, getCurrentTime, onClose }: FfmpegCommandModalProps) {
+  const [templates, setTemplates] = useState<CommandTemplate[]>([]);
+  const [shell, setShell] = useState<ShellKind>('posix');
+  const [selected, setSelected] = useState(0);
+  const [start, setStart] = useState(initialStart);
+  const [end, setEnd] = useState(initialEnd);
+  const [copied, setCopied] = useState(false);
+  const [drafts, setDrafts] = useState<CommandTemplate[] | null>(null); // Non-null while editing
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    const fetchTemplates = async () => {
+      try {
+        const res = await fetch('/api/ffmpeg-templates');
+        const data = await res.json();
+        if (data.success) {
+          setTemplates(data.templates);
+          setShell(data.shell);
+        }
+      } catch (err) {
+        console.error('Error loading ffmpeg templates:', err);
+      }
+    };
+    fetchTemplates();
+  }, []);
+
+  // Escape closes this modal only, not the player underneath (capture runs before its listener)
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        e.stopPropagation();
+        onClose();
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown, true);
+    return () => window.removeEventListener('keydown', handleKeyDown, true);
+  }, [onClose]);
+
+  const template = templates[selected];
+  const command = template
+    ? buildFfmpegCommand(template.template, { input: video.filePath, start, end }, shell)
+    : '';
+
+  const handleCopy = useCallback(async () => {
+    try {
+      await navigator.clipboard.writeText(command);
+      setCopied(true);
+      setTimeout(() => setCopied(false), 1500);
+    } catch (err) {
+      console.error('Failed to copy ffmpeg command:', err);
+    }
+  }, [command]);
+
+  const saveTemplates = useCallback(async (method: 'PUT' | 'DELETE') => {
+    try {
+      const res = await fetch('/api/ffmpeg-templates', {
+        method,
+        headers: { 'Content-Type': 'application/json' },
+        body: method === 'PUT' ? JSON.stringify({ templates: drafts }) : undefined,
+      });
+      const data = await res.json();
+      if (data.success) {
+        setTemplates(data.templates);
+        setSelected((i) => Math.min(i, data.templates.length - 1));
+        setDrafts(null);
+        setError(null);
+      } else {
+        setError(data.error || 'Failed to save templates');
+      }
+    } catch (err) {
+      setError('Failed to save templates');
+      console.error('Error saving ffmpeg templates:', err);
+    }
+  }, [drafts]);
+
+  const updateDraft = (index: number, update: Partial<CommandTemplate>) => {
+    setDrafts((current) => current && current.map((draft, i) => (i === index ? { ...draft, ...update } : draft)));
+  };
+
+  const frameRate = video.frameRate ?? DEFAULT_FRAME_RATE;
+  const renderTimeField = (label: string, value: number, onChange: (value: number) => void) => (
+    <label className="flex items-center gap-2 text-xs text-muted">
+      {label}
+      <input
+        type="number"
+        min={0}
+        max={video.duration}
+        step={0.001}
+        value={value}
+        onChange={(e) => onChange(Math.max(0, parseFloat(e.target.value) || 0))}
+        className="w-28 px-2 py-1 bg-background border border-card-border rounded text-foreground tabular-nums"
+      />
+      <span className="font-mono">{formatTimecode(value, frameRate)}</span>
+      {getCurrentTime && (
+        <button
+          onClick={() => onChange(getCurrentTime())}
+          className="text-accent hover:text-accent-hover"
+        >
+          Set to current
+        </button>
+      )}
+    </label>
+  );
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/70"
+      onClick={(e) => {
+        e.stopPropagation();
+        onClose();
+      }}
+    >
+      <div
+        className="relative w-full max-w-2xl max-h-[80vh] bg-card rounded-xl overflow-hidden shadow-2xl flex flex-col"
+        onClick={(e) => e.stopPropagation()}
+      >
+        <div className="flex items-center justify-between p-6 border-b border-card-border">
+          <div className="min-w-0">
+            <h2 className="text-xl font-semibold">Copy ffmpeg command</h2>
+            <p className="text-xs text-muted truncate" title={video.filePath}>{video.fileName}</p>
+          </div>
+          <button
+            onClick={() => {
+              setDrafts(drafts ? null : templates);
+              setError(null);
+            }}
+            className="text-xs text-muted hover:text-foreground"
+          >
+            {drafts ? 'Done editing' : 'Edit templates'}
+          </button>
+        </div>
+
+        <div className="flex-1 overflow-auto p-6 space-y-4">
+          {drafts ? (
+            <>
+              <p className="text-xs text-muted">
+                Placeholders: {'{input}'} (clip path), {'{stem}'} (path without extension), {'{start}'} and {'{end}'} (seconds).
+                Words with a path are quoted automatically; other words can&apos;t contain spaces.
+              </p>
+              {drafts.map((draft, i) => (
+                <div key={i} className="space-y-1">
+                  <div className="flex items-center gap-2">
+                    <input
+                      value={draft.label}
+                      onChange={(e) => updateDraft(i, { label: e.target.value })}
+                      placeholder="Name"
+                      className="flex-1 px-2 py-1 bg-background border border-card-border rounded text-sm"
+                    />
+                    <button
+                      onClick={() => setDrafts(drafts.filter((_, j) => j !== i))}
+                      className="text-xs text-muted hover:text-error"
+                    >
+                      Remove
+                    </button>
+                  </div>
+                  <textarea
+                    value={draft.template}
+                    onChange={(e) => updateDraft(i, { template: e.target.value })}
+                    rows={2}
+                    spellCheck={false}
+                    className="w-full px-2 py-1 bg-background border border-card-border rounded text-xs font-mono resize-y"
+                  />
+                </div>
+              ))}
+              {error && <p className="text-xs text-error">{error}</p>}
+              <div className="flex items-center gap-3">
+                <button
+                  onClick={() => setDrafts([...drafts, { label: '', template: 'ffmpeg -i {input} ' }])}
+                  className="text-xs text-accent hover:text-accent-hover"
+                >
+                  + Add template
+                </button>
+                <div className="flex-1" />
+                <button
+                  onClick={() => saveTemplates('DELETE')}
+                  className="px-3 py-1.5 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+                >
+                  Reset to defaults
+                </button>
+                <button
+                  onClick={() => saveTemplates('PUT')}
+                  className="px-3 py-1.5 text-xs rounded-lg bg-accent text-white hover:bg-accent-hover"
+                >
+                  Save templates
+                </button>
+              </div>
+            </>
+          ) : (
+            <>
+              <div className="space-y-1">
+                {templates.map((entry, i) => (
+                  <label key={i} className="flex items-center gap-2 text-sm cursor-pointer">
+                    <input
+                      type="radio"
+                      checked={selected === i}
+                      onChange={() => setSelected(i)}
+                    />
+                    {entry.label}
+                  </label>
+                ))}
+              </div>
+
+              {template && templateUsesTimes(template.template) && (
+                <div className="space-y-2">
+                  {renderTimeField('Start', start, setStart)}
+                  {/\{end\}/.test(template.template) && renderTimeField('End', end, setEnd)}
+                  {/\{end\}/.test(template.template) && end <= start && (
+                    <p className="text-xs text-warning">End is not after start.</p>
+                  )}
+                </div>
+              )}
+
+              {command && (
+                <pre className="text-xs font-mono bg-background px-3 py-2 rounded whitespace-pre-wrap break-all select-all">
+                  {command}
+                </pre>
+              )}
+              <p className="text-xs text-muted">
+                Quoted for {shell === 'powershell' ? 'PowerShell' : 'POSIX shells (bash, zsh)'}.
+              </p>
+            </>
+          )}
+        </div>
+
+        {!drafts && (
+          <div className="flex justify-end gap-3 p-6 border-t border-card-border">
+            <button
+              onClick={onClose}
+              className="px-4 py-2 text-sm rounded-lg bg-card-border text-muted hover:text-foreground"
+            >
+              Close
+            </button>
+            <button
+              onClick={handleCopy}
+              disabled={!command}
+              className={`px-4 py-2 text-sm rounded-lg text-white disabled:opacity-50 ${copied ? 'bg-success' : 'bg-accent hover:bg-accent-hover'}`}
+            >
+              {copied ? 'Copied' : 'Copy command'}
+            </button>
+          </div>
+        )}
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,127 @@
+'use client';
+
+import { useState, useEffect } from 'react';
+import { formatDuration } from '@/app/lib/utils';
+
+interface FilmStripProps {
+  videoId: string;
+  duration: number;
+  spriteUrl: string | null;
+  spriteColumns: number | null;
+  spriteRows: number | null;
+  spriteFrames: number | null;
+  playhead: number; // Seconds; the frame covering it is highlighted
+  onSeek: (time: number) => void;
+}
+
+// Frames in the strip; fixed so resizing the player only stretches them
+const FILM_STRIP_FRAMES = 12;
+// Frames decoded at once when there's no sprite sheet
+const DECODE_CONCURRENCY = 2;
+// Videos whose decoded frames are kept for reopening
+const FRAME_CACHE_VIDEOS = 8;
+
+// Video id -> object URLs of decoded frames (sparse while loading). Insertion order is
+// the eviction order.
+const frameCache = new Map<string, (string | undefined)[]>();
+
+function getCachedFrames(videoId: string): (string | undefined)[] {
+  let frames = frameCache.get(videoId);
+  if (frames) {
+    frameCache.delete(videoId);
+  } else {
+    frames = new Array(FILM_STRIP_FRAMES);
+  }
+  frameCache.set(videoId, frames);
+
+  for (const [id, evicted] of frameCache) {
+    if (frameCache.size <= FRAME_CACHE_VIDEOS) break;
+    evicted.forEach((url) => url && URL.revokeObjectURL(url));
+    frameCache.delete(id);
+  }
+  return frames;
+}
+
+// Middle of each equal slice of the clip
+const frameTime = (index: number, duration: number) => ((index + 0.5) * duration) / FILM_STRIP_FRAMES;
+
+// Representative frames across the timeline under the seek bar; click one to jump there.
+// Tiles come from the hover sprite sheet when there is one, otherwise keyframes are
+// decoded on the server a couple at a time.
+export default function FilmStrip({
+  videoId,
+  duration,
+  spriteUrl,
+  spriteColumns,
+  spriteRows,
+  spriteFrames,
+  playhead,
+  onSeek,
+}: FilmStripProps) {
+  const hasGrid = !!(spriteUrl && spriteColumns && spriteRows && spriteFrames);
+  const [frames, setFrames] = useState<(string | undefined)[]>([]);
+
+  useEffect(() => {
+    if (hasGrid) return;
+
+    const cached = getCachedFrames(videoId);
+    setFrames([...cached]);
+    let cancelled = false;
+
+    const pending = Array.from({ length: FILM_STRIP_FRAMES }, (_, i) => i).filter((i) => !cached[i]);
+    const decodeNext = async (): Promise<void> => {
+      const index = pending.shift();
+      if (index === undefined || cancelled) return;
+      try {
+        const res = await fetch(`/api/videos/${videoId}/frame?time=${frameTime(index, duration)}&width=320&keyframe=1`);
+        if (res.ok) {
+          cached[index] = URL.createObjectURL(await res.blob());
+          if (!cancelled) setFrames([...cached]);
+        }
+      } catch (err) {
+        console.error('Error loading film strip frame:', err);
+      }
+      return decodeNext();
+    };
+    for (let i = 0; i < DECODE_CONCURRENCY; i++) decodeNext();
+
+    // Frames already requested still land in the cache for the next open
+    return () => {
+      cancelled = true;
+    };
+  }, [videoId, duration, hasGrid]);
+
+  const current = Math.min(FILM_STRIP_FRAMES - 1, Math.floor((playhead / duration) * FILM_STRIP_FRAMES));
+
+  return (
+    <div className="flex h-12 bg-black/40 gap-px">
+      {Array.from({ length: FILM_STRIP_FRAMES }, (_, i) => {
+        const time = frameTime(i, duration);
+        let style: React.CSSProperties;
+        if (hasGrid) {
+          const frame = Math.min(Math.floor((i + 0.5) * spriteFrames! / FILM_STRIP_FRAMES), spriteFrames! - 1);
+          const column = frame % spriteColumns!;
+          const row = Math.floor(frame / spriteColumns!);
+          style = {
+            backgroundImage: `url(${spriteUrl})`,
+            backgroundSize: `${spriteColumns! * 100}% ${spriteRows! * 100}%`,
+            backgroundPosition: `${spriteColumns! > 1 ? (column / (spriteColumns! - 1)) * 100 : 0}% ${spriteRows! > 1 ? (row / (spriteRows! - 1)) * 100 : 0}%`,
+          };
+        } else {
+          style = frames[i] ? { backgroundImage: `url(${frames[i]})`, backgroundSize: 'cover', backgroundPosition: 'center' } : {};
+        }
+        return (
+          <button
+            key={i}
+            onClick={() => onSeek(time)}
+            title={formatDuration(time)}
+            className={`flex-1 min-w-0 h-full bg-white/5 transition-opacity ${
+              i === current ? 'ring-2 ring-inset ring-accent' : 'opacity-60 hover:opacity-100'
+            }`}
+            style={style}
+          />
+        );
+      })}
+    </div>
+  );
+}
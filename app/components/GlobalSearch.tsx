@@ -0,0 +1,134 @@
+'use client';
+
+import { useState, useEffect, useRef } from 'react';
+import { GlobalSearchResult } from '@/app/lib/types';
+import { formatDuration, formatFileSize } from '@/app/lib/utils';
+
+interface GlobalSearchProps {
+  currentPath: string | null;
+  onOpenResult: (result: GlobalSearchResult) => void;
+  onClose: () => void;
+}
+
+// Wait for typing to pause before querying every library
+const SEARCH_DEBOUNCE_MS = 250;
+
+export default function GlobalSearch({ currentPath, onOpenResult, onClose }: GlobalSearchProps) {
+  const [query, setQuery] = useState('');
+  const [results, setResults] = useState<GlobalSearchResult[]>([]);
+  const [isSearching, setIsSearching] = useState(false);
+  const inputRef = useRef<HTMLInputElement>(null);
+
+  useEffect(() => {
+    inputRef.current?.focus();
+  }, []);
+
+  // Handle escape key to close
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        onClose();
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [onClose]);
+
+  useEffect(() => {
+    if (!query.trim()) {
+      setResults([]);
+      return;
+    }
+
+    let cancelled = false;
+    const timer = setTimeout(async () => {
+      setIsSearching(true);
+      try {
+        const res = await fetch(`/api/search?q=${encodeURIComponent(query)}`);
+        const data = await res.json();
+        if (!cancelled && data.success) {
+          setResults(data.results);
+        }
+      } catch (error) {
+        console.error('Global search failed:', error);
+      } finally {
+        if (!cancelled) setIsSearching(false);
+      }
+    }, SEARCH_DEBOUNCE_MS);
+
+    return () => {
+      cancelled = true;
+      clearTimeout(timer);
+    };
+  }, [query]);
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-start justify-center bg-black/80 pt-[10vh]"
+      onClick={onClose}
+    >
+      <div
+        className="w-full max-w-5xl max-h-[80vh] bg-card rounded-xl shadow-2xl flex flex-col overflow-hidden"
+        onClick={(e) => e.stopPropagation()}
+      >
+        <div className="flex items-center gap-3 p-4 border-b border-card-border">
+          <svg className="w-5 h-5 text-muted shrink-0" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+            <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z" />
+          </svg>
+          <input
+            ref={inputRef}
+            type="text"
+            value={query}
+            onChange={(e) => setQuery(e.target.value)}
+            placeholder="Search all libraries..."
+            className="flex-1 bg-transparent text-foreground placeholder:text-muted focus:outline-none"
+          />
+          {isSearching && (
+            <div className="w-4 h-4 border-2 border-muted/40 border-t-accent rounded-full animate-spin" />
+          )}
+        </div>
+
+        <div className="flex-1 overflow-auto p-4">
+          {query.trim() && !isSearching && results.length === 0 && (
+            <p className="text-sm text-muted">No matches in any library.</p>
+          )}
+
+          <div className="grid grid-cols-2 md:grid-cols-4 gap-4">
+            {results.map((result) => (
+              <button
+                key={`${result.libraryPath}:${result.video.id}`}
+                onClick={() => onOpenResult(result)}
+                className="text-left rounded-lg overflow-hidden bg-background border border-card-border hover:border-accent transition-colors"
+              >
+                <div
+                  className="relative aspect-video bg-black bg-cover bg-center"
+                  style={{
+                    backgroundImage: `url("/api/search/thumbnail?library=${encodeURIComponent(result.libraryPath)}&id=${encodeURIComponent(result.video.id)}")`,
+                  }}
+                >
+                  <span
+                    className={`
+                      absolute top-2 left-2 px-2 py-0.5 rounded text-xs max-w-[80%] truncate
+                      ${result.libraryPath === currentPath ? 'bg-accent text-white' : 'bg-black/70 text-white'}
+                    `}
+                    title={result.libraryPath}
+                  >
+                    {result.libraryName}
+                  </span>
+                  <span className="absolute bottom-2 right-2 bg-black/70 px-1.5 py-0.5 rounded text-xs">
+                    {formatDuration(result.video.duration)}
+                  </span>
+                </div>
+                <div className="p-2">
+                  <p className="text-sm truncate" title={result.video.fileName}>{result.video.fileName}</p>
+                  <p className="text-xs text-muted">{formatFileSize(result.video.fileSize)}</p>
+                </div>
+              </button>
+            ))}
+          </div>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -1,67 +1,228 @@
 'use client';
 
 import { useState, useCallback, useRef, useEffect } from 'react';
+import ZoomPreview from './ZoomPreview';
+import { scrubVideoPool } from '@/app/lib/scrubVideoPool';
 
 interface HoverScrubberProps {
   videoId: string;
   thumbnailUrl: string;
+  hasThumbnail: boolean;
   spriteUrl: string | null;
   duration: number;
   hasSprite: boolean;
   hasProxy: boolean;
+  fileName: string; // Shown in the Alt zoom preview, with the dimensions below
+  width: number;
+  height: number;
+  onlineOnly?: boolean; // The file's data is only in the cloud; decoding it would download it
+  children?: React.ReactNode; // Overlays (badges, buttons) drawn over the thumbnail
 }
 
+// Keyframe fallback: frame width, and positions across the card (limits requests per sweep)
+const FALLBACK_FRAME_WIDTH = 480;
+const FALLBACK_FRAME_STEPS = 40;
+// Scrub positions across the card; smaller moves don't re-render or seek
+const SCRUB_STEPS = 200;
+
 export default function HoverScrubber({
   videoId,
   thumbnailUrl,
+  hasThumbnail,
   duration,
   hasProxy,
+  fileName,
+  width,
+  height,
+  onlineOnly = false,
+  children,
 }: HoverScrubberProps) {
   const containerRef = useRef<HTMLDivElement>(null);
   const videoRef = useRef<HTMLVideoElement>(null);
   const [isHovering, setIsHovering] = useState(false);
   const [scrubPosition, setScrubPosition] = useState(0);
-  const [currentTime, setCurrentTime] = useState(0);
   const [videoReady, setVideoReady] = useState(false);
+  // Once opened, the scrub video stays mounted so re-hovering reuses it instead of re-opening
+  // the file, until the pool releases it for more recently hovered cards
+  const [hasOpenedVideo, setHasOpenedVideo] = useState(false);
+  const poolOwnerRef = useRef({});
+  // Hover start and whether the video was already open, for the scrub-open measures
+  const openTimingRef = useRef<{ start: number; reused: boolean } | null>(null);
+  // The browser can't decode some originals (ProRes, MXF, AVCHD without a proxy); those
+  // scrub through keyframes decoded server-side instead, one request in flight at a time
+  const [useFrameFallback, setUseFrameFallback] = useState(false);
+  const [frameUrl, setFrameUrl] = useState<string | null>(null);
+  const [frameReady, setFrameReady] = useState(false);
+  const frameLoadingRef = useRef(false);
+  const queuedFrameRef = useRef<string | null>(null);
+  // Card bounds while Alt is held over it; null hides the zoom preview
+  const [zoomAnchor, setZoomAnchor] = useState<DOMRect | null>(null);
+  // Mouse moves are applied once per animation frame, and the scrub video gets one seek
+  // at a time; a parked cursor or a static frame then costs nothing
+  const pendingMoveRef = useRef<{ clientX: number; altKey: boolean } | null>(null);
+  const moveFrameRef = useRef<number | null>(null);
+  const pendingSeekRef = useRef<number | null>(null);
 
   // Video URL for scrubbing (use proxy if available)
   const videoUrl = hasProxy
     ? `/api/videos/${videoId}/stream?type=proxy`
     : `/api/videos/${videoId}/stream?type=original`;
 
-  // Seek video when scrub position changes
+  const currentTime = scrubPosition * duration;
+
+  // Seek video when scrub position changes; while a seek is decoding, only the latest
+  // position is kept and applied when it lands
+  useEffect(() => {
+    const video = videoRef.current;
+    if (!video || !videoReady || !isHovering) return;
+    if (video.seeking) {
+      pendingSeekRef.current = currentTime;
+    } else {
+      video.currentTime = currentTime;
+    }
+  }, [currentTime, videoReady, isHovering]);
+
+  const handleSeeked = useCallback(() => {
+    // First frame at the hovered position: how long a fresh open took against a reused video
+    // (Performance panel in dev builds, e.g. hover a large file twice)
+    const timing = openTimingRef.current;
+    openTimingRef.current = null;
+    if (timing && process.env.NODE_ENV !== 'production') {
+      performance.measure(timing.reused ? 'scrub-open-reused' : 'scrub-open-fresh', { start: timing.start });
+    }
+
+    const next = pendingSeekRef.current;
+    pendingSeekRef.current = null;
+    if (next !== null && videoRef.current && videoRef.current.currentTime !== next) {
+      videoRef.current.currentTime = next;
+    }
+  }, []);
+
   useEffect(() => {
-    if (videoRef.current && videoReady && isHovering) {
-      const seekTime = scrubPosition * duration;
-      videoRef.current.currentTime = seekTime;
+    const owner = poolOwnerRef.current;
+    return () => {
+      if (moveFrameRef.current !== null) cancelAnimationFrame(moveFrameRef.current);
+      scrubVideoPool.forget(owner);
+    };
+  }, []);
+
+  // Unload the scrub video when the pool evicts this card; dropping the source first frees
+  // the decoder right away instead of whenever the element is collected
+  const releaseVideo = useCallback(() => {
+    const video = videoRef.current;
+    if (video) {
+      video.removeAttribute('src');
+      video.load();
     }
-  }, [scrubPosition, duration, videoReady, isHovering]);
+    setHasOpenedVideo(false);
+    setVideoReady(false);
+  }, []);
+
+  // Request the keyframe for the current step; a newer step replaces any queued one
+  const fallbackStep = Math.round(scrubPosition * FALLBACK_FRAME_STEPS);
+  useEffect(() => {
+    if (!useFrameFallback || !isHovering) return;
+    const time = (fallbackStep / FALLBACK_FRAME_STEPS) * duration;
+    const url = `/api/videos/${videoId}/frame?time=${time.toFixed(2)}&keyframe=1&width=${FALLBACK_FRAME_WIDTH}`;
+    if (frameLoadingRef.current) {
+      queuedFrameRef.current = url;
+      return;
+    }
+    frameLoadingRef.current = true;
+    setFrameUrl(url);
+  }, [useFrameFallback, isHovering, fallbackStep, duration, videoId]);
+
+  const handleFrameSettled = useCallback((loaded: boolean) => {
+    if (loaded) setFrameReady(true);
+    const next = queuedFrameRef.current;
+    queuedFrameRef.current = null;
+    frameLoadingRef.current = next !== null;
+    if (next) setFrameUrl(next);
+  }, []);
+
+  // The whole card, so the zoom preview doesn't cover its info line either
+  const getCardRect = useCallback((): DOMRect | null => {
+    const container = containerRef.current;
+    if (!container) return null;
+    return (container.closest('[data-video-card]') ?? container).getBoundingClientRect();
+  }, []);
+
+  // Alt can be pressed or released without moving the mouse
+  useEffect(() => {
+    if (!isHovering) return;
+
+    const handleKey = (e: KeyboardEvent) => {
+      if (e.key !== 'Alt') return;
+      e.preventDefault(); // Keep Alt from focusing the browser menu bar on Windows
+      setZoomAnchor(e.type === 'keydown' ? getCardRect() : null);
+    };
+    window.addEventListener('keydown', handleKey);
+    window.addEventListener('keyup', handleKey);
+    return () => {
+      window.removeEventListener('keydown', handleKey);
+      window.removeEventListener('keyup', handleKey);
+    };
+  }, [isHovering, getCardRect]);
 
   const handleMouseMove = useCallback((e: React.MouseEvent) => {
     if (!containerRef.current) return;
 
-    const rect = containerRef.current.getBoundingClientRect();
-    const x = e.clientX - rect.left;
-    const position = Math.max(0, Math.min(1, x / rect.width));
+    // Freeze the preview while the pointer is over an overlay button
+    if ((e.target as HTMLElement).closest('[data-scrub-ignore]')) return;
+
+    pendingMoveRef.current = { clientX: e.clientX, altKey: e.altKey };
+    if (moveFrameRef.current !== null) return;
+
+    moveFrameRef.current = requestAnimationFrame(() => {
+      moveFrameRef.current = null;
+      const move = pendingMoveRef.current;
+      const container = containerRef.current;
+      if (!move || !container) return;
+
+      const rect = container.getBoundingClientRect();
+      const x = move.clientX - rect.left;
+      const position = Math.round(Math.max(0, Math.min(1, x / rect.width)) * SCRUB_STEPS) / SCRUB_STEPS;
 
-    setScrubPosition(position);
-    setCurrentTime(position * duration);
-  }, [duration]);
+      // Same values bail out of the re-render
+      setScrubPosition(position);
+      setZoomAnchor((anchor) => (move.altKey ? anchor ?? getCardRect() : null));
+    });
+  }, [getCardRect]);
 
   const handleMouseEnter = useCallback(() => {
+    openTimingRef.current = { start: performance.now(), reused: videoReady };
     setIsHovering(true);
-  }, []);
+    setHasOpenedVideo(true);
+    scrubVideoPool.retain(poolOwnerRef.current, releaseVideo);
+  }, [videoReady, releaseVideo]);
 
   const handleMouseLeave = useCallback(() => {
+    if (moveFrameRef.current !== null) {
+      cancelAnimationFrame(moveFrameRef.current);
+      moveFrameRef.current = null;
+    }
+    pendingMoveRef.current = null;
+    pendingSeekRef.current = null;
+    openTimingRef.current = null;
     setIsHovering(false);
     setScrubPosition(0);
-    setVideoReady(false);
+    setZoomAnchor(null);
+    // Reset position rather than unloading the video
+    if (videoRef.current) {
+      videoRef.current.currentTime = 0;
+    }
   }, []);
 
   const handleVideoLoaded = useCallback(() => {
     setVideoReady(true);
   }, []);
 
+  const handleVideoError = useCallback(() => {
+    setUseFrameFallback(true);
+  }, []);
+
+  const isPreviewShown = isHovering && (videoReady || (useFrameFallback && frameReady));
+
   // Format time display
   const formatTime = (seconds: number): string => {
     const mins = Math.floor(seconds / 60);
@@ -69,6 +230,31 @@ export default function HoverScrubber({
     return `${mins}:${secs.toString().padStart(2, '0')}`;
   };
 
+  // Nothing is decoded for an online-only file; hovering explains why there is no preview
+  if (onlineOnly) {
+    return (
+      <div
+        ref={containerRef}
+        className="absolute inset-0 overflow-hidden"
+        onMouseEnter={() => setIsHovering(true)}
+        onMouseLeave={() => setIsHovering(false)}
+      >
+        {hasThumbnail && (
+          <div className="absolute inset-0 bg-cover bg-center" style={{ backgroundImage: `url(${thumbnailUrl})` }} />
+        )}
+        <div className="absolute inset-0 flex flex-col items-center justify-center gap-1 p-4 text-center bg-black/40">
+          <span className="text-2xl text-white/80">☁</span>
+          {isHovering && (
+            <p className="text-xs text-white/80">
+              Online-only: the file isn&apos;t downloaded. Download it, then rescan to preview it here.
+            </p>
+          )}
+        </div>
+        {children}
+      </div>
+    );
+  }
+
   return (
     <div
       ref={containerRef}
@@ -78,25 +264,52 @@ export default function HoverScrubber({
       onMouseLeave={handleMouseLeave}
     >
       {/* Thumbnail layer (visible when not hovering or video not ready) */}
-      <div
-        className="absolute inset-0 bg-cover bg-center transition-opacity duration-150"
-        style={{
-          backgroundImage: `url(${thumbnailUrl})`,
-          opacity: isHovering && videoReady ? 0 : 1,
-        }}
-      />
+      {hasThumbnail ? (
+        <div
+          className="absolute inset-0 bg-cover bg-center transition-opacity duration-150"
+          style={{
+            backgroundImage: `url(${thumbnailUrl})`,
+            opacity: isPreviewShown ? 0 : 1,
+          }}
+        />
+      ) : (
+        // No generated thumbnail (e.g. FFmpeg failed): let the browser decode a frame at 10%
+        <video
+          src={`${videoUrl}#t=${(duration * 0.1).toFixed(2)}`}
+          className="absolute inset-0 w-full h-full object-cover transition-opacity duration-150"
+          style={{ opacity: isPreviewShown ? 0 : 1 }}
+          muted
+          playsInline
+          preload="metadata"
+        />
+      )}
+
+      {/* Keyframe scrub layer, for originals the browser can't decode */}
+      {useFrameFallback && frameUrl && (
+        // eslint-disable-next-line @next/next/no-img-element
+        <img
+          src={frameUrl}
+          alt=""
+          className="absolute inset-0 w-full h-full object-cover"
+          style={{ opacity: isHovering && frameReady ? 1 : 0 }}
+          onLoad={() => handleFrameSettled(true)}
+          onError={() => handleFrameSettled(false)}
+        />
+      )}
 
       {/* Video scrub layer (visible on hover) */}
-      {isHovering && (
+      {hasOpenedVideo && !useFrameFallback && (
         <video
           ref={videoRef}
           src={videoUrl}
           className="absolute inset-0 w-full h-full object-cover"
-          style={{ opacity: videoReady ? 1 : 0 }}
+          style={{ opacity: isHovering && videoReady ? 1 : 0 }}
           muted
           playsInline
           preload="auto"
           onLoadedData={handleVideoLoaded}
+          onSeeked={handleSeeked}
+          onError={handleVideoError}
         />
       )}
 
@@ -118,11 +331,26 @@ export default function HoverScrubber({
       )}
 
       {/* Loading indicator */}
-      {isHovering && !videoReady && (
+      {isHovering && !isPreviewShown && (
         <div className="absolute inset-0 flex items-center justify-center bg-black/30">
           <div className="w-6 h-6 border-2 border-white/30 border-t-white rounded-full animate-spin" />
         </div>
       )}
+
+      {/* Larger preview beside the card while Alt is held */}
+      {isHovering && zoomAnchor && (
+        <ZoomPreview
+          videoUrl={videoUrl}
+          anchorRect={zoomAnchor}
+          time={currentTime}
+          fileName={fileName}
+          duration={duration}
+          width={width}
+          height={height}
+        />
+      )}
+
+      {children}
     </div>
   );
 }
@@ -0,0 +1,159 @@
+'use client';
+
+import { useState, useEffect, useRef } from 'react';
+import { LibraryHealth as LibraryHealthInfo } from '@/app/lib/types';
+import { formatCodecName, formatDuration, formatFileSize } from '@/app/lib/utils';
+
+interface LibraryHealthProps {
+  refreshKey: number; // Recompute when this changes (scan finished, thumbnails regenerated)
+  onVerify: () => void;
+  codecFilter: string | null; // Codec the grid is filtered to ('' for unknown), null for none
+  onFilterCodec: (codec: string | null) => void;
+}
+
+const COMPONENTS: { key: keyof Pick<LibraryHealthInfo, 'thumbnails' | 'sprites' | 'metadata' | 'onDisk'>; label: string }[] = [
+  { key: 'thumbnails', label: 'Thumbnails' },
+  { key: 'sprites', label: 'Scrub sprites' },
+  { key: 'metadata', label: 'Resolution known' },
+  { key: 'onDisk', label: 'Files on disk' },
+];
+
+function getHealthColor(score: number): string {
+  if (score > 0.9) return 'bg-success';
+  if (score >= 0.6) return 'bg-warning';
+  return 'bg-error';
+}
+
+const formatPercent = (value: number) => `${Math.floor(value * 100)}%`;
+
+// Toolbar dot for the library's health score; click for the stats behind it
+export default function LibraryHealth({ refreshKey, onVerify, codecFilter, onFilterCodec }: LibraryHealthProps) {
+  const [health, setHealth] = useState<LibraryHealthInfo | null>(null);
+  const [isOpen, setIsOpen] = useState(false);
+  const panelRef = useRef<HTMLDivElement>(null);
+
+  useEffect(() => {
+    const fetchHealth = async () => {
+      try {
+        const res = await fetch('/api/health');
+        const data = await res.json();
+        if (data.success) setHealth(data.health);
+      } catch (error) {
+        console.error('Error fetching library health:', error);
+      }
+    };
+
+    fetchHealth();
+  }, [refreshKey]);
+
+  // Close panel when clicking outside
+  useEffect(() => {
+    const handleClickOutside = (e: MouseEvent) => {
+      if (panelRef.current && !panelRef.current.contains(e.target as Node)) {
+        setIsOpen(false);
+      }
+    };
+    if (isOpen) {
+      document.addEventListener('mousedown', handleClickOutside);
+    }
+    return () => document.removeEventListener('mousedown', handleClickOutside);
+  }, [isOpen]);
+
+  if (!health) return null;
+
+  const breakdown = [
+    `Library health: ${formatPercent(health.score)}`,
+    ...COMPONENTS.map(({ key, label }) => `${label}: ${formatPercent(health[key])}`),
+  ].join('\n');
+
+  return (
+    <div ref={panelRef} className="relative">
+      <button
+        onClick={() => setIsOpen(!isOpen)}
+        className="h-9 px-3 rounded-lg flex items-center gap-2 bg-card-border text-muted hover:text-foreground transition-colors text-sm"
+        title={breakdown}
+      >
+        <span className={`w-3 h-3 rounded-full ${getHealthColor(health.score)}`} />
+        <span className="tabular-nums">{formatPercent(health.score)}</span>
+      </button>
+
+      {isOpen && (
+        <div className="absolute right-0 top-full mt-2 bg-card border border-card-border rounded-lg shadow-xl p-4 z-50 w-[300px] space-y-4">
+          <div>
+            <h3 className="text-sm font-semibold">Library stats</h3>
+            <p className="text-xs text-muted mt-1">
+              {health.total} videos · {formatDuration(health.totalDuration)} · {formatFileSize(health.totalSize)}
+            </p>
+          </div>
+
+          <ul className="space-y-2">
+            {COMPONENTS.map(({ key, label }) => (
+              <li key={key}>
+                <div className="flex items-center justify-between text-xs">
+                  <span>{label}</span>
+                  <span className="tabular-nums text-muted">
+                    {Math.round(health[key] * health.total)} / {health.total}
+                  </span>
+                </div>
+                <div className="h-1.5 bg-card-border rounded-full overflow-hidden mt-1">
+                  <div className={`h-full ${getHealthColor(health[key])}`} style={{ width: `${health[key] * 100}%` }} />
+                </div>
+              </li>
+            ))}
+          </ul>
+
+          {health.codecs.length > 0 && (
+            <div>
+              <h3 className="text-xs text-muted mb-2">Codecs (click to filter)</h3>
+              <ul className="space-y-1">
+                {health.codecs.map(({ codec, count }) => {
+                  const value = codec ?? '';
+                  const isActive = codecFilter === value;
+                  return (
+                    <li key={value}>
+                      <button
+                        onClick={() => {
+                          onFilterCodec(isActive ? null : value);
+                          setIsOpen(false);
+                        }}
+                        className={`w-full flex items-center gap-2 text-xs rounded px-1 py-0.5 ${
+                          isActive ? 'bg-accent/20 text-foreground' : 'hover:bg-card-border'
+                        }`}
+                        title={isActive ? 'Show all codecs' : `Show only ${formatCodecName(codec)}`}
+                      >
+                        <span className="w-20 shrink-0 text-left truncate">{formatCodecName(codec)}</span>
+                        <span className="flex-1 h-2.5">
+                          <span
+                            className={`block h-full rounded-sm ${codec ? 'bg-accent' : 'bg-muted/50'}`}
+                            style={{ width: `${(count / health.codecs[0].count) * 100}%` }}
+                          />
+                        </span>
+                        <span className="w-10 shrink-0 text-right tabular-nums text-muted">{count}</span>
+                      </button>
+                    </li>
+                  );
+                })}
+              </ul>
+            </div>
+          )}
+
+          <p className="text-xs text-muted">
+            The score is the average of these. Missing thumbnails and sprites are made by the next scan.
+          </p>
+
+          {health.onDisk < 1 && (
+            <button
+              onClick={() => {
+                setIsOpen(false);
+                onVerify();
+              }}
+              className="px-3 py-1.5 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+            >
+              Verify files…
+            </button>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,173 @@
+'use client';
+
+import { useState, useEffect, useRef, useCallback } from 'react';
+import { LogEntry, LogLevel } from '@/app/lib/types';
+
+interface LogViewerProps {
+  onClose: () => void;
+}
+
+// How often the log is re-read while the viewer is open
+const POLL_INTERVAL_MS = 2000;
+const COPY_LINE_COUNT = 200;
+
+const LEVEL_ORDER: LogLevel[] = ['debug', 'info', 'warn', 'error'];
+
+const LEVEL_FILTERS: { value: LogLevel; label: string }[] = [
+  { value: 'debug', label: 'All' },
+  { value: 'info', label: 'Info and above' },
+  { value: 'warn', label: 'Warnings and errors' },
+  { value: 'error', label: 'Errors only' },
+];
+
+const LEVEL_CLASSES: Record<LogLevel, string> = {
+  debug: 'text-muted/60',
+  info: 'text-muted',
+  warn: 'text-warning',
+  error: 'text-error',
+};
+
+function formatEntry(entry: LogEntry): string {
+  return `${entry.time} ${entry.level.toUpperCase()} ${entry.message}`;
+}
+
+// Tails today's server log file
+export default function LogViewer({ onClose }: LogViewerProps) {
+  const [entries, setEntries] = useState<LogEntry[]>([]);
+  const [logPath, setLogPath] = useState('');
+  const [error, setError] = useState<string | null>(null);
+  const [minLevel, setMinLevel] = useState<LogLevel>('info');
+  const [autoScroll, setAutoScroll] = useState(true);
+  const [copied, setCopied] = useState(false);
+  const listRef = useRef<HTMLDivElement>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+
+    const fetchLogs = async () => {
+      try {
+        const res = await fetch('/api/logs');
+        const data = await res.json();
+        if (cancelled) return;
+
+        if (data.success) {
+          setEntries(data.entries);
+          setLogPath(data.path);
+          setError(null);
+        } else {
+          setError(data.error || 'Failed to load logs');
+        }
+      } catch (err) {
+        if (!cancelled) setError('Failed to load logs');
+        console.error('Error fetching logs:', err);
+      }
+    };
+
+    fetchLogs();
+    const interval = setInterval(fetchLogs, POLL_INTERVAL_MS);
+    return () => {
+      cancelled = true;
+      clearInterval(interval);
+    };
+  }, []);
+
+  // Handle escape key to close
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        onClose();
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [onClose]);
+
+  const visibleEntries = entries.filter(
+    (entry) => LEVEL_ORDER.indexOf(entry.level) >= LEVEL_ORDER.indexOf(minLevel)
+  );
+
+  useEffect(() => {
+    if (autoScroll && listRef.current) {
+      listRef.current.scrollTop = listRef.current.scrollHeight;
+    }
+  }, [visibleEntries.length, autoScroll]);
+
+  // Unfiltered, so a bug report has the context around the error
+  const handleCopy = useCallback(async () => {
+    const text = entries.slice(-COPY_LINE_COUNT).map(formatEntry).join('\n');
+    try {
+      await navigator.clipboard.writeText(text);
+      setCopied(true);
+      setTimeout(() => setCopied(false), 2000);
+    } catch (err) {
+      console.error('Error copying logs:', err);
+    }
+  }, [entries]);
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/90"
+      onClick={onClose}
+    >
+      <div
+        className="relative w-full max-w-5xl h-[80vh] bg-card rounded-xl overflow-hidden shadow-2xl flex flex-col"
+        onClick={(e) => e.stopPropagation()}
+      >
+        <div className="flex items-center gap-3 p-6 border-b border-card-border">
+          <div className="flex-1 min-w-0">
+            <h2 className="text-xl font-semibold">Logs</h2>
+            <p className="text-xs text-muted font-mono truncate select-all" title={logPath}>
+              {logPath}
+            </p>
+          </div>
+          <select
+            value={minLevel}
+            onChange={(e) => setMinLevel(e.target.value as LogLevel)}
+            className="px-3 py-2 bg-background border border-card-border rounded-lg text-sm"
+          >
+            {LEVEL_FILTERS.map((filter) => (
+              <option key={filter.value} value={filter.value}>{filter.label}</option>
+            ))}
+          </select>
+          <label className="flex items-center gap-2 text-sm text-muted cursor-pointer">
+            <input
+              type="checkbox"
+              checked={autoScroll}
+              onChange={(e) => setAutoScroll(e.target.checked)}
+            />
+            Follow
+          </label>
+          <button
+            onClick={handleCopy}
+            disabled={entries.length === 0}
+            className="px-3 py-2 text-sm rounded-lg bg-card-border text-muted hover:text-foreground disabled:opacity-50"
+          >
+            {copied ? 'Copied' : `Copy last ${COPY_LINE_COUNT} lines`}
+          </button>
+          <button
+            onClick={onClose}
+            className="w-10 h-10 bg-black/50 hover:bg-black/70 rounded-full flex items-center justify-center transition-colors"
+          >
+            <svg className="w-6 h-6" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+              <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M6 18L18 6M6 6l12 12" />
+            </svg>
+          </button>
+        </div>
+
+        <div ref={listRef} className="flex-1 overflow-auto p-6 font-mono text-xs space-y-0.5">
+          {error && <p className="text-error text-sm font-sans">{error}</p>}
+          {!error && visibleEntries.length === 0 && (
+            <p className="text-sm text-muted font-sans">Nothing logged yet.</p>
+          )}
+          {visibleEntries.map((entry, i) => (
+            <div key={i} className={`whitespace-pre-wrap break-all ${LEVEL_CLASSES[entry.level]}`}>
+              <span className="text-muted/50">{new Date(entry.time).toLocaleTimeString()}</span>
+              {' '}{entry.message}
+            </div>
+          ))}
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,103 @@
+'use client';
+
+import { useState, useRef, useEffect } from 'react';
+import { VideoWithSelection } from '@/app/lib/types';
+import { buildM3u8 } from '@/app/lib/playlist';
+import { getBaseName } from '@/app/lib/utils';
+
+interface PlaylistExportProps {
+  videos: VideoWithSelection[]; // Videos in their current display order
+  rootPath: string;
+}
+
+export default function PlaylistExport({ videos, rootPath }: PlaylistExportProps) {
+  const [showMenu, setShowMenu] = useState(false);
+  const [useRelativePaths, setUseRelativePaths] = useState(false);
+  const [favoritesOnly, setFavoritesOnly] = useState(false);
+  const menuRef = useRef<HTMLDivElement>(null);
+
+  // Close menu when clicking outside
+  useEffect(() => {
+    const handleClickOutside = (e: MouseEvent) => {
+      if (menuRef.current && !menuRef.current.contains(e.target as Node)) {
+        setShowMenu(false);
+      }
+    };
+    if (showMenu) {
+      document.addEventListener('mousedown', handleClickOutside);
+    }
+    return () => document.removeEventListener('mousedown', handleClickOutside);
+  }, [showMenu]);
+
+  const exportVideos = favoritesOnly
+    ? videos.filter((v) => v.selection?.isFavorite)
+    : videos;
+
+  const handleExport = () => {
+    const content = buildM3u8(exportVideos, useRelativePaths ? rootPath : undefined);
+    const blob = new Blob([content], { type: 'audio/x-mpegurl;charset=utf-8' });
+    const url = URL.createObjectURL(blob);
+
+    const libraryName = getBaseName(rootPath) || 'playlist';
+    const link = document.createElement('a');
+    link.href = url;
+    link.download = `${libraryName}${favoritesOnly ? '-favorites' : ''}.m3u8`;
+    link.click();
+    URL.revokeObjectURL(url);
+
+    setShowMenu(false);
+  };
+
+  return (
+    <div ref={menuRef} className="relative">
+      <button
+        onClick={() => setShowMenu(!showMenu)}
+        disabled={videos.length === 0}
+        className="
+          px-3 py-1.5 text-sm rounded-lg transition-colors
+          bg-card border border-card-border text-muted
+          hover:text-foreground disabled:opacity-50
+          flex items-center gap-2
+        "
+      >
+        <svg className="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+          <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M4 6h16M4 10h16M4 14h10m-6 4h6m4-4v6m0 0l-2-2m2 2l2-2" />
+        </svg>
+        Export Playlist
+      </button>
+
+      {showMenu && (
+        <div className="absolute left-0 top-full mt-2 bg-card border border-card-border rounded-lg shadow-xl p-4 z-50 min-w-[280px]">
+          <p className="text-sm text-foreground mb-3">
+            Export {exportVideos.length.toLocaleString()} video{exportVideos.length !== 1 ? 's' : ''} in the current order as M3U8.
+          </p>
+          <label className="flex items-center gap-2 text-sm text-muted mb-2 cursor-pointer">
+            <input
+              type="checkbox"
+              checked={favoritesOnly}
+              onChange={(e) => setFavoritesOnly(e.target.checked)}
+            />
+            Favorites only
+          </label>
+          <label className="flex items-center gap-2 text-sm text-muted mb-3 cursor-pointer">
+            <input
+              type="checkbox"
+              checked={useRelativePaths}
+              onChange={(e) => setUseRelativePaths(e.target.checked)}
+            />
+            Relative paths (save inside the library folder)
+          </label>
+          <div className="flex justify-end">
+            <button
+              onClick={handleExport}
+              disabled={exportVideos.length === 0}
+              className="px-3 py-1.5 text-sm rounded-lg bg-accent hover:bg-accent-hover text-white disabled:opacity-50"
+            >
+              Download .m3u8
+            </button>
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,189 @@
+'use client';
+
+import { useState, useEffect, useCallback } from 'react';
+import { ShowToast } from './Toast';
+import { RecentLibrary, RelinkResult } from '@/app/lib/types';
+import { formatFileSize, formatTimeAgo } from '@/app/lib/utils';
+
+interface RecentLibrariesProps {
+  onOpen: (path: string) => void;
+  onRelinked: (path: string) => void; // Open the relinked library without rescanning
+  onShowAll: () => void;
+  onToast: ShowToast;
+}
+
+// Libraries shown under the folder picker
+const MAX_SHOWN = 8;
+
+export default function RecentLibraries({ onOpen, onRelinked, onShowAll, onToast }: RecentLibrariesProps) {
+  const [libraries, setLibraries] = useState<RecentLibrary[] | null>(null); // Null while loading
+  const [locatingPath, setLocatingPath] = useState<string | null>(null);
+  const [newPath, setNewPath] = useState('');
+  const [isRelinking, setIsRelinking] = useState(false);
+  const [result, setResult] = useState<(RelinkResult & { path: string }) | null>(null);
+
+  useEffect(() => {
+    const fetchLibraries = async () => {
+      try {
+        const res = await fetch('/api/libraries');
+        const data = await res.json();
+        setLibraries(data.success ? data.libraries : []);
+      } catch (error) {
+        setLibraries([]);
+        console.error('Error fetching recent libraries:', error);
+      }
+    };
+
+    fetchLibraries();
+  }, []);
+
+  const handleRelink = useCallback(async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!locatingPath || !newPath.trim()) return;
+
+    setIsRelinking(true);
+    try {
+      const res = await fetch('/api/libraries/relink', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ oldPath: locatingPath, newPath: newPath.trim() }),
+      });
+      const data = await res.json();
+
+      if (!data.success) {
+        onToast(data.error || 'Failed to relink library', 'error');
+        return;
+      }
+
+      setLocatingPath(null);
+      setNewPath('');
+      if (data.missingCount > 0) {
+        // Partial relink: keep the report up so the missing files can be checked
+        setResult(data);
+      } else {
+        onToast(`Relinked ${data.relinked} video${data.relinked !== 1 ? 's' : ''}`, 'success');
+        onRelinked(data.path);
+      }
+    } catch (error) {
+      onToast('Failed to relink library', 'error');
+      console.error('Error relinking library:', error);
+    } finally {
+      setIsRelinking(false);
+    }
+  }, [locatingPath, newPath, onToast, onRelinked]);
+
+  // Checking whether each folder is still there can be slow for offline network drives
+  if (!libraries) {
+    return <p className="mt-6 text-xs text-muted">Loading recent libraries…</p>;
+  }
+  if (libraries.length === 0) return null;
+
+  return (
+    <div className="mt-6">
+      <div className="flex items-center justify-between mb-2">
+        <h4 className="text-xs text-muted uppercase tracking-wider">Recent libraries</h4>
+        {libraries.length > 1 && (
+          <button
+            onClick={onShowAll}
+            className="text-xs text-accent hover:underline"
+          >
+            All libraries →
+          </button>
+        )}
+      </div>
+
+      <div className="space-y-2">
+        {libraries.slice(0, MAX_SHOWN).map((library) => (
+          <div key={library.path} className="bg-card border border-card-border rounded-lg p-3">
+            {/* Unplugged drives are dimmed rather than opened into an error */}
+            <div className={`flex items-center gap-3 ${library.exists ? '' : 'opacity-60'}`}>
+              <div className="flex-1 min-w-0">
+                <p className={`text-sm font-mono truncate ${library.exists ? '' : 'text-muted'}`} title={library.path}>
+                  {!library.exists && (
+                    <span className="mr-2 px-1.5 py-0.5 rounded bg-card-border text-xs font-sans uppercase tracking-wider">
+                      Offline
+                    </span>
+                  )}
+                  {library.path}
+                </p>
+                <p className="text-xs text-muted">
+                  {library.lastScannedAt
+                    ? `Scanned ${formatTimeAgo(library.lastScannedAt)}`
+                    : `Opened ${new Date(library.lastOpenedAt).toLocaleDateString()}`}
+                  {library.videoCount !== undefined && (
+                    <> · {library.videoCount.toLocaleString()} video{library.videoCount === 1 ? '' : 's'}</>
+                  )}
+                  {library.totalBytes !== undefined && <> · {formatFileSize(library.totalBytes)}</>}
+                  {!library.exists && <> · drive not mounted or folder moved</>}
+                </p>
+              </div>
+              {library.exists ? (
+                <button
+                  onClick={() => onOpen(library.path)}
+                  className="px-3 py-1.5 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+                >
+                  Open
+                </button>
+              ) : (
+                <button
+                  onClick={() => {
+                    setLocatingPath(locatingPath === library.path ? null : library.path);
+                    setNewPath('');
+                  }}
+                  className="px-3 py-1.5 text-xs rounded-lg bg-warning/20 text-warning hover:bg-warning/30"
+                >
+                  Locate…
+                </button>
+              )}
+            </div>
+
+            {/* New root for a moved library */}
+            {locatingPath === library.path && (
+              <form onSubmit={handleRelink} className="flex gap-2 mt-3">
+                <input
+                  type="text"
+                  value={newPath}
+                  onChange={(e) => setNewPath(e.target.value)}
+                  placeholder="New folder path"
+                  autoFocus
+                  className="flex-1 px-3 py-1.5 bg-background border border-card-border rounded-lg text-sm font-mono focus:outline-none focus:ring-2 focus:ring-accent"
+                />
+                <button
+                  type="submit"
+                  disabled={!newPath.trim() || isRelinking}
+                  className="px-3 py-1.5 text-xs rounded-lg bg-accent text-white hover:bg-accent-hover disabled:opacity-50"
+                >
+                  {isRelinking ? 'Checking...' : 'Relink'}
+                </button>
+              </form>
+            )}
+          </div>
+        ))}
+      </div>
+
+      {/* Partial relink report */}
+      {result && (
+        <div className="mt-4 bg-warning/10 border border-warning/30 rounded-lg p-4 text-sm">
+          <p className="mb-2">
+            Relinked {result.relinked} video{result.relinked !== 1 ? 's' : ''} ({result.verified}/{result.sampled} sampled files verified).
+            {' '}{result.missingCount} still missing:
+          </p>
+          <ul className="max-h-40 overflow-auto text-xs text-muted font-mono space-y-1 mb-3">
+            {result.missing.map((filePath) => (
+              <li key={filePath} className="break-all">{filePath}</li>
+            ))}
+            {result.missingCount > result.missing.length && (
+              <li>…and {result.missingCount - result.missing.length} more</li>
+            )}
+          </ul>
+          <button
+            onClick={() => onRelinked(result.path)}
+            className="px-3 py-1.5 text-xs rounded-lg bg-accent text-white hover:bg-accent-hover"
+          >
+            Open library
+          </button>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,210 @@
+'use client';
+
+import { useState, useEffect, useCallback } from 'react';
+import { ShowToast } from './Toast';
+import { VideoWithSelection } from '@/app/lib/types';
+import { formatDuration, formatFileSize } from '@/app/lib/utils';
+
+interface RecentlyRemovedViewProps {
+  onClose: () => void;
+  onCatalogChanged: () => void; // Refresh the main grid after videos are restored
+  onToast: ShowToast;
+}
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+export default function RecentlyRemovedView({ onClose, onCatalogChanged, onToast }: RecentlyRemovedViewProps) {
+  const [videos, setVideos] = useState<VideoWithSelection[] | null>(null);
+  const [retentionDays, setRetentionDays] = useState(30);
+  const [error, setError] = useState<string | null>(null);
+  const [isBusy, setIsBusy] = useState(false);
+
+  const fetchRemoved = useCallback(async () => {
+    try {
+      const res = await fetch('/api/removed');
+      const data = await res.json();
+      if (data.success) {
+        setVideos(data.videos);
+        setRetentionDays(data.retentionDays);
+        setError(null);
+      } else {
+        setError(data.error || 'Failed to load removed videos');
+      }
+    } catch (err) {
+      setError('Failed to load removed videos');
+      console.error('Error fetching removed videos:', err);
+    }
+  }, []);
+
+  useEffect(() => {
+    fetchRemoved();
+  }, [fetchRemoved]);
+
+  // Handle escape key to close
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        onClose();
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [onClose]);
+
+  const handleRestore = async (videoIds: string[]) => {
+    setIsBusy(true);
+    try {
+      const res = await fetch('/api/removed/restore', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ videoIds }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        onToast(`Restored ${data.restored} video${data.restored !== 1 ? 's' : ''}`, 'success');
+        onCatalogChanged();
+      } else {
+        onToast(data.error || 'Failed to restore videos', 'error');
+      }
+      await fetchRemoved();
+    } catch (err) {
+      onToast('Failed to restore videos', 'error');
+      console.error('Error restoring videos:', err);
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  const handlePurge = async (videoIds: string[]) => {
+    const count = `${videoIds.length} video${videoIds.length !== 1 ? 's' : ''}`;
+    if (!window.confirm(`Delete ${count} from the catalog for good? Favorites, notes and generated previews go too; the files on disk are kept.`)) {
+      return;
+    }
+
+    setIsBusy(true);
+    try {
+      const res = await fetch('/api/removed', {
+        method: 'DELETE',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ videoIds }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        onToast(`Deleted ${data.purged} video${data.purged !== 1 ? 's' : ''} for good`, 'success');
+      } else {
+        onToast(data.error || 'Failed to delete videos', 'error');
+      }
+      await fetchRemoved();
+    } catch (err) {
+      onToast('Failed to delete videos', 'error');
+      console.error('Error purging removed videos:', err);
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  const getDaysLeft = (video: VideoWithSelection) =>
+    Math.max(0, Math.ceil((Date.parse(video.removedAt!) + retentionDays * DAY_MS - Date.now()) / DAY_MS));
+
+  const allIds = videos?.map((video) => video.id) ?? [];
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/90"
+      onClick={onClose}
+    >
+      <div
+        className="relative w-full max-w-4xl h-[80vh] bg-card rounded-xl overflow-hidden shadow-2xl flex flex-col"
+        onClick={(e) => e.stopPropagation()}
+      >
+        <div className="flex items-center justify-between p-6 border-b border-card-border">
+          <div>
+            <h2 className="text-xl font-semibold">Recently Removed</h2>
+            <p className="text-sm text-muted">
+              Removed videos are deleted for good after {retentionDays} days. Scans leave them out until then.
+            </p>
+          </div>
+          <div className="flex items-center gap-2">
+            {allIds.length > 0 && (
+              <>
+                <button
+                  onClick={() => handleRestore(allIds)}
+                  disabled={isBusy}
+                  className="px-3 py-1.5 text-xs rounded-lg bg-card border border-card-border text-muted hover:text-foreground disabled:opacity-50"
+                >
+                  Restore all
+                </button>
+                <button
+                  onClick={() => handlePurge(allIds)}
+                  disabled={isBusy}
+                  className="px-3 py-1.5 text-xs rounded-lg bg-red-500/20 text-red-400 hover:bg-red-500/30 disabled:opacity-50"
+                >
+                  Delete all
+                </button>
+              </>
+            )}
+            <button
+              onClick={onClose}
+              className="w-10 h-10 bg-black/50 hover:bg-black/70 rounded-full flex items-center justify-center transition-colors"
+            >
+              <svg className="w-6 h-6" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M6 18L18 6M6 6l12 12" />
+              </svg>
+            </button>
+          </div>
+        </div>
+
+        <div className="flex-1 overflow-auto p-6 space-y-2">
+          {error && <p className="text-error text-sm">{error}</p>}
+
+          {videos && videos.length === 0 && (
+            <p className="text-sm text-muted">Nothing was removed recently.</p>
+          )}
+
+          {videos?.map((video) => (
+            <div key={video.id} className="flex items-center gap-4 bg-background border border-card-border rounded-lg p-2">
+              <div
+                className="w-32 shrink-0 aspect-video bg-black bg-cover bg-center rounded"
+                style={video.thumbnailPath
+                  ? { backgroundImage: `url(/api/videos/${video.id}/thumbnail?v=${video.thumbnailVersion ?? 0})` }
+                  : undefined}
+              />
+              <div className="flex-1 min-w-0 text-xs space-y-1">
+                <p className="text-sm font-medium truncate">
+                  {video.selection?.isFavorite && <span className="text-yellow-400 mr-1" title="Favorite">★</span>}
+                  {video.fileName}
+                </p>
+                <p className="font-mono text-muted truncate" title={video.filePath}>{video.filePath}</p>
+                <p className="text-muted">
+                  {formatFileSize(video.fileSize)} · {formatDuration(video.duration)}
+                  {' · '}
+                  Removed {new Date(video.removedAt!).toLocaleDateString()}
+                  {' · '}
+                  {getDaysLeft(video)} day{getDaysLeft(video) !== 1 ? 's' : ''} left
+                </p>
+              </div>
+              <div className="flex shrink-0 gap-2">
+                <button
+                  onClick={() => handleRestore([video.id])}
+                  disabled={isBusy}
+                  className="px-3 py-1.5 text-xs rounded-lg bg-card border border-card-border text-muted hover:text-foreground disabled:opacity-50"
+                >
+                  Restore
+                </button>
+                <button
+                  onClick={() => handlePurge([video.id])}
+                  disabled={isBusy}
+                  className="px-3 py-1.5 text-xs rounded-lg bg-red-500/20 text-red-400 hover:bg-red-500/30 disabled:opacity-50"
+                  title="Delete from the catalog for good (the file stays on disk)"
+                >
+                  Delete
+                </button>
+              </div>
+            </div>
+          ))}
+        </div>
+      </div>
+    </div>
+  );
+}
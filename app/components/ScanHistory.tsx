@@ -0,0 +1,192 @@
+'use client';
+
+import { useState, useEffect } from 'react';
+import { ScanCounts, ScanReport } from '@/app/lib/types';
+import { formatDuration, getBaseName } from '@/app/lib/utils';
+
+interface ScanHistoryProps {
+  onClose: () => void;
+}
+
+const COUNT_COLUMNS: { key: keyof ScanCounts; label: string; className: string }[] = [
+  { key: 'added', label: 'Added', className: 'text-success' },
+  { key: 'updated', label: 'Updated', className: 'text-accent' },
+  { key: 'skipped', label: 'Skipped', className: 'text-muted' },
+  { key: 'removed', label: 'Removed', className: 'text-warning' },
+  { key: 'errored', label: 'Errors', className: 'text-error' },
+];
+
+function getScanSeconds(scan: ScanReport): number | null {
+  if (!scan.completedAt) return null;
+  return (new Date(scan.completedAt).getTime() - new Date(scan.startedAt).getTime()) / 1000;
+}
+
+// Download one scan's details as JSON
+function exportReport(scan: ScanReport) {
+  const report = { ...scan, durationSeconds: getScanSeconds(scan) };
+  const blob = new Blob([JSON.stringify(report, null, 2)], { type: 'application/json' });
+  const url = URL.createObjectURL(blob);
+
+  const libraryName = scan.rootPath.split('/').filter(Boolean).pop() || 'library';
+  const link = document.createElement('a');
+  link.href = url;
+  link.download = `${libraryName}-scan-${scan.startedAt.slice(0, 19).replace(/:/g, '-')}.json`;
+  link.click();
+  URL.revokeObjectURL(url);
+}
+
+export default function ScanHistory({ onClose }: ScanHistoryProps) {
+  const [scans, setScans] = useState<ScanReport[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [selectedId, setSelectedId] = useState<string | null>(null);
+
+  useEffect(() => {
+    const fetchHistory = async () => {
+      try {
+        const res = await fetch('/api/scan/history');
+        const data = await res.json();
+        if (data.success) {
+          setScans(data.scans);
+          setSelectedId(data.scans[0]?.id ?? null);
+        } else {
+          setError(data.error || 'Failed to load scan history');
+        }
+      } catch (err) {
+        setError('Failed to load scan history');
+        console.error('Error fetching scan history:', err);
+      }
+    };
+
+    fetchHistory();
+  }, []);
+
+  // Handle escape key to close
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        onClose();
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [onClose]);
+
+  const renderCounts = (counts: ScanCounts) =>
+    COUNT_COLUMNS.map((column) => (
+      <td key={column.key} className={`px-3 py-1.5 text-right tabular-nums ${counts[column.key] > 0 ? column.className : 'text-muted/50'}`}>
+        {counts[column.key]}
+      </td>
+    ));
+
+  const renderScan = (scan: ScanReport) => {
+    const isSelected = scan.id === selectedId;
+    const seconds = getScanSeconds(scan);
+    const folders = Object.entries(scan.folders).sort(([a], [b]) => a.localeCompare(b));
+
+    return (
+      <div key={scan.id} className="bg-background border border-card-border rounded-lg">
+        <button
+          onClick={() => setSelectedId(isSelected ? null : scan.id)}
+          className="w-full flex items-center justify-between gap-4 p-4 text-left"
+        >
+          <div>
+            <p className="text-sm font-medium">
+              {new Date(scan.startedAt).toLocaleString()}
+              {scan.scanPath && <span className="text-muted font-normal"> · {getBaseName(scan.scanPath)} only</span>}
+            </p>
+            <p className="text-xs text-muted">
+              {scan.status === 'scanning' ? 'Running' : scan.status === 'error' ? (scan.error || 'Failed') : 'Complete'}
+              {seconds !== null && ` · took ${formatDuration(seconds)}`}
+              {` · ${scan.videosFound} video${scan.videosFound !== 1 ? 's' : ''}`}
+            </p>
+          </div>
+          <div className="flex gap-3 text-xs">
+            {COUNT_COLUMNS.filter((column) => scan[column.key] > 0).map((column) => (
+              <span key={column.key} className={column.className}>
+                {scan[column.key]} {column.label.toLowerCase()}
+              </span>
+            ))}
+          </div>
+        </button>
+
+        {isSelected && (
+          <div className="border-t border-card-border p-4 space-y-4">
+            {folders.length > 0 && (
+              <table className="w-full text-xs">
+                <thead>
+                  <tr className="text-muted">
+                    <th className="px-3 py-1.5 text-left font-normal">Folder</th>
+                    {COUNT_COLUMNS.map((column) => (
+                      <th key={column.key} className="px-3 py-1.5 text-right font-normal">{column.label}</th>
+                    ))}
+                  </tr>
+                </thead>
+                <tbody>
+                  {folders.map(([folder, counts]) => (
+                    <tr key={folder} className="border-t border-card-border/50">
+                      <td className="px-3 py-1.5 font-mono break-all">{folder}</td>
+                      {renderCounts(counts)}
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            )}
+
+            {scan.errors.length > 0 && (
+              <details>
+                <summary className="text-xs text-error cursor-pointer">
+                  {scan.errors.length} error{scan.errors.length !== 1 ? 's' : ''}
+                </summary>
+                <ul className="mt-2 space-y-1 text-xs text-muted font-mono">
+                  {scan.errors.map((message, i) => (
+                    <li key={i} className="break-all">{message}</li>
+                  ))}
+                </ul>
+              </details>
+            )}
+
+            <button
+              onClick={() => exportReport(scan)}
+              className="px-3 py-1.5 text-xs rounded-lg bg-card border border-card-border text-muted hover:text-foreground"
+            >
+              Export report
+            </button>
+          </div>
+        )}
+      </div>
+    );
+  };
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/90"
+      onClick={onClose}
+    >
+      <div
+        className="relative w-full max-w-4xl h-[80vh] bg-card rounded-xl overflow-hidden shadow-2xl flex flex-col"
+        onClick={(e) => e.stopPropagation()}
+      >
+        <div className="flex items-center justify-between p-6 border-b border-card-border">
+          <h2 className="text-xl font-semibold">Scan history</h2>
+          <button
+            onClick={onClose}
+            className="w-10 h-10 bg-black/50 hover:bg-black/70 rounded-full flex items-center justify-center transition-colors"
+          >
+            <svg className="w-6 h-6" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+              <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M6 18L18 6M6 6l12 12" />
+            </svg>
+          </button>
+        </div>
+
+        <div className="flex-1 overflow-auto p-6 space-y-3">
+          {error && <p className="text-error text-sm">{error}</p>}
+          {scans && scans.length === 0 && (
+            <p className="text-sm text-muted">No scans recorded yet.</p>
+          )}
+          {scans?.map(renderScan)}
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,138 @@
+'use client';
+
+import { useEffect } from 'react';
+import { ScanPreview, ScanPreviewCounts } from '@/app/lib/types';
+import { formatDuration, formatFileSize } from '@/app/lib/utils';
+
+interface ScanPreviewModalProps {
+  preview: ScanPreview;
+  onProceed: (path: string) => void;
+  onClose: () => void;
+}
+
+const COUNT_COLUMNS: { key: keyof ScanPreviewCounts; label: string; className: string }[] = [
+  { key: 'new', label: 'New', className: 'text-success' },
+  { key: 'changed', label: 'Changed', className: 'text-accent' },
+  { key: 'cached', label: 'Cached', className: 'text-muted' },
+];
+
+// Results of a dry-run scan, with the option to go ahead with the real one
+export default function ScanPreviewModal({ preview, onProceed, onClose }: ScanPreviewModalProps) {
+  // Handle escape key to close
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        onClose();
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [onClose]);
+
+  const folders = Object.entries(preview.folders).sort(([a], [b]) => a.localeCompare(b));
+  const toProcess = preview.new + preview.changed;
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/90"
+      onClick={onClose}
+    >
+      <div
+        className="relative w-full max-w-3xl max-h-[80vh] bg-card rounded-xl overflow-hidden shadow-2xl flex flex-col"
+        onClick={(e) => e.stopPropagation()}
+      >
+        <div className="p-6 border-b border-card-border">
+          <h2 className="text-xl font-semibold">Scan preview</h2>
+          <p className="text-xs text-muted font-mono truncate" title={preview.rootPath}>{preview.rootPath}</p>
+        </div>
+
+        <div className="flex-1 overflow-auto p-6 space-y-4">
+          <div className="grid grid-cols-4 gap-3 text-center">
+            {COUNT_COLUMNS.map((column) => (
+              <div key={column.key} className="bg-background rounded-lg p-3">
+                <p className={`text-2xl font-semibold tabular-nums ${column.className}`}>{preview[column.key]}</p>
+                <p className="text-xs text-muted">{column.label}</p>
+              </div>
+            ))}
+            <div className="bg-background rounded-lg p-3">
+              <p className="text-2xl font-semibold tabular-nums text-warning">{preview.excluded}</p>
+              <p className="text-xs text-muted">Excluded</p>
+            </div>
+          </div>
+
+          <p className="text-sm">
+            {toProcess > 0 ? (
+              <>
+                A full scan would index {toProcess} file{toProcess !== 1 ? 's' : ''} ({formatFileSize(preview.bytes)}
+                {preview.estimatedDuration !== null && `, about ${formatDuration(preview.estimatedDuration)} of footage`}).
+              </>
+            ) : (
+              'Everything here is already indexed.'
+            )}
+            {preview.cached > 0 && (
+              <span className="text-muted">
+                {' '}{preview.cached} cached file{preview.cached !== 1 ? 's' : ''} ({formatFileSize(preview.cachedBytes)}, {formatDuration(preview.cachedDuration)}) would be skipped.
+              </span>
+            )}
+          </p>
+
+          {folders.length > 0 && (
+            <table className="w-full text-xs">
+              <thead>
+                <tr className="text-muted">
+                  <th className="px-3 py-1.5 text-left font-normal">Folder</th>
+                  {COUNT_COLUMNS.map((column) => (
+                    <th key={column.key} className="px-3 py-1.5 text-right font-normal">{column.label}</th>
+                  ))}
+                  <th className="px-3 py-1.5 text-right font-normal">To index</th>
+                </tr>
+              </thead>
+              <tbody>
+                {folders.map(([folder, counts]) => (
+                  <tr key={folder} className="border-t border-card-border/50">
+                    <td className="px-3 py-1.5 font-mono break-all">{folder}</td>
+                    {COUNT_COLUMNS.map((column) => (
+                      <td key={column.key} className={`px-3 py-1.5 text-right tabular-nums ${counts[column.key] > 0 ? column.className : 'text-muted/50'}`}>
+                        {counts[column.key]}
+                      </td>
+                    ))}
+                    <td className="px-3 py-1.5 text-right tabular-nums text-muted">{formatFileSize(counts.bytes)}</td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          )}
+
+          {preview.errors.length > 0 && (
+            <details>
+              <summary className="text-xs text-error cursor-pointer">
+                {preview.errors.length} unreadable file{preview.errors.length !== 1 ? 's' : ''}
+              </summary>
+              <ul className="mt-2 space-y-1 text-xs text-muted font-mono">
+                {preview.errors.map((message, i) => (
+                  <li key={i} className="break-all">{message}</li>
+                ))}
+              </ul>
+            </details>
+          )}
+        </div>
+
+        <div className="flex justify-end gap-3 p-6 border-t border-card-border">
+          <button
+            onClick={onClose}
+            className="px-4 py-2 text-sm rounded-lg bg-card-border text-muted hover:text-foreground"
+          >
+            Cancel
+          </button>
+          <button
+            onClick={() => onProceed(preview.rootPath)}
+            className="px-4 py-2 text-sm rounded-lg bg-accent text-white hover:bg-accent-hover"
+          >
+            Proceed with full scan
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -1,6 +1,7 @@
 'use client';
 
 import { useEffect, useRef, useCallback } from 'react';
+import { formatFileSize } from '@/app/lib/utils';
 
 interface ScanProgressProps {
   status: 'counting' | 'scanning' | 'complete' | 'error' | 'idle';
@@ -8,8 +9,15 @@ interface ScanProgressProps {
   totalVideos: number;
   videosProcessed: number;
   videosSkipped: number;
+  videosTooSmall?: number; // Left out for being under the minimum file size
+  totalBytes?: number; // Size of the videos found so far
+  bytesProcessed?: number;
+  videosOnlineOnly?: number; // Online-only cloud files, not downloaded
   currentFile: string;
   message: string;
+  errors?: string[]; // Non-fatal problems (e.g. malformed sidecars)
+  folder?: string | null; // Only this folder of the library is being scanned
+  compact?: boolean; // One line above the grid, for rescans of the open library
   onComplete?: () => void;
 }
 
@@ -19,8 +27,15 @@ export default function ScanProgress({
   totalVideos,
   videosProcessed,
   videosSkipped,
+  videosTooSmall = 0,
+  totalBytes = 0,
+  bytesProcessed = 0,
+  videosOnlineOnly = 0,
   currentFile,
   message,
+  errors = [],
+  folder,
+  compact = false,
   onComplete,
 }: ScanProgressProps) {
   const hasPlayedSound = useRef(false);
@@ -82,6 +97,41 @@ export default function ScanProgress({
   // Render based on status
   if (status === 'idle') return null;
 
+  if (compact) {
+    return (
+      <div className="flex items-center gap-3 px-4 py-2 text-sm">
+        {status === 'complete' ? (
+          <span className="text-success">✓</span>
+        ) : (
+          <div className="w-4 h-4 shrink-0 border-2 border-accent/30 border-t-accent rounded-full animate-spin" />
+        )}
+        <span className="shrink-0">{message}</span>
+        {status === 'scanning' && totalVideos > 0 && (
+          <>
+            <div className="flex-1 min-w-16 h-1.5 bg-card-border rounded-full overflow-hidden">
+              <div
+                className="h-full bg-accent transition-all duration-300 ease-out"
+                style={{ width: `${progressPercent}%` }}
+              />
+            </div>
+            <span className="shrink-0 text-muted tabular-nums">
+              {videosProcessed + videosSkipped} / {totalVideos}
+            </span>
+          </>
+        )}
+        {status === 'complete' && (
+          <span className="text-muted">
+            {videosProcessed.toLocaleString()} new · {videosSkipped.toLocaleString()} cached
+            {videosOnlineOnly > 0 && ` · ${videosOnlineOnly.toLocaleString()} online-only`}
+          </span>
+        )}
+        {(status === 'scanning' || status === 'counting') && fileName && (
+          <span className="min-w-0 truncate text-muted" title={currentFile}>{fileName}</span>
+        )}
+      </div>
+    );
+  }
+
   return (
     <div className="flex flex-col items-center justify-center w-full max-w-2xl mx-auto p-8">
       {/* Spinner or checkmark */}
@@ -105,6 +155,11 @@ export default function ScanProgress({
 
       {/* Main status message */}
       <h3 className="text-lg font-medium mb-2 text-center">{message}</h3>
+      {folder && (
+        <p className="text-sm text-muted mb-2 text-center break-all" title={folder}>
+          Only scanning {folder}
+        </p>
+      )}
 
       {/* Progress bar (only during scanning) */}
       {(status === 'scanning' || status === 'counting') && totalVideos > 0 && (
@@ -121,6 +176,9 @@ export default function ScanProgress({
           <div className="flex justify-between text-sm text-muted">
             <span>
               {videosProcessed + videosSkipped} / {totalVideos} videos
+              {status === 'scanning' && totalBytes > 0 && (
+                <> · {formatFileSize(bytesProcessed)} / {formatFileSize(totalBytes)}</>
+              )}
             </span>
             <span>{progressPercent}%</span>
           </div>
@@ -133,6 +191,9 @@ export default function ScanProgress({
           <p className="text-2xl font-bold text-accent mb-1">
             {totalVideos.toLocaleString()} videos found
           </p>
+          {totalBytes > 0 && (
+            <p className="text-sm text-foreground mb-1">{formatFileSize(totalBytes)}</p>
+          )}
           <p className="text-sm text-muted">Counting files...</p>
         </div>
       )}
@@ -173,6 +234,34 @@ export default function ScanProgress({
         </div>
       )}
 
+      {/* Camera preview clips and other tiny files left out */}
+      {(status === 'scanning' || status === 'complete') && videosTooSmall > 0 && (
+        <p className="text-xs text-muted mb-4">
+          {videosTooSmall.toLocaleString()} file{videosTooSmall !== 1 ? 's' : ''} under the minimum size skipped
+        </p>
+      )}
+
+      {/* Cloud files whose data isn't on this machine; reading them would download them */}
+      {(status === 'scanning' || status === 'complete') && videosOnlineOnly > 0 && (
+        <p className="text-xs text-muted mb-4">
+          {videosOnlineOnly.toLocaleString()} online-only cloud file{videosOnlineOnly !== 1 ? 's' : ''} not downloaded
+        </p>
+      )}
+
+      {/* Non-fatal scan errors */}
+      {status === 'complete' && errors.length > 0 && (
+        <details className="w-full mb-4 text-sm">
+          <summary className="text-warning cursor-pointer">
+            {errors.length} warning{errors.length !== 1 ? 's' : ''} during scan
+          </summary>
+          <ul className="mt-2 max-h-40 overflow-auto text-xs text-muted space-y-1">
+            {errors.map((error, i) => (
+              <li key={i} className="break-all">{error}</li>
+            ))}
+          </ul>
+        </details>
+      )}
+
       {/* Current file being processed */}
       {(status === 'scanning' || status === 'counting') && fileName && (
         <p className="text-sm text-muted truncate max-w-full" title={currentFile}>
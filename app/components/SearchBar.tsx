@@ -0,0 +1,43 @@
+'use client';
+
+import { SearchMode } from '@/app/lib/types';
+
+interface SearchBarProps {
+  query: string;
+  onQueryChange: (query: string) => void;
+  mode: SearchMode;
+  onModeChange: (mode: SearchMode) => void;
+}
+
+export default function SearchBar({ query, onQueryChange, mode, onModeChange }: SearchBarProps) {
+  return (
+    <div className="flex items-center bg-card border border-card-border rounded-lg focus-within:ring-2 focus-within:ring-accent">
+      <svg className="w-4 h-4 ml-2.5 text-muted shrink-0" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+        <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z" />
+      </svg>
+      <input
+        type="text"
+        value={query}
+        onChange={(e) => onQueryChange(e.target.value)}
+        onKeyDown={(e) => {
+          if (e.key === 'Escape') onQueryChange('');
+        }}
+        placeholder={mode === 'name' ? 'Search file names...' : 'Search names, paths, codecs, notes...'}
+        className="w-56 px-2 py-1.5 bg-transparent text-sm text-foreground placeholder:text-muted focus:outline-none"
+      />
+      {/* Search scope toggle */}
+      <button
+        onClick={() => onModeChange(mode === 'name' ? 'all' : 'name')}
+        className={`
+          mr-1 px-2 py-0.5 text-xs rounded transition-colors
+          ${mode === 'all' ? 'bg-accent text-white' : 'bg-card-border text-muted hover:text-foreground'}
+        `}
+        title={mode === 'name'
+          ? 'Searching file names only. Click to also search path, codec, resolution, notes and keywords.'
+          : 'Searching name, path, codec, resolution, notes and keywords. Click to search file names only.'}
+      >
+        {mode === 'name' ? 'Name' : 'All'}
+      </button>
+    </div>
+  );
+}
@@ -0,0 +1,289 @@
+'use client';
+
+import { useState, useEffect, useRef, useCallback } from 'react';
+import { ShowToast } from './Toast';
+import { SelectionSet } from '@/app/lib/types';
+import { VIDEO_DRAG_TYPE } from '@/app/lib/utils';
+
+interface SelectionSetsProps {
+  currentPath: string;
+  selectedIds: string[]; // Current selection, saved by "Save selection…"
+  // Select the set's remaining videos, optionally filtering the grid to them
+  onRestore: (set: SelectionSet, videoIds: string[], filter: boolean) => void;
+  onToast: ShowToast;
+}
+
+const isVideoDrag = (e: React.DragEvent | DragEvent) => !!e.dataTransfer?.types.includes(VIDEO_DRAG_TYPE);
+
+// Dropdown of named selection sets next to the selection count. Unlike smart views they
+// are static lists of videos; cards dragged over the button open it, and dropping them on
+// a set adds them to the end.
+export default function SelectionSets({ currentPath, selectedIds, onRestore, onToast }: SelectionSetsProps) {
+  const [isOpen, setIsOpen] = useState(false);
+  const [sets, setSets] = useState<SelectionSet[]>([]);
+  const [isDraggingVideos, setIsDraggingVideos] = useState(false); // A card drag is in progress
+  const [dropTargetId, setDropTargetId] = useState<string | null>(null);
+  const panelRef = useRef<HTMLDivElement>(null);
+  const openedByDragRef = useRef(false);
+
+  // Highlight every set as a drop target while cards are dragged, and close the dropdown
+  // again afterwards if the drag opened it
+  useEffect(() => {
+    const handleDragStart = (e: DragEvent) => {
+      if (isVideoDrag(e)) setIsDraggingVideos(true);
+    };
+    const handleDragEnd = () => {
+      setIsDraggingVideos(false);
+      setDropTargetId(null);
+      if (openedByDragRef.current) {
+        openedByDragRef.current = false;
+        setIsOpen(false);
+      }
+    };
+
+    window.addEventListener('dragstart', handleDragStart);
+    window.addEventListener('dragend', handleDragEnd);
+    return () => {
+      window.removeEventListener('dragstart', handleDragStart);
+      window.removeEventListener('dragend', handleDragEnd);
+    };
+  }, []);
+
+  // Close panel when clicking outside
+  useEffect(() => {
+    const handleClickOutside = (e: MouseEvent) => {
+      if (panelRef.current && !panelRef.current.contains(e.target as Node)) {
+        setIsOpen(false);
+      }
+    };
+    if (isOpen) {
+      document.addEventListener('mousedown', handleClickOutside);
+    }
+    return () => document.removeEventListener('mousedown', handleClickOutside);
+  }, [isOpen]);
+
+  // Sets are stored per library
+  useEffect(() => {
+    const fetchSets = async () => {
+      try {
+        const res = await fetch('/api/selection-sets');
+        const data = await res.json();
+        setSets(data.success ? data.sets : []);
+      } catch (error) {
+        console.error('Error fetching selection sets:', error);
+      }
+    };
+
+    fetchSets();
+  }, [currentPath]);
+
+  const handleSave = useCallback(async () => {
+    const name = window.prompt(`Name this selection of ${selectedIds.length} video${selectedIds.length === 1 ? '' : 's'}:`);
+    if (!name?.trim()) return;
+
+    try {
+      const res = await fetch('/api/selection-sets', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ name, videoIds: selectedIds }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        setSets(data.sets);
+        onToast(`Saved selection "${data.set.name}"`, 'success');
+      } else {
+        onToast(data.error || 'Failed to save selection', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to save selection', 'error');
+      console.error('Error saving selection set:', error);
+    }
+  }, [selectedIds, onToast]);
+
+  const handleRestore = useCallback(async (set: SelectionSet, filter: boolean) => {
+    setIsOpen(false);
+    try {
+      const res = await fetch(`/api/selection-sets/${set.id}`);
+      const data = await res.json();
+      if (!data.success) {
+        onToast(data.error || 'Failed to restore selection', 'error');
+        return;
+      }
+      // Removed videos are skipped rather than failing the restore
+      if (data.missing > 0) {
+        onToast(
+          `${data.missing} video${data.missing === 1 ? ' is' : 's are'} no longer in the library; restored ${data.videoIds.length}`,
+          'error'
+        );
+      }
+      onRestore(set, data.videoIds, filter);
+    } catch (error) {
+      onToast('Failed to restore selection', 'error');
+      console.error('Error restoring selection set:', error);
+    }
+  }, [onRestore, onToast]);
+
+  const handleDrop = useCallback(async (e: React.DragEvent, set: SelectionSet) => {
+    e.preventDefault();
+    setDropTargetId(null);
+    let videoIds: string[];
+    try {
+      videoIds = JSON.parse(e.dataTransfer.getData(VIDEO_DRAG_TYPE));
+    } catch {
+      return;
+    }
+
+    try {
+      const res = await fetch(`/api/selection-sets/${set.id}`, {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ videoIds }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        setSets(data.sets);
+        onToast(
+          data.added > 0
+            ? `Added ${data.added} video${data.added === 1 ? '' : 's'} to "${set.name}"`
+            : `Already in "${set.name}"`,
+          'success'
+        );
+      } else {
+        onToast(data.error || 'Failed to add to selection', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to add to selection', 'error');
+      console.error('Error adding to selection set:', error);
+    }
+  }, [onToast]);
+
+  const handleRename = useCallback(async (set: SelectionSet) => {
+    const name = window.prompt('Rename selection:', set.name);
+    if (!name?.trim() || name.trim() === set.name) return;
+
+    try {
+      const res = await fetch(`/api/selection-sets/${set.id}`, {
+        method: 'PATCH',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ name }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        setSets(data.sets);
+      } else {
+        onToast(data.error || 'Failed to rename selection', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to rename selection', 'error');
+      console.error('Error renaming selection set:', error);
+    }
+  }, [onToast]);
+
+  const handleDelete = useCallback(async (set: SelectionSet) => {
+    if (!window.confirm(`Delete selection "${set.name}"? The videos are not affected.`)) return;
+
+    try {
+      const res = await fetch(`/api/selection-sets/${set.id}`, { method: 'DELETE' });
+      const data = await res.json();
+      if (data.success) {
+        setSets(data.sets);
+      } else {
+        onToast(data.error || 'Failed to delete selection', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to delete selection', 'error');
+      console.error('Error deleting selection set:', error);
+    }
+  }, [onToast]);
+
+  return (
+    <div ref={panelRef} className="relative">
+      <button
+        onClick={() => setIsOpen(!isOpen)}
+        onDragEnter={(e) => {
+          if (!isVideoDrag(e) || isOpen) return;
+          openedByDragRef.current = true;
+          setIsOpen(true);
+        }}
+        className={`px-2 py-1 text-xs rounded-lg bg-card border text-muted hover:text-foreground flex items-center gap-1 ${
+          isDraggingVideos ? 'border-accent' : 'border-card-border'
+        }`}
+        title="Saved selections; drag cards here to add them to one"
+      >
+        Sets
+        <svg className="w-3 h-3" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+          <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M19 9l-7 7-7-7" />
+        </svg>
+      </button>
+
+      {isOpen && (
+        <div className="absolute left-0 top-full mt-2 bg-card border border-card-border rounded-lg shadow-xl p-2 z-50 w-72">
+          {sets.length === 0 && (
+            <p className="px-2 py-1.5 text-sm text-muted">No saved selections yet.</p>
+          )}
+
+          {sets.map((set) => (
+            <div
+              key={set.id}
+              className={`group flex items-center gap-1 rounded-lg border hover:bg-card-border ${
+                dropTargetId === set.id
+                  ? 'border-accent bg-accent/20'
+                  : isDraggingVideos ? 'border-accent/50' : 'border-transparent'
+              }`}
+              onDragOver={(e) => {
+                if (!isVideoDrag(e)) return;
+                e.preventDefault();
+                e.dataTransfer.dropEffect = 'copy';
+                setDropTargetId(set.id);
+              }}
+              onDragLeave={(e) => {
+                if (!e.currentTarget.contains(e.relatedTarget as Node | null)) setDropTargetId(null);
+              }}
+              onDrop={(e) => handleDrop(e, set)}
+            >
+              <button
+                onClick={() => handleRestore(set, false)}
+                className="flex-1 min-w-0 text-left px-2 py-1.5 flex items-center gap-2"
+                title="Select these videos"
+              >
+                <span className="flex-1 text-sm truncate">{set.name}</span>
+                <span className="text-xs text-muted tabular-nums">{set.videoIds.length}</span>
+              </button>
+              <button
+                onClick={() => handleRestore(set, true)}
+                className="px-1.5 text-xs text-muted hover:text-foreground opacity-0 group-hover:opacity-100"
+                title="Select and show only these videos"
+              >
+                Filter
+              </button>
+              <button
+                onClick={() => handleRename(set)}
+                className="px-1.5 text-xs text-muted hover:text-foreground opacity-0 group-hover:opacity-100"
+                title="Rename"
+              >
+                ✎
+              </button>
+              <button
+                onClick={() => handleDelete(set)}
+                className="px-1.5 text-xs text-muted hover:text-red-400 opacity-0 group-hover:opacity-100"
+                title="Delete"
+              >
+                ✕
+              </button>
+            </div>
+          ))}
+
+          <div className="border-t border-card-border mt-2 pt-2">
+            <button
+              onClick={handleSave}
+              disabled={selectedIds.length === 0}
+              className="w-full text-left px-2 py-1.5 text-sm rounded-lg text-muted hover:text-foreground hover:bg-card-border disabled:opacity-50 disabled:hover:bg-transparent"
+            >
+              Save selection…
+            </button>
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,851 @@
+'use client';
+
+import { useState, useEffect, useRef, useCallback } from 'react';
+import LogViewer from './LogViewer';
+import { ShowToast } from './Toast';
+import { BitrateClass, BitrateThresholds, CloudPlaceholderMode, DatabaseStats } from '@/app/lib/types';
+import { formatFileSize } from '@/app/lib/utils';
+
+interface SettingsPanelProps {
+  onToast: ShowToast;
+  onVideosChanged?: () => void; // A setting changed what the video list contains
+  onBitrateThresholdsChange?: (thresholds: BitrateThresholds) => void;
+}
+
+const BITRATE_CLASS_LABELS: [BitrateClass, string][] = [
+  ['sd', 'SD'],
+  ['720p', '720p'],
+  ['1080p', '1080p'],
+  ['4k', '4K+'],
+];
+
+export default function SettingsPanel({ onToast, onVideosChanged, onBitrateThresholdsChange }: SettingsPanelProps) {
+  const [isOpen, setIsOpen] = useState(false);
+  const [databaseStats, setDatabaseStats] = useState<DatabaseStats | null>(null);
+  const [isCompacting, setIsCompacting] = useState(false);
+  const [silenceThreshold, setSilenceThreshold] = useState<string | null>(null); // Seconds, as typed
+  const [minFileSizeMb, setMinFileSizeMb] = useState<string | null>(null); // As typed
+  // Sprite density as typed; null when no library is open
+  const [spriteDensity, setSpriteDensity] = useState<{ custom: boolean; tileWidth: string; columns: string; targetTiles: string } | null>(null);
+  const [isRegeneratingSprites, setIsRegeneratingSprites] = useState(false);
+  // Mbps per resolution class, as typed; null when no library is open
+  const [bitrateThresholds, setBitrateThresholds] = useState<Record<BitrateClass, string> | null>(null);
+  const [catalogApiEnabled, setCatalogApiEnabled] = useState(false);
+  const [catalogApiPort, setCatalogApiPort] = useState(''); // As typed
+  const [catalogApiServer, setCatalogApiServer] = useState<{ running: boolean; port: number | null; error: string | null } | null>(null);
+  const [placeholderMode, setPlaceholderMode] = useState<CloudPlaceholderMode | null>(null); // Null when no library is open
+  const [portableEnabled, setPortableEnabled] = useState(false);
+  const [copyWithFolder, setCopyWithFolder] = useState(false);
+  const [measureLoudness, setMeasureLoudness] = useState<boolean | null>(null); // Null when no library is open
+  const [isConverting, setIsConverting] = useState(false);
+  const [showLogs, setShowLogs] = useState(false);
+  const [transferMessage, setTransferMessage] = useState<{ text: string; isError: boolean } | null>(null);
+  // One pattern per line; null when no library is open
+  const [exclusionsText, setExclusionsText] = useState<string | null>(null);
+  const [savedExclusions, setSavedExclusions] = useState('');
+  const [exclusionsError, setExclusionsError] = useState<string | null>(null);
+  const panelRef = useRef<HTMLDivElement>(null);
+  const importInputRef = useRef<HTMLInputElement>(null);
+
+  // Close panel when clicking outside
+  useEffect(() => {
+    const handleClickOutside = (e: MouseEvent) => {
+      if (panelRef.current && !panelRef.current.contains(e.target as Node)) {
+        setIsOpen(false);
+      }
+    };
+    if (isOpen) {
+      document.addEventListener('mousedown', handleClickOutside);
+    }
+    return () => document.removeEventListener('mousedown', handleClickOutside);
+  }, [isOpen]);
+
+  // Load settings when opened
+  useEffect(() => {
+    if (!isOpen) return;
+
+    const fetchSettings = async () => {
+      try {
+        const [catalogRes, portableRes, exclusionsRes, databaseRes, silenceRes, duplicateNamesRes, minSizeRes, spritesRes, loudnessRes, bitrateRes, placeholdersRes] = await Promise.all([
+          fetch('/api/catalog'),
+          fetch('/api/portable'),
+          fetch('/api/exclusions'),
+          fetch('/api/database'),
+          fetch('/api/silence'),
+          fetch('/api/duplicate-names'),
+          fetch('/api/scan/min-size'),
+          fetch('/api/sprites'),
+          fetch('/api/scan/loudness'),
+          fetch('/api/bitrate'),
+          fetch('/api/scan/placeholders'),
+        ]);
+        const [catalogData, portableData, exclusionsData, databaseData, silenceData, duplicateNamesData, minSizeData, spritesData, loudnessData, bitrateData, placeholdersData] = await Promise.all([
+          catalogRes.json(),
+          portableRes.json(),
+          exclusionsRes.json(),
+          databaseRes.json(),
+          silenceRes.json(),
+          duplicateNamesRes.json(),
+          minSizeRes.json(),
+          spritesRes.json(),
+          loudnessRes.json(),
+          bitrateRes.json(),
+          placeholdersRes.json(),
+        ]);
+        if (catalogData.success) {
+          setCatalogApiEnabled(catalogData.enabled);
+          setCatalogApiPort(catalogData.port !== null ? String(catalogData.port) : '');
+          setCatalogApiServer(catalogData);
+        }
+        if (portableData.success) {
+          setPortableEnabled(portableData.enabled);
+        }
+        if (duplicateNamesData.success) {
+          setCopyWithFolder(duplicateNamesData.copyWithFolder);
+        }
+        const exclusions = exclusionsData.success ? exclusionsData.patterns.join('\n') : null;
+        setExclusionsText(exclusions);
+        setSavedExclusions(exclusions ?? '');
+        setExclusionsError(null);
+        setDatabaseStats(databaseData.success ? databaseData.stats : null);
+        setSilenceThreshold(silenceData.success ? String(silenceData.threshold) : null);
+        setMinFileSizeMb(minSizeData.success ? String(minSizeData.bytes / (1024 * 1024)) : null);
+        setMeasureLoudness(loudnessData.success ? loudnessData.enabled : null);
+        setPlaceholderMode(placeholdersData.success ? placeholdersData.mode : null);
+        setBitrateThresholds(bitrateData.success
+          ? Object.fromEntries(BITRATE_CLASS_LABELS.map(([key]) => [key, String(bitrateData.thresholds[key])])) as Record<BitrateClass, string>
+          : null);
+        if (spritesData.success) {
+          const density = spritesData.density ?? spritesData.defaults;
+          setSpriteDensity({
+            custom: spritesData.density !== null,
+            tileWidth: String(density.tileWidth),
+            columns: String(density.columns),
+            targetTiles: String(density.targetTiles),
+          });
+        } else {
+          setSpriteDensity(null);
+        }
+      } catch (error) {
+        console.error('Error fetching settings:', error);
+      }
+    };
+
+    fetchSettings();
+  }, [isOpen]);
+
+  const handleToggleCatalogApi = useCallback(async (enabled: boolean) => {
+    try {
+      const res = await fetch('/api/catalog', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ enabled }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        setCatalogApiEnabled(data.enabled);
+        setCatalogApiServer(data);
+      }
+    } catch (error) {
+      console.error('Error updating catalog API setting:', error);
+    }
+  }, []);
+
+  const handleSaveCatalogApiPort = useCallback(async () => {
+    try {
+      const res = await fetch('/api/catalog', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ port: Number(catalogApiPort) }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        setCatalogApiPort(String(data.port));
+        setCatalogApiServer(data);
+      } else {
+        onToast(data.error || 'Failed to save catalog API port', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to save catalog API port', 'error');
+      console.error('Error saving catalog API port:', error);
+    }
+  }, [catalogApiPort, onToast]);
+
+  const handleChangePlaceholderMode = useCallback(async (mode: CloudPlaceholderMode) => {
+    try {
+      const res = await fetch('/api/scan/placeholders', {
+        method: 'PUT',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ mode }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        setPlaceholderMode(data.mode);
+      }
+    } catch (error) {
+      console.error('Error updating online-only file setting:', error);
+    }
+  }, []);
+
+  // Cards get the name to copy with the video list, so it is fetched again
+  const handleToggleCopyWithFolder = useCallback(async (enabled: boolean) => {
+    try {
+      const res = await fetch('/api/duplicate-names', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ copyWithFolder: enabled }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        setCopyWithFolder(data.copyWithFolder);
+        onVideosChanged?.();
+      }
+    } catch (error) {
+      console.error('Error updating duplicate name setting:', error);
+    }
+  }, [onVideosChanged]);
+
+  const handleToggleMeasureLoudness = useCallback(async (enabled: boolean) => {
+    try {
+      const res = await fetch('/api/scan/loudness', {
+        method: 'PUT',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ enabled }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        setMeasureLoudness(data.enabled);
+      }
+    } catch (error) {
+      console.error('Error updating loudness setting:', error);
+    }
+  }, []);
+
+  // Converts every stored path of the open library in place
+  const handleTogglePortable = useCallback(async (enabled: boolean) => {
+    setIsConverting(true);
+    try {
+      const res = await fetch('/api/portable', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ enabled }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        setPortableEnabled(data.enabled);
+      }
+    } catch (error) {
+      console.error('Error converting library paths:', error);
+    } finally {
+      setIsConverting(false);
+    }
+  }, []);
+
+  // Takes effect on the next scan, which also drops already indexed files that are now excluded
+  const handleSaveExclusions = useCallback(async () => {
+    if (exclusionsText === null) return;
+    try {
+      const res = await fetch('/api/exclusions', {
+        method: 'PUT',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ patterns: exclusionsText.split('\n') }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        const saved = data.patterns.join('\n');
+        setExclusionsText(saved);
+        setSavedExclusions(saved);
+        setExclusionsError(null);
+      } else {
+        setExclusionsError(data.error || 'Failed to save');
+      }
+    } catch (error) {
+      setExclusionsError('Failed to save');
+      console.error('Error saving exclusions:', error);
+    }
+  }, [exclusionsText]);
+
+  const handleCompactDatabase = useCallback(async () => {
+    setIsCompacting(true);
+    try {
+      const res = await fetch('/api/database', { method: 'POST' });
+      const data = await res.json();
+      if (data.success) {
+        setDatabaseStats(data.stats);
+        onToast(`Compacted from ${formatFileSize(data.before)} to ${formatFileSize(data.after)}`, 'success');
+      } else {
+        onToast(data.error || 'Failed to compact database', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to compact database', 'error');
+      console.error('Error compacting database:', error);
+    } finally {
+      setIsCompacting(false);
+    }
+  }, [onToast]);
+
+  const handleSaveSilenceThreshold = useCallback(async () => {
+    try {
+      const res = await fetch('/api/silence', {
+        method: 'PUT',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ threshold: Number(silenceThreshold) }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        onToast('Silence threshold saved; dropout badges update on the next refresh', 'success');
+      } else {
+        onToast(data.error || 'Failed to save threshold', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to save threshold', 'error');
+      console.error('Error saving silence threshold:', error);
+    }
+  }, [silenceThreshold, onToast]);
+
+  const handleSaveMinFileSize = useCallback(async () => {
+    try {
+      const res = await fetch('/api/scan/min-size', {
+        method: 'PUT',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ bytes: Number(minFileSizeMb) * 1024 * 1024 }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        onToast('Minimum file size saved; it applies from the next scan', 'success');
+      } else {
+        onToast(data.error || 'Failed to save minimum file size', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to save minimum file size', 'error');
+      console.error('Error saving minimum file size:', error);
+    }
+  }, [minFileSizeMb, onToast]);
+
+  const handleSaveSpriteDensity = useCallback(async () => {
+    if (!spriteDensity) return;
+    try {
+      const res = await fetch('/api/sprites', {
+        method: 'PUT',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({
+          density: spriteDensity.custom
+            ? {
+              tileWidth: Number(spriteDensity.tileWidth),
+              columns: Number(spriteDensity.columns),
+              targetTiles: Number(spriteDensity.targetTiles),
+            }
+            : null,
+        }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        onToast('Sprite layout saved; regenerate sprites to apply it to existing videos', 'success');
+      } else {
+        onToast(data.error || 'Failed to save sprite layout', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to save sprite layout', 'error');
+      console.error('Error saving sprite density:', error);
+    }
+  }, [spriteDensity, onToast]);
+
+  const handleSaveBitrateThresholds = useCallback(async () => {
+    if (!bitrateThresholds) return;
+    try {
+      const res = await fetch('/api/bitrate', {
+        method: 'PUT',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({
+          thresholds: Object.fromEntries(BITRATE_CLASS_LABELS.map(([key]) => [key, Number(bitrateThresholds[key])])),
+        }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        onBitrateThresholdsChange?.(data.thresholds);
+        onToast('Bitrate thresholds saved', 'success');
+      } else {
+        onToast(data.error || 'Failed to save bitrate thresholds', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to save bitrate thresholds', 'error');
+      console.error('Error saving bitrate thresholds:', error);
+    }
+  }, [bitrateThresholds, onBitrateThresholdsChange, onToast]);
+
+  // Progress shows in the tasks panel
+  const handleRegenerateSprites = useCallback(async () => {
+    setIsRegeneratingSprites(true);
+    try {
+      const res = await fetch('/api/sprites', { method: 'POST' });
+      const data = await res.json();
+      if (data.success) {
+        onToast('Regenerating sprites in the background', 'success');
+      } else {
+        onToast(data.error || 'Failed to start sprite regeneration', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to start sprite regeneration', 'error');
+      console.error('Error starting sprite regeneration:', error);
+    } finally {
+      setIsRegeneratingSprites(false);
+    }
+  }, [onToast]);
+
+  // Download the app-wide settings as JSON for another machine
+  const handleExportSettings = useCallback(async () => {
+    try {
+      const res = await fetch('/api/app-settings');
+      const data = await res.json();
+      if (!data.success) {
+        setTransferMessage({ text: data.error || 'Export failed', isError: true });
+        return;
+      }
+
+      const blob = new Blob([JSON.stringify(data.settings, null, 2)], { type: 'application/json' });
+      const url = URL.createObjectURL(blob);
+      const link = document.createElement('a');
+      link.href = url;
+      link.download = `video-catalog-browser-settings-${data.settings.exportedAt.slice(0, 10)}.json`;
+      link.click();
+      URL.revokeObjectURL(url);
+      setTransferMessage(null);
+    } catch (error) {
+      setTransferMessage({ text: 'Export failed', isError: true });
+      console.error('Error exporting settings:', error);
+    }
+  }, []);
+
+  const handleImportSettings = useCallback(async (e: React.ChangeEvent<HTMLInputElement>) => {
+    const file = e.target.files?.[0];
+    e.target.value = ''; // Allow picking the same file again
+    if (!file) return;
+
+    try {
+      const res = await fetch('/api/app-settings', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: await file.text(),
+      });
+      const data = await res.json();
+      setTransferMessage(data.success
+        ? {
+          text: `Added ${data.added} librar${data.added !== 1 ? 'ies' : 'y'} (${data.skipped} already known)`
+            + (data.templatesImported ? ' and ffmpeg templates' : '')
+            + (data.presetsImported ? ' and transcode presets' : ''),
+          isError: false,
+        }
+        : { text: data.error || 'Import failed', isError: true });
+    } catch (error) {
+      setTransferMessage({ text: 'Import failed', isError: true });
+      console.error('Error importing settings:', error);
+    }
+  }, []);
+
+  return (
+    <div ref={panelRef} className="relative">
+      <button
+        onClick={() => setIsOpen(!isOpen)}
+        className="w-9 h-9 rounded-lg flex items-center justify-center bg-card-border text-muted hover:text-foreground transition-colors"
+        title="Settings"
+      >
+        <svg className="w-5 h-5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+          <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M10.325 4.317c.426-1.756 2.924-1.756 3.35 0a1.724 1.724 0 002.573 1.066c1.543-.94 3.31.826 2.37 2.37a1.724 1.724 0 001.065 2.572c1.756.426 1.756 2.924 0 3.35a1.724 1.724 0 00-1.066 2.573c.94 1.543-.826 3.31-2.37 2.37a1.724 1.724 0 00-2.572 1.065c-.426 1.756-2.924 1.756-3.35 0a1.724 1.724 0 00-2.573-1.066c-1.543.94-3.31-.826-2.37-2.37a1.724 1.724 0 00-1.065-2.572c-1.756-.426-1.756-2.924 0-3.35a1.724 1.724 0 001.066-2.573c-.94-1.543.826-3.31 2.37-2.37.996.608 2.296.07 2.572-1.065z" />
+          <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M15 12a3 3 0 11-6 0 3 3 0 016 0z" />
+        </svg>
+      </button>
+
+      {isOpen && (
+        <div className="absolute right-0 top-full mt-2 bg-card border border-card-border rounded-lg shadow-xl p-4 z-50 w-[360px] space-y-4">
+          <h3 className="text-sm font-semibold">Settings</h3>
+
+          {/* Local catalog API */}
+          <div>
+            <label className="flex items-center justify-between text-sm cursor-pointer">
+              <span>Local catalog API</span>
+              <input
+                type="checkbox"
+                checked={catalogApiEnabled}
+                onChange={(e) => handleToggleCatalogApi(e.target.checked)}
+              />
+            </label>
+            <p className="text-xs text-muted mt-1">
+              Read-only JSON endpoints for other tools, served on 127.0.0.1 only.
+            </p>
+            {catalogApiEnabled && (
+              <>
+                <div className="flex items-center justify-between text-sm mt-2">
+                  <span>Port</span>
+                  <div className="flex items-center gap-2">
+                    <input
+                      type="number"
+                      min={1024}
+                      max={65535}
+                      value={catalogApiPort}
+                      onChange={(e) => setCatalogApiPort(e.target.value)}
+                      onKeyDown={(e) => {
+                        if (e.key === 'Enter') handleSaveCatalogApiPort();
+                      }}
+                      className="w-20 px-2 py-1 bg-background border border-card-border rounded text-xs text-right tabular-nums"
+                    />
+                    <button
+                      onClick={handleSaveCatalogApiPort}
+                      className="px-3 py-1 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+                    >
+                      Save
+                    </button>
+                  </div>
+                </div>
+                {catalogApiServer?.error ? (
+                  <p className="text-xs text-error mt-2">{catalogApiServer.error}</p>
+                ) : (
+                  <p className="text-xs font-mono bg-background px-2 py-1 rounded mt-2 break-all">
+                    http://127.0.0.1:{catalogApiServer?.port ?? catalogApiPort}/videos · /videos/:id · /videos/:id/thumbnail · /stats
+                  </p>
+                )}
+              </>
+            )}
+          </div>
+
+          {/* Online-only cloud files during scans (per library) */}
+          {placeholderMode !== null && (
+            <div>
+              <div className="flex items-center justify-between text-sm">
+                <span>Online-only cloud files</span>
+                <select
+                  value={placeholderMode}
+                  onChange={(e) => handleChangePlaceholderMode(e.target.value as CloudPlaceholderMode)}
+                  className="px-2 py-1 bg-background border border-card-border rounded text-xs"
+                >
+                  <option value="skip">Skip</option>
+                  <option value="catalog">Catalog by name</option>
+                </select>
+              </div>
+              <p className="text-xs text-muted mt-1">
+                iCloud Drive, Dropbox and OneDrive files that aren&apos;t downloaded would be fetched in full to index them. Skipping leaves them out; cataloging lists them with only their name and size until they are downloaded and rescanned.
+              </p>
+            </div>
+          )}
+
+          {/* Portable library */}
+          <div>
+            <label className="flex items-center justify-between text-sm cursor-pointer">
+              <span>Portable library</span>
+              <input
+                type="checkbox"
+                checked={portableEnabled}
+                disabled={isConverting}
+                onChange={(e) => handleTogglePortable(e.target.checked)}
+              />
+            </label>
+            <p className="text-xs text-muted mt-1">
+              {isConverting
+                ? 'Converting paths...'
+                : 'Store paths relative to the library folder so the catalog still works when the drive is mounted elsewhere.'}
+            </p>
+          </div>
+
+          {/* Copying file names that aren't unique */}
+          <div>
+            <label className="flex items-center justify-between text-sm cursor-pointer">
+              <span>Copy shared names with folder</span>
+              <input
+                type="checkbox"
+                checked={copyWithFolder}
+                onChange={(e) => handleToggleCopyWithFolder(e.target.checked)}
+              />
+            </label>
+            <p className="text-xs text-muted mt-1">
+              When several videos have the same file name (marked ≈ on the card), &quot;Copy filename&quot; gives folder/name instead.
+            </p>
+          </div>
+
+          {/* Excluded folders (per library) */}
+          {exclusionsText !== null && (
+            <div>
+              <div className="flex items-center justify-between text-sm">
+                <span>Excluded folders</span>
+                <button
+                  onClick={handleSaveExclusions}
+                  disabled={exclusionsText === savedExclusions}
+                  className="px-3 py-1 text-xs rounded-lg bg-card-border text-muted hover:text-foreground disabled:opacity-50"
+                >
+                  Save
+                </button>
+              </div>
+              <textarea
+                value={exclusionsText}
+                onChange={(e) => setExclusionsText(e.target.value)}
+                placeholder={'Renders\nArchive/2019\n**/Cache'}
+                rows={4}
+                spellCheck={false}
+                className="w-full mt-2 px-2 py-1.5 bg-background border border-card-border rounded text-xs font-mono resize-y focus:outline-none focus:ring-1 focus:ring-accent"
+              />
+              <p className={`text-xs mt-1 ${exclusionsError ? 'text-error' : 'text-muted'}`}>
+                {exclusionsError
+                  ?? 'One glob per line. A bare name matches at any depth; a path with / matches from the library root. Applies from the next scan.'}
+              </p>
+            </div>
+          )}
+
+          {/* Smallest file a scan indexes (per library) */}
+          {minFileSizeMb !== null && (
+            <div>
+              <div className="flex items-center justify-between text-sm">
+                <span>Skip videos smaller than</span>
+                <div className="flex items-center gap-2">
+                  <input
+                    type="number"
+                    min={0}
+                    step={0.5}
+                    value={minFileSizeMb}
+                    onChange={(e) => setMinFileSizeMb(e.target.value)}
+                    onKeyDown={(e) => {
+                      if (e.key === 'Enter') handleSaveMinFileSize();
+                    }}
+                    className="w-16 px-2 py-1 bg-background border border-card-border rounded text-xs text-right tabular-nums"
+                  />
+                  <span className="text-xs text-muted">MB</span>
+                  <button
+                    onClick={handleSaveMinFileSize}
+                    className="px-3 py-1 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+                  >
+                    Save
+                  </button>
+                </div>
+              </div>
+              <p className="text-xs text-muted mt-1">
+                Leaves out camera preview clips and other tiny files. 0 indexes everything. Applies from the next scan, which also drops smaller files already indexed.
+              </p>
+            </div>
+          )}
+
+          {/* Loudness measurement during scans (per library) */}
+          {measureLoudness !== null && (
+            <div>
+              <label className="flex items-center justify-between text-sm cursor-pointer">
+                <span>Measure loudness when scanning</span>
+                <input
+                  type="checkbox"
+                  checked={measureLoudness}
+                  onChange={(e) => handleToggleMeasureLoudness(e.target.checked)}
+                />
+              </label>
+              <p className="text-xs text-muted mt-1">
+                Stores each clip&apos;s integrated loudness (LUFS) for the Loud and Quiet filters. Decodes all of the audio, so scans get slower. The next scan measures clips that have none yet.
+              </p>
+            </div>
+          )}
+
+          {/* Database size and compaction */}
+          {databaseStats && (
+            <div>
+              <div className="flex items-center justify-between text-sm">
+                <span>Database: {formatFileSize(databaseStats.fileSize)}</span>
+                <div className="flex items-center gap-2">
+                  {databaseStats.compactable && (
+                    <button
+                      onClick={handleCompactDatabase}
+                      disabled={isCompacting}
+                      className="px-3 py-1 text-xs rounded-lg bg-card-border text-muted hover:text-foreground disabled:opacity-50"
+                    >
+                      {isCompacting ? 'Compacting…' : 'Compact'}
+                    </button>
+                  )}
+                  <a
+                    href="/api/database/backup"
+                    download
+                    className="px-3 py-1 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+                    title="Download a consistent copy of catalog.db, safe while a scan is running"
+                  >
+                    Back up
+                  </a>
+                </div>
+              </div>
+              <p className="text-xs text-muted mt-1">
+                {databaseStats.compactable
+                  ? 'More than 20% of the file is free space left by deleted rows. Compacting rewrites it.'
+                  : 'No significant free space to reclaim.'}
+              </p>
+            </div>
+          )}
+
+          {/* Silence detection threshold */}
+          {silenceThreshold !== null && (
+            <div>
+              <div className="flex items-center justify-between text-sm">
+                <span>Flag silence longer than</span>
+                <div className="flex items-center gap-2">
+                  <input
+                    type="number"
+                    min={1}
+                    step={1}
+                    value={silenceThreshold}
+                    onChange={(e) => setSilenceThreshold(e.target.value)}
+                    onKeyDown={(e) => {
+                      if (e.key === 'Enter') handleSaveSilenceThreshold();
+                    }}
+                    className="w-16 px-2 py-1 bg-background border border-card-border rounded text-xs text-right tabular-nums"
+                  />
+                  <span className="text-xs text-muted">s</span>
+                  <button
+                    onClick={handleSaveSilenceThreshold}
+                    className="px-3 py-1 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+                  >
+                    Save
+                  </button>
+                </div>
+              </div>
+              <p className="text-xs text-muted mt-1">
+                Clips checked for silence get a dropout badge when the audio is silent this long. No new check is needed after changing it.
+              </p>
+            </div>
+          )}
+
+          {/* Sprite sheet density */}
+          {spriteDensity !== null && (
+            <div>
+              <div className="flex items-center justify-between text-sm">
+                <span>Sprite layout</span>
+                <div className="flex items-center gap-2">
+                  <select
+                    value={spriteDensity.custom ? 'custom' : 'auto'}
+                    onChange={(e) => setSpriteDensity({ ...spriteDensity, custom: e.target.value === 'custom' })}
+                    className="px-2 py-1 bg-background border border-card-border rounded text-xs"
+                  >
+                    <option value="auto">By duration</option>
+                    <option value="custom">Custom</option>
+                  </select>
+                  <button
+                    onClick={handleSaveSpriteDensity}
+                    className="px-3 py-1 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+                  >
+                    Save
+                  </button>
+                </div>
+              </div>
+              {spriteDensity.custom && (
+                <div className="flex items-center gap-3 mt-2 text-xs text-muted">
+                  {([
+                    ['tileWidth', 'Tile width (px)'],
+                    ['columns', 'Columns'],
+                    ['targetTiles', 'Tiles'],
+                  ] as const).map(([key, label]) => (
+                    <label key={key} className="flex flex-col gap-1">
+                      {label}
+                      <input
+                        type="number"
+                        min={1}
+                        step={1}
+                        value={spriteDensity[key]}
+                        onChange={(e) => setSpriteDensity({ ...spriteDensity, [key]: e.target.value })}
+                        className="w-20 px-2 py-1 bg-background border border-card-border rounded text-xs text-right tabular-nums text-foreground"
+                      />
+                    </label>
+                  ))}
+                </div>
+              )}
+              <div className="flex items-center justify-between mt-2">
+                <p className="text-xs text-muted">
+                  Clips under 10 seconds get no sprite. Existing sprites keep their layout until regenerated.
+                </p>
+                <button
+                  onClick={handleRegenerateSprites}
+                  disabled={isRegeneratingSprites}
+                  className="ml-2 shrink-0 px-3 py-1 text-xs rounded-lg bg-card-border text-muted hover:text-foreground disabled:opacity-50"
+                >
+                  Regenerate sprites
+                </button>
+              </div>
+            </div>
+          )}
+
+          {/* Bit rate thresholds for the Inefficient clips view */}
+          {bitrateThresholds !== null && (
+            <div>
+              <div className="flex items-center justify-between text-sm">
+                <span>Inefficient above (Mbps)</span>
+                <button
+                  onClick={handleSaveBitrateThresholds}
+                  className="px-3 py-1 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+                >
+                  Save
+                </button>
+              </div>
+              <div className="flex items-center gap-3 mt-2 text-xs text-muted">
+                {BITRATE_CLASS_LABELS.map(([key, label]) => (
+                  <label key={key} className="flex flex-col gap-1">
+                    {label}
+                    <input
+                      type="number"
+                      min={1}
+                      value={bitrateThresholds[key]}
+                      onChange={(e) => setBitrateThresholds({ ...bitrateThresholds, [key]: e.target.value })}
+                      className="w-16 px-2 py-1 bg-background border border-card-border rounded text-xs text-right tabular-nums text-foreground"
+                    />
+                  </label>
+                ))}
+              </div>
+              <p className="text-xs text-muted mt-1">
+                Clips above the rate for their resolution show in the Inefficient clips smart view.
+              </p>
+            </div>
+          )}
+
+          {/* Settings file */}
+          <div>
+            <div className="flex items-center justify-between text-sm">
+              <span>Settings file</span>
+              <div className="flex gap-2">
+                <button
+                  onClick={handleExportSettings}
+                  className="px-3 py-1 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+                >
+                  Export…
+                </button>
+                <button
+                  onClick={() => importInputRef.current?.click()}
+                  className="px-3 py-1 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+                >
+                  Import…
+                </button>
+                <input
+                  ref={importInputRef}
+                  type="file"
+                  accept=".json,application/json"
+                  onChange={handleImportSettings}
+                  className="hidden"
+                />
+              </div>
+            </div>
+            <p className={`text-xs mt-1 ${transferMessage?.isError ? 'text-error' : 'text-muted'}`}>
+              {transferMessage?.text
+                ?? 'Library history, ffmpeg templates and transcode presets, for moving to another machine. Favorites and notes are stored with each library.'}
+            </p>
+          </div>
+
+          {/* Logs */}
+          <div>
+            <div className="flex items-center justify-between text-sm">
+              <span>Logs</span>
+              <button
+                onClick={() => {
+                  setShowLogs(true);
+                  setIsOpen(false);
+                }}
+                className="px-3 py-1 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+              >
+                View logs
+              </button>
+            </div>
+            <p className="text-xs text-muted mt-1">
+              Server output, kept for the last 5 days. Attach it when reporting a bug.
+            </p>
+          </div>
+        </div>
+      )}
+
+      {showLogs && <LogViewer onClose={() => setShowLogs(false)} />}
+    </div>
+  );
+}
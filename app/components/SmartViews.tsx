@@ -0,0 +1,260 @@
+'use client';
+
+import { useState, useEffect, useRef, useCallback, useMemo } from 'react';
+import { ShowToast } from './Toast';
+import { SmartView, SmartViewCriteria } from '@/app/lib/types';
+
+interface SmartViewsProps {
+  currentPath: string;
+  criteria: SmartViewCriteria; // Current search, favorites filter and sort
+  onApply: (criteria: SmartViewCriteria) => void;
+  countMatches: (criteria: SmartViewCriteria) => number | null; // Null when not every video is loaded
+  onTranscode: (criteria: SmartViewCriteria) => void; // Apply a view and open batch transcode on it
+  onToast: ShowToast;
+}
+
+// Built-in view, listed above the saved ones. Not stored, so it can't be renamed or deleted.
+const INEFFICIENT_VIEW: SmartView = {
+  id: 'preset:inefficient',
+  name: 'Inefficient clips',
+  query: '',
+  searchMode: 'name',
+  favoritesOnly: false,
+  watchedFilter: 'any',
+  inefficientOnly: true,
+  sortBy: 'bitrate-desc',
+};
+
+function isSameCriteria(a: SmartViewCriteria, b: SmartViewCriteria): boolean {
+  return a.query.trim() === b.query.trim()
+    && a.searchMode === b.searchMode
+    && a.favoritesOnly === b.favoritesOnly
+    && a.watchedFilter === b.watchedFilter
+    && a.inefficientOnly === b.inefficientOnly
+    && a.sortBy === b.sortBy;
+}
+
+// Dropdown of saved searches. The active view is whichever one matches the current
+// criteria, so changing the search or Favorites toggle afterwards leaves the view.
+export default function SmartViews({ currentPath, criteria, onApply, countMatches, onTranscode, onToast }: SmartViewsProps) {
+  const [isOpen, setIsOpen] = useState(false);
+  const [views, setViews] = useState<SmartView[]>([]);
+  const panelRef = useRef<HTMLDivElement>(null);
+
+  // Close panel when clicking outside
+  useEffect(() => {
+    const handleClickOutside = (e: MouseEvent) => {
+      if (panelRef.current && !panelRef.current.contains(e.target as Node)) {
+        setIsOpen(false);
+      }
+    };
+    if (isOpen) {
+      document.addEventListener('mousedown', handleClickOutside);
+    }
+    return () => document.removeEventListener('mousedown', handleClickOutside);
+  }, [isOpen]);
+
+  // Views are stored per library
+  useEffect(() => {
+    const fetchViews = async () => {
+      try {
+        const res = await fetch('/api/smart-views');
+        const data = await res.json();
+        setViews(data.success ? data.views : []);
+      } catch (error) {
+        console.error('Error fetching smart views:', error);
+      }
+    };
+
+    fetchViews();
+  }, [currentPath]);
+
+  const handleSave = useCallback(async () => {
+    const name = window.prompt('Name this view:', criteria.query);
+    if (!name?.trim()) return;
+
+    try {
+      const res = await fetch('/api/smart-views', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ name, criteria }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        setViews(data.views);
+        onToast(`Saved view "${data.view.name}"`, 'success');
+      } else {
+        onToast(data.error || 'Failed to save view', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to save view', 'error');
+      console.error('Error saving smart view:', error);
+    }
+  }, [criteria, onToast]);
+
+  const handleRename = useCallback(async (view: SmartView) => {
+    const name = window.prompt('Rename view:', view.name);
+    if (!name?.trim() || name.trim() === view.name) return;
+
+    try {
+      const res = await fetch(`/api/smart-views/${view.id}`, {
+        method: 'PATCH',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ name }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        setViews(data.views);
+      } else {
+        onToast(data.error || 'Failed to rename view', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to rename view', 'error');
+      console.error('Error renaming smart view:', error);
+    }
+  }, [onToast]);
+
+  const handleDelete = useCallback(async (view: SmartView) => {
+    if (!window.confirm(`Delete view "${view.name}"?`)) return;
+
+    try {
+      const res = await fetch(`/api/smart-views/${view.id}`, { method: 'DELETE' });
+      const data = await res.json();
+      if (data.success) {
+        setViews(data.views);
+      } else {
+        onToast(data.error || 'Failed to delete view', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to delete view', 'error');
+      console.error('Error deleting smart view:', error);
+    }
+  }, [onToast]);
+
+  const activeView = [INEFFICIENT_VIEW, ...views].find((view) => isSameCriteria(view, criteria));
+
+  // Views are re-evaluated against the current videos, like live folders
+  const matchCounts = useMemo(
+    () => new Map<string, number | null>(
+      isOpen ? [INEFFICIENT_VIEW, ...views].map((view): [string, number | null] => [view.id, countMatches(view)]) : []
+    ),
+    [isOpen, views, countMatches]
+  );
+
+  return (
+    <div ref={panelRef} className="relative">
+      <button
+        onClick={() => setIsOpen(!isOpen)}
+        className={`px-4 py-2 text-sm rounded-lg transition-colors flex items-center gap-1.5 ${
+          activeView
+            ? 'bg-accent text-white'
+            : 'bg-card-border text-muted hover:text-foreground'
+        }`}
+        title="Saved searches"
+      >
+        <span className="max-w-[10rem] truncate">{activeView ? activeView.name : 'Smart Views'}</span>
+        <svg className="w-3.5 h-3.5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+          <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M19 9l-7 7-7-7" />
+        </svg>
+      </button>
+
+      {isOpen && (
+        <div className="absolute right-0 top-full mt-2 bg-card border border-card-border rounded-lg shadow-xl p-2 z-50 w-72">
+          <div
+            className={`flex items-center gap-1 rounded-lg ${
+              activeView?.id === INEFFICIENT_VIEW.id ? 'bg-accent/20' : 'hover:bg-card-border'
+            }`}
+          >
+            <button
+              onClick={() => {
+                onApply(INEFFICIENT_VIEW);
+                setIsOpen(false);
+              }}
+              className="flex-1 min-w-0 text-left px-2 py-1.5"
+            >
+              <div className="flex items-center gap-2">
+                <p className="flex-1 text-sm truncate">{INEFFICIENT_VIEW.name}</p>
+                {matchCounts.get(INEFFICIENT_VIEW.id) != null && (
+                  <span className="text-xs text-muted tabular-nums">{matchCounts.get(INEFFICIENT_VIEW.id)}</span>
+                )}
+              </div>
+              <p className="text-xs text-muted truncate">Bitrate above the threshold for the resolution</p>
+            </button>
+            <button
+              onClick={() => {
+                onTranscode(INEFFICIENT_VIEW);
+                setIsOpen(false);
+              }}
+              className="px-2 py-1 text-xs rounded text-muted hover:text-foreground"
+              title="Show these clips and open batch transcode for them"
+            >
+              Transcode…
+            </button>
+          </div>
+
+          <div className="border-t border-card-border my-2" />
+
+          {views.length === 0 && (
+            <p className="px-2 py-1.5 text-sm text-muted">No saved views yet.</p>
+          )}
+
+          {views.map((view) => (
+            <div
+              key={view.id}
+              className={`group flex items-center gap-1 rounded-lg ${
+                view.id === activeView?.id ? 'bg-accent/20' : 'hover:bg-card-border'
+              }`}
+            >
+              <button
+                onClick={() => {
+                  onApply(view);
+                  setIsOpen(false);
+                }}
+                className="flex-1 min-w-0 text-left px-2 py-1.5"
+              >
+                <div className="flex items-center gap-2">
+                  <p className="flex-1 text-sm truncate">{view.name}</p>
+                  {matchCounts.get(view.id) != null && (
+                    <span className="text-xs text-muted tabular-nums">{matchCounts.get(view.id)}</span>
+                  )}
+                </div>
+                <p className="text-xs text-muted truncate">
+                  {[
+                    view.favoritesOnly && '★ Favorites',
+                    view.watchedFilter !== 'any' && (view.watchedFilter === 'watched' ? '✓ Watched' : 'Unwatched'),
+                    view.inefficientOnly && 'Inefficient',
+                    view.query && `"${view.query}"${view.searchMode === 'all' ? ' (all fields)' : ''}`,
+                  ].filter(Boolean).join(' · ') || 'All videos'}
+                </p>
+              </button>
+              <button
+                onClick={() => handleRename(view)}
+                className="px-1.5 text-xs text-muted hover:text-foreground opacity-0 group-hover:opacity-100"
+                title="Rename"
+              >
+                ✎
+              </button>
+              <button
+                onClick={() => handleDelete(view)}
+                className="px-1.5 text-xs text-muted hover:text-red-400 opacity-0 group-hover:opacity-100"
+                title="Delete"
+              >
+                ✕
+              </button>
+            </div>
+          ))}
+
+          <div className="border-t border-card-border mt-2 pt-2">
+            <button
+              onClick={handleSave}
+              disabled={!!activeView}
+              className="w-full text-left px-2 py-1.5 text-sm rounded-lg text-muted hover:text-foreground hover:bg-card-border disabled:opacity-50 disabled:hover:bg-transparent"
+            >
+              Save current view…
+            </button>
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -1,47 +1,132 @@
 'use client';
 
-import { useState } from 'react';
-import { SortOption } from '@/app/lib/types';
+import { useState, useEffect } from 'react';
+import { CacheClearScope, CacheSizeBreakdown, SortOption, ViewLayout } from '@/app/lib/types';
+import CacheSize from './CacheSize';
+import { formatDuration, formatFileSize } from '@/app/lib/utils';
 
 interface SortControlsProps {
   value: SortOption;
   onChange: (value: SortOption) => void;
+  layout: ViewLayout;
+  onLayoutChange: (layout: ViewLayout) => void;
+  stripFrames: number;
+  onStripFramesChange: (frames: number) => void;
   videoCount: number;
-  onClearCache?: () => void;
+  watchStats?: { watched: number; total: number; remainingSeconds: number };
+  onClearCache?: () => void; // Everything was deleted, including the database
+  onAssetsCleared?: () => void; // Thumbnails, sprites or proxies were deleted
 }
 
-const SORT_OPTIONS: { value: SortOption; label: string }[] = [
+export const SORT_OPTIONS: { value: SortOption; label: string }[] = [
   { value: 'date-desc', label: 'Newest First' },
   { value: 'date-asc', label: 'Oldest First' },
+  { value: 'best-date-desc', label: 'Recorded, Newest First' },
+  { value: 'best-date-asc', label: 'Recorded, Oldest First' },
+  { value: 'scanned-desc', label: 'Recently Added' },
+  { value: 'scanned-asc', label: 'Added, Oldest First' },
   { value: 'duration-desc', label: 'Longest First' },
   { value: 'duration-asc', label: 'Shortest First' },
+  { value: 'bitrate-desc', label: 'Highest Bitrate' },
+  { value: 'bitrate-asc', label: 'Lowest Bitrate' },
   { value: 'name-asc', label: 'Name A-Z' },
   { value: 'name-desc', label: 'Name Z-A' },
+  { value: 'watched-asc', label: 'Watched Oldest' },
+  { value: 'unwatched-first', label: 'Unwatched First' },
 ];
 
-export default function SortControls({ value, onChange, videoCount, onClearCache }: SortControlsProps) {
+const LAYOUT_OPTIONS: { value: ViewLayout; label: string; title: string }[] = [
+  { value: 'grid', label: 'Grid', title: 'Uniform card sizes' },
+  { value: 'smart', label: 'Smart Grid', title: 'Larger cards for higher resolutions' },
+  { value: 'strip', label: 'Strip', title: 'Several frames per video, no hover needed' },
+  { value: 'date', label: 'By Date', title: 'Grouped under a header per day shot' },
+  { value: 'folder', label: 'By Folder', title: 'Grouped under a header per folder, which can be collapsed' },
+];
+
+// Categories Clear Cache can remove on their own; the database goes only with everything
+const PARTIAL_CLEAR_OPTIONS: { scope: Exclude<CacheClearScope, 'all'>; label: string }[] = [
+  { scope: 'thumbnails', label: 'Thumbnails' },
+  { scope: 'sprites', label: 'Hover sprites' },
+  { scope: 'proxies', label: 'Proxies' },
+];
+
+// Frames per card in the strip layout
+const STRIP_FRAME_OPTIONS = [3, 4, 5, 6, 7, 8, 9];
+
+export default function SortControls({
+  value,
+  onChange,
+  layout,
+  onLayoutChange,
+  stripFrames,
+  onStripFramesChange,
+  videoCount,
+  watchStats,
+  onClearCache,
+  onAssetsCleared,
+}: SortControlsProps) {
   const [showConfirm, setShowConfirm] = useState(false);
-  const [isClearing, setIsClearing] = useState(false);
+  const [confirmAll, setConfirmAll] = useState(false);
+  const [clearing, setClearing] = useState<CacheClearScope | null>(null);
+  const [cacheSize, setCacheSize] = useState<CacheSizeBreakdown | null>(null);
+  const [clearCount, setClearCount] = useState(0); // Refreshes the cache size label
+
+  // Measure .vcb-data afresh whenever the dialog opens
+  useEffect(() => {
+    if (!showConfirm) return;
+    setCacheSize(null);
+    setConfirmAll(false);
+
+    const fetchCacheSize = async () => {
+      try {
+        const res = await fetch('/api/cache?refresh=true');
+        const data = await res.json();
+        if (data.success) setCacheSize(data.cacheSize);
+      } catch (error) {
+        console.error('Error fetching cache size:', error);
+      }
+    };
 
-  const handleClearCache = async () => {
-    setIsClearing(true);
+    fetchCacheSize();
+  }, [showConfirm]);
+
+  const handleClearCache = async (scope: CacheClearScope) => {
+    setClearing(scope);
     try {
-      const res = await fetch('/api/cache/clear', { method: 'POST' });
+      const res = await fetch('/api/cache/clear', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ scope }),
+      });
       if (res.ok) {
-        setShowConfirm(false);
-        onClearCache?.();
+        const data = await res.json();
+        if (scope === 'all') {
+          setShowConfirm(false);
+          onClearCache?.();
+        } else {
+          setCacheSize(data.cacheSize);
+          setClearCount((count) => count + 1);
+          onAssetsCleared?.();
+        }
       }
     } catch (error) {
       console.error('Failed to clear cache:', error);
     } finally {
-      setIsClearing(false);
+      setClearing(null);
     }
   };
 
   return (
     <div className="flex items-center justify-between py-3 px-4 border-b border-card-border">
-      <div className="text-sm text-muted">
-        {videoCount} video{videoCount !== 1 ? 's' : ''}
+      <div className="flex items-center gap-3 text-sm text-muted">
+        <span>{videoCount} video{videoCount !== 1 ? 's' : ''}</span>
+        {watchStats && watchStats.total > 0 && (
+          <span title={`${formatDuration(watchStats.remainingSeconds)} of unwatched footage left`}>
+            Watched: {watchStats.watched}/{watchStats.total} ({Math.round((watchStats.watched / watchStats.total) * 100)}%)
+            {watchStats.remainingSeconds > 0 && ` · ${formatDuration(watchStats.remainingSeconds)} left`}
+          </span>
+        )}
+        <CacheSize refreshKey={`${videoCount}:${clearCount}`} />
       </div>
 
       <div className="flex items-center gap-4">
@@ -62,32 +147,89 @@ export default function SortControls({ value, onChange, videoCount, onClearCache
             Clear Cache
           </button>
 
-          {/* Confirmation Dialog */}
+          {/* Clear options with the size of each category */}
           {showConfirm && (
-            <div className="absolute right-0 top-full mt-2 bg-card border border-card-border rounded-lg shadow-xl p-4 z-50 min-w-[250px]">
+            <div className="absolute right-0 top-full mt-2 bg-card border border-card-border rounded-lg shadow-xl p-4 z-50 w-72">
               <p className="text-sm text-foreground mb-3">
-                Delete all cached data? This will remove proxies, thumbnails, and the database.
+                Cached data: {cacheSize ? formatFileSize(cacheSize.total) : 'measuring…'}
+              </p>
+              <div className="space-y-1.5 mb-3">
+                {PARTIAL_CLEAR_OPTIONS.map(({ scope, label }) => (
+                  <div key={scope} className="flex items-center gap-2 text-sm">
+                    <span className="flex-1">{label}</span>
+                    <span className="text-xs text-muted tabular-nums">
+                      {cacheSize ? formatFileSize(cacheSize[scope]) : '…'}
+                    </span>
+                    <button
+                      onClick={() => handleClearCache(scope)}
+                      disabled={clearing !== null || cacheSize?.[scope] === 0}
+                      className="px-2 py-1 text-xs rounded-lg bg-card border border-card-border text-muted hover:text-red-400 hover:border-red-500/50 disabled:opacity-50 disabled:hover:text-muted disabled:hover:border-card-border"
+                    >
+                      {clearing === scope ? 'Clearing…' : 'Clear'}
+                    </button>
+                  </div>
+                ))}
+                <div className="flex items-center gap-2 text-sm">
+                  <span className="flex-1">Database</span>
+                  <span className="text-xs text-muted tabular-nums">
+                    {cacheSize ? formatFileSize(cacheSize.database) : '…'}
+                  </span>
+                </div>
+              </div>
+              <p className="text-xs text-muted mb-3">
+                The next scan regenerates cleared thumbnails and sprites; proxies are made again on request.
               </p>
-              <div className="flex gap-2 justify-end">
+              <div className="flex gap-2 justify-end border-t border-card-border pt-3">
                 <button
                   onClick={() => setShowConfirm(false)}
                   className="px-3 py-1.5 text-sm rounded-lg bg-card border border-card-border text-muted hover:text-foreground"
-                  disabled={isClearing}
+                  disabled={clearing !== null}
                 >
-                  Cancel
+                  Close
                 </button>
                 <button
-                  onClick={handleClearCache}
-                  disabled={isClearing}
+                  onClick={() => (confirmAll ? handleClearCache('all') : setConfirmAll(true))}
+                  disabled={clearing !== null}
                   className="px-3 py-1.5 text-sm rounded-lg bg-red-500 text-white hover:bg-red-600 disabled:opacity-50"
+                  title="Remove proxies, thumbnails, sprites and the database"
                 >
-                  {isClearing ? 'Clearing...' : 'Delete'}
+                  {clearing === 'all' ? 'Clearing...' : confirmAll ? 'Delete everything?' : 'Clear everything'}
                 </button>
               </div>
             </div>
           )}
         </div>
 
+        {/* Layout toggle */}
+        <div className="flex items-center bg-card border border-card-border rounded-lg overflow-hidden text-sm">
+          {LAYOUT_OPTIONS.map((option) => (
+            <button
+              key={option.value}
+              onClick={() => onLayoutChange(option.value)}
+              title={option.title}
+              className={`px-3 py-1.5 transition-colors ${
+                layout === option.value
+                  ? 'bg-accent text-white'
+                  : 'text-muted hover:text-foreground'
+              }`}
+            >
+              {option.label}
+            </button>
+          ))}
+        </div>
+        {layout === 'strip' && (
+          <select
+            value={stripFrames}
+            onChange={(e) => onStripFramesChange(Number(e.target.value))}
+            className="px-2 py-1.5 bg-card border border-card-border rounded-lg text-sm text-foreground"
+            title="Frames per video"
+          >
+            {STRIP_FRAME_OPTIONS.map((frames) => (
+              <option key={frames} value={frames}>{frames} frames</option>
+            ))}
+          </select>
+        )}
+
         <label className="text-sm text-muted">Sort by:</label>
         <select
           value={value}
@@ -0,0 +1,134 @@
+'use client';
+
+import { useState, useEffect, useRef } from 'react';
+import { FFmpegMetadata } from '@/app/lib/types';
+import { formatDuration } from '@/app/lib/utils';
+
+interface StreamModalProps {
+  url: string;
+  onClose: () => void;
+}
+
+export default function StreamModal({ url, onClose }: StreamModalProps) {
+  const [metadata, setMetadata] = useState<FFmpegMetadata | null>(null);
+  const [isLive, setIsLive] = useState(false);
+  const [isPaused, setIsPaused] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const videoRef = useRef<HTMLVideoElement>(null);
+
+  // Probe the stream before playing so live sources can hide seeking
+  useEffect(() => {
+    const probe = async () => {
+      try {
+        const res = await fetch(`/api/stream-url/probe?url=${encodeURIComponent(url)}`);
+        const data = await res.json();
+        if (data.success) {
+          setMetadata(data.metadata);
+          setIsLive(data.isLive);
+        } else {
+          setError(data.error || 'Could not open stream');
+        }
+      } catch (err) {
+        setError('Could not open stream');
+        console.error('Error probing stream:', err);
+      }
+    };
+
+    probe();
+  }, [url]);
+
+  // Handle escape key to close
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        onClose();
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [onClose]);
+
+  const togglePlayback = () => {
+    const videoEl = videoRef.current;
+    if (!videoEl) return;
+    if (videoEl.paused) {
+      videoEl.play();
+    } else {
+      videoEl.pause();
+    }
+  };
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/90"
+      onClick={onClose}
+    >
+      <div
+        className="relative w-full max-w-6xl max-h-[90vh] bg-card rounded-xl overflow-hidden shadow-2xl"
+        onClick={(e) => e.stopPropagation()}
+      >
+        {/* Close button */}
+        <button
+          onClick={onClose}
+          className="absolute top-4 right-4 z-10 w-10 h-10 bg-black/50 hover:bg-black/70 rounded-full flex items-center justify-center transition-colors"
+        >
+          <svg className="w-6 h-6" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+            <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M6 18L18 6M6 6l12 12" />
+          </svg>
+        </button>
+
+        {/* Video player */}
+        <div className="relative bg-black min-h-[40vh] flex items-center justify-center">
+          {error ? (
+            <p className="text-error text-sm p-8">{error}</p>
+          ) : metadata ? (
+            <>
+              {/* Live streams have no known duration, so native seek controls are hidden */}
+              <video
+                ref={videoRef}
+                src={`/api/stream-url?url=${encodeURIComponent(url)}`}
+                controls={!isLive}
+                autoPlay
+                onPlay={() => setIsPaused(false)}
+                onPause={() => setIsPaused(true)}
+                onError={() => setError('Playback failed')}
+                className="w-full max-h-[60vh] object-contain"
+              />
+
+              {isLive && (
+                <div className="absolute bottom-4 left-4 flex items-center gap-2">
+                  <button
+                    onClick={togglePlayback}
+                    className="px-3 py-1.5 bg-black/60 hover:bg-black/80 rounded-lg text-sm"
+                  >
+                    {isPaused ? 'Play' : 'Pause'}
+                  </button>
+                  <span className="bg-error/80 text-white px-2 py-1 rounded text-xs font-medium">
+                    LIVE
+                  </span>
+                </div>
+              )}
+            </>
+          ) : (
+            <div className="w-8 h-8 border-2 border-white/30 border-t-white rounded-full animate-spin" />
+          )}
+        </div>
+
+        {/* Stream info */}
+        <div className="p-6">
+          <h2 className="text-xl font-semibold mb-1 break-all">{url}</h2>
+          {metadata && (
+            <div className="flex items-center gap-3 text-sm text-muted">
+              <span>{isLive ? 'Live stream' : formatDuration(metadata.duration)}</span>
+              <span>•</span>
+              <span>{metadata.width}×{metadata.height}</span>
+              <span>•</span>
+              <span>{metadata.codec}</span>
+            </div>
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
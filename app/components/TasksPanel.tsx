@@ -0,0 +1,201 @@
+'use client';
+
+import { useState, useEffect, useRef, useCallback } from 'react';
+import { TaskInfo, TaskItem } from '@/app/lib/types';
+
+// Poll quickly while work is running so progress bars stay live
+const ACTIVE_POLL_MS = 500;
+const IDLE_POLL_MS = 3000;
+
+const ITEM_STATUS_LABELS: Record<TaskItem['status'], string> = {
+  queued: 'Queued',
+  running: '',
+  complete: 'Done',
+  error: 'Failed',
+  cancelled: 'Cancelled',
+};
+
+const ITEM_STATUS_CLASSES: Record<TaskItem['status'], string> = {
+  queued: 'text-muted',
+  running: 'text-accent tabular-nums',
+  complete: 'text-success',
+  error: 'text-error',
+  cancelled: 'text-muted',
+};
+
+interface TasksPanelProps {
+  onTaskFinished?: (task: TaskInfo) => void; // A task seen running has stopped
+}
+
+export default function TasksPanel({ onTaskFinished }: TasksPanelProps) {
+  const [isOpen, setIsOpen] = useState(false);
+  const [tasks, setTasks] = useState<TaskInfo[]>([]);
+  const panelRef = useRef<HTMLDivElement>(null);
+  const runningIdsRef = useRef<Set<string>>(new Set());
+
+  const runningCount = tasks.filter((t) => t.status === 'running').length;
+
+  // Close panel when clicking outside
+  useEffect(() => {
+    const handleClickOutside = (e: MouseEvent) => {
+      if (panelRef.current && !panelRef.current.contains(e.target as Node)) {
+        setIsOpen(false);
+      }
+    };
+    if (isOpen) {
+      document.addEventListener('mousedown', handleClickOutside);
+    }
+    return () => document.removeEventListener('mousedown', handleClickOutside);
+  }, [isOpen]);
+
+  const fetchTasks = useCallback(async () => {
+    try {
+      const res = await fetch('/api/tasks');
+      const data = await res.json();
+      if (data.success) {
+        const fetched: TaskInfo[] = data.tasks;
+        for (const task of fetched) {
+          if (task.status !== 'running' && runningIdsRef.current.has(task.id)) onTaskFinished?.(task);
+        }
+        runningIdsRef.current = new Set(fetched.filter((t) => t.status === 'running').map((t) => t.id));
+        setTasks(fetched);
+      }
+    } catch (error) {
+      console.error('Error fetching tasks:', error);
+    }
+  }, [onTaskFinished]);
+
+  useEffect(() => {
+    fetchTasks();
+    const interval = setInterval(fetchTasks, runningCount > 0 ? ACTIVE_POLL_MS : IDLE_POLL_MS);
+    return () => clearInterval(interval);
+  }, [fetchTasks, runningCount]);
+
+  const handlePause = async (id: string, paused: boolean) => {
+    try {
+      await fetch(`/api/tasks/${encodeURIComponent(id)}/pause`, {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ paused }),
+      });
+      fetchTasks();
+    } catch (error) {
+      console.error('Error pausing task:', error);
+    }
+  };
+
+  const handleCancel = async (id: string) => {
+    try {
+      await fetch(`/api/tasks/${encodeURIComponent(id)}/cancel`, { method: 'POST' });
+      fetchTasks();
+    } catch (error) {
+      console.error('Error cancelling task:', error);
+    }
+  };
+
+  return (
+    <div ref={panelRef} className="relative">
+      <button
+        onClick={() => setIsOpen(!isOpen)}
+        className="h-9 px-3 rounded-lg flex items-center gap-2 bg-card-border text-muted hover:text-foreground transition-colors text-sm"
+        title="Background tasks"
+      >
+        {runningCount > 0 ? (
+          <div className="w-4 h-4 border-2 border-muted/40 border-t-accent rounded-full animate-spin" />
+        ) : (
+          <svg className="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+            <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M9 5H7a2 2 0 00-2 2v12a2 2 0 002 2h10a2 2 0 002-2V7a2 2 0 00-2-2h-2M9 5a2 2 0 002 2h2a2 2 0 002-2M9 5a2 2 0 012-2h2a2 2 0 012 2m-6 9l2 2 4-4" />
+          </svg>
+        )}
+        {runningCount > 0 && <span>{runningCount}</span>}
+      </button>
+
+      {isOpen && (
+        <div className="absolute right-0 top-full mt-2 bg-card border border-card-border rounded-lg shadow-xl p-4 z-50 w-[360px] max-h-[70vh] overflow-auto">
+          <h3 className="text-sm font-semibold mb-3">Background tasks</h3>
+
+          {tasks.length === 0 ? (
+            <p className="text-sm text-muted">Nothing running.</p>
+          ) : (
+            <ul className="space-y-3">
+              {tasks.map((task) => {
+                const percent = task.total > 0 ? Math.round((task.done / task.total) * 100) : null;
+                return (
+                  <li key={task.id}>
+                    <div className="flex items-center justify-between gap-2 text-sm">
+                      <span className="truncate">{task.label}</span>
+                      {task.status === 'running' ? (
+                        <div className="flex items-center gap-2 shrink-0">
+                          {task.pausable && (
+                            <button
+                              onClick={() => handlePause(task.id, !task.paused)}
+                              className="text-xs text-muted hover:text-foreground"
+                              title={task.paused ? undefined : 'Pauses after the current item'}
+                            >
+                              {task.paused ? 'Resume' : 'Pause'}
+                            </button>
+                          )}
+                          <button
+                            onClick={() => handleCancel(task.id)}
+                            className="text-xs text-muted hover:text-error"
+                          >
+                            Cancel
+                          </button>
+                        </div>
+                      ) : (
+                        <span className={`text-xs shrink-0 ${task.status === 'complete' ? 'text-success' : task.status === 'error' ? 'text-error' : 'text-muted'}`}>
+                          {task.status === 'complete' ? 'Done' : task.status === 'error' ? 'Failed' : 'Cancelled'}
+                        </span>
+                      )}
+                    </div>
+
+                    {task.status === 'running' && (
+                      <div className="h-1.5 bg-card-border rounded-full overflow-hidden mt-1.5">
+                        <div
+                          className={`h-full bg-accent transition-all ${percent === null ? 'w-1/3 animate-pulse' : ''}`}
+                          style={percent !== null ? { width: `${percent}%` } : undefined}
+                        />
+                      </div>
+                    )}
+
+                    <p className="text-xs text-muted truncate mt-1" title={task.error || task.detail}>
+                      {task.error || (percent !== null ? `${task.done} / ${task.total}` : task.detail)}
+                      {task.paused && ' · paused'}
+                    </p>
+
+                    {task.items && task.items.length > 0 && (
+                      <ul className="mt-2 max-h-48 overflow-auto space-y-1.5 border-l border-card-border pl-2">
+                        {task.items.map((item, i) => (
+                          <li key={i} className="text-xs">
+                            <div className="flex items-center justify-between gap-2">
+                              <span className="truncate" title={item.detail ? `${item.label} → ${item.detail}` : item.label}>
+                                {item.label}
+                              </span>
+                              <span className={`shrink-0 ${ITEM_STATUS_CLASSES[item.status]}`}>
+                                {item.status === 'running' ? `${item.progress}%` : ITEM_STATUS_LABELS[item.status]}
+                              </span>
+                            </div>
+                            {item.status === 'running' && (
+                              <div className="h-1 bg-card-border rounded-full overflow-hidden mt-1">
+                                <div className="h-full bg-accent transition-all" style={{ width: `${item.progress}%` }} />
+                              </div>
+                            )}
+                            {item.error && (
+                              <pre className="mt-1 p-1.5 bg-background rounded text-[10px] leading-tight text-error whitespace-pre-wrap break-all max-h-24 overflow-auto select-text">
+                                {item.error}
+                              </pre>
+                            )}
+                          </li>
+                        ))}
+                      </ul>
+                    )}
+                  </li>
+                );
+              })}
+            </ul>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
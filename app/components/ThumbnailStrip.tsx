@@ -0,0 +1,57 @@
+'use client';
+
+interface ThumbnailStripProps {
+  spriteUrl: string | null;
+  thumbnailUrl: string;
+  frames: number; // Tiles in the strip
+  spriteColumns: number | null;
+  spriteRows: number | null;
+  spriteFrames: number | null;
+  children?: React.ReactNode; // Overlays (badges, buttons) drawn over the strip
+}
+
+// Evenly spaced frames from the hover sprite sheet, side by side. Falls back to the
+// single thumbnail when the sprite's grid isn't known yet.
+export default function ThumbnailStrip({
+  spriteUrl,
+  thumbnailUrl,
+  frames,
+  spriteColumns,
+  spriteRows,
+  spriteFrames,
+  children,
+}: ThumbnailStripProps) {
+  const hasGrid = spriteUrl && spriteColumns && spriteRows && spriteFrames;
+
+  return (
+    <div className="absolute inset-0 overflow-hidden">
+      {hasGrid ? (
+        <div className="absolute inset-0 flex">
+          {Array.from({ length: frames }, (_, i) => {
+            // Middle of each equal slice of the clip
+            const frame = Math.min(Math.floor(((i + 0.5) * spriteFrames) / frames), spriteFrames - 1);
+            const column = frame % spriteColumns;
+            const row = Math.floor(frame / spriteColumns);
+            return (
+              <div
+                key={i}
+                className="flex-1 h-full"
+                style={{
+                  backgroundImage: `url(${spriteUrl})`,
+                  backgroundSize: `${spriteColumns * 100}% ${spriteRows * 100}%`,
+                  backgroundPosition: `${spriteColumns > 1 ? (column / (spriteColumns - 1)) * 100 : 0}% ${spriteRows > 1 ? (row / (spriteRows - 1)) * 100 : 0}%`,
+                }}
+              />
+            );
+          })}
+        </div>
+      ) : (
+        <div
+          className="absolute inset-0 bg-contain bg-no-repeat bg-center"
+          style={{ backgroundImage: `url(${thumbnailUrl})` }}
+        />
+      )}
+      {children}
+    </div>
+  );
+}
@@ -0,0 +1,57 @@
+'use client';
+
+import { useState, useEffect } from 'react';
+import { VideoWithSelection } from '@/app/lib/types';
+
+interface ThumbnailWarmupProps {
+  videos: VideoWithSelection[];
+}
+
+// Banner is shown for at most this long after the library opens
+const WARMUP_MS = 500;
+
+// Thumbnails preloaded on open (roughly the first couple of screens)
+const WARMUP_COUNT = 48;
+
+// "Loading thumbnails…" banner shown while the first thumbnails warm the browser cache.
+// Mounted when the grid appears, so mount time is when the library was opened.
+export default function ThumbnailWarmup({ videos }: ThumbnailWarmupProps) {
+  // Captured once on open; later refetches don't restart the warm-up
+  const [warmupVideos] = useState(() => videos.filter((v) => v.thumbnailPath).slice(0, WARMUP_COUNT));
+  const total = warmupVideos.length;
+  const [loaded, setLoaded] = useState(0);
+  const [isExpired, setIsExpired] = useState(false);
+
+  // Preload the first thumbnails once, on open
+  useEffect(() => {
+    const images = warmupVideos.map((video) => {
+      const img = new Image();
+      img.onload = img.onerror = () => setLoaded((n) => n + 1);
+      img.src = `/api/videos/${video.id}/thumbnail?v=${video.thumbnailVersion ?? 0}`;
+      return img;
+    });
+
+    const timer = setTimeout(() => setIsExpired(true), WARMUP_MS);
+    return () => {
+      clearTimeout(timer);
+      images.forEach((img) => {
+        img.onload = img.onerror = null;
+      });
+    };
+  }, [warmupVideos]);
+
+  if (isExpired || loaded >= total) return null;
+
+  return (
+    <div className="absolute top-0 left-0 right-0 z-20 bg-black/60 backdrop-blur-sm px-4 py-2 flex items-center gap-3 text-sm pointer-events-none">
+      <span>Loading thumbnails…</span>
+      <div className="flex-1 max-w-xs h-1.5 bg-card-border rounded-full overflow-hidden">
+        <div
+          className="h-full bg-accent transition-all"
+          style={{ width: `${Math.round((loaded / total) * 100)}%` }}
+        />
+      </div>
+      <span className="text-muted text-xs">{loaded} / {total}</span>
+    </div>
+  );
+}
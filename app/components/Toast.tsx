@@ -0,0 +1,58 @@
+'use client';
+
+import { useEffect } from 'react';
+
+export type ToastType = 'success' | 'error';
+
+export interface ToastMessage {
+  id: number;
+  message: string;
+  type: ToastType;
+}
+
+// Callback components use to raise a toast
+export type ShowToast = (message: string, type?: ToastType) => void;
+
+interface ToastProps {
+  toast: ToastMessage | null;
+  onDismiss: () => void;
+}
+
+// How long a toast stays on screen
+const TOAST_DURATION_MS = 3000;
+
+export default function Toast({ toast, onDismiss }: ToastProps) {
+  // Auto-dismiss; restarts whenever a new toast replaces the current one
+  useEffect(() => {
+    if (!toast) return;
+    const timeout = setTimeout(onDismiss, TOAST_DURATION_MS);
+    return () => clearTimeout(timeout);
+  }, [toast, onDismiss]);
+
+  if (!toast) return null;
+
+  return (
+    <div
+      className={`
+        fixed bottom-24 left-1/2 -translate-x-1/2 z-[60] px-4 py-2 rounded-lg shadow-xl text-sm
+        border flex items-center gap-2
+        ${toast.type === 'error'
+          ? 'bg-card border-error/40 text-error'
+          : 'bg-card border-success/40 text-success'
+        }
+      `}
+      onClick={onDismiss}
+    >
+      {toast.type === 'error' ? (
+        <svg className="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+          <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M6 18L18 6M6 6l12 12" />
+        </svg>
+      ) : (
+        <svg className="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+          <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M5 13l4 4L19 7" />
+        </svg>
+      )}
+      <span className="text-foreground">{toast.message}</span>
+    </div>
+  );
+}
@@ -0,0 +1,300 @@
+'use client';
+
+import { useState, useEffect, useCallback } from 'react';
+import { TranscodePreset, VideoWithSelection } from '@/app/lib/types';
+import { formatDuration } from '@/app/lib/utils';
+import { ShowToast } from './Toast';
+
+interface TranscodeModalProps {
+  videos: VideoWithSelection[]; // The current view; all start checked
+  rootPath: string;
+  onClose: () => void;
+  onToast: ShowToast;
+}
+
+// Queue a batch transcode of checked clips to a preset; progress shows in Background tasks
+export default function TranscodeModal({ videos, rootPath, onClose, onToast }: TranscodeModalProps) {
+  const [presets, setPresets] = useState<TranscodePreset[]>([]);
+  const [selectedPreset, setSelectedPreset] = useState(0);
+  const [checked, setChecked] = useState<Set<string>>(() => new Set(videos.map((v) => v.id)));
+  const [destination, setDestination] = useState('');
+  const [addToCatalog, setAddToCatalog] = useState(true);
+  const [isStarting, setIsStarting] = useState(false);
+  const [drafts, setDrafts] = useState<TranscodePreset[] | null>(null); // Non-null while editing
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    const fetchPresets = async () => {
+      try {
+        const res = await fetch('/api/transcode/presets');
+        const data = await res.json();
+        if (data.success) setPresets(data.presets);
+      } catch (err) {
+        console.error('Error loading transcode presets:', err);
+      }
+    };
+    fetchPresets();
+  }, []);
+
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') onClose();
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [onClose]);
+
+  const toggle = (id: string) => {
+    setChecked((current) => {
+      const next = new Set(current);
+      if (next.has(id)) {
+        next.delete(id);
+      } else {
+        next.add(id);
+      }
+      return next;
+    });
+  };
+
+  const checkedVideos = videos.filter((v) => checked.has(v.id));
+  const totalSeconds = checkedVideos.reduce((sum, v) => sum + v.duration, 0);
+
+  const handleStart = useCallback(async () => {
+    setIsStarting(true);
+    setError(null);
+    try {
+      const res = await fetch('/api/transcode', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({
+          videoIds: checkedVideos.map((v) => v.id),
+          preset: selectedPreset,
+          destination,
+          addToCatalog,
+        }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        onToast(`Queued ${data.queued} clip${data.queued !== 1 ? 's' : ''}; progress is under Background tasks`, 'success');
+        onClose();
+      } else {
+        setError(data.error || 'Failed to start transcode');
+      }
+    } catch (err) {
+      setError('Failed to start transcode');
+      console.error('Error starting transcode:', err);
+    } finally {
+      setIsStarting(false);
+    }
+  }, [checkedVideos, selectedPreset, destination, addToCatalog, onToast, onClose]);
+
+  const savePresets = useCallback(async (method: 'PUT' | 'DELETE') => {
+    try {
+      const res = await fetch('/api/transcode/presets', {
+        method,
+        headers: { 'Content-Type': 'application/json' },
+        body: method === 'PUT' ? JSON.stringify({ presets: drafts }) : undefined,
+      });
+      const data = await res.json();
+      if (data.success) {
+        setPresets(data.presets);
+        setSelectedPreset((i) => Math.min(i, data.presets.length - 1));
+        setDrafts(null);
+        setError(null);
+      } else {
+        setError(data.error || 'Failed to save presets');
+      }
+    } catch (err) {
+      setError('Failed to save presets');
+      console.error('Error saving transcode presets:', err);
+    }
+  }, [drafts]);
+
+  const updateDraft = (index: number, update: Partial<TranscodePreset>) => {
+    setDrafts((current) => current && current.map((draft, i) => (i === index ? { ...draft, ...update } : draft)));
+  };
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/90"
+      onClick={onClose}
+    >
+      <div
+        className="relative w-full max-w-2xl max-h-[85vh] bg-card rounded-xl overflow-hidden shadow-2xl flex flex-col"
+        onClick={(e) => e.stopPropagation()}
+      >
+        <div className="flex items-center justify-between p-6 border-b border-card-border">
+          <div>
+            <h2 className="text-xl font-semibold">Transcode</h2>
+            <p className="text-xs text-muted">
+              {checkedVideos.length} of {videos.length} clips · {formatDuration(totalSeconds)}
+            </p>
+          </div>
+          <button
+            onClick={() => {
+              setDrafts(drafts ? null : presets);
+              setError(null);
+            }}
+            className="text-xs text-muted hover:text-foreground"
+          >
+            {drafts ? 'Done editing' : 'Edit presets'}
+          </button>
+        </div>
+
+        <div className="flex-1 overflow-auto p-6 space-y-5">
+          {drafts ? (
+            <>
+              <p className="text-xs text-muted">
+                Arguments go between the input and the output file, split on spaces (no quoting).
+                Outputs are named after the clip with the preset&apos;s extension.
+              </p>
+              {drafts.map((draft, i) => (
+                <div key={i} className="space-y-1">
+                  <div className="flex items-center gap-2">
+                    <input
+                      value={draft.label}
+                      onChange={(e) => updateDraft(i, { label: e.target.value })}
+                      placeholder="Name"
+                      className="flex-1 px-2 py-1 bg-background border border-card-border rounded text-sm"
+                    />
+                    <input
+                      value={draft.extension}
+                      onChange={(e) => updateDraft(i, { extension: e.target.value })}
+                      placeholder="mp4"
+                      className="w-20 px-2 py-1 bg-background border border-card-border rounded text-sm font-mono"
+                    />
+                    <button
+                      onClick={() => setDrafts(drafts.filter((_, j) => j !== i))}
+                      className="text-xs text-muted hover:text-error"
+                    >
+                      Remove
+                    </button>
+                  </div>
+                  <textarea
+                    value={draft.args}
+                    onChange={(e) => updateDraft(i, { args: e.target.value })}
+                    rows={2}
+                    spellCheck={false}
+                    className="w-full px-2 py-1 bg-background border border-card-border rounded text-xs font-mono resize-y"
+                  />
+                </div>
+              ))}
+              {error && <p className="text-xs text-error">{error}</p>}
+              <div className="flex items-center gap-3">
+                <button
+                  onClick={() => setDrafts([...drafts, { label: '', args: '-c:v libx264 -crf 20 -c:a aac', extension: 'mp4' }])}
+                  className="text-xs text-accent hover:text-accent-hover"
+                >
+                  + Add preset
+                </button>
+                <div className="flex-1" />
+                <button
+                  onClick={() => savePresets('DELETE')}
+                  className="px-3 py-1.5 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+                >
+                  Reset to defaults
+                </button>
+                <button
+                  onClick={() => savePresets('PUT')}
+                  className="px-3 py-1.5 text-xs rounded-lg bg-accent text-white hover:bg-accent-hover"
+                >
+                  Save presets
+                </button>
+              </div>
+            </>
+          ) : (
+            <>
+              <div>
+                <div className="flex items-center justify-between mb-2">
+                  <h3 className="text-sm font-medium">Clips</h3>
+                  <div className="flex gap-3 text-xs">
+                    <button onClick={() => setChecked(new Set(videos.map((v) => v.id)))} className="text-accent hover:text-accent-hover">
+                      All
+                    </button>
+                    <button onClick={() => setChecked(new Set())} className="text-accent hover:text-accent-hover">
+                      None
+                    </button>
+                  </div>
+                </div>
+                <div className="max-h-48 overflow-auto border border-card-border rounded-lg divide-y divide-card-border">
+                  {videos.map((video) => (
+                    <label key={video.id} className="flex items-center gap-2 px-3 py-1.5 text-sm cursor-pointer hover:bg-card-border/40">
+                      <input type="checkbox" checked={checked.has(video.id)} onChange={() => toggle(video.id)} />
+                      <span className="truncate flex-1" title={video.filePath}>{video.fileName}</span>
+                      <span className="text-xs text-muted tabular-nums">{formatDuration(video.duration)}</span>
+                    </label>
+                  ))}
+                </div>
+              </div>
+
+              <div>
+                <h3 className="text-sm font-medium mb-2">Preset</h3>
+                <div className="space-y-1">
+                  {presets.map((preset, i) => (
+                    <label key={i} className="flex items-center gap-2 text-sm cursor-pointer">
+                      <input
+                        type="radio"
+                        checked={selectedPreset === i}
+                        onChange={() => setSelectedPreset(i)}
+                      />
+                      {preset.label}
+                      <span className="text-xs text-muted font-mono">.{preset.extension}</span>
+                    </label>
+                  ))}
+                </div>
+              </div>
+
+              <div>
+                <h3 className="text-sm font-medium mb-2">Destination folder</h3>
+                <div className="flex items-center gap-2">
+                  <input
+                    value={destination}
+                    onChange={(e) => setDestination(e.target.value)}
+                    placeholder="/Volumes/ExternalDrive/Exports"
+                    className="flex-1 px-3 py-1.5 bg-background border border-card-border rounded-lg text-sm font-mono"
+                  />
+                  <button
+                    onClick={() => setDestination(rootPath)}
+                    className="px-3 py-1.5 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+                  >
+                    Library folder
+                  </button>
+                </div>
+                <p className="text-xs text-muted mt-1">
+                  Existing files are never overwritten; a suffix like _1 is added instead.
+                </p>
+              </div>
+
+              <label className="flex items-center gap-2 text-sm cursor-pointer">
+                <input type="checkbox" checked={addToCatalog} onChange={(e) => setAddToCatalog(e.target.checked)} />
+                Add outputs to the catalog
+                <span className="text-xs text-muted">(only those inside the library folder)</span>
+              </label>
+
+              {error && <p className="text-xs text-error">{error}</p>}
+            </>
+          )}
+        </div>
+
+        {!drafts && (
+          <div className="flex justify-end gap-3 p-6 border-t border-card-border">
+            <button
+              onClick={onClose}
+              className="px-4 py-2 text-sm rounded-lg bg-card-border text-muted hover:text-foreground"
+            >
+              Cancel
+            </button>
+            <button
+              onClick={handleStart}
+              disabled={isStarting || checkedVideos.length === 0 || !destination.trim() || !presets[selectedPreset]}
+              className="px-4 py-2 text-sm rounded-lg bg-accent text-white hover:bg-accent-hover disabled:opacity-50"
+            >
+              {isStarting ? 'Starting…' : `Transcode ${checkedVideos.length} clip${checkedVideos.length !== 1 ? 's' : ''}`}
+            </button>
+          </div>
+        )}
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,253 @@
+'use client';
+
+import { useState, useEffect, useCallback } from 'react';
+import { ShowToast } from './Toast';
+import { VerifyBucket, VerifyEntry, VerifyReport } from '@/app/lib/types';
+import { formatFileSize } from '@/app/lib/utils';
+
+interface VerifyViewProps {
+  onClose: () => void;
+  onCatalogChanged: () => void; // Refresh the main grid after a rescan or corrupt flag
+  onToast: ShowToast;
+}
+
+// How often progress is polled while verifying
+const POLL_INTERVAL_MS = 1000;
+
+const BUCKETS: { key: VerifyBucket; label: string; description: string; className: string }[] = [
+  {
+    key: 'mismatched',
+    label: 'Suspicious',
+    description: 'Content differs but size and modified time are unchanged. Likely bit rot or a bad copy.',
+    className: 'text-error',
+  },
+  {
+    key: 'changed',
+    label: 'Changed',
+    description: 'Size or modified time differs. Probably edited or re-exported on purpose.',
+    className: 'text-warning',
+  },
+  {
+    key: 'missing',
+    label: 'Missing',
+    description: 'No file at the catalogued path.',
+    className: 'text-muted',
+  },
+];
+
+export default function VerifyView({ onClose, onCatalogChanged, onToast }: VerifyViewProps) {
+  const [report, setReport] = useState<VerifyReport | null>(null);
+  const [isRunning, setIsRunning] = useState(false);
+  const [taskId, setTaskId] = useState<string | null>(null);
+  const [busyId, setBusyId] = useState<string | null>(null);
+
+  const fetchReport = useCallback(async () => {
+    try {
+      const res = await fetch('/api/verify');
+      const data = await res.json();
+      if (data.success) {
+        setReport(data.report);
+        setIsRunning(data.running);
+        setTaskId(data.taskId);
+      }
+    } catch (error) {
+      console.error('Error fetching verify report:', error);
+    }
+  }, []);
+
+  useEffect(() => {
+    fetchReport();
+  }, [fetchReport]);
+
+  useEffect(() => {
+    if (!isRunning) return;
+    const interval = setInterval(fetchReport, POLL_INTERVAL_MS);
+    return () => clearInterval(interval);
+  }, [isRunning, fetchReport]);
+
+  // Handle escape key to close
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') {
+        onClose();
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [onClose]);
+
+  const handleStart = async () => {
+    try {
+      const res = await fetch('/api/verify', { method: 'POST' });
+      const data = await res.json();
+      if (data.success) {
+        setTaskId(data.taskId);
+        setIsRunning(true);
+        fetchReport();
+      } else {
+        onToast(data.error || 'Failed to start verification', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to start verification', 'error');
+      console.error('Error starting verify:', error);
+    }
+  };
+
+  const handleCancel = async () => {
+    if (!taskId) return;
+    try {
+      await fetch(`/api/tasks/${taskId}/cancel`, { method: 'POST' });
+    } catch (error) {
+      console.error('Error cancelling verify:', error);
+    }
+  };
+
+  const handleAction = async (entry: VerifyEntry, action: 'rescan' | 'corrupt' | 'clear') => {
+    setBusyId(entry.videoId);
+    try {
+      const res = await fetch('/api/verify/resolve', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ videoId: entry.videoId, action }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        await fetchReport();
+        onCatalogChanged();
+        if (action === 'rescan') onToast(`Rescanned ${entry.fileName}`, 'success');
+      } else {
+        onToast(data.error || 'Failed to update video', 'error');
+      }
+    } catch (error) {
+      onToast('Failed to update video', 'error');
+      console.error('Error resolving verify entry:', error);
+    } finally {
+      setBusyId(null);
+    }
+  };
+
+  const renderEntry = (entry: VerifyEntry) => (
+    <li key={entry.videoId} className="flex items-center gap-3 py-2 border-t border-card-border/50">
+      <div className="flex-1 min-w-0">
+        <p className="text-sm truncate" title={entry.filePath}>
+          {entry.fileName}
+          {entry.isCorrupt && <span className="ml-2 text-xs text-error">Marked corrupt</span>}
+        </p>
+        <p className="text-xs text-muted font-mono truncate">
+          {entry.currentSize === null
+            ? entry.filePath
+            : `${formatFileSize(entry.storedSize)} → ${formatFileSize(entry.currentSize)}`
+              + ` · modified ${entry.currentMtime ? new Date(entry.currentMtime).toLocaleString() : '?'}`}
+        </p>
+      </div>
+      {entry.bucket !== 'missing' && (
+        <button
+          onClick={() => handleAction(entry, 'rescan')}
+          disabled={busyId === entry.videoId}
+          className="px-3 py-1.5 text-xs rounded-lg bg-card-border text-muted hover:text-foreground disabled:opacity-50"
+          title="Re-index the file as it is now"
+        >
+          Rescan metadata
+        </button>
+      )}
+      <button
+        onClick={() => handleAction(entry, entry.isCorrupt ? 'clear' : 'corrupt')}
+        disabled={busyId === entry.videoId}
+        className="px-3 py-1.5 text-xs rounded-lg bg-card-border text-muted hover:text-foreground disabled:opacity-50"
+      >
+        {entry.isCorrupt ? 'Clear corrupt' : 'Mark corrupt'}
+      </button>
+    </li>
+  );
+
+  return (
+    <div
+      className="fixed inset-0 z-50 flex items-center justify-center bg-black/90"
+      onClick={onClose}
+    >
+      <div
+        className="relative w-full max-w-4xl h-[80vh] bg-card rounded-xl overflow-hidden shadow-2xl flex flex-col"
+        onClick={(e) => e.stopPropagation()}
+      >
+        <div className="flex items-center gap-3 p-6 border-b border-card-border">
+          <div className="flex-1">
+            <h2 className="text-xl font-semibold">Verify files</h2>
+            <p className="text-xs text-muted">
+              Re-checks each file against the fingerprint from its last scan (first 64KB, size and modified time).
+            </p>
+          </div>
+          {isRunning ? (
+            <button
+              onClick={handleCancel}
+              disabled={!taskId}
+              className="px-4 py-2 text-sm rounded-lg bg-card-border text-muted hover:text-foreground disabled:opacity-50"
+            >
+              Cancel
+            </button>
+          ) : (
+            <button
+              onClick={handleStart}
+              className="px-4 py-2 text-sm rounded-lg bg-accent text-white hover:bg-accent-hover"
+            >
+              {report ? 'Verify again' : 'Start verify'}
+            </button>
+          )}
+          <button
+            onClick={onClose}
+            className="w-10 h-10 bg-black/50 hover:bg-black/70 rounded-full flex items-center justify-center transition-colors"
+          >
+            <svg className="w-6 h-6" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+              <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M6 18L18 6M6 6l12 12" />
+            </svg>
+          </button>
+        </div>
+
+        <div className="flex-1 overflow-auto p-6 space-y-6">
+          {!report && !isRunning && (
+            <p className="text-sm text-muted">
+              Reads the start of every catalogued file, so this can take a while on large or slow drives.
+            </p>
+          )}
+
+          {report && (
+            <>
+              <div>
+                <div className="h-2 bg-background rounded-full overflow-hidden">
+                  <div
+                    className="h-full bg-accent transition-all"
+                    style={{ width: `${report.total > 0 ? (report.done / report.total) * 100 : 100}%` }}
+                  />
+                </div>
+                <p className="text-xs text-muted mt-2">
+                  {report.done} of {report.total} checked · {report.unchanged} unchanged
+                  {report.skippedOnlineOnly > 0 && ` · ${report.skippedOnlineOnly} online-only skipped`}
+                  {report.cancelled && ' · cancelled'}
+                  {report.completedAt && !report.cancelled && ` · finished ${new Date(report.completedAt).toLocaleString()}`}
+                </p>
+              </div>
+
+              {BUCKETS.map((bucket) => {
+                const entries = report.entries.filter((entry) => entry.bucket === bucket.key);
+                if (entries.length === 0) return null;
+                return (
+                  <section key={bucket.key}>
+                    <h3 className={`text-sm font-semibold ${bucket.className}`}>
+                      {bucket.label} ({entries.length})
+                    </h3>
+                    <p className="text-xs text-muted mb-2">{bucket.description}</p>
+                    <ul>{entries.map(renderEntry)}</ul>
+                  </section>
+                );
+              })}
+
+              {report.completedAt && report.entries.length === 0 && !report.cancelled && (
+                <p className="text-sm text-success">Every file matches its fingerprint.</p>
+              )}
+            </>
+          )}
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -1,23 +1,51 @@
 'use client';
 
 import { useState, useCallback, useRef, useEffect } from 'react';
+import { createPortal } from 'react-dom';
 import HoverScrubber from './HoverScrubber';
-import { VideoWithSelection } from '@/app/lib/types';
-import { formatDuration, formatFileSize } from '@/app/lib/utils';
+import ThumbnailStrip from './ThumbnailStrip';
+import FfmpegCommandModal from './FfmpegCommandModal';
+import { ShowToast } from './Toast';
+import { COLOR_LABELS, ColorLabel, ThumbnailStatus, VideoWithSelection } from '@/app/lib/types';
+import { formatBitrate, formatDuration, formatFileSize, formatLoudness, formatMbPerMinute, getBitrate, formatTimecode, getBestDate, getColorBadge, imageUrlToPngBlob, DateSource, DEFAULT_FRAME_RATE, COLOR_LABEL_CLASSES, VIDEO_DRAG_TYPE } from '@/app/lib/utils';
 
-type CopyOption = 'filename' | 'path';
+type CopyOption = 'filename' | 'path' | 'timecode' | 'thumbnail';
 
 interface VideoCardProps {
   video: VideoWithSelection;
+  isHighlighted?: boolean;
+  isSelected?: boolean; // Part of the batch selection (Ctrl+A)
+  isBeingScanned?: boolean; // A rescan is processing this file right now
+  stripFrames?: number; // Show this many frames side by side instead of the hover thumbnail
   onSelect: (video: VideoWithSelection) => void;
   onToggleFavorite: (videoId: string, isFavorite: boolean) => void;
+  onToggleWatched: (videoId: string, watched: boolean) => void;
+  onShowSiblings?: (video: VideoWithSelection) => void; // Filter the grid to this video's folder
+  onRescanFolder?: (video: VideoWithSelection) => void; // Scan just this video's folder into the library
+  onShowSameName?: (video: VideoWithSelection) => void; // Filter the grid to videos with this file name
+  onSetColorLabel?: (video: VideoWithSelection, label: ColorLabel | null) => void;
+  onRemoveFromCatalog?: (video: VideoWithSelection) => void; // Into Recently Removed
+  thumbnailStatus?: ThumbnailStatus; // Unset once the thumbnail exists (or nothing is known)
+  onRetryThumbnail?: (video: VideoWithSelection) => void;
+  getDragIds?: (video: VideoWithSelection) => string[]; // Videos carried when the card is dragged
+  onToast: ShowToast;
 }
 
-export default function VideoCard({ video, onSelect, onToggleFavorite }: VideoCardProps) {
+// Where the card's date came from; file name dates get an asterisk as they're a guess
+const DATE_SOURCE_TITLES: Record<DateSource, string> = {
+  media: 'Recorded (container metadata)',
+  name: 'Parsed from the file name',
+  file: 'File created',
+};
+
+export default function VideoCard({ video, isHighlighted = false, isSelected = false, isBeingScanned = false, stripFrames, onSelect, onToggleFavorite, onToggleWatched, onShowSiblings, onRescanFolder, onShowSameName, onSetColorLabel, onRemoveFromCatalog, thumbnailStatus, onRetryThumbnail, getDragIds, onToast }: VideoCardProps) {
   const [isHovered, setIsHovered] = useState(false);
   const [showCopyMenu, setShowCopyMenu] = useState(false);
   const [copySuccess, setCopySuccess] = useState<CopyOption | null>(null);
+  const [contextMenu, setContextMenu] = useState<{ x: number; y: number } | null>(null);
+  const [showFfmpegCommand, setShowFfmpegCommand] = useState(false);
   const copyMenuRef = useRef<HTMLDivElement>(null);
+  const contextMenuRef = useRef<HTMLDivElement>(null);
 
   // Close copy menu when clicking outside
   useEffect(() => {
@@ -32,10 +60,75 @@ export default function VideoCard({ video, onSelect, onToggleFavorite }: VideoCa
     return () => document.removeEventListener('mousedown', handleClickOutside);
   }, [showCopyMenu]);
 
+  // Close context menu when clicking outside
+  useEffect(() => {
+    const handleClickOutside = (e: MouseEvent) => {
+      if (contextMenuRef.current && !contextMenuRef.current.contains(e.target as Node)) {
+        setContextMenu(null);
+      }
+    };
+    if (contextMenu) {
+      document.addEventListener('mousedown', handleClickOutside);
+    }
+    return () => document.removeEventListener('mousedown', handleClickOutside);
+  }, [contextMenu]);
+
+  const handleContextMenu = useCallback((e: React.MouseEvent) => {
+    e.preventDefault();
+    setContextMenu({ x: e.clientX, y: e.clientY });
+  }, []);
+
+  const isWatched = !!video.selection?.watchedAt;
+
   const handleClick = useCallback(() => {
     onSelect(video);
   }, [video, onSelect]);
 
+  const handleDragStart = useCallback((e: React.DragEvent) => {
+    if (!getDragIds) return;
+    const ids = getDragIds(video);
+    e.dataTransfer.setData(VIDEO_DRAG_TYPE, JSON.stringify(ids));
+    e.dataTransfer.setData('text/plain', ids.length === 1 ? video.fileName : `${ids.length} videos`);
+    e.dataTransfer.effectAllowed = 'copy';
+  }, [video, getDragIds]);
+
+  const bestDate = getBestDate(video);
+  const bitrate = getBitrate(video);
+  const folderName = video.directory.split(/[\\/]/).filter(Boolean).pop() || video.directory;
+
+  const handleFolderClick = useCallback((e: React.MouseEvent) => {
+    e.stopPropagation();
+    onShowSiblings?.(video);
+  }, [video, onShowSiblings]);
+
+  // Paths of the videos sharing this name, fetched the first time the badge is hovered
+  const [sameNamePaths, setSameNamePaths] = useState<string[] | null>(null);
+  useEffect(() => {
+    setSameNamePaths(null); // Another copy appeared or went away
+  }, [video.sameNameCount]);
+  const handleSameNameHover = useCallback(async () => {
+    if (sameNamePaths) return;
+    try {
+      const res = await fetch(`/api/duplicate-names/paths?name=${encodeURIComponent(video.fileName)}`);
+      const data = await res.json();
+      if (data.success) {
+        setSameNamePaths((data.paths as string[]).filter((p) => p !== video.filePath));
+      }
+    } catch (error) {
+      console.error('Error fetching paths with the same name:', error);
+    }
+  }, [sameNamePaths, video.fileName, video.filePath]);
+
+  const handleSameNameClick = useCallback((e: React.MouseEvent) => {
+    e.stopPropagation();
+    onShowSameName?.(video);
+  }, [video, onShowSameName]);
+
+  const handleRetryThumbnailClick = useCallback((e: React.MouseEvent) => {
+    e.stopPropagation();
+    onRetryThumbnail?.(video);
+  }, [video, onRetryThumbnail]);
+
   const handleFavoriteClick = useCallback((e: React.MouseEvent) => {
     e.stopPropagation();
     onToggleFavorite(video.id, !video.selection?.isFavorite);
@@ -48,7 +141,11 @@ export default function VideoCard({ video, onSelect, onToggleFavorite }: VideoCa
 
   const handleCopy = useCallback(async (option: CopyOption, e: React.MouseEvent) => {
     e.stopPropagation();
-    const textToCopy = option === 'filename' ? video.fileName : video.filePath;
+    const textToCopy = option === 'filename'
+      ? video.copyName ?? video.fileName
+      : option === 'path'
+        ? video.filePath
+        : formatTimecode(video.duration, video.frameRate ?? DEFAULT_FRAME_RATE);
     try {
       await navigator.clipboard.writeText(textToCopy);
       setCopySuccess(option);
@@ -57,143 +154,360 @@ export default function VideoCard({ video, onSelect, onToggleFavorite }: VideoCa
       console.error('Failed to copy:', err);
     }
     setShowCopyMenu(false);
-  }, [video.fileName, video.filePath]);
+  }, [video.fileName, video.copyName, video.filePath, video.duration, video.frameRate]);
+
+  const handleOpenSubtitlesFolder = useCallback(async () => {
+    setContextMenu(null);
+    try {
+      const res = await fetch(`/api/videos/${video.id}/reveal`, { method: 'POST' });
+      const data = await res.json();
+      if (!data.success) {
+        onToast(data.error || 'Could not open folder', 'error');
+      }
+    } catch (err) {
+      console.error('Failed to open folder:', err);
+      onToast('Could not open folder', 'error');
+    }
+  }, [video.id, onToast]);
 
+  // Version query keeps the browser from serving a stale image after regeneration
   const thumbnailUrl = video.thumbnailPath
-    ? `/api/videos/${video.id}/thumbnail`
+    ? `/api/videos/${video.id}/thumbnail?v=${video.thumbnailVersion ?? 0}`
     : '/placeholder-video.svg';
 
   const spriteUrl = video.hasSprite
-    ? `/api/videos/${video.id}/sprite`
+    ? `/api/videos/${video.id}/sprite?v=${video.spriteVersion ?? 0}`
     : null;
 
-  return (
-    <div
-      className={`
-        group relative rounded-lg overflow-hidden bg-card border transition-all duration-200 cursor-pointer
-        ${isHovered ? 'border-accent ring-1 ring-accent' : 'border-card-border'}
-        hover:scale-[1.02] hover:shadow-xl
-      `}
-      onClick={handleClick}
-      onMouseEnter={() => setIsHovered(true)}
-      onMouseLeave={() => setIsHovered(false)}
-    >
-      {/* Video thumbnail with hover scrub */}
-      <div className="relative aspect-video bg-black">
-        <HoverScrubber
-          videoId={video.id}
-          thumbnailUrl={thumbnailUrl}
-          spriteUrl={spriteUrl}
-          duration={video.duration}
-          hasSprite={video.hasSprite}
-          hasProxy={video.hasProxy}
-        />
+  // Copy the thumbnail image; decode a frame at 10% when no thumbnail exists yet
+  const handleCopyThumbnail = useCallback(async (e: React.MouseEvent) => {
+    e.stopPropagation();
+    setShowCopyMenu(false);
+    const imageUrl = video.thumbnailPath
+      ? thumbnailUrl
+      : `/api/videos/${video.id}/frame?time=${video.duration * 0.1}`;
+    try {
+      const blob = await imageUrlToPngBlob(imageUrl);
+      await navigator.clipboard.write([new ClipboardItem({ 'image/png': blob })]);
+      setCopySuccess('thumbnail');
+      setTimeout(() => setCopySuccess(null), 1500);
+      onToast('Thumbnail copied to clipboard');
+    } catch (err) {
+      console.error('Failed to copy thumbnail:', err);
+      onToast(`Could not copy thumbnail: ${err instanceof Error ? err.message : 'clipboard unavailable'}`, 'error');
+    }
+  }, [video.id, video.thumbnailPath, video.duration, thumbnailUrl, onToast]);
 
-        {/* Top buttons row */}
-        <div className="absolute top-2 right-2 flex items-center gap-1 z-10">
-          {/* Copy button with dropdown */}
-          <div ref={copyMenuRef} className="relative">
+  // Buttons and badges drawn over the thumbnail or strip
+  const colorBadge = getColorBadge(video);
+
+  const overlays = (
+    <>
+      {/* Thumbnail being made: wait a second */}
+      {(thumbnailStatus === 'pending' || thumbnailStatus === 'generating') && (
+        <div
+          className="absolute inset-0 flex items-center justify-center pointer-events-none"
+          title={thumbnailStatus === 'pending' ? 'Waiting to generate the thumbnail' : 'Generating thumbnail'}
+        >
+          <div
+            className={`w-6 h-6 border-2 rounded-full ${
+              thumbnailStatus === 'pending' ? 'border-white/20' : 'border-white/30 border-t-white animate-spin'
+            }`}
+          />
+        </div>
+      )}
+
+      {/* Thumbnail generation failed: placeholder with a retry for just this file */}
+      {thumbnailStatus === 'failed' && (
+        <div className="absolute inset-0 flex flex-col items-center justify-center gap-2 bg-[#1a1a1a] text-[#444]">
+          <span className="text-2xl">▶</span>
+          {onRetryThumbnail && (
             <button
-              onClick={handleCopyClick}
-              className={`
-                w-8 h-8 rounded-full flex items-center justify-center
-                transition-all duration-200
-                ${copySuccess ? 'bg-success text-white' : 'bg-black/50 text-white/70 hover:bg-black/70 hover:text-white'}
-              `}
-              title="Copy"
+              data-scrub-ignore
+              onClick={handleRetryThumbnailClick}
+              onDoubleClick={(e) => e.stopPropagation()}
+              className="px-2 py-0.5 rounded bg-black/50 text-white/70 hover:bg-black/70 hover:text-white text-xs"
+              title="Thumbnail generation failed. Try again for this file"
             >
-              {copySuccess ? (
-                <svg className="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
-                  <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M5 13l4 4L19 7" />
-                </svg>
-              ) : (
-                <svg className="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
-                  <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M8 16H6a2 2 0 01-2-2V6a2 2 0 012-2h8a2 2 0 012 2v2m-6 12h8a2 2 0 002-2v-8a2 2 0 00-2-2h-8a2 2 0 00-2 2v8a2 2 0 002 2z" />
-                </svg>
-              )}
+              ↻ Retry
             </button>
+          )}
+        </div>
+      )}
 
-            {/* Dropdown menu */}
-            {showCopyMenu && (
-              <div className="absolute top-full right-0 mt-1 bg-card border border-card-border rounded-lg shadow-xl overflow-hidden min-w-[140px]">
-                <button
-                  onClick={(e) => handleCopy('filename', e)}
-                  className="w-full px-3 py-2 text-left text-sm hover:bg-accent/20 transition-colors flex items-center gap-2"
-                >
-                  <svg className="w-4 h-4 text-muted" fill="none" stroke="currentColor" viewBox="0 0 24 24">
-                    <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M7 21h10a2 2 0 002-2V9.414a1 1 0 00-.293-.707l-5.414-5.414A1 1 0 0012.586 3H7a2 2 0 00-2 2v14a2 2 0 002 2z" />
-                  </svg>
-                  Filename
-                </button>
-                <button
-                  onClick={(e) => handleCopy('path', e)}
-                  className="w-full px-3 py-2 text-left text-sm hover:bg-accent/20 transition-colors flex items-center gap-2"
-                >
-                  <svg className="w-4 h-4 text-muted" fill="none" stroke="currentColor" viewBox="0 0 24 24">
-                    <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M3 7v10a2 2 0 002 2h14a2 2 0 002-2V9a2 2 0 00-2-2h-6l-2-2H5a2 2 0 00-2 2z" />
-                  </svg>
-                  Full Path
-                </button>
-              </div>
-            )}
-          </div>
-
-          {/* Favorite button */}
+      {/* Top buttons row */}
+      <div data-scrub-ignore className="absolute top-2 right-2 flex items-center gap-1 z-10">
+        {/* Copy button with dropdown */}
+        <div ref={copyMenuRef} className="relative">
           <button
-            onClick={handleFavoriteClick}
+            onClick={handleCopyClick}
             className={`
               w-8 h-8 rounded-full flex items-center justify-center
               transition-all duration-200
-              ${video.selection?.isFavorite
-                ? 'bg-yellow-500 text-black'
-                : 'bg-black/50 text-white/70 hover:bg-black/70 hover:text-white'
-              }
+              ${copySuccess ? 'bg-success text-white' : 'bg-black/50 text-white/70 hover:bg-black/70 hover:text-white'}
             `}
+            title="Copy"
           >
-            <svg
-              className="w-4 h-4"
-              fill={video.selection?.isFavorite ? 'currentColor' : 'none'}
-              stroke="currentColor"
-              viewBox="0 0 24 24"
-            >
-              <path
-                strokeLinecap="round"
-                strokeLinejoin="round"
-                strokeWidth={2}
-                d="M11.049 2.927c.3-.921 1.603-.921 1.902 0l1.519 4.674a1 1 0 00.95.69h4.915c.969 0 1.371 1.24.588 1.81l-3.976 2.888a1 1 0 00-.363 1.118l1.518 4.674c.3.922-.755 1.688-1.538 1.118l-3.976-2.888a1 1 0 00-1.176 0l-3.976 2.888c-.783.57-1.838-.197-1.538-1.118l1.518-4.674a1 1 0 00-.363-1.118l-3.976-2.888c-.784-.57-.38-1.81.588-1.81h4.914a1 1 0 00.951-.69l1.519-4.674z"
-              />
-            </svg>
+            {copySuccess ? (
+              <svg className="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M5 13l4 4L19 7" />
+              </svg>
+            ) : (
+              <svg className="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M8 16H6a2 2 0 01-2-2V6a2 2 0 012-2h8a2 2 0 012 2v2m-6 12h8a2 2 0 002-2v-8a2 2 0 00-2-2h-8a2 2 0 00-2 2v8a2 2 0 002 2z" />
+              </svg>
+            )}
           </button>
+
+          {/* Dropdown menu */}
+          {showCopyMenu && (
+            <div className="absolute top-full right-0 mt-1 bg-card border border-card-border rounded-lg shadow-xl overflow-hidden min-w-[180px]">
+              <button
+                onClick={(e) => handleCopy('filename', e)}
+                className="w-full px-3 py-2 text-left text-sm hover:bg-accent/20 transition-colors flex items-center gap-2"
+              >
+                <svg className="w-4 h-4 text-muted" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                  <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M7 21h10a2 2 0 002-2V9.414a1 1 0 00-.293-.707l-5.414-5.414A1 1 0 0012.586 3H7a2 2 0 00-2 2v14a2 2 0 002 2z" />
+                </svg>
+                {video.copyName ? 'Filename with folder' : 'Filename'}
+              </button>
+              <button
+                onClick={(e) => handleCopy('path', e)}
+                className="w-full px-3 py-2 text-left text-sm hover:bg-accent/20 transition-colors flex items-center gap-2"
+              >
+                <svg className="w-4 h-4 text-muted" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                  <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M3 7v10a2 2 0 002 2h14a2 2 0 002-2V9a2 2 0 00-2-2h-6l-2-2H5a2 2 0 00-2 2z" />
+                </svg>
+                Full Path
+              </button>
+              <button
+                onClick={(e) => handleCopy('timecode', e)}
+                className="w-full px-3 py-2 text-left text-sm hover:bg-accent/20 transition-colors flex items-center gap-2"
+              >
+                <svg className="w-4 h-4 text-muted" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                  <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M12 8v4l3 3m6-3a9 9 0 11-18 0 9 9 0 0118 0z" />
+                </svg>
+                Duration as Timecode
+              </button>
+              <button
+                onClick={handleCopyThumbnail}
+                className="w-full px-3 py-2 text-left text-sm hover:bg-accent/20 transition-colors flex items-center gap-2"
+              >
+                <svg className="w-4 h-4 text-muted" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                  <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M4 16l4.586-4.586a2 2 0 012.828 0L16 16m-2-2l1.586-1.586a2 2 0 012.828 0L20 14m-6-6h.01M6 20h12a2 2 0 002-2V6a2 2 0 00-2-2H6a2 2 0 00-2 2v12a2 2 0 002 2z" />
+                </svg>
+                Thumbnail Image
+              </button>
+            </div>
+          )}
         </div>
 
-        {/* Duration badge */}
-        <div className="absolute bottom-2 right-2 bg-black/80 px-2 py-1 rounded text-xs font-mono">
-          {formatDuration(video.duration)}
+        {/* Favorite button */}
+        <button
+          onClick={handleFavoriteClick}
+          className={`
+            w-8 h-8 rounded-full flex items-center justify-center
+            transition-all duration-200
+            ${video.selection?.isFavorite
+              ? 'bg-yellow-500 text-black'
+              : 'bg-black/50 text-white/70 hover:bg-black/70 hover:text-white'
+            }
+          `}
+        >
+          <svg
+            className="w-4 h-4"
+            fill={video.selection?.isFavorite ? 'currentColor' : 'none'}
+            stroke="currentColor"
+            viewBox="0 0 24 24"
+          >
+            <path
+              strokeLinecap="round"
+              strokeLinejoin="round"
+              strokeWidth={2}
+              d="M11.049 2.927c.3-.921 1.603-.921 1.902 0l1.519 4.674a1 1 0 00.95.69h4.915c.969 0 1.371 1.24.588 1.81l-3.976 2.888a1 1 0 00-.363 1.118l1.518 4.674c.3.922-.755 1.688-1.538 1.118l-3.976-2.888a1 1 0 00-1.176 0l-3.976 2.888c-.783.57-1.838-.197-1.538-1.118l1.518-4.674a1 1 0 00-.363-1.118l-3.976-2.888c-.784-.57-.38-1.81.588-1.81h4.914a1 1 0 00.951-.69l1.519-4.674z"
+            />
+          </svg>
+        </button>
+      </div>
+
+      {/* Watched overlay */}
+      {isWatched && (
+        <div
+          className="absolute top-2 left-2 w-6 h-6 rounded-full bg-success text-white text-xs flex items-center justify-center"
+          title={`Watched ${new Date(video.selection!.watchedAt!).toLocaleDateString()}`}
+        >
+          ✓
         </div>
+      )}
 
-        {/* Proxy status badge */}
-        <div className="absolute bottom-2 left-2">
-          {video.hasProxy ? (
-            <span className="bg-success/20 text-success px-2 py-1 rounded text-xs">
-              Proxy Ready
-            </span>
-          ) : (
-            <span className="bg-muted/20 text-muted px-2 py-1 rounded text-xs">
-              No Proxy
-            </span>
-          )}
+      {/* HDR, log or another non-Rec. 709 color space */}
+      {colorBadge && (
+        <div
+          className={`absolute top-2 ${isWatched ? 'left-9' : 'left-2'} px-1.5 py-0.5 rounded text-xs font-medium ${colorBadge.className}`}
+          title={colorBadge.title}
+        >
+          {colorBadge.label}
+        </div>
+      )}
+
+      {/* Flagged from a verify report */}
+      {video.corruptAt && (
+        <div
+          className="absolute bottom-9 right-2 bg-error/80 text-white px-1.5 py-0.5 rounded text-xs"
+          title={`Marked corrupt ${new Date(video.corruptAt).toLocaleDateString()}`}
+        >
+          ⚠ Corrupt
+        </div>
+      )}
+
+      {/* Long silent stretch found by silence detection */}
+      {video.silenceWarning !== undefined && (
+        <div
+          className="absolute bottom-9 left-2 bg-warning/80 text-black px-1.5 py-0.5 rounded text-xs"
+          title={`Audio is silent for ${formatDuration(video.silenceWarning)} at one point`}
+        >
+          🔇 Dropout
         </div>
+      )}
+
+      {/* Duration badge */}
+      <div
+        className="absolute bottom-2 right-2 bg-black/80 px-2 py-1 rounded text-xs font-mono"
+        title={video.loudnessLufs !== null ? `🔊 ${formatLoudness(video.loudnessLufs)}` : undefined}
+      >
+        {formatDuration(video.duration)}
+      </div>
+
+      {/* Proxy status badge */}
+      <div className="absolute bottom-2 left-2">
+        {video.hasProxy ? (
+          <span className="bg-success/20 text-success px-2 py-1 rounded text-xs">
+            Proxy Ready
+          </span>
+        ) : (
+          <span className="bg-muted/20 text-muted px-2 py-1 rounded text-xs">
+            No Proxy
+          </span>
+        )}
+      </div>
+    </>
+  );
+
+  return (
+    <div
+      data-video-card={video.id}
+      className={`
+        group relative rounded-lg overflow-hidden bg-card border transition-all duration-200 cursor-pointer
+        ${isHighlighted || isSelected
+          ? 'border-accent ring-2 ring-accent'
+          : isHovered ? 'border-accent ring-1 ring-accent' : 'border-card-border'
+        }
+        hover:scale-[1.02] hover:shadow-xl
+      `}
+      onClick={handleClick}
+      onContextMenu={handleContextMenu}
+      draggable={!!getDragIds}
+      onDragStart={handleDragStart}
+      onMouseEnter={() => setIsHovered(true)}
+      onMouseLeave={() => setIsHovered(false)}
+    >
+      {/* Pulsing border while a rescan processes this file */}
+      {isBeingScanned && (
+        <div className="absolute inset-0 z-20 rounded-lg ring-2 ring-inset ring-accent animate-pulse pointer-events-none" />
+      )}
+
+      {/* Color label edge */}
+      {video.selection?.colorLabel && (
+        <div
+          className={`absolute inset-y-0 left-0 w-1 z-10 ${COLOR_LABEL_CLASSES[video.selection.colorLabel]}`}
+          title={`Label: ${video.selection.colorLabel}`}
+        />
+      )}
+
+      {/* Video thumbnail with hover scrub, or a strip of frames */}
+      <div
+        className={`relative bg-black ${stripFrames ? '' : 'aspect-video'}`}
+        style={stripFrames ? { aspectRatio: `${stripFrames * 16} / 9` } : undefined}
+      >
+        {stripFrames ? (
+          <ThumbnailStrip
+            spriteUrl={spriteUrl}
+            thumbnailUrl={thumbnailUrl}
+            frames={stripFrames}
+            spriteColumns={video.spriteColumns}
+            spriteRows={video.spriteRows}
+            spriteFrames={video.spriteFrames}
+          >
+            {overlays}
+          </ThumbnailStrip>
+        ) : (
+          <HoverScrubber
+            videoId={video.id}
+            thumbnailUrl={thumbnailUrl}
+            hasThumbnail={!!video.thumbnailPath}
+            spriteUrl={spriteUrl}
+            duration={video.duration}
+            hasSprite={video.hasSprite}
+            hasProxy={video.hasProxy}
+            fileName={video.fileName}
+            width={video.width}
+            height={video.height}
+            onlineOnly={video.onlineOnly}
+          >
+            {overlays}
+          </HoverScrubber>
+        )}
       </div>
 
       {/* Video info */}
       <div className="p-3 bg-gradient-to-t from-card to-card/80">
-        <h3 className="font-medium text-sm truncate mb-1" title={video.fileName}>
-          {video.fileName}
-        </h3>
+        <div className="flex items-center gap-2 mb-1">
+          <h3 className="flex-1 min-w-0 font-medium text-sm truncate" title={video.fileName}>
+            {video.fileName}
+          </h3>
+          {/* Same file name elsewhere in the library (e.g. C0001.MP4 from several cards) */}
+          {(video.sameNameCount ?? 0) > 1 && (
+            <button
+              onClick={handleSameNameClick}
+              onMouseEnter={handleSameNameHover}
+              onDoubleClick={(e) => e.stopPropagation()}
+              className="shrink-0 px-1.5 rounded bg-warning/20 text-warning text-xs tabular-nums hover:bg-warning/30"
+              title={sameNamePaths
+                ? `Same name as:\n${sameNamePaths.join('\n')}\n\nClick to show all of them`
+                : `${video.sameNameCount} videos share this name. Click to show all of them`}
+            >
+              ≈{video.sameNameCount}
+            </button>
+          )}
+        </div>
         <div className="flex items-center gap-2 text-xs text-muted">
-          <span>{formatFileSize(video.fileSize)}</span>
-          <span>•</span>
-          <span>{new Date(video.createdAt).toLocaleDateString()}</span>
+          {/* Parent folder; clicking filters the grid to it without opening the video */}
+          {onShowSiblings ? (
+            <button
+              onClick={handleFolderClick}
+              onDoubleClick={(e) => e.stopPropagation()}
+              className="min-w-0 truncate hover:text-foreground hover:underline"
+              title={video.directory}
+            >
+              📁 {folderName}
+            </button>
+          ) : (
+            <span className="min-w-0 truncate" title={video.directory}>📁 {folderName}</span>
+          )}
+          <span className="shrink-0">•</span>
+          <span className="shrink-0" title={bitrate !== null ? `${formatBitrate(bitrate)} · ${formatMbPerMinute(bitrate)}` : undefined}>
+            {formatFileSize(video.fileSize)}
+          </span>
+          <span className="shrink-0">•</span>
+          <span className="shrink-0" title={DATE_SOURCE_TITLES[bestDate.source]}>
+            {new Date(bestDate.date).toLocaleDateString()}
+            {bestDate.source === 'name' && <span className="text-muted/60">*</span>}
+          </span>
+          {!!video.selection?.rating && (
+            <span className="shrink-0 text-yellow-400" title={`Rated ${video.selection.rating} of 5`}>
+              {'★'.repeat(video.selection.rating)}
+            </span>
+          )}
         </div>
 
         {/* Notes preview if exists */}
@@ -203,6 +517,135 @@ export default function VideoCard({ video, onSelect, onToggleFavorite }: VideoCa
           </p>
         )}
       </div>
+
+      {/* Right-click menu (portaled so the card's overflow and transform don't clip it) */}
+      {contextMenu && createPortal(
+        <div
+          ref={contextMenuRef}
+          className="fixed z-50 bg-card border border-card-border rounded-lg shadow-xl overflow-hidden min-w-[180px]"
+          style={{ left: contextMenu.x, top: contextMenu.y }}
+          onClick={(e) => e.stopPropagation()}
+        >
+          <button
+            onClick={() => {
+              setContextMenu(null);
+              onSelect(video);
+            }}
+            className="w-full px-3 py-2 text-left text-sm hover:bg-accent/20 transition-colors"
+          >
+            ▶ Play
+          </button>
+          <button
+            onClick={() => {
+              setContextMenu(null);
+              onToggleWatched(video.id, !isWatched);
+            }}
+            className="w-full px-3 py-2 text-left text-sm hover:bg-accent/20 transition-colors"
+          >
+            {isWatched ? '↺ Mark Unwatched' : '✓ Mark Watched'}
+          </button>
+          <button
+            onClick={() => {
+              setContextMenu(null);
+              onToggleFavorite(video.id, !video.selection?.isFavorite);
+            }}
+            className="w-full px-3 py-2 text-left text-sm hover:bg-accent/20 transition-colors"
+          >
+            {video.selection?.isFavorite ? '☆ Remove from Favorites' : '★ Add to Favorites'}
+          </button>
+          {onSetColorLabel && (
+            <div className="flex items-center gap-1.5 px-3 py-2 border-b border-card-border">
+              {COLOR_LABELS.map((label, i) => (
+                <button
+                  key={label}
+                  onClick={() => {
+                    setContextMenu(null);
+                    onSetColorLabel(video, label);
+                  }}
+                  className={`w-4 h-4 rounded-full ${COLOR_LABEL_CLASSES[label]} ${
+                    video.selection?.colorLabel === label ? 'ring-2 ring-foreground' : 'hover:scale-110'
+                  }`}
+                  title={`${label[0].toUpperCase()}${label.slice(1)} (${i + 1})`}
+                />
+              ))}
+              {video.selection?.colorLabel && (
+                <button
+                  onClick={() => {
+                    setContextMenu(null);
+                    onSetColorLabel(video, null);
+                  }}
+                  className="ml-1 text-xs text-muted hover:text-foreground"
+                  title="Remove label (0)"
+                >
+                  ✕
+                </button>
+              )}
+            </div>
+          )}
+          {onShowSiblings && (
+            <button
+              onClick={() => {
+                setContextMenu(null);
+                onShowSiblings(video);
+              }}
+              className="w-full px-3 py-2 text-left text-sm hover:bg-accent/20 transition-colors"
+            >
+              📁 Show Siblings
+            </button>
+          )}
+          {onRescanFolder && (
+            <button
+              onClick={() => {
+                setContextMenu(null);
+                onRescanFolder(video);
+              }}
+              className="w-full px-3 py-2 text-left text-sm hover:bg-accent/20 transition-colors"
+            >
+              🔄 Rescan Folder
+            </button>
+          )}
+          {video.subtitleFiles.length > 0 && (
+            <button
+              onClick={handleOpenSubtitlesFolder}
+              className="w-full px-3 py-2 text-left text-sm hover:bg-accent/20 transition-colors"
+            >
+              📂 Open Subtitles Folder
+            </button>
+          )}
+          <button
+            onClick={() => {
+              setContextMenu(null);
+              setShowFfmpegCommand(true);
+            }}
+            className="w-full px-3 py-2 text-left text-sm hover:bg-accent/20 transition-colors"
+          >
+            ⌨ Copy ffmpeg command…
+          </button>
+          {onRemoveFromCatalog && (
+            <button
+              onClick={() => {
+                setContextMenu(null);
+                onRemoveFromCatalog(video);
+              }}
+              className="w-full px-3 py-2 text-left text-sm text-red-400 hover:bg-red-500/20 transition-colors border-t border-card-border"
+            >
+              🗑 Remove from Catalog
+            </button>
+          )}
+        </div>,
+        document.body
+      )}
+
+      {/* Portaled so the card's hover transform doesn't offset the fixed overlay */}
+      {showFfmpegCommand && createPortal(
+        <FfmpegCommandModal
+          video={video}
+          initialStart={0}
+          initialEnd={video.duration}
+          onClose={() => setShowFfmpegCommand(false)}
+        />,
+        document.body
+      )}
     </div>
   );
 }
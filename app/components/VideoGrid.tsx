@@ -1,54 +1,318 @@
 'use client';
 
-import { useRef, useMemo } from 'react';
+import { useRef, useMemo, useState, useEffect, useCallback } from 'react';
 import { useVirtualizer } from '@tanstack/react-virtual';
 import VideoCard from './VideoCard';
-import { VideoWithSelection } from '@/app/lib/types';
+import { ShowToast } from './Toast';
+import { ColorLabel, DirectoryStats, SortOption, ThumbnailStatus, VideoWithSelection, ViewLayout } from '@/app/lib/types';
+import { computeSmartGridLayout, groupVideosByDate, groupVideosByFolder } from '@/app/lib/layout';
+import { formatDuration, formatFileSize } from '@/app/lib/utils';
 
 interface VideoGridProps {
   videos: VideoWithSelection[];
   isLoading: boolean;
+  layout: ViewLayout;
+  sortBy: SortOption; // Orders the day groups in the date layout
+  emptyMessage?: string;
+  highlightedVideoId?: string | null; // Scrolled into view and ringed (e.g. random pick)
+  selectedIds?: Set<string>; // Batch selection, ringed
+  onSelectionChange?: (ids: Set<string>) => void; // Rubber-band selection in the empty space
+  stripFrames: number; // Frames per card in the strip layout
   onSelectVideo: (video: VideoWithSelection) => void;
   onToggleFavorite: (videoId: string, isFavorite: boolean) => void;
+  onToggleWatched: (videoId: string, watched: boolean) => void;
+  onShowSiblings?: (video: VideoWithSelection) => void;
+  onRescanFolder?: (video: VideoWithSelection) => void;
+  onShowSameName?: (video: VideoWithSelection) => void;
+  onSetColorLabel?: (video: VideoWithSelection, label: ColorLabel | null) => void;
+  onRemoveFromCatalog?: (video: VideoWithSelection) => void;
+  scanningFilePath?: string | null; // File a rescan is processing right now, pulsed
+  thumbnailStatuses?: Record<string, ThumbnailStatus>; // Thumbnails pending, generating or failed
+  onRetryThumbnail?: (video: VideoWithSelection) => void;
+  getDragIds?: (video: VideoWithSelection) => string[]; // Cards are draggable onto selection sets
+  // Folder layout: the library root, cached totals and collapse state keyed by relative folder
+  rootPath?: string | null;
+  directoryStats?: Map<string, DirectoryStats>;
+  collapsedDirectories?: Record<string, boolean>;
+  onToggleDirectory?: (directory: string, collapsed: boolean) => void;
+  onToast: ShowToast;
 }
 
 // Number of columns in the grid
 const COLUMNS = 4;
 const GAP = 16; // Gap between cards in pixels
 const CARD_ASPECT_RATIO = 0.85; // Height/Width ratio for cards (including info)
+const SMART_GRID_CARD_SIZE = 240; // Width of a 1×1 slot in the smart grid
+const PADDING_X = 16; // Horizontal padding around rows (px-4)
+const STRIP_COLUMNS = 2; // Strip cards are wide, so fewer per row
+const CARD_INFO_HEIGHT = 88; // Title, size/date and notes lines under the thumbnail
+const DATE_HEADER_HEIGHT = 48; // Day or folder separator row in the date and folder layouts
+
+const BAND_DRAG_THRESHOLD = 4; // Pixels before a press in the empty space becomes a drag
+const BAND_AUTOSCROLL_EDGE = 40; // Dragging this close to the top/bottom scrolls the grid
+const BAND_AUTOSCROLL_STEP = 20;
+
+// Rectangle in the scroll content's coordinates
+interface ContentRect {
+  left: number;
+  top: number;
+  right: number;
+  bottom: number;
+}
+
+// Drag in progress: where it started, the selection it adds to, and the last seen
+// position of every card it has passed (cards leave the DOM when scrolled away)
+interface BandDrag {
+  pointerId: number;
+  originX: number;
+  originY: number;
+  clientX: number;
+  clientY: number;
+  base: Set<string>;
+  cardRects: Map<string, ContentRect>;
+  moved: boolean;
+}
+
+// A virtualized row: either cards or, in the date and folder layouts, a group header
+interface GridRow {
+  key: string;
+  header?: string;
+  detail?: string; // Muted text after the header
+  directory?: string; // Folder headers toggle this folder's collapse state
+  videos: VideoWithSelection[];
+}
 
 export default function VideoGrid({
   videos,
   isLoading,
+  layout,
+  sortBy,
+  emptyMessage = 'Select a folder to scan for videos',
+  highlightedVideoId = null,
+  selectedIds,
+  stripFrames,
   onSelectVideo,
   onToggleFavorite,
+  onToggleWatched,
+  onShowSiblings,
+  onRescanFolder,
+  onShowSameName,
+  onSetColorLabel,
+  onRemoveFromCatalog,
+  scanningFilePath = null,
+  thumbnailStatuses,
+  onRetryThumbnail,
+  getDragIds,
+  rootPath,
+  directoryStats,
+  collapsedDirectories,
+  onToggleDirectory,
+  onToast,
 }: VideoGridProps) {
   const parentRef = useRef<HTMLDivElement>(null);
+  const [containerWidth, setContainerWidth] = useState(0);
+  const hasVideos = !isLoading && videos.length > 0;
+
+  // Track the grid width so the smart grid can re-pack on resize
+  useEffect(() => {
+    const el = parentRef.current;
+    if (!el) return;
+    const observer = new ResizeObserver(() => setContainerWidth(el.clientWidth));
+    observer.observe(el);
+    setContainerWidth(el.clientWidth);
+    return () => observer.disconnect();
+  }, [hasVideos]);
+
+  // Shelf-packed rows for the smart grid (card size follows resolution)
+  const shelves = useMemo(() => {
+    if (layout !== 'smart' || containerWidth === 0) return [];
+    return computeSmartGridLayout(
+      videos,
+      containerWidth - PADDING_X * 2,
+      SMART_GRID_CARD_SIZE,
+      GAP,
+      CARD_ASPECT_RATIO
+    );
+  }, [layout, videos, containerWidth]);
+
+  const columns = layout === 'strip' ? STRIP_COLUMNS : COLUMNS;
 
   // Calculate rows for virtualization
   const rows = useMemo(() => {
-    const result: VideoWithSelection[][] = [];
-    for (let i = 0; i < videos.length; i += COLUMNS) {
-      result.push(videos.slice(i, i + COLUMNS));
+    const result: GridRow[] = [];
+    const pushCardRows = (groupVideos: VideoWithSelection[], keyPrefix: string) => {
+      for (let i = 0; i < groupVideos.length; i += columns) {
+        result.push({ key: `${keyPrefix}${i}`, videos: groupVideos.slice(i, i + columns) });
+      }
+    };
+
+    if (layout === 'date') {
+      // Each day starts a new row under its own header
+      for (const group of groupVideosByDate(videos, sortBy)) {
+        result.push({
+          key: group.key,
+          header: `📅 ${group.label} — ${group.videos.length} video${group.videos.length !== 1 ? 's' : ''}`,
+          videos: [],
+        });
+        pushCardRows(group.videos, `${group.key}-`);
+      }
+    } else if (layout === 'folder' && rootPath) {
+      // Each folder starts a new row; collapsed folders show only their header
+      for (const group of groupVideosByFolder(videos, rootPath)) {
+        const isCollapsed = collapsedDirectories?.[group.key] ?? false;
+        const stats = directoryStats?.get(group.key);
+        result.push({
+          key: `folder:${group.key}`,
+          header: `${isCollapsed ? '▸' : '▾'} 📁 ${group.label} — ${group.videos.length} video${group.videos.length !== 1 ? 's' : ''}`,
+          detail: stats
+            ? `${formatDuration(stats.totalDuration)} · ${formatFileSize(stats.totalSize)} with subfolders, as of the last scan`
+            : undefined,
+          directory: group.key,
+          videos: [],
+        });
+        if (!isCollapsed) pushCardRows(group.videos, `folder:${group.key}-`);
+      }
+    } else {
+      pushCardRows(videos, '');
     }
     return result;
-  }, [videos]);
+  }, [videos, columns, layout, sortBy, rootPath, directoryStats, collapsedDirectories]);
 
   // Estimate row height based on container width
   const estimateRowHeight = () => {
     if (!parentRef.current) return 300;
     const containerWidth = parentRef.current.clientWidth;
-    const cardWidth = (containerWidth - GAP * (COLUMNS - 1)) / COLUMNS;
+    const cardWidth = (containerWidth - GAP * (columns - 1)) / columns;
+    if (layout === 'strip') {
+      // Strip of 16:9 frames spanning the card width
+      return cardWidth / (stripFrames * 16 / 9) + CARD_INFO_HEIGHT + GAP;
+    }
     return cardWidth * CARD_ASPECT_RATIO + GAP;
   };
 
   const virtualizer = useVirtualizer({
-    count: rows.length,
+    count: layout === 'smart' ? shelves.length : rows.length,
     getScrollElement: () => parentRef.current,
-    estimateSize: estimateRowHeight,
+    estimateSize: layout === 'smart'
+      ? (index) => shelves[index].height + GAP
+      : (index) => (rows[index].header ? DATE_HEADER_HEIGHT : estimateRowHeight()),
     overscan: 2,
   });
 
+  // Shelf heights differ per row, strip rows change height with the frame count, and
+  // date headers move when the videos change, so re-measure when any of them change
+  useEffect(() => {
+    virtualizer.measure();
+  }, [virtualizer, shelves, rows, layout, stripFrames]);
+
+  // Scroll the row containing the highlighted video into view (once per highlight, not on every refetch)
+  const scrolledToId = useRef<string | null>(null);
+  useEffect(() => {
+    if (!highlightedVideoId || highlightedVideoId === scrolledToId.current) return;
+    const rowIndex = layout === 'smart'
+      ? shelves.findIndex((shelf) => shelf.items.some((item) => item.video.id === highlightedVideoId))
+      : rows.findIndex((row) => row.videos.some((v) => v.id === highlightedVideoId));
+    if (rowIndex >= 0) {
+      virtualizer.scrollToIndex(rowIndex, { align: 'center' });
+      scrolledToId.current = highlightedVideoId;
+    }
+  }, [highlightedVideoId, layout, shelves, rows, virtualizer]);
+
+  // Rubber-band selection: drag from the space between cards to select the cards the
+  // rectangle touches (Shift or Ctrl/Cmd adds to the selection); a click there clears it
+  const bandDrag = useRef<BandDrag | null>(null);
+  const [band, setBand] = useState<ContentRect | null>(null);
+
+  const toContent = useCallback((clientX: number, clientY: number) => {
+    const el = parentRef.current!;
+    const bounds = el.getBoundingClientRect();
+    return { x: clientX - bounds.left + el.scrollLeft, y: clientY - bounds.top + el.scrollTop };
+  }, []);
+
+  const updateBand = useCallback(() => {
+    const drag = bandDrag.current;
+    const el = parentRef.current;
+    if (!drag || !el || !onSelectionChange) return;
+
+    const { x, y } = toContent(drag.clientX, drag.clientY);
+    if (!drag.moved && Math.hypot(x - drag.originX, y - drag.originY) < BAND_DRAG_THRESHOLD) return;
+    drag.moved = true;
+
+    const rect = {
+      left: Math.min(drag.originX, x),
+      top: Math.min(drag.originY, y),
+      right: Math.max(drag.originX, x),
+      bottom: Math.max(drag.originY, y),
+    };
+    setBand(rect);
+
+    el.querySelectorAll<HTMLElement>('[data-video-card]').forEach((card) => {
+      const bounds = card.getBoundingClientRect();
+      const topLeft = toContent(bounds.left, bounds.top);
+      drag.cardRects.set(card.dataset.videoCard!, {
+        left: topLeft.x,
+        top: topLeft.y,
+        right: topLeft.x + bounds.width,
+        bottom: topLeft.y + bounds.height,
+      });
+    });
+
+    const next = new Set(drag.base);
+    for (const [id, card] of drag.cardRects) {
+      if (card.left < rect.right && card.right > rect.left && card.top < rect.bottom && card.bottom > rect.top) {
+        next.add(id);
+      }
+    }
+    onSelectionChange(next);
+  }, [onSelectionChange, toContent]);
+
+  const handleBandPointerDown = useCallback((e: React.PointerEvent<HTMLDivElement>) => {
+    const el = parentRef.current;
+    if (!el || !onSelectionChange || e.button !== 0) return;
+    if ((e.target as HTMLElement).closest('[data-video-card]')) return;
+    // Presses on the scrollbar aren't in the empty space
+    if (e.clientX - el.getBoundingClientRect().left >= el.clientWidth) return;
+
+    const { x, y } = toContent(e.clientX, e.clientY);
+    const additive = e.shiftKey || e.ctrlKey || e.metaKey;
+    bandDrag.current = {
+      pointerId: e.pointerId,
+      originX: x,
+      originY: y,
+      clientX: e.clientX,
+      clientY: e.clientY,
+      base: additive ? new Set(selectedIds) : new Set(),
+      cardRects: new Map(),
+      moved: false,
+    };
+    el.setPointerCapture(e.pointerId);
+    e.preventDefault(); // No text selection while dragging
+  }, [onSelectionChange, selectedIds, toContent]);
+
+  const handleBandPointerMove = useCallback((e: React.PointerEvent<HTMLDivElement>) => {
+    const drag = bandDrag.current;
+    const el = parentRef.current;
+    if (!drag || !el || e.pointerId !== drag.pointerId) return;
+    drag.clientX = e.clientX;
+    drag.clientY = e.clientY;
+
+    const bounds = el.getBoundingClientRect();
+    if (e.clientY < bounds.top + BAND_AUTOSCROLL_EDGE) {
+      el.scrollTop -= BAND_AUTOSCROLL_STEP;
+    } else if (e.clientY > bounds.bottom - BAND_AUTOSCROLL_EDGE) {
+      el.scrollTop += BAND_AUTOSCROLL_STEP;
+    }
+    updateBand();
+  }, [updateBand]);
+
+  const handleBandPointerUp = useCallback((e: React.PointerEvent<HTMLDivElement>) => {
+    const drag = bandDrag.current;
+    if (!drag || e.pointerId !== drag.pointerId) return;
+    bandDrag.current = null;
+    setBand(null);
+    if (!drag.moved) onSelectionChange?.(drag.base);
+  }, [onSelectionChange]);
+
   if (isLoading) {
     return (
       <div className="grid grid-cols-4 gap-4 p-4">
@@ -82,7 +346,7 @@ export default function VideoGrid({
           />
         </svg>
         <h3 className="text-lg font-medium mb-2">No videos found</h3>
-        <p className="text-sm">Select a folder to scan for videos</p>
+        <p className="text-sm">{emptyMessage}</p>
       </div>
     );
   }
@@ -91,6 +355,11 @@ export default function VideoGrid({
     <div
       ref={parentRef}
       className="absolute inset-0 overflow-auto"
+      onPointerDown={handleBandPointerDown}
+      onPointerMove={handleBandPointerMove}
+      onPointerUp={handleBandPointerUp}
+      onPointerCancel={handleBandPointerUp}
+      onScroll={updateBand}
     >
       <div
         className="relative w-full"
@@ -98,8 +367,88 @@ export default function VideoGrid({
           height: `${virtualizer.getTotalSize()}px`,
         }}
       >
-        {virtualizer.getVirtualItems().map((virtualRow) => {
-          const rowVideos = rows[virtualRow.index];
+        {band && (
+          <div
+            className="absolute z-20 border border-accent bg-accent/20 pointer-events-none"
+            style={{
+              left: band.left,
+              top: band.top,
+              width: band.right - band.left,
+              height: band.bottom - band.top,
+            }}
+          />
+        )}
+        {layout === 'smart' && virtualizer.getVirtualItems().map((virtualRow) => {
+          const shelf = shelves[virtualRow.index];
+          return (
+            <div
+              key={virtualRow.key}
+              className="absolute top-0 left-0 w-full px-4"
+              style={{
+                height: `${virtualRow.size}px`,
+                transform: `translateY(${virtualRow.start}px)`,
+              }}
+            >
+              <div className="relative">
+                {shelf.items.map(({ video, rect }) => (
+                  <div
+                    key={video.id}
+                    className="absolute top-0"
+                    style={{ left: `${rect.x}px`, width: `${rect.width}px` }}
+                  >
+                    <VideoCard
+                      video={video}
+                      isHighlighted={video.id === highlightedVideoId}
+                      isSelected={selectedIds?.has(video.id)}
+                      isBeingScanned={video.filePath === scanningFilePath}
+                      onSelect={onSelectVideo}
+                      onToggleFavorite={onToggleFavorite}
+                      onToggleWatched={onToggleWatched}
+                      onShowSiblings={onShowSiblings}
+                      onRescanFolder={onRescanFolder}
+                      onShowSameName={onShowSameName}
+                      onSetColorLabel={onSetColorLabel}
+                      onRemoveFromCatalog={onRemoveFromCatalog}
+                      thumbnailStatus={thumbnailStatuses?.[video.id]}
+                      onRetryThumbnail={onRetryThumbnail}
+                      getDragIds={getDragIds}
+                      onToast={onToast}
+                    />
+                  </div>
+                ))}
+              </div>
+            </div>
+          );
+        })}
+        {layout !== 'smart' && virtualizer.getVirtualItems().map((virtualRow) => {
+          const row = rows[virtualRow.index];
+          const rowVideos = row.videos;
+          if (row.header) {
+            return (
+              <div
+                key={virtualRow.key}
+                className="absolute top-0 left-0 w-full px-4 flex items-end pb-2"
+                style={{
+                  height: `${virtualRow.size}px`,
+                  transform: `translateY(${virtualRow.start}px)`,
+                }}
+              >
+                <h3 className="w-full text-sm font-medium text-muted border-b border-card-border pb-1">
+                  {row.directory !== undefined ? (
+                    <button
+                      onClick={() => onToggleDirectory?.(row.directory!, !(collapsedDirectories?.[row.directory!] ?? false))}
+                      className="hover:text-foreground"
+                    >
+                      {row.header}
+                    </button>
+                  ) : (
+                    row.header
+                  )}
+                  {row.detail && <span className="ml-2 text-xs font-normal">{row.detail}</span>}
+                </h3>
+              </div>
+            );
+          }
           return (
             <div
               key={virtualRow.key}
@@ -112,20 +461,34 @@ export default function VideoGrid({
               <div
                 className="grid gap-4"
                 style={{
-                  gridTemplateColumns: `repeat(${COLUMNS}, 1fr)`,
+                  gridTemplateColumns: `repeat(${columns}, 1fr)`,
                 }}
               >
                 {rowVideos.map((video) => (
                   <VideoCard
                     key={video.id}
                     video={video}
+                    isHighlighted={video.id === highlightedVideoId}
+                    isSelected={selectedIds?.has(video.id)}
+                    isBeingScanned={video.filePath === scanningFilePath}
+                    stripFrames={layout === 'strip' ? stripFrames : undefined}
                     onSelect={onSelectVideo}
                     onToggleFavorite={onToggleFavorite}
+                    onToggleWatched={onToggleWatched}
+                    onShowSiblings={onShowSiblings}
+                    onRescanFolder={onRescanFolder}
+                    onShowSameName={onShowSameName}
+                    onSetColorLabel={onSetColorLabel}
+                    onRemoveFromCatalog={onRemoveFromCatalog}
+                    thumbnailStatus={thumbnailStatuses?.[video.id]}
+                    onRetryThumbnail={onRetryThumbnail}
+                    getDragIds={getDragIds}
+                    onToast={onToast}
                   />
                 ))}
                 {/* Fill empty slots in last row */}
-                {rowVideos.length < COLUMNS &&
-                  Array.from({ length: COLUMNS - rowVideos.length }).map((_, i) => (
+                {rowVideos.length < columns &&
+                  Array.from({ length: columns - rowVideos.length }).map((_, i) => (
                     <div key={`empty-${i}`} />
                   ))}
               </div>
@@ -1,37 +1,167 @@
 'use client';
 
 import { useState, useCallback, useEffect, useRef } from 'react';
-import { VideoWithSelection } from '@/app/lib/types';
-import { formatDuration, formatFileSize } from '@/app/lib/utils';
+import { Chapter, SilenceReport, VideoWithSelection } from '@/app/lib/types';
+import FfmpegCommandModal from './FfmpegCommandModal';
+import ClipExportModal from './ClipExportModal';
+import WaveformStrip from './WaveformStrip';
+import FilmStrip from './FilmStrip';
+import { clampPlayerGeometry, loadPlayerGeometry, savePlayerGeometry, PlayerGeometry, PLAYER_SCREEN_MARGIN } from '@/app/lib/playerGeometry';
+import { captureVideoFrame, formatBitrate, formatCoordinates, formatDuration, formatLoudness, formatFileSize, formatMbPerMinute, getBitrate, formatTimecode, getMapsUrl, getSubtitleLabel, imageDataToPngBlob, DEFAULT_FRAME_RATE } from '@/app/lib/utils';
 
 interface VideoModalProps {
   video: VideoWithSelection;
   onClose: () => void;
   onToggleFavorite: (videoId: string, isFavorite: boolean) => void;
+  onToggleWatched: (videoId: string, watched: boolean) => void;
   onUpdateNotes: (videoId: string, notes: string) => void;
+  onVideoUpdated: (video: VideoWithSelection) => void;
 }
 
+// Share of a clip that must actually be played (not skipped over) to mark it watched
+const AUTO_WATCHED_FRACTION = 0.8;
+// Previous chapter restarts the current one unless within this many seconds of its start
+const CHAPTER_RESTART_SECONDS = 3;
+// Minimum gap between seeks while dragging the seek bar (~5 per second)
+const DRAG_SEEK_INTERVAL_MS = 200;
+// Clips at least this long (seconds) get a film strip under the seek bar
+const FILM_STRIP_MIN_DURATION = 60;
+
 export default function VideoModal({
   video,
   onClose,
   onToggleFavorite,
+  onToggleWatched,
   onUpdateNotes,
+  onVideoUpdated,
 }: VideoModalProps) {
   const [notes, setNotes] = useState(video.selection?.notes || '');
   const [isEditingNotes, setIsEditingNotes] = useState(false);
+  const [timecodeCopied, setTimecodeCopied] = useState(false);
+  const [coordinatesCopied, setCoordinatesCopied] = useState(false);
+  const [isSavingPoster, setIsSavingPoster] = useState(false);
+  // An online-only file is only streamed (and so downloaded) once the user asks for it
+  const [streamAllowedId, setStreamAllowedId] = useState<string | null>(null);
+  const isStreamHeld = video.onlineOnly && streamAllowedId !== video.id;
+  const [audioTrack, setAudioTrack] = useState(0);
+  const [subtitleIndex, setSubtitleIndex] = useState(-1); // -1 = subtitles off
+  const [bufferedRanges, setBufferedRanges] = useState<[number, number][]>([]); // [start, end] in seconds
+  const [playhead, setPlayhead] = useState(0);
+  const [mediaDuration, setMediaDuration] = useState(video.duration);
+  const [chapters, setChapters] = useState<Chapter[]>([]);
+  const [silence, setSilence] = useState<SilenceReport | null>(null);
+  const [silenceThreshold, setSilenceThreshold] = useState(0);
+  const [isCheckingSilence, setIsCheckingSilence] = useState(false);
+  const [ffmpegCommandStart, setFfmpegCommandStart] = useState<number | null>(null); // Non-null while the picker is open
+  const [dragTime, setDragTime] = useState<number | null>(null); // Seek bar target while dragging
+  const [markA, setMarkA] = useState<number | null>(null); // In point for clip export
+  const [markB, setMarkB] = useState<number | null>(null); // Out point
+  const [showClipExport, setShowClipExport] = useState(false);
+  const pendingSeekRef = useRef<number | null>(null);
+  const lastDragSeekRef = useRef(0);
   const videoRef = useRef<HTMLVideoElement>(null);
+  const audioRef = useRef<HTMLAudioElement>(null);
+  const modalRef = useRef<HTMLDivElement>(null);
+  // Saved size/position, fitted to this window; null keeps the default centered layout
+  const [geometry, setGeometry] = useState<PlayerGeometry | null>(() => {
+    const saved = loadPlayerGeometry();
+    return saved && clampPlayerGeometry(saved, window.innerWidth, window.innerHeight);
+  });
+  const frameDragRef = useRef<{ mode: 'move' | 'resize'; startX: number; startY: number; start: PlayerGeometry; latest: PlayerGeometry } | null>(null);
+
+  // Chapter markers, if the container has any
+  useEffect(() => {
+    let cancelled = false;
+    setChapters([]);
+    if (isStreamHeld) return;
+    fetch(`/api/videos/${video.id}/chapters`)
+      .then((res) => res.json())
+      .then((data) => {
+        if (!cancelled && data.success) setChapters(data.chapters);
+      })
+      .catch((err) => console.error('Error loading chapters:', err));
+    return () => {
+      cancelled = true;
+    };
+  }, [video.id, isStreamHeld]);
 
-  // Handle escape key to close
+  useEffect(() => {
+    let cancelled = false;
+    setSilence(null);
+    fetch(`/api/videos/${video.id}/silence`)
+      .then((res) => res.json())
+      .then((data) => {
+        if (cancelled || !data.success) return;
+        setSilence(data.report);
+        setSilenceThreshold(data.threshold);
+      })
+      .catch((err) => console.error('Error loading silence report:', err));
+    return () => {
+      cancelled = true;
+    };
+  }, [video.id]);
+
+  const handleCheckSilence = useCallback(async () => {
+    setIsCheckingSilence(true);
+    try {
+      const res = await fetch(`/api/videos/${video.id}/silence`, { method: 'POST' });
+      const data = await res.json();
+      if (!data.success) return;
+      const report: SilenceReport = data.report;
+      setSilence(report);
+      setSilenceThreshold(data.threshold);
+
+      // Keep the card's dropout badge in step
+      const longest = Math.max(0, ...report.ranges.map((range) => range.end - range.start));
+      onVideoUpdated({ ...video, silenceWarning: longest >= data.threshold ? longest : undefined });
+    } catch (err) {
+      console.error('Error checking for silence:', err);
+    } finally {
+      setIsCheckingSilence(false);
+    }
+  }, [video, onVideoUpdated]);
+
+  const seekToChapter = useCallback((direction: 1 | -1) => {
+    const videoEl = videoRef.current;
+    if (!videoEl || chapters.length === 0) return;
+
+    const time = videoEl.currentTime;
+    const target = direction === 1
+      ? chapters.find((chapter) => chapter.start > time + 0.5)
+      : [...chapters].reverse().find((chapter) => chapter.start < time - CHAPTER_RESTART_SECONDS)
+        ?? chapters[0];
+    if (target) videoEl.currentTime = target.start;
+  }, [chapters]);
+
+  // Set a marker at the current position; a marker on the wrong side of the other clears it
+  const setMarker = useCallback((marker: 'a' | 'b') => {
+    const time = videoRef.current?.currentTime ?? 0;
+    if (marker === 'a') {
+      setMarkA(time);
+      if (markB !== null && markB <= time) setMarkB(null);
+    } else {
+      setMarkB(time);
+      if (markA !== null && markA >= time) setMarkA(null);
+    }
+  }, [markA, markB]);
+
+  // Escape closes; [ and ] jump between chapters; A and B set the clip markers
   useEffect(() => {
     const handleKeyDown = (e: KeyboardEvent) => {
       if (e.key === 'Escape') {
         onClose();
+        return;
       }
+      if (e.target instanceof HTMLTextAreaElement || e.target instanceof HTMLInputElement || e.target instanceof HTMLSelectElement) return;
+      if (e.metaKey || e.ctrlKey || e.altKey) return;
+      if (e.key === '[') seekToChapter(-1);
+      if (e.key === ']') seekToChapter(1);
+      if (e.key === 'a' || e.key === 'b') setMarker(e.key);
     };
 
     window.addEventListener('keydown', handleKeyDown);
     return () => window.removeEventListener('keydown', handleKeyDown);
-  }, [onClose]);
+  }, [onClose, seekToChapter, setMarker]);
 
   // Prevent body scroll when modal is open
   useEffect(() => {
@@ -41,6 +171,171 @@ export default function VideoModal({
     };
   }, []);
 
+  // Tracks after the first play from an extracted file kept in sync with the muted video
+  useEffect(() => {
+    const videoEl = videoRef.current;
+    const audioEl = audioRef.current;
+    if (!videoEl) return;
+
+    videoEl.muted = audioTrack > 0;
+    if (audioTrack === 0 || !audioEl) return;
+
+    const syncTime = () => {
+      audioEl.currentTime = videoEl.currentTime;
+    };
+    const syncPlay = () => {
+      syncTime();
+      audioEl.play().catch(() => {});
+    };
+    const syncPause = () => audioEl.pause();
+    const syncRate = () => {
+      audioEl.playbackRate = videoEl.playbackRate;
+    };
+
+    syncRate();
+    if (!videoEl.paused) syncPlay();
+
+    videoEl.addEventListener('play', syncPlay);
+    videoEl.addEventListener('pause', syncPause);
+    videoEl.addEventListener('seeked', syncTime);
+    videoEl.addEventListener('ratechange', syncRate);
+    return () => {
+      videoEl.removeEventListener('play', syncPlay);
+      videoEl.removeEventListener('pause', syncPause);
+      videoEl.removeEventListener('seeked', syncTime);
+      videoEl.removeEventListener('ratechange', syncRate);
+      audioEl.pause();
+    };
+  }, [audioTrack]);
+
+  // Mark watched automatically once most of the clip has been played
+  useEffect(() => {
+    const videoEl = videoRef.current;
+    if (!videoEl || video.selection?.watchedAt || video.duration <= 0) return;
+
+    let played = 0;
+    let lastTime = videoEl.currentTime;
+    const handleTimeUpdate = () => {
+      const delta = videoEl.currentTime - lastTime;
+      lastTime = videoEl.currentTime;
+      // timeupdate fires a few times a second; larger jumps are seeks
+      if (delta > 0 && delta < 2) played += delta;
+      if (played >= video.duration * AUTO_WATCHED_FRACTION) {
+        videoEl.removeEventListener('timeupdate', handleTimeUpdate);
+        onToggleWatched(video.id, true);
+      }
+    };
+
+    videoEl.addEventListener('timeupdate', handleTimeUpdate);
+    return () => videoEl.removeEventListener('timeupdate', handleTimeUpdate);
+  }, [video.id, video.duration, video.selection?.watchedAt, onToggleWatched]);
+
+  // Track what the browser has downloaded, for the buffer bar under the player.
+  // Local originals are usually fully buffered almost at once; proxies over a slow share aren't.
+  useEffect(() => {
+    const videoEl = videoRef.current;
+    if (!videoEl) return;
+
+    const handleProgress = () => {
+      const ranges: [number, number][] = [];
+      for (let i = 0; i < videoEl.buffered.length; i++) {
+        ranges.push([videoEl.buffered.start(i), videoEl.buffered.end(i)]);
+      }
+      setBufferedRanges(ranges);
+    };
+    const handleTimeUpdate = () => setPlayhead(videoEl.currentTime);
+    const handleDurationChange = () => {
+      if (Number.isFinite(videoEl.duration)) setMediaDuration(videoEl.duration);
+    };
+
+    videoEl.addEventListener('progress', handleProgress);
+    videoEl.addEventListener('loadeddata', handleProgress);
+    videoEl.addEventListener('timeupdate', handleTimeUpdate);
+    videoEl.addEventListener('seeked', handleProgress);
+    videoEl.addEventListener('durationchange', handleDurationChange);
+    return () => {
+      videoEl.removeEventListener('progress', handleProgress);
+      videoEl.removeEventListener('loadeddata', handleProgress);
+      videoEl.removeEventListener('timeupdate', handleTimeUpdate);
+      videoEl.removeEventListener('seeked', handleProgress);
+      videoEl.removeEventListener('durationchange', handleDurationChange);
+    };
+  }, []);
+
+  // One seek in flight at a time: a target requested mid-seek replaces any earlier
+  // waiting one and is applied on seeked, so big originals don't queue up seeks.
+  // fastSeek (keyframe-accurate) is used while dragging; the release seeks exactly.
+  const requestSeek = useCallback((time: number, fast: boolean = false) => {
+    const videoEl = videoRef.current;
+    if (!videoEl) return;
+    if (videoEl.seeking) {
+      pendingSeekRef.current = time;
+      return;
+    }
+    pendingSeekRef.current = null;
+    if (fast && typeof videoEl.fastSeek === 'function') {
+      videoEl.fastSeek(time);
+    } else {
+      videoEl.currentTime = time;
+    }
+  }, []);
+
+  useEffect(() => {
+    const videoEl = videoRef.current;
+    if (!videoEl) return;
+
+    const handleSeeked = () => {
+      const pending = pendingSeekRef.current;
+      if (pending === null) return;
+      pendingSeekRef.current = null;
+      videoEl.currentTime = pending;
+    };
+
+    videoEl.addEventListener('seeked', handleSeeked);
+    return () => videoEl.removeEventListener('seeked', handleSeeked);
+  }, []);
+
+  // Drag (or click) the seek bar: throttled seeks while dragging, an exact one on release
+  const getBarTime = useCallback((e: React.PointerEvent<HTMLDivElement>) => {
+    const rect = e.currentTarget.getBoundingClientRect();
+    return Math.max(0, Math.min(1, (e.clientX - rect.left) / rect.width)) * mediaDuration;
+  }, [mediaDuration]);
+
+  const handleSeekBarPointerDown = useCallback((e: React.PointerEvent<HTMLDivElement>) => {
+    if (mediaDuration <= 0) return;
+    e.currentTarget.setPointerCapture(e.pointerId);
+    const time = getBarTime(e);
+    setDragTime(time);
+    lastDragSeekRef.current = Date.now();
+    requestSeek(time, true);
+  }, [mediaDuration, getBarTime, requestSeek]);
+
+  const handleSeekBarPointerMove = useCallback((e: React.PointerEvent<HTMLDivElement>) => {
+    if (dragTime === null) return;
+    const time = getBarTime(e);
+    setDragTime(time);
+    if (Date.now() - lastDragSeekRef.current >= DRAG_SEEK_INTERVAL_MS) {
+      lastDragSeekRef.current = Date.now();
+      requestSeek(time, true);
+    }
+  }, [dragTime, getBarTime, requestSeek]);
+
+  const handleSeekBarPointerUp = useCallback((e: React.PointerEvent<HTMLDivElement>) => {
+    if (dragTime === null) return;
+    requestSeek(getBarTime(e));
+    setPlayhead(getBarTime(e)); // Don't snap back while the final seek completes
+    setDragTime(null);
+  }, [dragTime, getBarTime, requestSeek]);
+
+  // Only the chosen subtitle track is shown (and fetched)
+  useEffect(() => {
+    const tracks = videoRef.current?.textTracks;
+    if (!tracks) return;
+    for (let i = 0; i < tracks.length; i++) {
+      tracks[i].mode = i === subtitleIndex ? 'showing' : 'disabled';
+    }
+  }, [subtitleIndex]);
+
   const handleSaveNotes = useCallback(() => {
     onUpdateNotes(video.id, notes);
     setIsEditingNotes(false);
@@ -50,6 +345,129 @@ export default function VideoModal({
     onToggleFavorite(video.id, !video.selection?.isFavorite);
   }, [video.id, video.selection?.isFavorite, onToggleFavorite]);
 
+  const handleCopyTimecode = useCallback(async () => {
+    const currentTime = videoRef.current?.currentTime ?? 0;
+    try {
+      await navigator.clipboard.writeText(formatTimecode(currentTime, video.frameRate ?? DEFAULT_FRAME_RATE));
+      setTimecodeCopied(true);
+      setTimeout(() => setTimecodeCopied(false), 1500);
+    } catch (err) {
+      console.error('Failed to copy timecode:', err);
+    }
+  }, [video.frameRate]);
+
+  const bitrate = getBitrate(video);
+
+  const handleCopyCoordinates = useCallback(async () => {
+    if (video.latitude === null || video.longitude === null) return;
+    try {
+      await navigator.clipboard.writeText(formatCoordinates(video.latitude, video.longitude));
+      setCoordinatesCopied(true);
+      setTimeout(() => setCoordinatesCopied(false), 1500);
+    } catch (err) {
+      console.error('Failed to copy coordinates:', err);
+    }
+  }, [video.latitude, video.longitude]);
+
+  // Download the paused/playing frame as a PNG named after the clip and timecode
+  const handleSaveFrame = useCallback(async () => {
+    const player = videoRef.current;
+    if (!player) return;
+
+    try {
+      const blob = await imageDataToPngBlob(captureVideoFrame(player));
+      const timecode = formatTimecode(player.currentTime, video.frameRate ?? DEFAULT_FRAME_RATE).replace(/:/g, '-');
+      const url = URL.createObjectURL(blob);
+      const link = document.createElement('a');
+      link.href = url;
+      link.download = `${video.fileName.replace(/\.[^.]+$/, '')}_${timecode}.png`;
+      link.click();
+      URL.revokeObjectURL(url);
+    } catch (err) {
+      console.error('Failed to save frame:', err);
+    }
+  }, [video.fileName, video.frameRate]);
+
+  // Set (time) or reset (null) the poster frame used as the card thumbnail
+  const savePosterTime = useCallback(async (time: number | null) => {
+    setIsSavingPoster(true);
+    try {
+      const res = await fetch(`/api/videos/${video.id}/poster`, {
+        method: time === null ? 'DELETE' : 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: time === null ? undefined : JSON.stringify({ time }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        onVideoUpdated(data.video);
+      }
+    } catch (err) {
+      console.error('Error updating poster frame:', err);
+    } finally {
+      setIsSavingPoster(false);
+    }
+  }, [video.id, onVideoUpdated]);
+
+  // Current geometry, pinned to where the modal is on screen (for the first move or resize)
+  const getPinnedGeometry = useCallback((): PlayerGeometry | null => {
+    const rect = modalRef.current?.getBoundingClientRect();
+    if (!rect) return null;
+    return { width: rect.width, height: rect.height, x: rect.left, y: rect.top, maximized: false };
+  }, []);
+
+  const handleFramePointerDown = useCallback((e: React.PointerEvent<HTMLDivElement>, mode: 'move' | 'resize') => {
+    if (geometry?.maximized) return;
+    const start = getPinnedGeometry();
+    if (!start) return;
+    e.preventDefault();
+    e.currentTarget.setPointerCapture(e.pointerId);
+    frameDragRef.current = { mode, startX: e.clientX, startY: e.clientY, start, latest: start };
+  }, [geometry?.maximized, getPinnedGeometry]);
+
+  const handleFramePointerMove = useCallback((e: React.PointerEvent<HTMLDivElement>) => {
+    const drag = frameDragRef.current;
+    if (!drag) return;
+    const dx = e.clientX - drag.startX;
+    const dy = e.clientY - drag.startY;
+    const next = drag.mode === 'move'
+      ? { ...drag.start, x: drag.start.x! + dx, y: drag.start.y! + dy }
+      : { ...drag.start, width: drag.start.width + dx, height: drag.start.height + dy };
+    drag.latest = clampPlayerGeometry(next, window.innerWidth, window.innerHeight);
+    setGeometry(drag.latest);
+  }, []);
+
+  const handleFramePointerUp = useCallback(() => {
+    const drag = frameDragRef.current;
+    if (!drag) return;
+    frameDragRef.current = null;
+    savePlayerGeometry(drag.latest);
+  }, []);
+
+  // Double-click the video to fill the window, and again to go back
+  const handleToggleMaximized = useCallback(() => {
+    const base = geometry ?? getPinnedGeometry();
+    if (!base) return;
+    const next = { ...base, maximized: !base.maximized };
+    setGeometry(next);
+    savePlayerGeometry(next);
+  }, [geometry, getPinnedGeometry]);
+
+  const frameStyle: React.CSSProperties | undefined = geometry?.maximized
+    ? {
+      position: 'fixed',
+      left: PLAYER_SCREEN_MARGIN,
+      top: PLAYER_SCREEN_MARGIN,
+      width: `calc(100vw - ${PLAYER_SCREEN_MARGIN * 2}px)`,
+      height: `calc(100vh - ${PLAYER_SCREEN_MARGIN * 2}px)`,
+    }
+    : geometry
+      ? {
+        width: geometry.width,
+        height: geometry.height,
+        ...(geometry.x !== null && geometry.y !== null ? { position: 'fixed', left: geometry.x, top: geometry.y } : {}),
+      }
+      : undefined;
+
   const videoUrl = video.hasProxy
     ? `/api/videos/${video.id}/stream?type=proxy`
     : `/api/videos/${video.id}/stream?type=original`;
@@ -60,9 +478,24 @@ export default function VideoModal({
       onClick={onClose}
     >
       <div
-        className="relative w-full max-w-6xl max-h-[90vh] bg-card rounded-xl overflow-hidden shadow-2xl"
+        ref={modalRef}
+        className={`relative bg-card rounded-xl overflow-hidden shadow-2xl ${frameStyle ? 'flex flex-col' : 'w-full max-w-6xl max-h-[90vh]'}`}
+        style={frameStyle}
         onClick={(e) => e.stopPropagation()}
       >
+        {/* Drag strip for moving the player */}
+        {!geometry?.maximized && (
+          <div
+            onPointerDown={(e) => handleFramePointerDown(e, 'move')}
+            onPointerMove={handleFramePointerMove}
+            onPointerUp={handleFramePointerUp}
+            className="absolute top-0 inset-x-0 h-3 z-[5] cursor-move flex justify-center group/grip"
+            title="Drag to move"
+          >
+            <div className="mt-1 w-12 h-1 rounded-full bg-white/20 group-hover/grip:bg-white/50" />
+          </div>
+        )}
+
         {/* Close button */}
         <button
           onClick={onClose}
@@ -74,17 +507,132 @@ export default function VideoModal({
         </button>
 
         {/* Video player */}
-        <div className="relative bg-black">
-          <video
-            ref={videoRef}
-            src={videoUrl}
-            controls
-            autoPlay
-            className="w-full max-h-[60vh] object-contain"
-          />
+        <div className={`relative bg-black ${frameStyle ? 'flex-1 min-h-0 flex flex-col' : ''}`}>
+          {isStreamHeld ? (
+            <div className={`flex flex-col items-center justify-center gap-3 p-8 text-center ${frameStyle ? 'flex-1 min-h-0' : 'aspect-video max-h-[60vh]'}`}>
+              <span className="text-4xl text-white/80">☁</span>
+              <p className="text-sm text-white/80 max-w-md">
+                This file is online-only: its data is in the cloud, not on this machine. Download it
+                from your cloud folder and rescan to index it fully, or play it now and let the
+                provider download it.
+              </p>
+              <button
+                onClick={() => setStreamAllowedId(video.id)}
+                className="px-4 py-2 text-sm rounded-lg bg-accent text-white hover:bg-accent/80"
+              >
+                Download and play
+              </button>
+            </div>
+          ) : (
+            <video
+              ref={videoRef}
+              src={videoUrl}
+              controls
+              autoPlay
+              onDoubleClick={handleToggleMaximized}
+              className={`w-full object-contain ${frameStyle ? 'flex-1 min-h-0' : 'max-h-[60vh]'}`}
+            >
+              {video.subtitleFiles.map((fileName, i) => (
+                <track
+                  key={fileName}
+                  kind="subtitles"
+                  src={`/api/videos/${video.id}/subtitles?index=${i}`}
+                  label={getSubtitleLabel(fileName, video.fileName)}
+                />
+              ))}
+            </video>
+          )}
+          {audioTrack > 0 && (
+            <audio
+              key={audioTrack}
+              ref={audioRef}
+              src={`/api/videos/${video.id}/audio?track=${audioTrack}`}
+              preload="auto"
+            />
+          )}
+
+          {/* Waveform over the bar: buffered ranges (grey) behind playback position (accent), with chapter ticks */}
+          {mediaDuration > 0 && !isStreamHeld && (
+            <div
+              onPointerDown={handleSeekBarPointerDown}
+              onPointerMove={handleSeekBarPointerMove}
+              onPointerUp={handleSeekBarPointerUp}
+              onPointerCancel={() => setDragTime(null)}
+              className="cursor-pointer touch-none"
+            >
+              <WaveformStrip videoId={video.id} progress={Math.min(1, (dragTime ?? playhead) / mediaDuration)} />
+              <div className="relative h-1.5 bg-white/10">
+                {bufferedRanges.map(([start, end], i) => (
+                  <div
+                    key={i}
+                    className="absolute inset-y-0 bg-white/30"
+                    style={{
+                      left: `${(start / mediaDuration) * 100}%`,
+                      width: `${((end - start) / mediaDuration) * 100}%`,
+                    }}
+                  />
+                ))}
+                <div
+                  className="absolute inset-y-0 left-0 bg-accent"
+                  style={{ width: `${Math.min(100, ((dragTime ?? playhead) / mediaDuration) * 100)}%` }}
+                />
+                {/* A–B range (or a lone marker) */}
+                {markA !== null && (
+                  <div
+                    className="absolute -inset-y-1 bg-warning/40 border-l-2 border-warning pointer-events-none"
+                    style={{
+                      left: `${(markA / mediaDuration) * 100}%`,
+                      width: markB !== null ? `${((markB - markA) / mediaDuration) * 100}%` : 0,
+                    }}
+                  />
+                )}
+                {markB !== null && (
+                  <div
+                    className="absolute -inset-y-1 w-0.5 -translate-x-1/2 bg-warning pointer-events-none"
+                    style={{ left: `${(markB / mediaDuration) * 100}%` }}
+                  />
+                )}
+                {chapters.map((chapter, i) => (
+                  <div
+                    key={i}
+                    onPointerDown={(e) => {
+                      e.stopPropagation();
+                      requestSeek(chapter.start);
+                    }}
+                    className="absolute -inset-y-1 w-3 -translate-x-1/2 flex justify-center group/chapter"
+                    style={{ left: `${(chapter.start / mediaDuration) * 100}%` }}
+                    title={`${chapter.title ?? `Chapter ${i + 1}`} (${formatDuration(chapter.start)})`}
+                  >
+                    <div className="w-0.5 h-full bg-white/70 group-hover/chapter:bg-white" />
+                  </div>
+                ))}
+                {/* Target time while dragging */}
+                {dragTime !== null && (
+                  <div
+                    className="absolute bottom-full mb-2 -translate-x-1/2 bg-black/80 px-2 py-1 rounded text-xs font-mono pointer-events-none"
+                    style={{ left: `${(dragTime / mediaDuration) * 100}%` }}
+                  >
+                    {formatTimecode(dragTime, video.frameRate ?? DEFAULT_FRAME_RATE)}
+                  </div>
+                )}
+              </div>
+            </div>
+          )}
+          {mediaDuration >= FILM_STRIP_MIN_DURATION && (
+            <FilmStrip
+              videoId={video.id}
+              duration={mediaDuration}
+              spriteUrl={video.hasSprite ? `/api/videos/${video.id}/sprite?v=${video.spriteVersion ?? 0}` : null}
+              spriteColumns={video.spriteColumns}
+              spriteRows={video.spriteRows}
+              spriteFrames={video.spriteFrames}
+              playhead={dragTime ?? playhead}
+              onSeek={(time) => requestSeek(time)}
+            />
+          )}
 
           {/* No proxy warning */}
-          {!video.hasProxy && (
+          {!video.hasProxy && !isStreamHeld && (
             <div className="absolute top-4 left-4 bg-warning/20 text-warning px-3 py-1.5 rounded-lg text-sm">
               ⚠️ Playing original file - may buffer with large 4K files
             </div>
@@ -92,7 +640,7 @@ export default function VideoModal({
         </div>
 
         {/* Video info panel */}
-        <div className="p-6">
+        <div className={`p-6 ${frameStyle ? 'shrink-0 max-h-[45%] overflow-auto' : ''}`}>
           <div className="flex items-start justify-between mb-4">
             <div>
               <h2 className="text-xl font-semibold mb-1">{video.fileName}</h2>
@@ -100,39 +648,212 @@ export default function VideoModal({
                 <span>{formatDuration(video.duration)}</span>
                 <span>•</span>
                 <span>{formatFileSize(video.fileSize)}</span>
+                {bitrate !== null && (
+                  <>
+                    <span>•</span>
+                    <span title={video.bitRate ? 'Container bit rate' : 'Estimated from file size and duration'}>
+                      {formatBitrate(bitrate)} ({formatMbPerMinute(bitrate)})
+                    </span>
+                  </>
+                )}
                 <span>•</span>
                 <span>{video.width}×{video.height}</span>
+                {(video.videoStreamCount ?? 0) > 1 && (
+                  <>
+                    <span>•</span>
+                    <span>🎞 {video.videoStreamCount} video streams</span>
+                  </>
+                )}
+                {(video.audioTrackCount ?? 0) > 1 && (
+                  <>
+                    <span>•</span>
+                    <span>🔊 {video.audioTrackCount} audio tracks</span>
+                  </>
+                )}
+                {video.loudnessLufs !== null && (
+                  <>
+                    <span>•</span>
+                    <span title="Integrated loudness">🔊 {formatLoudness(video.loudnessLufs)}</span>
+                  </>
+                )}
                 <span>•</span>
                 <span>{new Date(video.createdAt).toLocaleDateString()}</span>
               </div>
+              {/* Where it was shot, from the GPS tag phones and drones write */}
+              {video.latitude !== null && video.longitude !== null && (
+                <div className="flex items-center gap-3 mt-1 text-sm text-muted">
+                  <span title={video.location ?? undefined}>📍 {formatCoordinates(video.latitude, video.longitude)}</span>
+                  <button onClick={handleCopyCoordinates} className="text-xs hover:text-foreground hover:underline">
+                    {coordinatesCopied ? 'Copied' : 'Copy coordinates'}
+                  </button>
+                  <a
+                    href={getMapsUrl(video.latitude, video.longitude)}
+                    target="_blank"
+                    rel="noopener noreferrer"
+                    className="text-xs hover:text-foreground hover:underline"
+                  >
+                    Open in Maps
+                  </a>
+                </div>
+              )}
             </div>
 
-            {/* Favorite button */}
-            <button
-              onClick={handleFavoriteClick}
-              className={`
-                flex items-center gap-2 px-4 py-2 rounded-lg transition-colors
-                ${video.selection?.isFavorite
-                  ? 'bg-yellow-500/20 text-yellow-500'
-                  : 'bg-card-border hover:bg-muted/20 text-muted hover:text-foreground'
-                }
-              `}
-            >
-              <svg
-                className="w-5 h-5"
-                fill={video.selection?.isFavorite ? 'currentColor' : 'none'}
-                stroke="currentColor"
-                viewBox="0 0 24 24"
+            <div className="flex items-center gap-2">
+              {/* Audio track selector (only for multi-track files) */}
+              {(video.audioTrackCount ?? 0) > 1 && (
+                <select
+                  value={audioTrack}
+                  onChange={(e) => setAudioTrack(Number(e.target.value))}
+                  className="px-3 py-2 rounded-lg bg-card border border-card-border text-sm text-foreground"
+                  title="Audio track"
+                >
+                  {Array.from({ length: video.audioTrackCount ?? 0 }, (_, i) => (
+                    <option key={i} value={i}>
+                      Audio track {i + 1}
+                    </option>
+                  ))}
+                </select>
+              )}
+
+              {/* Subtitle selector (files found next to the video) */}
+              {video.subtitleFiles.length > 0 && (
+                <select
+                  value={subtitleIndex}
+                  onChange={(e) => setSubtitleIndex(Number(e.target.value))}
+                  className="px-3 py-2 rounded-lg bg-card border border-card-border text-sm text-foreground"
+                  title="Subtitles"
+                >
+                  <option value={-1}>Subtitles off</option>
+                  {video.subtitleFiles.map((fileName, i) => (
+                    <option key={fileName} value={i}>
+                      {getSubtitleLabel(fileName, video.fileName)}
+                    </option>
+                  ))}
+                </select>
+              )}
+
+              {/* Chapter navigation (only for files with chapter markers) */}
+              {chapters.length > 0 && (
+                <div className="flex items-center rounded-lg bg-card-border text-muted">
+                  <button
+                    onClick={() => seekToChapter(-1)}
+                    className="px-3 py-2 hover:text-foreground"
+                    title="Previous chapter ([)"
+                  >
+                    ⏮
+                  </button>
+                  <span className="text-xs tabular-nums">{chapters.length} chapters</span>
+                  <button
+                    onClick={() => seekToChapter(1)}
+                    className="px-3 py-2 hover:text-foreground"
+                    title="Next chapter (])"
+                  >
+                    ⏭
+                  </button>
+                </div>
+              )}
+
+              {/* A–B markers and clip export */}
+              <div className="flex items-center rounded-lg bg-card-border text-muted text-sm">
+                <button
+                  onClick={() => setMarker('a')}
+                  className={`px-3 py-2 hover:text-foreground ${markA !== null ? 'text-warning' : ''}`}
+                  title={markA !== null ? `A: ${formatTimecode(markA, video.frameRate ?? DEFAULT_FRAME_RATE)} (A to move)` : 'Set in point at the current position (A)'}
+                >
+                  A
+                </button>
+                <button
+                  onClick={() => setMarker('b')}
+                  className={`px-3 py-2 hover:text-foreground ${markB !== null ? 'text-warning' : ''}`}
+                  title={markB !== null ? `B: ${formatTimecode(markB, video.frameRate ?? DEFAULT_FRAME_RATE)} (B to move)` : 'Set out point at the current position (B)'}
+                >
+                  B
+                </button>
+                {markA !== null && markB !== null && (
+                  <>
+                    <button
+                      onClick={() => setShowClipExport(true)}
+                      className="px-3 py-2 hover:text-foreground"
+                      title="Export the A–B range as a new file"
+                    >
+                      Export clip…
+                    </button>
+                    <button
+                      onClick={() => {
+                        setMarkA(null);
+                        setMarkB(null);
+                      }}
+                      className="px-2 py-2 hover:text-foreground"
+                      title="Clear markers"
+                    >
+                      ✕
+                    </button>
+                  </>
+                )}
+              </div>
+
+              {/* Copy current position */}
+              <button
+                onClick={handleCopyTimecode}
+                className={`
+                  flex items-center gap-2 px-4 py-2 rounded-lg transition-colors
+                  ${timecodeCopied
+                    ? 'bg-success/20 text-success'
+                    : 'bg-card-border hover:bg-muted/20 text-muted hover:text-foreground'
+                  }
+                `}
+                title="Copy current position as timecode"
               >
-                <path
-                  strokeLinecap="round"
-                  strokeLinejoin="round"
-                  strokeWidth={2}
-                  d="M11.049 2.927c.3-.921 1.603-.921 1.902 0l1.519 4.674a1 1 0 00.95.69h4.915c.969 0 1.371 1.24.588 1.81l-3.976 2.888a1 1 0 00-.363 1.118l1.518 4.674c.3.922-.755 1.688-1.538 1.118l-3.976-2.888a1 1 0 00-1.176 0l-3.976 2.888c-.783.57-1.838-.197-1.538-1.118l1.518-4.674a1 1 0 00-.363-1.118l-3.976-2.888c-.784-.57-.38-1.81.588-1.81h4.914a1 1 0 00.951-.69l1.519-4.674z"
-                />
-              </svg>
-              <span>{video.selection?.isFavorite ? 'Favorited' : 'Add to Favorites'}</span>
-            </button>
+                <svg className="w-5 h-5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                  <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M12 8v4l3 3m6-3a9 9 0 11-18 0 9 9 0 0118 0z" />
+                </svg>
+                <span>{timecodeCopied ? 'Copied' : 'Copy Timecode'}</span>
+              </button>
+
+              {/* Favorite button */}
+              <button
+                onClick={handleFavoriteClick}
+                className={`
+                  flex items-center gap-2 px-4 py-2 rounded-lg transition-colors
+                  ${video.selection?.isFavorite
+                    ? 'bg-yellow-500/20 text-yellow-500'
+                    : 'bg-card-border hover:bg-muted/20 text-muted hover:text-foreground'
+                  }
+                `}
+              >
+                <svg
+                  className="w-5 h-5"
+                  fill={video.selection?.isFavorite ? 'currentColor' : 'none'}
+                  stroke="currentColor"
+                  viewBox="0 0 24 24"
+                >
+                  <path
+                    strokeLinecap="round"
+                    strokeLinejoin="round"
+                    strokeWidth={2}
+                    d="M11.049 2.927c.3-.921 1.603-.921 1.902 0l1.519 4.674a1 1 0 00.95.69h4.915c.969 0 1.371 1.24.588 1.81l-3.976 2.888a1 1 0 00-.363 1.118l1.518 4.674c.3.922-.755 1.688-1.538 1.118l-3.976-2.888a1 1 0 00-1.176 0l-3.976 2.888c-.783.57-1.838-.197-1.538-1.118l1.518-4.674a1 1 0 00-.363-1.118l-3.976-2.888c-.784-.57-.38-1.81.588-1.81h4.914a1 1 0 00.951-.69l1.519-4.674z"
+                  />
+                </svg>
+                <span>{video.selection?.isFavorite ? 'Favorited' : 'Add to Favorites'}</span>
+              </button>
+
+              {/* Watched button */}
+              <button
+                onClick={() => onToggleWatched(video.id, !video.selection?.watchedAt)}
+                className={`
+                  flex items-center gap-2 px-4 py-2 rounded-lg transition-colors
+                  ${video.selection?.watchedAt
+                    ? 'bg-success/20 text-success'
+                    : 'bg-card-border hover:bg-muted/20 text-muted hover:text-foreground'
+                  }
+                `}
+                title={video.selection?.watchedAt
+                  ? `Watched ${new Date(video.selection.watchedAt).toLocaleString()}`
+                  : undefined}
+              >
+                <span>{video.selection?.watchedAt ? '✓ Watched' : '✓ Mark Watched'}</span>
+              </button>
+            </div>
           </div>
 
           {/* File path */}
@@ -143,6 +864,107 @@ export default function VideoModal({
             </p>
           </div>
 
+          {/* Sidecar metadata (.xmp/.json next to the clip) */}
+          {(video.sidecarTitle || video.sidecarDescription || video.sidecarKeywords) && (
+            <div className="mb-4">
+              <label className="text-xs text-muted uppercase tracking-wider">Sidecar</label>
+              {video.sidecarTitle && (
+                <p className="text-sm font-medium mt-1">{video.sidecarTitle}</p>
+              )}
+              {video.sidecarDescription && (
+                <p className="text-sm text-muted mt-1 whitespace-pre-wrap">{video.sidecarDescription}</p>
+              )}
+              {video.sidecarKeywords && (
+                <div className="flex flex-wrap gap-1.5 mt-2">
+                  {video.sidecarKeywords.split(',').map((keyword) => keyword.trim()).map((keyword) => (
+                    <span key={keyword} className="text-xs bg-card-border text-muted px-2 py-0.5 rounded">
+                      {keyword}
+                    </span>
+                  ))}
+                </div>
+              )}
+            </div>
+          )}
+
+          {/* Poster frame */}
+          <div className="mb-4 flex items-center justify-between">
+            <label className="text-xs text-muted uppercase tracking-wider">Thumbnail</label>
+            <div className="flex items-center gap-3">
+              {video.posterTime !== null && (
+                <button
+                  onClick={() => savePosterTime(null)}
+                  disabled={isSavingPoster}
+                  className="text-xs text-muted hover:text-foreground disabled:opacity-50"
+                >
+                  Reset to automatic
+                </button>
+              )}
+              <button
+                onClick={() => savePosterTime(videoRef.current?.currentTime ?? 0)}
+                disabled={isSavingPoster}
+                className="text-xs text-accent hover:text-accent-hover disabled:opacity-50"
+              >
+                {isSavingPoster ? 'Saving...' : 'Set current frame as thumbnail'}
+              </button>
+              <button
+                onClick={handleSaveFrame}
+                className="text-xs text-muted hover:text-foreground"
+                title="Download the current frame as PNG"
+              >
+                Save frame
+              </button>
+              <button
+                onClick={() => setFfmpegCommandStart(videoRef.current?.currentTime ?? 0)}
+                className="text-xs text-muted hover:text-foreground"
+                title="Copy a ready-to-run ffmpeg command for this clip"
+              >
+                ffmpeg command…
+              </button>
+            </div>
+          </div>
+
+          {/* Silent stretches (audio dropouts) */}
+          <div className="mb-4">
+            <div className="flex items-center justify-between">
+              <label className="text-xs text-muted uppercase tracking-wider">Audio dropouts</label>
+              {!silence && (
+                <button
+                  onClick={handleCheckSilence}
+                  disabled={isCheckingSilence}
+                  className="text-xs text-accent hover:text-accent-hover disabled:opacity-50"
+                >
+                  {isCheckingSilence ? 'Checking...' : 'Check for silence'}
+                </button>
+              )}
+            </div>
+            {silence && !silence.hasAudio && (
+              <p className="text-sm text-muted mt-1">No audio track</p>
+            )}
+            {silence?.hasAudio && silence.ranges.length === 0 && (
+              <p className="text-sm text-muted mt-1">No silent stretches</p>
+            )}
+            {silence?.hasAudio && silence.ranges.length > 0 && (
+              <ul className="mt-1 max-h-32 overflow-auto text-sm">
+                {silence.ranges.map((range) => {
+                  const length = range.end - range.start;
+                  return (
+                    <li key={range.start}>
+                      <button
+                        onClick={() => requestSeek(range.start)}
+                        className={`w-full flex justify-between px-2 py-0.5 rounded hover:bg-card-border ${
+                          length >= silenceThreshold ? 'text-warning' : 'text-muted'
+                        }`}
+                      >
+                        <span className="font-mono">{formatDuration(range.start)}</span>
+                        <span className="tabular-nums">{length.toFixed(1)}s silent</span>
+                      </button>
+                    </li>
+                  );
+                })}
+              </ul>
+            )}
+          </div>
+
           {/* Notes section */}
           <div>
             <div className="flex items-center justify-between mb-2">
@@ -195,6 +1017,37 @@ export default function VideoModal({
             )}
           </div>
         </div>
+
+        {ffmpegCommandStart !== null && (
+          <FfmpegCommandModal
+            video={video}
+            initialStart={markA ?? ffmpegCommandStart}
+            initialEnd={markB ?? video.duration}
+            getCurrentTime={() => videoRef.current?.currentTime ?? 0}
+            onClose={() => setFfmpegCommandStart(null)}
+          />
+        )}
+
+        {showClipExport && markA !== null && markB !== null && (
+          <ClipExportModal
+            video={video}
+            start={markA}
+            end={markB}
+            onClose={() => setShowClipExport(false)}
+          />
+        )}
+
+        {/* Resize handle */}
+        {!geometry?.maximized && (
+          <div
+            onPointerDown={(e) => handleFramePointerDown(e, 'resize')}
+            onPointerMove={handleFramePointerMove}
+            onPointerUp={handleFramePointerUp}
+            className="absolute bottom-0 right-0 w-4 h-4 z-[5] cursor-se-resize"
+            style={{ background: 'linear-gradient(135deg, transparent 50%, rgba(255,255,255,0.25) 50%)' }}
+            title="Drag to resize"
+          />
+        )}
       </div>
     </div>
   );
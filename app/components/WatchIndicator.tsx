@@ -0,0 +1,99 @@
+'use client';
+
+import { useState, useEffect, useRef, useCallback } from 'react';
+
+interface WatchIndicatorProps {
+  onCatalogChanged: () => void; // Called when the watcher added, updated or removed videos
+}
+
+interface WatchStatus {
+  enabled: boolean;
+  active: boolean;
+  pending: number;
+  changeCount: number;
+  error: string | null;
+}
+
+// How often to check the watcher for catalog changes
+const POLL_INTERVAL_MS = 3000;
+
+export default function WatchIndicator({ onCatalogChanged }: WatchIndicatorProps) {
+  const [status, setStatus] = useState<WatchStatus | null>(null);
+  const lastChangeCount = useRef<number | null>(null);
+
+  const applyStatus = useCallback((data: WatchStatus) => {
+    setStatus(data);
+    if (lastChangeCount.current !== null && data.changeCount !== lastChangeCount.current) {
+      onCatalogChanged();
+    }
+    lastChangeCount.current = data.changeCount;
+  }, [onCatalogChanged]);
+
+  // Poll watcher status while browsing
+  useEffect(() => {
+    const poll = async () => {
+      try {
+        const res = await fetch('/api/watch');
+        const data = await res.json();
+        if (data.success) {
+          applyStatus(data);
+        }
+      } catch (error) {
+        console.error('Error fetching watch status:', error);
+      }
+    };
+
+    poll();
+    const interval = setInterval(poll, POLL_INTERVAL_MS);
+    return () => clearInterval(interval);
+  }, [applyStatus]);
+
+  const handleToggle = async () => {
+    try {
+      const res = await fetch('/api/watch', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ enabled: !status?.enabled }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        applyStatus(data);
+      }
+    } catch (error) {
+      console.error('Error updating watch setting:', error);
+    }
+  };
+
+  if (!status) return null;
+
+  const label = status.error
+    ? 'Watch failed'
+    : !status.enabled
+      ? 'Not watching'
+      : status.pending > 0
+        ? `Updating ${status.pending}...`
+        : 'Watching';
+
+  const dotClass = status.error
+    ? 'bg-error'
+    : !status.active
+      ? 'bg-muted'
+      : status.pending > 0
+        ? 'bg-warning animate-pulse'
+        : 'bg-success';
+
+  return (
+    <button
+      onClick={handleToggle}
+      className="px-3 py-2 text-sm rounded-lg transition-colors bg-card-border text-muted hover:text-foreground flex items-center gap-2"
+      title={status.error
+        ? `Folder watcher stopped: ${status.error}`
+        : status.enabled
+          ? 'New and changed videos are added automatically. Click to stop watching.'
+          : 'Click to watch this folder for new and changed videos'}
+    >
+      <span className={`w-2 h-2 rounded-full ${dotClass}`} />
+      {label}
+    </button>
+  );
+}
@@ -0,0 +1,99 @@
+'use client';
+
+import { useState, useEffect, useRef } from 'react';
+
+interface WaveformStripProps {
+  videoId: string;
+  progress: number; // Played fraction (0-1), tinted in the accent color
+}
+
+const STRIP_HEIGHT = 40;
+
+// Audio peaks drawn above the player's seek bar, filled in as the server decodes them.
+// Silent clips come back as all zeros and show as a flat line.
+export default function WaveformStrip({ videoId, progress }: WaveformStripProps) {
+  const canvasRef = useRef<HTMLCanvasElement>(null);
+  const peaksRef = useRef<Uint8Array | null>(null);
+  const [filled, setFilled] = useState(0); // Buckets received so far
+  const [width, setWidth] = useState(0);
+
+  // Read the stream as it arrives; the request doesn't hold up the video
+  useEffect(() => {
+    const controller = new AbortController();
+    peaksRef.current = null;
+    setFilled(0);
+
+    const load = async () => {
+      try {
+        const res = await fetch(`/api/videos/${videoId}/waveform`, { signal: controller.signal });
+        const bucketCount = Number(res.headers.get('X-Waveform-Buckets'));
+        if (!res.ok || !res.body || !bucketCount) return;
+
+        const peaks = new Uint8Array(bucketCount);
+        peaksRef.current = peaks;
+        const reader = res.body.getReader();
+        let offset = 0;
+        for (;;) {
+          const { done, value } = await reader.read();
+          if (done) break;
+          peaks.set(value.subarray(0, bucketCount - offset), offset);
+          offset = Math.min(bucketCount, offset + value.length);
+          setFilled(offset);
+        }
+      } catch (err) {
+        if (!controller.signal.aborted) console.error('Error loading waveform:', err);
+      }
+    };
+    load();
+
+    return () => controller.abort();
+  }, [videoId]);
+
+  useEffect(() => {
+    const canvas = canvasRef.current;
+    if (!canvas) return;
+    const observer = new ResizeObserver(([entry]) => setWidth(entry.contentRect.width));
+    observer.observe(canvas);
+    return () => observer.disconnect();
+  }, []);
+
+  // One column per pixel, taking the loudest bucket that falls in it
+  useEffect(() => {
+    const canvas = canvasRef.current;
+    const peaks = peaksRef.current;
+    if (!canvas || width === 0) return;
+
+    const scale = window.devicePixelRatio || 1;
+    canvas.width = Math.round(width * scale);
+    canvas.height = Math.round(STRIP_HEIGHT * scale);
+    const context = canvas.getContext('2d');
+    if (!context || !peaks) return;
+
+    const styles = getComputedStyle(document.documentElement);
+    const playedColor = styles.getPropertyValue('--accent').trim() || '#3b82f6';
+    const unplayedColor = 'rgba(255, 255, 255, 0.35)';
+    const columns = canvas.width;
+    const middle = canvas.height / 2;
+    const playedColumns = Math.round(progress * columns);
+
+    for (let x = 0; x < columns; x++) {
+      const from = Math.floor((x / columns) * peaks.length);
+      const to = Math.max(from + 1, Math.floor(((x + 1) / columns) * peaks.length));
+      if (from >= filled) break;
+
+      let peak = 0;
+      for (let i = from; i < Math.min(to, filled); i++) peak = Math.max(peak, peaks[i]);
+      const half = Math.max(0.5 * scale, (peak / 255) * middle);
+      context.fillStyle = x < playedColumns ? playedColor : unplayedColor;
+      context.fillRect(x, middle - half, 1, half * 2);
+    }
+  }, [filled, width, progress]);
+
+  return (
+    <canvas
+      ref={canvasRef}
+      className="block w-full bg-black/40"
+      style={{ height: STRIP_HEIGHT }}
+    />
+  );
+}
@@ -0,0 +1,92 @@
+'use client';
+
+import { useEffect, useRef, useState } from 'react';
+import { createPortal } from 'react-dom';
+import { formatDuration } from '@/app/lib/utils';
+
+interface ZoomPreviewProps {
+  videoUrl: string;
+  anchorRect: DOMRect; // The card being scrubbed; the preview is placed beside it, never over it
+  time: number;
+  fileName: string;
+  duration: number;
+  width: number;
+  height: number;
+}
+
+// Preview width when there is room for it
+const ZOOM_PREVIEW_WIDTH = 640;
+// Gap between the card and the preview, and the minimum distance from the viewport edge
+const ZOOM_PREVIEW_MARGIN = 12;
+// Name and timing line under the frame
+const INFO_HEIGHT = 56;
+
+// Beside the card (right, then left), else above or below it, clamped to the viewport
+function placePreview(anchor: DOMRect, previewWidth: number, previewHeight: number): { left: number; top: number } {
+  const viewportWidth = window.innerWidth;
+  const viewportHeight = window.innerHeight;
+  const clampTop = (top: number) => Math.max(ZOOM_PREVIEW_MARGIN, Math.min(top, viewportHeight - previewHeight - ZOOM_PREVIEW_MARGIN));
+  const clampLeft = (left: number) => Math.max(ZOOM_PREVIEW_MARGIN, Math.min(left, viewportWidth - previewWidth - ZOOM_PREVIEW_MARGIN));
+  const centeredTop = anchor.top + anchor.height / 2 - previewHeight / 2;
+  const centeredLeft = anchor.left + anchor.width / 2 - previewWidth / 2;
+
+  if (anchor.right + ZOOM_PREVIEW_MARGIN + previewWidth <= viewportWidth - ZOOM_PREVIEW_MARGIN) {
+    return { left: anchor.right + ZOOM_PREVIEW_MARGIN, top: clampTop(centeredTop) };
+  }
+  if (anchor.left - ZOOM_PREVIEW_MARGIN - previewWidth >= ZOOM_PREVIEW_MARGIN) {
+    return { left: anchor.left - ZOOM_PREVIEW_MARGIN - previewWidth, top: clampTop(centeredTop) };
+  }
+  const spaceAbove = anchor.top - ZOOM_PREVIEW_MARGIN;
+  const spaceBelow = viewportHeight - anchor.bottom - ZOOM_PREVIEW_MARGIN;
+  return spaceAbove >= spaceBelow
+    ? { left: clampLeft(centeredLeft), top: Math.max(ZOOM_PREVIEW_MARGIN, anchor.top - ZOOM_PREVIEW_MARGIN - previewHeight) }
+    : { left: clampLeft(centeredLeft), top: anchor.bottom + ZOOM_PREVIEW_MARGIN };
+}
+
+// Large floating scrub preview shown while Alt is held over a card
+export default function ZoomPreview({ videoUrl, anchorRect, time, fileName, duration, width, height }: ZoomPreviewProps) {
+  const videoRef = useRef<HTMLVideoElement>(null);
+  const [videoReady, setVideoReady] = useState(false);
+
+  useEffect(() => {
+    if (videoRef.current && videoReady) {
+      videoRef.current.currentTime = time;
+    }
+  }, [time, videoReady]);
+
+  // Shrink on small windows rather than cover the card
+  const previewWidth = Math.min(ZOOM_PREVIEW_WIDTH, window.innerWidth - ZOOM_PREVIEW_MARGIN * 2);
+  const frameHeight = width > 0 && height > 0 ? previewWidth * (height / width) : previewWidth * (9 / 16);
+  const { left, top } = placePreview(anchorRect, previewWidth, frameHeight + INFO_HEIGHT);
+
+  return createPortal(
+    <div
+      className="fixed z-50 bg-card border border-card-border rounded-lg shadow-2xl overflow-hidden pointer-events-none"
+      style={{ left, top, width: previewWidth }}
+    >
+      <div className="relative bg-black" style={{ height: frameHeight }}>
+        <video
+          ref={videoRef}
+          src={videoUrl}
+          className="w-full h-full object-contain"
+          muted
+          playsInline
+          preload="auto"
+          onLoadedData={() => setVideoReady(true)}
+        />
+        {!videoReady && (
+          <div className="absolute inset-0 flex items-center justify-center">
+            <div className="w-6 h-6 border-2 border-white/30 border-t-white rounded-full animate-spin" />
+          </div>
+        )}
+      </div>
+      <div className="px-3 py-2" style={{ height: INFO_HEIGHT }}>
+        <p className="text-sm truncate" title={fileName}>{fileName}</p>
+        <p className="text-xs text-muted font-mono">
+          {formatDuration(time)} / {formatDuration(duration)} · {width}×{height}
+        </p>
+      </div>
+    </div>,
+    document.body
+  );
+}
@@ -0,0 +1,106 @@
+import { getLibraryHistory, LibraryHistoryEntry, mergeLibraryHistory } from './libraryHistory';
+import { getCommandTemplates, setCommandTemplates, validateCommandTemplates } from './commandTemplates';
+import { CommandTemplate } from './ffmpegCommand';
+import { getTranscodePresets, setTranscodePresets, validateTranscodePresets } from './transcodePresets';
+import { TranscodePreset } from './types';
+
+// Export and import of app-wide data for moving to another machine (server-side only).
+// Favorites, notes, smart views and per-library settings live in each library's
+// catalog.db on its drive, so they travel with the drive and aren't part of this file.
+
+export const APP_SETTINGS_FORMAT = 'video-catalog-browser/settings';
+export const APP_SETTINGS_VERSION = 1;
+
+export interface AppSettingsExport {
+  format: typeof APP_SETTINGS_FORMAT;
+  version: number;
+  exportedAt: string;
+  libraries: LibraryHistoryEntry[];
+  commandTemplates?: CommandTemplate[]; // Missing from exports made before templates existed
+  transcodePresets?: TranscodePreset[]; // Likewise for batch transcode presets
+}
+
+export function exportAppSettings(): AppSettingsExport {
+  return {
+    format: APP_SETTINGS_FORMAT,
+    version: APP_SETTINGS_VERSION,
+    exportedAt: new Date().toISOString(),
+    libraries: getLibraryHistory(),
+    commandTemplates: getCommandTemplates(),
+    transcodePresets: getTranscodePresets(),
+  };
+}
+
+// Check an uploaded export; the error names the first key with a wrong type
+export function validateAppSettings(data: unknown): { settings: AppSettingsExport } | { error: string } {
+  if (typeof data !== 'object' || data === null || Array.isArray(data)) {
+    return { error: 'Settings file must contain a JSON object' };
+  }
+
+  const record = data as Record<string, unknown>;
+  if (record.format !== APP_SETTINGS_FORMAT) {
+    return { error: `"format" must be "${APP_SETTINGS_FORMAT}". Is this a settings export?` };
+  }
+  if (typeof record.version !== 'number' || record.version > APP_SETTINGS_VERSION) {
+    return { error: `"version" must be a number no higher than ${APP_SETTINGS_VERSION}` };
+  }
+  if (!Array.isArray(record.libraries)) {
+    return { error: '"libraries" must be an array' };
+  }
+
+  for (const [i, entry] of record.libraries.entries()) {
+    if (typeof entry?.path !== 'string' || !entry.path) {
+      return { error: `"libraries[${i}].path" must be a non-empty string` };
+    }
+    if (typeof entry.lastOpenedAt !== 'string' || Number.isNaN(Date.parse(entry.lastOpenedAt))) {
+      return { error: `"libraries[${i}].lastOpenedAt" must be an ISO date string` };
+    }
+  }
+
+  if (record.commandTemplates !== undefined) {
+    const templates = validateCommandTemplates(record.commandTemplates);
+    if ('error' in templates) {
+      return { error: templates.error.replace('"templates', '"commandTemplates') };
+    }
+  }
+
+  if (record.transcodePresets !== undefined) {
+    const presets = validateTranscodePresets(record.transcodePresets);
+    if ('error' in presets) {
+      return { error: presets.error.replace('"presets', '"transcodePresets') };
+    }
+  }
+
+  return { settings: data as AppSettingsExport };
+}
+
+// Merge an export into this machine's data; existing library entries win, while
+// ffmpeg templates and transcode presets (single lists, not entries to merge) are replaced
+export function importAppSettings(settings: AppSettingsExport): {
+  added: number;
+  skipped: number;
+  templatesImported: boolean;
+  presetsImported: boolean;
+} {
+  const added = mergeLibraryHistory(settings.libraries.map(({ path, lastOpenedAt }) => ({
+    path,
+    lastOpenedAt: new Date(lastOpenedAt).toISOString(), // Sorted as strings, so normalize to UTC
+  })));
+
+  const templates = settings.commandTemplates && validateCommandTemplates(settings.commandTemplates);
+  if (templates && 'templates' in templates) {
+    setCommandTemplates(templates.templates);
+  }
+
+  const presets = settings.transcodePresets && validateTranscodePresets(settings.transcodePresets);
+  if (presets && 'presets' in presets) {
+    setTranscodePresets(presets.presets);
+  }
+
+  return {
+    added,
+    skipped: settings.libraries.length - added,
+    templatesImported: !!templates,
+    presetsImported: !!presets,
+  };
+}
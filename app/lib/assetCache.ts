@@ -0,0 +1,112 @@
+import fs from 'fs';
+import fsPromises from 'fs/promises';
+import os from 'os';
+import pLimit from 'p-limit';
+
+// Server-side cache of generated asset versions (thumbnail/sprite file mtimes).
+// Asset URLs carry the version so browsers drop stale images after a rewrite.
+// Also holds thumbnails read ahead of the grid asking for them.
+
+// How long a stat result is trusted before re-checking the file on disk
+const VERSION_TTL_MS = 5000;
+
+const versions = new Map<string, { version: number | null; checkedAt: number }>();
+
+// Get the current version of an asset file (null if missing)
+export function getAssetVersion(filePath: string | null): number | null {
+  if (!filePath) return null;
+
+  const now = Date.now();
+  const cached = versions.get(filePath);
+  if (cached && now - cached.checkedAt < VERSION_TTL_MS) {
+    return cached.version;
+  }
+
+  let version: number | null = null;
+  try {
+    version = Math.floor(fs.statSync(filePath).mtimeMs);
+  } catch {
+    version = null;
+  }
+
+  versions.set(filePath, { version, checkedAt: now });
+  return version;
+}
+
+// Thumbnails warmed per video list (the top of the grid in its current sort order)
+export const WARM_THUMBNAIL_COUNT = 50;
+// Cap on warmed thumbnails held in memory that haven't been served yet
+const WARM_THUMBNAIL_LIMIT = 200;
+
+// Read-ahead thumbnails, each served once; the browser caches it from then on
+const warmThumbnails = new Map<string, Buffer>();
+// Paths already warmed (or being warmed) since the library was opened or the file rewritten
+const warmedPaths = new Set<string>();
+// Bumped on clear so an in-flight warm-up stops adding stale files
+let warmGeneration = 0;
+
+// Read thumbnails into memory in the background, one at a time so serving and
+// scanning aren't starved. Paths already warmed are skipped, so this is cheap to
+// call on every list fetch; after a scan the new thumbnails get warmed.
+export function warmThumbnailCache(thumbnailPaths: (string | null)[]): void {
+  const pending = thumbnailPaths
+    .slice(0, WARM_THUMBNAIL_COUNT)
+    .filter((filePath): filePath is string => filePath !== null && !warmedPaths.has(filePath));
+  if (pending.length === 0) return;
+  pending.forEach((filePath) => warmedPaths.add(filePath));
+
+  const generation = warmGeneration;
+  (async () => {
+    for (const filePath of pending) {
+      if (!(await readWarmThumbnail(filePath, generation))) return;
+      await new Promise((resolve) => setImmediate(resolve));
+    }
+  })();
+}
+
+// Batch read-ahead for when nothing else is competing for the disk (a scan just
+// finished): up to the memory cap, one read per CPU at a time. Resolves when done.
+export async function prefetchThumbnails(thumbnailPaths: (string | null)[]): Promise<void> {
+  const pending = thumbnailPaths
+    .filter((filePath): filePath is string => filePath !== null && !warmedPaths.has(filePath))
+    .slice(0, WARM_THUMBNAIL_LIMIT);
+  pending.forEach((filePath) => warmedPaths.add(filePath));
+
+  const generation = warmGeneration;
+  const limit = pLimit(os.cpus().length);
+  await Promise.all(pending.map((filePath) => limit(() => readWarmThumbnail(filePath, generation))));
+}
+
+// Read one thumbnail into the warm cache; false once the cache is full or was cleared
+async function readWarmThumbnail(filePath: string, generation: number): Promise<boolean> {
+  if (generation !== warmGeneration || warmThumbnails.size >= WARM_THUMBNAIL_LIMIT) return false;
+  try {
+    const data = await fsPromises.readFile(filePath);
+    if (generation === warmGeneration) warmThumbnails.set(filePath, data);
+  } catch {
+    // Missing thumbnails are served as a placeholder by the route
+  }
+  return true;
+}
+
+// A warmed thumbnail, removed from memory as it's handed out; null if not warmed
+export function takeWarmThumbnail(filePath: string): Buffer | null {
+  const data = warmThumbnails.get(filePath) ?? null;
+  warmThumbnails.delete(filePath);
+  return data;
+}
+
+// Drop the cached version (and any warmed copy) for a file that was just rewritten
+export function invalidateAssetVersion(filePath: string): void {
+  versions.delete(filePath);
+  warmThumbnails.delete(filePath);
+  warmedPaths.delete(filePath);
+}
+
+// Drop all cached versions and warmed thumbnails (library switch or cache clear)
+export function clearAssetVersions(): void {
+  versions.clear();
+  warmThumbnails.clear();
+  warmedPaths.clear();
+  warmGeneration++;
+}
@@ -0,0 +1,36 @@
+import { BitrateClass, BitrateThresholds } from './types';
+import { getSetting, setSetting } from './db';
+
+// Per-library bit rate thresholds for the "Inefficient clips" view (server-side only).
+// Above these, a clip is worth re-encoding: typical camera and phone footage sits
+// well below them, screen recordings and intermediate codecs well above.
+
+const THRESHOLDS_SETTING = 'bitrate_thresholds';
+export const DEFAULT_BITRATE_THRESHOLDS: BitrateThresholds = { sd: 8, '720p': 20, '1080p': 40, '4k': 120 };
+const BITRATE_CLASSES = Object.keys(DEFAULT_BITRATE_THRESHOLDS) as BitrateClass[];
+export const MAX_BITRATE_THRESHOLD = 10_000; // Mbps
+
+export function isValidBitrateThresholds(value: unknown): value is BitrateThresholds {
+  if (!value || typeof value !== 'object') return false;
+  const thresholds = value as Record<string, unknown>;
+  return BITRATE_CLASSES.every((key) => {
+    const n = thresholds[key];
+    return typeof n === 'number' && Number.isFinite(n) && n > 0 && n <= MAX_BITRATE_THRESHOLD;
+  });
+}
+
+export function getBitrateThresholds(): BitrateThresholds {
+  const value = getSetting(THRESHOLDS_SETTING);
+  if (!value) return DEFAULT_BITRATE_THRESHOLDS;
+  try {
+    const thresholds = JSON.parse(value);
+    return isValidBitrateThresholds(thresholds) ? thresholds : DEFAULT_BITRATE_THRESHOLDS;
+  } catch {
+    return DEFAULT_BITRATE_THRESHOLDS;
+  }
+}
+
+export function setBitrateThresholds(thresholds: BitrateThresholds): void {
+  const stored = Object.fromEntries(BITRATE_CLASSES.map((key) => [key, thresholds[key]]));
+  setSetting(THRESHOLDS_SETTING, JSON.stringify(stored));
+}
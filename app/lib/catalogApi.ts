@@ -0,0 +1,57 @@
+import { getSetting, setSetting, isDatabaseInitialized } from './db';
+import { CatalogVideo, VideoWithSelection } from './types';
+
+// Server-side settings and response shape for the read-only local catalog API, which
+// catalogServer.ts serves on 127.0.0.1
+
+const CATALOG_API_SETTING = 'catalog_api_enabled';
+const CATALOG_API_PORT_SETTING = 'catalog_api_port';
+
+export const DEFAULT_CATALOG_API_PORT = 3939;
+
+export function isCatalogApiEnabled(): boolean {
+  return isDatabaseInitialized() && getSetting(CATALOG_API_SETTING) === 'true';
+}
+
+export function setCatalogApiEnabled(enabled: boolean): void {
+  setSetting(CATALOG_API_SETTING, enabled ? 'true' : 'false');
+}
+
+// Unprivileged ports only, so the server never needs elevated rights
+export function isValidCatalogApiPort(port: unknown): port is number {
+  return typeof port === 'number' && Number.isInteger(port) && port >= 1024 && port <= 65535;
+}
+
+export function getCatalogApiPort(): number {
+  const port = parseInt(getSetting(CATALOG_API_PORT_SETTING) || '', 10);
+  return isValidCatalogApiPort(port) ? port : DEFAULT_CATALOG_API_PORT;
+}
+
+export function setCatalogApiPort(port: number): void {
+  setSetting(CATALOG_API_PORT_SETTING, String(port));
+}
+
+// Convert a video to the public catalog API shape
+export function toCatalogVideo(video: VideoWithSelection): CatalogVideo {
+  return {
+    id: video.id,
+    filePath: video.filePath,
+    fileName: video.fileName,
+    directory: video.directory,
+    fileSize: video.fileSize,
+    duration: video.duration,
+    width: video.width,
+    height: video.height,
+    frameRate: video.frameRate,
+    colorSpace: video.colorSpace,
+    hdrFormat: video.hdrFormat,
+    createdAt: video.createdAt,
+    isFavorite: video.selection?.isFavorite ?? false,
+    notes: video.selection?.notes ?? '',
+    watchedAt: video.selection?.watchedAt ?? null,
+    colorLabel: video.selection?.colorLabel ?? null,
+    rating: video.selection?.rating ?? null,
+    hasProxy: video.hasProxy,
+    thumbnailUrl: video.thumbnailPath ? `/videos/${video.id}/thumbnail` : null,
+  };
+}
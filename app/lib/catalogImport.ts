@@ -0,0 +1,99 @@
+import Database from 'better-sqlite3';
+import {
+  getVideoByFileHash,
+  getVideoByPath,
+  getVideoByNameAndSize,
+  getSelectionByVideoId,
+  upsertSelection,
+  setVideosColorLabel,
+  setVideosRating,
+} from './db';
+import { COLOR_LABELS, ColorLabel, MAX_RATING, Video } from './types';
+
+// Selection row joined with the legacy video it belongs to
+interface LegacySelectionRow {
+  file_path: string;
+  file_name: string;
+  file_size: number;
+  file_hash: string | null;
+  is_favorite: number;
+  notes: string | null;
+  color_label: string | null;
+  rating: number | null;
+}
+
+export interface CatalogImportResult {
+  matched: number;
+  unmatched: number;
+  updated: number;
+  unmatchedFiles: string[];
+}
+
+// Match a legacy video to the current library by content first, since ids differ between apps
+function matchVideo(row: LegacySelectionRow): Video | null {
+  return (row.file_hash ? getVideoByFileHash(row.file_hash) : null)
+    ?? getVideoByPath(row.file_path)
+    ?? getVideoByNameAndSize(row.file_name, row.file_size);
+}
+
+// Import favorites, notes, color labels and ratings from another catalog.db into the open
+// library. Idempotent: favorites are OR-ed and existing notes, labels and ratings are
+// never overwritten.
+export function importSelectionsFromCatalog(catalogPath: string): CatalogImportResult {
+  const legacy = new Database(catalogPath, { readonly: true, fileMustExist: true });
+
+  try {
+    const columns = legacy.prepare('PRAGMA table_info(videos)').all() as { name: string }[];
+    const hasFileHash = columns.some((c) => c.name === 'file_hash');
+    const selectionColumns = legacy.prepare('PRAGMA table_info(selections)').all() as { name: string }[];
+    const hasColorLabel = selectionColumns.some((c) => c.name === 'color_label');
+    const hasRating = selectionColumns.some((c) => c.name === 'rating');
+
+    const rows = legacy.prepare(`
+      SELECT v.file_path, v.file_name, v.file_size, ${hasFileHash ? 'v.file_hash' : 'NULL AS file_hash'},
+             s.is_favorite, s.notes, ${hasColorLabel ? 's.color_label' : 'NULL AS color_label'},
+             ${hasRating ? 's.rating' : 'NULL AS rating'}
+      FROM selections s
+      JOIN videos v ON v.id = s.video_id
+    `).all() as LegacySelectionRow[];
+
+    const result: CatalogImportResult = { matched: 0, unmatched: 0, updated: 0, unmatchedFiles: [] };
+
+    for (const row of rows) {
+      const video = matchVideo(row);
+      if (!video) {
+        result.unmatched++;
+        result.unmatchedFiles.push(row.file_path);
+        continue;
+      }
+      result.matched++;
+
+      const existing = getSelectionByVideoId(video.id);
+      const isFavorite = (existing?.isFavorite ?? false) || row.is_favorite === 1;
+      const notes = existing?.notes || row.notes || '';
+
+      const label = COLOR_LABELS.includes(row.color_label as ColorLabel) ? row.color_label as ColorLabel : null;
+      const selectionChanged = existing?.isFavorite !== isFavorite || (existing?.notes ?? '') !== notes;
+      const labelChanged = !existing?.colorLabel && label !== null;
+      const rating = Number.isInteger(row.rating) && row.rating! >= 1 && row.rating! <= MAX_RATING ? row.rating : null;
+      const ratingChanged = !existing?.rating && rating !== null;
+
+      if (selectionChanged) {
+        upsertSelection(video.id, isFavorite, notes);
+      }
+      if (labelChanged) {
+        setVideosColorLabel([video.id], label);
+      }
+      if (ratingChanged) {
+        setVideosRating([video.id], rating);
+      }
+      if (selectionChanged || labelChanged || ratingChanged) {
+        result.updated++;
+      }
+    }
+
+    return result;
+  } finally {
+    legacy.close();
+  }
+}
@@ -0,0 +1,213 @@
+import http from 'http';
+import fs from 'fs';
+import { AddressInfo } from 'net';
+import { getAllVideos, getFavorites, getCurrentRootPath, getVideoById, getSelectionByVideoId, searchVideos } from './db';
+import { getCatalogApiPort, isCatalogApiEnabled, toCatalogVideo } from './catalogApi';
+import { CatalogStats, CatalogVideosResponse, SortOption } from './types';
+
+// Embedded HTTP server for the read-only catalog API (server-side only). It listens on
+// 127.0.0.1 on its own port, so other machines can't reach it however the app itself is
+// served, and it checks the peer address as well rather than trusting the Host header.
+
+const LOOPBACK_ADDRESSES = ['127.0.0.1', '::1', '::ffff:127.0.0.1'];
+
+export interface CatalogServerStatus {
+  running: boolean;
+  port: number | null; // Where it listens (the configured port, unless started on port 0)
+  error: string | null; // Why it isn't running although enabled (e.g. port in use)
+}
+
+// Module-level state (one library is open at a time)
+let server: { handle: http.Server; requestedPort: number; port: number } | null = null;
+let serverError: string | null = null;
+let syncQueue: Promise<void> = Promise.resolve();
+
+function sendJson(res: http.ServerResponse, status: number, body: unknown): void {
+  const text = JSON.stringify(body);
+  res.writeHead(status, {
+    'Content-Type': 'application/json; charset=utf-8',
+    'Content-Length': Buffer.byteLength(text),
+  });
+  res.end(text);
+}
+
+function sendError(res: http.ServerResponse, status: number, error: string): void {
+  sendJson(res, status, { success: false, error });
+}
+
+// GET /videos
+// Params: search, favorites=true, minDuration, maxDuration, minSize, maxSize, directory, sort, limit, offset
+function handleVideos(searchParams: URLSearchParams, res: http.ServerResponse): void {
+  const getNumber = (name: string) => {
+    const value = parseFloat(searchParams.get(name) || '');
+    return Number.isFinite(value) ? value : undefined;
+  };
+  const limit = parseInt(searchParams.get('limit') || '', 10);
+
+  const { videos, total } = searchVideos({
+    search: searchParams.get('search') || undefined,
+    minDuration: getNumber('minDuration'),
+    maxDuration: getNumber('maxDuration'),
+    minSize: getNumber('minSize'),
+    maxSize: getNumber('maxSize'),
+    directory: searchParams.get('directory') || undefined,
+    isFavorite: searchParams.get('favorites') === 'true' ? true : undefined,
+    sort: (searchParams.get('sort') || 'date-desc') as SortOption,
+    limit: Number.isFinite(limit) && limit > 0 ? limit : undefined,
+    offset: parseInt(searchParams.get('offset') || '0', 10) || 0,
+  });
+
+  const response: CatalogVideosResponse = {
+    videos: videos.map((v) => toCatalogVideo({ ...v, selection: getSelectionByVideoId(v.id) || undefined })),
+    total,
+  };
+  sendJson(res, 200, response);
+}
+
+// GET /videos/:id
+function handleVideo(id: string, res: http.ServerResponse): void {
+  const video = getVideoById(id);
+  if (!video) {
+    sendError(res, 404, 'Video not found');
+    return;
+  }
+  sendJson(res, 200, toCatalogVideo({ ...video, selection: getSelectionByVideoId(id) || undefined }));
+}
+
+// GET /videos/:id/thumbnail, streaming the JPEG
+function handleThumbnail(id: string, res: http.ServerResponse): void {
+  const video = getVideoById(id);
+  if (!video || !video.thumbnailPath || !fs.existsSync(video.thumbnailPath)) {
+    sendError(res, 404, 'Thumbnail not found');
+    return;
+  }
+
+  res.writeHead(200, {
+    'Content-Type': 'image/jpeg',
+    'Content-Length': String(fs.statSync(video.thumbnailPath).size),
+  });
+  fs.createReadStream(video.thumbnailPath)
+    .on('error', (error) => {
+      console.error('Catalog API error:', error);
+      res.destroy();
+    })
+    .pipe(res);
+}
+
+// GET /stats
+function handleStats(res: http.ServerResponse): void {
+  const videos = getAllVideos();
+
+  const stats: CatalogStats = {
+    rootPath: getCurrentRootPath() || '',
+    totalVideos: videos.length,
+    totalDuration: videos.reduce((sum, v) => sum + v.duration, 0),
+    totalSize: videos.reduce((sum, v) => sum + v.fileSize, 0),
+    favorites: getFavorites().length,
+    withProxy: videos.filter((v) => v.hasProxy).length,
+  };
+  sendJson(res, 200, stats);
+}
+
+export function handleCatalogRequest(req: http.IncomingMessage, res: http.ServerResponse): void {
+  if (!LOOPBACK_ADDRESSES.includes(req.socket.remoteAddress ?? '')) {
+    sendError(res, 403, 'Catalog API is only available on 127.0.0.1');
+    return;
+  }
+  if (req.method !== 'GET') {
+    sendError(res, 405, 'Catalog API is read-only');
+    return;
+  }
+  // The open library may have changed since the server started
+  if (!isCatalogApiEnabled()) {
+    sendError(res, 403, 'Catalog API is disabled. Enable it in Settings.');
+    return;
+  }
+
+  try {
+    const url = new URL(req.url || '/', 'http://127.0.0.1');
+    const [resource, id, asset, ...rest] = url.pathname.split('/').filter(Boolean).map(decodeURIComponent);
+
+    if (resource === 'videos' && !id) {
+      handleVideos(url.searchParams, res);
+    } else if (resource === 'videos' && !asset) {
+      handleVideo(id, res);
+    } else if (resource === 'videos' && asset === 'thumbnail' && rest.length === 0) {
+      handleThumbnail(id, res);
+    } else if (resource === 'stats' && !id) {
+      handleStats(res);
+    } else {
+      sendError(res, 404, 'Not found');
+    }
+  } catch (error) {
+    console.error('Catalog API error:', error);
+    sendError(res, 500, 'Catalog API request failed');
+  }
+}
+
+// Listen on 127.0.0.1; resolves with the port (useful with port 0, which picks a free one)
+export function startCatalogServer(port: number): Promise<number> {
+  return new Promise((resolve, reject) => {
+    const handle = http.createServer(handleCatalogRequest);
+    handle.once('error', reject);
+    handle.listen(port, '127.0.0.1', () => {
+      handle.off('error', reject);
+      handle.on('error', (error) => console.error('Catalog API server error:', error));
+      // Never what keeps the process from exiting
+      handle.unref();
+      server = { handle, requestedPort: port, port: (handle.address() as AddressInfo).port };
+      resolve(server.port);
+    });
+  });
+}
+
+export function stopCatalogServer(): Promise<void> {
+  if (!server) return Promise.resolve();
+  const { handle } = server;
+  server = null;
+  return new Promise((resolve) => {
+    handle.close(() => resolve());
+    // Keep-alive connections from other tools would otherwise hold the port
+    handle.closeAllConnections();
+  });
+}
+
+async function applyCatalogServerSetting(): Promise<void> {
+  const port = isCatalogApiEnabled() ? getCatalogApiPort() : null;
+
+  if (server && server.requestedPort !== port) {
+    await stopCatalogServer();
+  }
+  if (port === null) {
+    serverError = null;
+    return;
+  }
+  if (server) return;
+
+  try {
+    await startCatalogServer(port);
+    serverError = null;
+  } catch (error) {
+    console.error('Failed to start catalog API server:', error);
+    serverError = (error as NodeJS.ErrnoException).code === 'EADDRINUSE'
+      ? `Port ${port} is already in use`
+      : error instanceof Error ? error.message : 'Failed to start catalog API server';
+  }
+}
+
+// Start, stop, or move the server to match the open library's setting and port. Calls
+// are queued so a library switch and a settings change can't start two servers.
+export function syncCatalogServer(): Promise<void> {
+  syncQueue = syncQueue
+    .then(applyCatalogServerSetting)
+    .catch((error) => console.error('Error syncing catalog API server:', error));
+  return syncQueue;
+}
+
+export function getCatalogServerStatus(): CatalogServerStatus {
+  return {
+    running: server !== null,
+    port: server?.port ?? null,
+    error: serverError,
+  };
+}
@@ -0,0 +1,108 @@
+import fs from 'fs/promises';
+import path from 'path';
+import { ClipExportOptions, Video } from './types';
+import { startTask } from './tasks';
+import { transcodeVideo, TranscodeError } from './ffmpeg';
+import { addOutputToCatalog, pickOutputPath } from './transcode';
+
+// Subclip export from the player's A–B markers (server-side only). The output is written
+// next to the source and added to the catalog. With both codecs on copy the cut is a
+// stream copy, which is fast but starts on the keyframe at or before A.
+
+const CANCEL_POLL_MS = 500;
+
+const VIDEO_CODEC_ARGS: Record<Exclude<ClipExportOptions['videoCodec'], 'copy'>, string[]> = {
+  h264: ['-c:v', 'libx264', '-preset', 'medium', '-pix_fmt', 'yuv420p'],
+  hevc: ['-c:v', 'libx265', '-preset', 'medium', '-tag:v', 'hvc1'],
+};
+
+// WebM only takes VP8/VP9/AV1 and Vorbis/Opus, none of which we encode, so it's copy-only
+export function validateClipOptions(options: ClipExportOptions): string | null {
+  if (!['mp4', 'mov', 'webm'].includes(options.container)) return 'Unknown container';
+  if (!['copy', 'h264', 'hevc'].includes(options.videoCodec)) return 'Unknown video codec';
+  if (!['copy', 'aac'].includes(options.audioCodec)) return 'Unknown audio codec';
+  if (!Number.isInteger(options.crf) || options.crf < 0 || options.crf > 51) return 'CRF must be a whole number from 0 to 51';
+  if (options.container === 'webm' && (options.videoCodec !== 'copy' || options.audioCodec !== 'copy')) {
+    return 'WebM exports can only copy streams (the source must already be VP9/Opus or similar)';
+  }
+  return null;
+}
+
+// Output arguments; subtitles are dropped since most can't be copied between containers
+function buildClipArgs(options: ClipExportOptions): string[] {
+  if (options.videoCodec === 'copy' && options.audioCodec === 'copy') {
+    return ['-c', 'copy', '-sn', '-avoid_negative_ts', 'make_zero'];
+  }
+  return [
+    ...(options.videoCodec === 'copy'
+      ? ['-c:v', 'copy']
+      : [...VIDEO_CODEC_ARGS[options.videoCodec], '-crf', String(options.crf)]),
+    ...(options.audioCodec === 'copy' ? ['-c:a', 'copy'] : ['-c:a', 'aac', '-b:a', '192k']),
+    '-sn',
+  ];
+}
+
+// `clip_12s-45s` style suffix, so exports of one source sort together
+function clipBaseName(video: Video, start: number, end: number): string {
+  return `${path.parse(video.fileName).name}_clip_${Math.floor(start)}s-${Math.ceil(end)}s`;
+}
+
+// Start the export as a task and return its id and output file name; the player polls
+// the task for progress
+export async function startClipExport(
+  video: Video,
+  start: number,
+  end: number,
+  options: ClipExportOptions,
+  rootPath: string
+): Promise<{ taskId: string; outputName: string }> {
+  const outputPath = await pickOutputPath(path.dirname(video.filePath), clipBaseName(video, start, end), options.container, new Set());
+  const outputName = path.basename(outputPath);
+  const task = startTask('clip', `Exporting ${outputName}`);
+  task.setProgress(0, 100, video.fileName);
+
+  const controller = new AbortController();
+  const cancelCheck = setInterval(() => {
+    if (task.isCancelled()) controller.abort();
+  }, CANCEL_POLL_MS);
+
+  const run = async () => {
+    try {
+      await transcodeVideo(
+        video.filePath,
+        outputPath,
+        buildClipArgs(options),
+        end - start,
+        (progress) => task.setProgress(progress, 100),
+        controller.signal,
+        ['-ss', String(start), '-to', String(end)]
+      );
+    } catch (error) {
+      await fs.rm(outputPath, { force: true }).catch(() => {});
+      if (controller.signal.aborted) {
+        task.finish('cancelled');
+        return;
+      }
+      const tail = error instanceof TranscodeError ? error.stderrTail : '';
+      const message = error instanceof Error ? error.message : String(error);
+      console.warn(`Clip export failed for ${video.filePath}: ${message}\n${tail}`);
+      task.finish('error', tail ? `${message}\n${tail}` : message);
+      return;
+    } finally {
+      clearInterval(cancelCheck);
+    }
+
+    await addOutputToCatalog(outputPath, rootPath).catch((error) => {
+      console.warn(`Could not add ${outputPath} to the catalog:`, error);
+    });
+    task.setProgress(100, 100, outputName);
+    task.finish();
+  };
+
+  run().catch((error) => {
+    console.error('Clip export failed:', error);
+    task.finish('error', error instanceof Error ? error.message : String(error));
+  });
+
+  return { taskId: task.id, outputName };
+}
@@ -0,0 +1,104 @@
+import fs from 'fs/promises';
+import path from 'path';
+import { execFile } from 'child_process';
+import { getSetting, setSetting } from './db';
+import { CloudPlaceholderMode } from './types';
+
+// Files synced from iCloud Drive, Dropbox, OneDrive or Google Drive can be "online-only":
+// the name and size are on disk but the data isn't. Reading one (the scan fingerprint,
+// ffprobe, thumbnails) makes the provider download all of it, so scans look for them
+// first and either leave them out or catalog them from the name alone.
+
+const CLOUD_PLACEHOLDERS_SETTING = 'cloud_placeholders';
+
+export function getCloudPlaceholderMode(): CloudPlaceholderMode {
+  return getSetting(CLOUD_PLACEHOLDERS_SETTING) === 'catalog' ? 'catalog' : 'skip';
+}
+
+export function setCloudPlaceholderMode(mode: CloudPlaceholderMode): void {
+  setSetting(CLOUD_PLACEHOLDERS_SETTING, mode);
+}
+
+// Older iCloud Drive replaces an evicted file with a hidden ".<name>.icloud" stub next to
+// where it was. Returns the name the stub stands in for, or null for any other name.
+export function getICloudStubTarget(name: string): string | null {
+  const match = /^\.(.+)\.icloud$/.exec(name);
+  return match ? match[1] : null;
+}
+
+// Windows marks Files On-Demand placeholders with these attributes, which Node's stat
+// doesn't expose
+const FILE_ATTRIBUTE_RECALL_ON_OPEN = 0x40000;
+const FILE_ATTRIBUTE_RECALL_ON_DATA_ACCESS = 0x400000;
+
+const LIST_RECALL_FILES_SCRIPT = [
+  '[Console]::OutputEncoding = [Text.Encoding]::UTF8',
+  'Get-ChildItem -LiteralPath $env:VCB_PLACEHOLDER_DIR -File -Force',
+  `| Where-Object { $_.Attributes.value__ -band ${FILE_ATTRIBUTE_RECALL_ON_OPEN | FILE_ATTRIBUTE_RECALL_ON_DATA_ACCESS} }`,
+  '| ForEach-Object { $_.Name }',
+].join(' ');
+
+// Names of the placeholders in one folder, read with a single PowerShell call. A folder
+// that can't be listed reports none, so its files are read as usual.
+function listRecallFiles(dir: string): Promise<Set<string>> {
+  return new Promise((resolve) => {
+    execFile(
+      'powershell.exe',
+      ['-NoProfile', '-NonInteractive', '-Command', LIST_RECALL_FILES_SCRIPT],
+      { env: { ...process.env, VCB_PLACEHOLDER_DIR: dir }, windowsHide: true, maxBuffer: 16 * 1024 * 1024 },
+      (error, stdout) => {
+        if (error) {
+          console.warn(`Could not check ${dir} for online-only files:`, error.message);
+          resolve(new Set());
+          return;
+        }
+        resolve(new Set(stdout.split(/\r?\n/).filter(Boolean)));
+      }
+    );
+  });
+}
+
+// macOS sets SF_DATALESS on File Provider files whose data was evicted to the cloud
+const SF_DATALESS = 0x40000000;
+
+// Read a file's BSD flags with stat(1), which Node's stat doesn't expose. A file that
+// can't be checked isn't treated as online-only.
+function isDataless(filePath: string): Promise<boolean> {
+  return new Promise((resolve) => {
+    execFile('/usr/bin/stat', ['-f', '%f', filePath], (error, stdout) => {
+      if (error) {
+        resolve(false);
+        return;
+      }
+      resolve((Number.parseInt(stdout.trim(), 10) & SF_DATALESS) !== 0);
+    });
+  });
+}
+
+export type PlaceholderCheck = (filePath: string) => Promise<boolean>;
+
+// Checker for one walk. A placeholder needs the platform's own marker: on Windows each
+// folder's attributes are listed once; on macOS a file with a size but no blocks allocated
+// is confirmed by its dataless flag (ZFS, network and FUSE mounts can report no blocks for
+// files that are there). Other platforms have no such marker, so only iCloud stubs are
+// recognized there. Files that can't be read aren't placeholders, so processing reports them.
+export function createPlaceholderCheck(): PlaceholderCheck {
+  if (process.platform === 'win32') {
+    const folders = new Map<string, Promise<Set<string>>>();
+    return async (filePath) => {
+      const dir = path.dirname(filePath);
+      if (!folders.has(dir)) folders.set(dir, listRecallFiles(dir));
+      return (await folders.get(dir)!).has(path.basename(filePath));
+    };
+  }
+
+  if (process.platform === 'darwin') {
+    return async (filePath) => {
+      const stats = await fs.stat(filePath).catch(() => null);
+      if (!stats || stats.size === 0 || stats.blocks !== 0) return false;
+      return isDataless(filePath);
+    };
+  }
+
+  return async () => false;
+}
@@ -0,0 +1,52 @@
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { CommandTemplate, DEFAULT_COMMAND_TEMPLATES, ShellKind } from './ffmpegCommand';
+
+// User-edited ffmpeg command templates (server-side only). App-wide rather than per
+// library, so they live next to the library history in the user's home directory.
+
+const TEMPLATES_PATH = path.join(os.homedir(), '.video-catalog-browser', 'ffmpeg-templates.json');
+
+// Quoting style for copied commands; the server's platform is the one the user is on
+export function getShellKind(): ShellKind {
+  return process.platform === 'win32' ? 'powershell' : 'posix';
+}
+
+// Check templates from a request or an import; the error names the first bad entry
+export function validateCommandTemplates(data: unknown): { templates: CommandTemplate[] } | { error: string } {
+  if (!Array.isArray(data)) {
+    return { error: '"templates" must be an array' };
+  }
+  for (const [i, entry] of data.entries()) {
+    if (typeof entry?.label !== 'string' || !entry.label.trim()) {
+      return { error: `"templates[${i}].label" must be a non-empty string` };
+    }
+    if (typeof entry.template !== 'string' || !entry.template.trim()) {
+      return { error: `"templates[${i}].template" must be a non-empty string` };
+    }
+  }
+  return {
+    templates: data.map((entry: CommandTemplate) => ({ label: entry.label.trim(), template: entry.template.trim() })),
+  };
+}
+
+// Saved templates, or the defaults if none were saved (or the file is unreadable)
+export function getCommandTemplates(): CommandTemplate[] {
+  try {
+    const validation = validateCommandTemplates(JSON.parse(fs.readFileSync(TEMPLATES_PATH, 'utf-8')));
+    return 'templates' in validation ? validation.templates : DEFAULT_COMMAND_TEMPLATES;
+  } catch {
+    return DEFAULT_COMMAND_TEMPLATES;
+  }
+}
+
+export function setCommandTemplates(templates: CommandTemplate[]): void {
+  fs.mkdirSync(path.dirname(TEMPLATES_PATH), { recursive: true });
+  fs.writeFileSync(TEMPLATES_PATH, JSON.stringify(templates, null, 2));
+}
+
+// Back to the built-in templates (new defaults in later versions then apply too)
+export function resetCommandTemplates(): void {
+  fs.rmSync(TEMPLATES_PATH, { force: true });
+}
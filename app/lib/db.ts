@@ -1,7 +1,14 @@
 import Database from 'better-sqlite3';
+import crypto from 'crypto';
 import path from 'path';
 import fs from 'fs';
-import { VideoRow, SelectionRow, ProxyJobRow, rowToVideo, rowToSelection, rowToProxyJob, Video, Selection, ProxyJob, SortOption } from './types';
+import { clearAssetVersions } from './assetCache';
+import { recordLibraryOpened } from './libraryHistory';
+import { syncCatalogServer } from './catalogServer';
+import { purgeExpiredVideos } from './removed';
+import type { SidecarMetadata } from './sidecar';
+import { parseCreationTime } from './utils';
+import { VideoRow, SelectionRow, ProxyJobRow, ScanRow, rowToVideo, rowToSelection, rowToProxyJob, rowToScanReport, Video, Selection, ProxyJob, ScanCounts, ScanReport, SortOption, SpriteConfig, DatabaseStats, ColorLabel, SilenceRange, SilenceReport, DirectoryStats, SelectionSet, FFmpegMetadata } from './types';
 
 // Database instance management
 let db: Database.Database | null = null;
@@ -34,6 +41,10 @@ export function initDatabase(rootPath: string): Database.Database {
     db = null;
   }
 
+  // Asset versions from the previous library must not leak into this one
+  clearAssetVersions();
+  duplicateFileNames = null;
+
   // Ensure data directory exists
   if (!fs.existsSync(dataDir)) {
     fs.mkdirSync(dataDir, { recursive: true });
@@ -47,6 +58,10 @@ export function initDatabase(rootPath: string): Database.Database {
   currentRootPath = rootPath;
 
   initializeSchema(db);
+  purgeExpiredVideos();
+  recordLibraryOpened(rootPath);
+  // The catalog API server follows the open library's setting
+  syncCatalogServer();
 
   return db;
 }
@@ -69,6 +84,60 @@ export function isDatabaseInitialized(): boolean {
   return db !== null;
 }
 
+// Whether a connection is the open library's (rather than another library opened read-only)
+function isOpenDatabase(database: Database.Database): boolean {
+  return database === db;
+}
+
+// Compaction is offered once the file is 20% larger than the pages in use
+const COMPACT_THRESHOLD = 1.2;
+
+// Bytes on disk for the open database, including the WAL file
+function getDatabaseFileSize(): number {
+  let size = 0;
+  for (const file of [currentDbPath!, `${currentDbPath}-wal`]) {
+    try {
+      size += fs.statSync(file).size;
+    } catch {
+      // No WAL file right after a checkpoint
+    }
+  }
+  return size;
+}
+
+export function getDatabaseStats(): DatabaseStats {
+  const database = getDatabase();
+  const pageSize = database.pragma('page_size', { simple: true }) as number;
+  const pageCount = database.pragma('page_count', { simple: true }) as number;
+  const freePages = database.pragma('freelist_count', { simple: true }) as number;
+  const usedSize = (pageCount - freePages) * pageSize;
+  const mainSize = fs.statSync(currentDbPath!).size;
+
+  return {
+    fileSize: getDatabaseFileSize(),
+    compactable: usedSize > 0 && mainSize / usedSize > COMPACT_THRESHOLD,
+  };
+}
+
+// Rebuild the file without free pages, then refresh the query planner's statistics.
+// The WAL is checkpointed and truncated so the size drop shows up right away.
+export function vacuumDatabase(): { before: number; after: number } {
+  const database = getDatabase();
+  const before = getDatabaseFileSize();
+
+  database.exec('VACUUM');
+  database.pragma('wal_checkpoint(TRUNCATE)');
+  database.pragma('optimize');
+
+  return { before, after: getDatabaseFileSize() };
+}
+
+// Write a consistent copy of the open database to destPath with SQLite's online backup
+// API. Copying catalog.db at the file level can catch a write half-done and skips the WAL.
+export async function backupDatabase(destPath: string): Promise<void> {
+  await getDatabase().backup(destPath);
+}
+
 // Initialize database schema
 function initializeSchema(database: Database.Database): void {
   database.exec(`
@@ -83,6 +152,7 @@ function initializeSchema(database: Database.Database): void {
       height INTEGER,
       created_at TEXT NOT NULL,
       directory TEXT NOT NULL,
+      online_only INTEGER DEFAULT 0,
       has_proxy INTEGER DEFAULT 0,
       has_sprite INTEGER DEFAULT 0,
       proxy_path TEXT,
@@ -90,7 +160,32 @@ function initializeSchema(database: Database.Database): void {
       thumbnail_path TEXT,
       file_hash TEXT,
       file_mtime TEXT,
-      scanned_at TEXT
+      scanned_at TEXT,
+      frame_rate REAL,
+      poster_time REAL,
+      sidecar_title TEXT,
+      sidecar_description TEXT,
+      sidecar_keywords TEXT,
+      sidecar_mtime TEXT,
+      codec TEXT,
+      video_stream_count INTEGER,
+      audio_track_count INTEGER,
+      subtitle_paths TEXT,
+      sprite_columns INTEGER,
+      sprite_rows INTEGER,
+      sprite_frames INTEGER,
+      corrupt_at TEXT,
+      media_date TEXT,
+      name_date TEXT,
+      color_space TEXT,
+      hdr_format TEXT,
+      location TEXT,
+      latitude REAL,
+      longitude REAL,
+      loudness_lufs REAL,
+      bit_rate INTEGER,
+      removed_at TEXT,
+      excluded INTEGER DEFAULT 0
     );
 
     CREATE INDEX IF NOT EXISTS idx_videos_directory ON videos(directory);
@@ -104,10 +199,13 @@ function initializeSchema(database: Database.Database): void {
       video_id TEXT NOT NULL REFERENCES videos(id) ON DELETE CASCADE,
       is_favorite INTEGER DEFAULT 0,
       notes TEXT,
-      created_at TEXT NOT NULL
+      created_at TEXT NOT NULL,
+      watched_at TEXT,
+      color_label TEXT,
+      rating INTEGER
     );
 
-    CREATE INDEX IF NOT EXISTS idx_selections_video_id ON selections(video_id);
+    CREATE UNIQUE INDEX IF NOT EXISTS idx_selections_video_id_unique ON selections(video_id);
 
     -- Proxy generation queue
     CREATE TABLE IF NOT EXISTS proxy_queue (
@@ -130,7 +228,56 @@ function initializeSchema(database: Database.Database): void {
       status TEXT NOT NULL DEFAULT 'scanning',
       videos_found INTEGER DEFAULT 0,
       started_at TEXT NOT NULL,
-      completed_at TEXT
+      completed_at TEXT,
+      added_count INTEGER DEFAULT 0,
+      updated_count INTEGER DEFAULT 0,
+      skipped_count INTEGER DEFAULT 0,
+      removed_count INTEGER DEFAULT 0,
+      error_count INTEGER DEFAULT 0,
+      folder_stats TEXT,
+      errors TEXT,
+      error TEXT,
+      scan_path TEXT -- Folder scanned inside the library; NULL for the whole library
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_scans_started_at ON scans(started_at);
+
+    -- Silence detection (an optional analysis, not run by scans)
+    CREATE TABLE IF NOT EXISTS silence_analyses (
+      video_id TEXT PRIMARY KEY REFERENCES videos(id) ON DELETE CASCADE,
+      file_hash TEXT, -- Fingerprint analyzed; a changed file needs a new analysis
+      has_audio INTEGER NOT NULL,
+      analyzed_at TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS silence_ranges (
+      video_id TEXT NOT NULL REFERENCES silence_analyses(video_id) ON DELETE CASCADE,
+      start_time REAL NOT NULL,
+      end_time REAL NOT NULL
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_silence_ranges_video_id ON silence_ranges(video_id);
+
+    -- Per-folder totals (recursive), rebuilt at the end of each scan
+    CREATE TABLE IF NOT EXISTS directory_stats (
+      directory TEXT PRIMARY KEY, -- Relative to the library root
+      video_count INTEGER NOT NULL,
+      total_duration REAL NOT NULL,
+      total_size INTEGER NOT NULL
+    );
+
+    -- Named selection sets; members aren't tied to videos so removed ones can be reported
+    CREATE TABLE IF NOT EXISTS selection_sets (
+      id TEXT PRIMARY KEY,
+      name TEXT NOT NULL,
+      created_at TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS selection_set_videos (
+      set_id TEXT NOT NULL REFERENCES selection_sets(id) ON DELETE CASCADE,
+      video_id TEXT NOT NULL,
+      position INTEGER NOT NULL,
+      PRIMARY KEY (set_id, video_id)
     );
 
     -- Application settings
@@ -139,9 +286,86 @@ function initializeSchema(database: Database.Database): void {
       value TEXT NOT NULL
     );
   `);
+
+  migrateSchema(database);
 }
 
-// Generate a simple hash ID from file path
+// Columns added to the videos table after the initial schema
+const VIDEO_COLUMN_MIGRATIONS: { name: string; type: string }[] = [
+  { name: 'frame_rate', type: 'REAL' },
+  { name: 'poster_time', type: 'REAL' },
+  { name: 'online_only', type: 'INTEGER DEFAULT 0' },
+  { name: 'sidecar_title', type: 'TEXT' },
+  { name: 'sidecar_description', type: 'TEXT' },
+  { name: 'sidecar_keywords', type: 'TEXT' },
+  { name: 'sidecar_mtime', type: 'TEXT' },
+  { name: 'codec', type: 'TEXT' },
+  { name: 'video_stream_count', type: 'INTEGER' },
+  { name: 'audio_track_count', type: 'INTEGER' },
+  { name: 'subtitle_paths', type: 'TEXT' },
+  { name: 'sprite_columns', type: 'INTEGER' },
+  { name: 'sprite_rows', type: 'INTEGER' },
+  { name: 'sprite_frames', type: 'INTEGER' },
+  { name: 'corrupt_at', type: 'TEXT' },
+  { name: 'media_date', type: 'TEXT' },
+  { name: 'name_date', type: 'TEXT' },
+  { name: 'color_space', type: 'TEXT' },
+  { name: 'hdr_format', type: 'TEXT' },
+  { name: 'location', type: 'TEXT' },
+  { name: 'latitude', type: 'REAL' },
+  { name: 'longitude', type: 'REAL' },
+  { name: 'loudness_lufs', type: 'REAL' },
+  { name: 'bit_rate', type: 'INTEGER' },
+  { name: 'removed_at', type: 'TEXT' },
+  { name: 'excluded', type: 'INTEGER DEFAULT 0' },
+];
+
+const SELECTION_COLUMN_MIGRATIONS: { name: string; type: string }[] = [
+  { name: 'watched_at', type: 'TEXT' },
+  { name: 'color_label', type: 'TEXT' },
+  { name: 'rating', type: 'INTEGER' },
+];
+
+// Scan report columns added after the scans table was introduced
+const SCAN_COLUMN_MIGRATIONS: { name: string; type: string }[] = [
+  { name: 'added_count', type: 'INTEGER DEFAULT 0' },
+  { name: 'updated_count', type: 'INTEGER DEFAULT 0' },
+  { name: 'skipped_count', type: 'INTEGER DEFAULT 0' },
+  { name: 'removed_count', type: 'INTEGER DEFAULT 0' },
+  { name: 'error_count', type: 'INTEGER DEFAULT 0' },
+  { name: 'folder_stats', type: 'TEXT' },
+  { name: 'errors', type: 'TEXT' },
+  { name: 'error', type: 'TEXT' },
+  { name: 'scan_path', type: 'TEXT' },
+];
+
+function addMissingColumns(
+  database: Database.Database,
+  table: string,
+  migrations: { name: string; type: string }[]
+): void {
+  const columns = database.prepare(`PRAGMA table_info(${table})`).all() as { name: string }[];
+  const existing = new Set(columns.map((c) => c.name));
+
+  for (const column of migrations) {
+    if (!existing.has(column.name)) {
+      database.exec(`ALTER TABLE ${table} ADD COLUMN ${column.name} ${column.type}`);
+    }
+  }
+}
+
+// Add missing columns to databases created by older versions
+function migrateSchema(database: Database.Database): void {
+  addMissingColumns(database, 'videos', VIDEO_COLUMN_MIGRATIONS);
+  addMissingColumns(database, 'selections', SELECTION_COLUMN_MIGRATIONS);
+  addMissingColumns(database, 'scans', SCAN_COLUMN_MIGRATIONS);
+
+  // Older databases only had a non-unique index, which upsertSelection's ON CONFLICT needs
+  database.exec('DROP INDEX IF EXISTS idx_selections_video_id');
+}
+
+// Generate a simple hash ID from file path. It's a 32-bit hash, so two paths can share an
+// ID (e.g. /x/Aa.mp4 and /x/BB.mp4)
 export function generateId(filePath: string): string {
   let hash = 0;
   for (let i = 0; i < filePath.length; i++) {
@@ -152,6 +376,155 @@ export function generateId(filePath: string): string {
   return Math.abs(hash).toString(36);
 }
 
+// Portable libraries store paths relative to the library root so the catalog
+// survives the drive being mounted somewhere else
+const PORTABLE_PATHS_SETTING = 'portable_paths';
+
+export function isPortableLibrary(): boolean {
+  return getSetting(PORTABLE_PATHS_SETTING) === 'true';
+}
+
+// Resolve a stored path against the library root; absolute paths (non-portable rows) pass through.
+// Relative paths are stored with / so a portable library opens on any platform.
+export function resolveLibraryPath(rootPath: string, storedPath: string): string {
+  return path.isAbsolute(storedPath) ? storedPath : path.join(rootPath, ...storedPath.split('/'));
+}
+
+// Whether a path.relative() result points outside its base. A name that merely starts
+// with '..' ('..clips') is still inside.
+export function isOutsideRoot(relative: string): boolean {
+  return relative === '..' || relative.startsWith('..' + path.sep) || path.isAbsolute(relative);
+}
+
+// Resolve every path of a video read from the library at rootPath
+export function resolveVideoPaths(video: Video, rootPath: string): Video {
+  const resolve = (p: string | null) => (p === null ? null : resolveLibraryPath(rootPath, p));
+  return {
+    ...video,
+    filePath: resolveLibraryPath(rootPath, video.filePath),
+    directory: resolveLibraryPath(rootPath, video.directory),
+    proxyPath: resolve(video.proxyPath),
+    spritePath: resolve(video.spritePath),
+    thumbnailPath: resolve(video.thumbnailPath),
+  };
+}
+
+// Relative form (with / separators) of a path inside the open library, or null if it is outside it
+function toRelativePath(filePath: string): string | null {
+  if (!currentRootPath) return null;
+  const relative = path.relative(currentRootPath, resolveLibraryPath(currentRootPath, filePath));
+  return isOutsideRoot(relative) ? null : relative.split(path.sep).join('/');
+}
+
+// Form a path is written in: relative in portable libraries, otherwise absolute
+function toStoredPath(filePath: string, portable: boolean = isPortableLibrary()): string {
+  if (portable) {
+    return toRelativePath(filePath) ?? filePath;
+  }
+  return currentRootPath ? resolveLibraryPath(currentRootPath, filePath) : filePath;
+}
+
+function toStoredPathOrNull(filePath: string | null, portable?: boolean): string | null {
+  return filePath === null ? null : toStoredPath(filePath, portable);
+}
+
+// Both forms a path may be stored in while a library is part-way through conversion
+function getStoredPathForms(filePath: string): string[] {
+  const absolute = currentRootPath ? resolveLibraryPath(currentRootPath, filePath) : filePath;
+  const relative = toRelativePath(filePath);
+  if (relative === null) return [absolute];
+  // Rows made portable on Windows before paths were stored with /
+  const native = relative.split('/').join(path.sep);
+  return native === relative ? [absolute, relative] : [absolute, relative, native];
+}
+
+function toVideo(row: VideoRow): Video {
+  const video = rowToVideo(row);
+  return currentRootPath ? resolveVideoPaths(video, currentRootPath) : video;
+}
+
+// Keep the ID of an existing row so selections stay attached when a file is re-indexed
+function getIdForInsert(filePath: string, storedPath: string): string {
+  const forms = getStoredPathForms(filePath);
+  const row = getDatabase()
+    .prepare(`SELECT id FROM videos WHERE file_path IN (${forms.map(() => '?').join(', ')})`)
+    .get(...forms) as { id: string } | undefined;
+  return row?.id ?? generateId(storedPath);
+}
+
+// Convert every stored path to the portable (relative) or absolute form; returns rows changed
+export function setPortableLibrary(portable: boolean): number {
+  const db = getDatabase();
+  const rows = db.prepare('SELECT id, file_path, directory, proxy_path, sprite_path, thumbnail_path FROM videos').all() as Pick<
+    VideoRow, 'id' | 'file_path' | 'directory' | 'proxy_path' | 'sprite_path' | 'thumbnail_path'
+  >[];
+  const update = db.prepare(`
+    UPDATE videos
+    SET file_path = ?, directory = ?, proxy_path = ?, sprite_path = ?, thumbnail_path = ?
+    WHERE id = ?
+  `);
+
+  const convert = db.transaction(() => {
+    let changed = 0;
+    for (const row of rows) {
+      const next = [
+        toStoredPath(row.file_path, portable),
+        toStoredPath(row.directory, portable),
+        toStoredPathOrNull(row.proxy_path, portable),
+        toStoredPathOrNull(row.sprite_path, portable),
+        toStoredPathOrNull(row.thumbnail_path, portable),
+      ];
+      if (next[0] !== row.file_path || next[1] !== row.directory || next[2] !== row.proxy_path
+        || next[3] !== row.sprite_path || next[4] !== row.thumbnail_path) {
+        update.run(...next, row.id);
+        changed++;
+      }
+    }
+    setSetting(PORTABLE_PATHS_SETTING, portable ? 'true' : 'false');
+    return changed;
+  });
+
+  return convert();
+}
+
+// Point absolute paths under oldRoot at newRoot (after a drive was remounted elsewhere);
+// relative (portable) paths need no change. Returns rows changed.
+export function relinkVideoPaths(oldRoot: string, newRoot: string): number {
+  const db = getDatabase();
+  const rows = db.prepare('SELECT id, file_path, directory, proxy_path, sprite_path, thumbnail_path FROM videos').all() as Pick<
+    VideoRow, 'id' | 'file_path' | 'directory' | 'proxy_path' | 'sprite_path' | 'thumbnail_path'
+  >[];
+  const update = db.prepare(`
+    UPDATE videos
+    SET file_path = ?, directory = ?, proxy_path = ?, sprite_path = ?, thumbnail_path = ?
+    WHERE id = ?
+  `);
+
+  const relink = (p: string | null): string | null => {
+    if (p === null || !path.isAbsolute(p)) return p;
+    if (p === oldRoot) return newRoot;
+    return p.startsWith(oldRoot + path.sep) ? path.join(newRoot, p.slice(oldRoot.length + 1)) : p;
+  };
+
+  return db.transaction(() => {
+    let changed = 0;
+    for (const row of rows) {
+      const filePath = relink(row.file_path)!;
+      if (filePath === row.file_path) continue;
+      update.run(
+        filePath,
+        relink(row.directory),
+        relink(row.proxy_path),
+        relink(row.sprite_path),
+        relink(row.thumbnail_path),
+        row.id
+      );
+      changed++;
+    }
+    return changed;
+  })();
+}
+
 // Video insert data type
 export interface VideoInsertData {
   filePath: string;
@@ -164,32 +537,82 @@ export interface VideoInsertData {
   directory: string;
   fileHash?: string;
   fileMtime?: string;
+  frameRate?: number | null;
+  posterTime?: number | null;
+  codec?: string | null;
+  videoStreamCount?: number | null;
+  audioTrackCount?: number | null;
+  mediaDate?: string | null; // Container creation_time; '' when it has none, null if not probed
+  nameDate?: string | null; // Parsed from the file name
+  colorSpace?: string | null;
+  hdrFormat?: string | null;
+  location?: string | null;
+  latitude?: number | null;
+  longitude?: number | null;
+  bitRate?: number | null; // Container bit rate in bits/s; 0 when not reported
+  onlineOnly?: boolean; // Only the name and size were read (an online-only cloud file)
 }
 
+// Re-indexing a changed file updates its row in place. INSERT OR REPLACE would delete the
+// row first, and the foreign key cascade would take its favorite, notes and watched state
+// with it. Generated assets and sidecar data are reset, as they would be for a new row,
+// but scanned_at keeps the first insert time so Recently Added doesn't reshuffle on edits.
+const UPSERT_VIDEO_SQL = `
+  INSERT INTO videos (id, file_path, file_name, file_size, duration, width, height, created_at, directory, file_hash, file_mtime, scanned_at, frame_rate, poster_time, codec, video_stream_count, audio_track_count, media_date, name_date, color_space, hdr_format, location, latitude, longitude, bit_rate, online_only)
+  VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+  ON CONFLICT(id) DO UPDATE SET
+    file_path = excluded.file_path, file_name = excluded.file_name, file_size = excluded.file_size,
+    duration = excluded.duration, width = excluded.width, height = excluded.height,
+    created_at = excluded.created_at, directory = excluded.directory, file_hash = excluded.file_hash,
+    file_mtime = excluded.file_mtime, scanned_at = COALESCE(scanned_at, excluded.scanned_at), frame_rate = excluded.frame_rate,
+    poster_time = excluded.poster_time, codec = excluded.codec,
+    video_stream_count = excluded.video_stream_count, audio_track_count = excluded.audio_track_count,
+    media_date = excluded.media_date, name_date = excluded.name_date,
+    color_space = excluded.color_space, hdr_format = excluded.hdr_format,
+    location = excluded.location, latitude = excluded.latitude, longitude = excluded.longitude,
+    bit_rate = excluded.bit_rate, online_only = excluded.online_only,
+    has_proxy = 0, has_sprite = 0, proxy_path = NULL, sprite_path = NULL, thumbnail_path = NULL,
+    sprite_columns = NULL, sprite_rows = NULL, sprite_frames = NULL,
+    sidecar_title = NULL, sidecar_description = NULL, sidecar_keywords = NULL, sidecar_mtime = NULL,
+    subtitle_paths = NULL, corrupt_at = NULL, loudness_lufs = NULL
+`;
+
 // Video operations
 export function insertVideo(video: VideoInsertData): Video {
   const db = getDatabase();
-  const id = generateId(video.filePath);
+  const storedPath = toStoredPath(video.filePath);
+  const id = getIdForInsert(video.filePath, storedPath);
   const scannedAt = new Date().toISOString();
 
-  const stmt = db.prepare(`
-    INSERT OR REPLACE INTO videos (id, file_path, file_name, file_size, duration, width, height, created_at, directory, file_hash, file_mtime, scanned_at)
-    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-  `);
+  const stmt = db.prepare(UPSERT_VIDEO_SQL);
 
   stmt.run(
     id,
-    video.filePath,
+    storedPath,
     video.fileName,
     video.fileSize,
     video.duration,
     video.width,
     video.height,
     video.createdAt,
-    video.directory,
+    toStoredPath(video.directory),
     video.fileHash || null,
     video.fileMtime || null,
-    scannedAt
+    scannedAt,
+    video.frameRate ?? null,
+    video.posterTime ?? null,
+    video.codec ?? null,
+    video.videoStreamCount ?? null,
+    video.audioTrackCount ?? null,
+    video.mediaDate ?? null,
+    video.nameDate ?? null,
+    video.colorSpace ?? null,
+    video.hdrFormat ?? null,
+    video.location ?? null,
+    video.latitude ?? null,
+    video.longitude ?? null,
+    video.bitRate ?? null,
+    video.onlineOnly ? 1 : 0
   );
 
   return getVideoById(id)!;
@@ -200,28 +623,41 @@ export function insertVideosBatch(videos: VideoInsertData[]): Video[] {
   const db = getDatabase();
   const scannedAt = new Date().toISOString();
 
-  const insertStmt = db.prepare(`
-    INSERT OR REPLACE INTO videos (id, file_path, file_name, file_size, duration, width, height, created_at, directory, file_hash, file_mtime, scanned_at)
-    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-  `);
+  const insertStmt = db.prepare(UPSERT_VIDEO_SQL);
 
+  const portable = isPortableLibrary();
   const insertMany = db.transaction((videoList: VideoInsertData[]) => {
     const insertedIds: string[] = [];
     for (const video of videoList) {
-      const id = generateId(video.filePath);
+      const storedPath = toStoredPath(video.filePath, portable);
+      const id = getIdForInsert(video.filePath, storedPath);
       insertStmt.run(
         id,
-        video.filePath,
+        storedPath,
         video.fileName,
         video.fileSize,
         video.duration,
         video.width,
         video.height,
         video.createdAt,
-        video.directory,
+        toStoredPath(video.directory, portable),
         video.fileHash || null,
         video.fileMtime || null,
-        scannedAt
+        scannedAt,
+        video.frameRate ?? null,
+        video.posterTime ?? null,
+        video.codec ?? null,
+        video.videoStreamCount ?? null,
+        video.audioTrackCount ?? null,
+        video.mediaDate ?? null,
+        video.nameDate ?? null,
+        video.colorSpace ?? null,
+        video.hdrFormat ?? null,
+        video.location ?? null,
+        video.latitude ?? null,
+        video.longitude ?? null,
+        video.bitRate ?? null,
+        video.onlineOnly ? 1 : 0
       );
       insertedIds.push(id);
     }
@@ -235,119 +671,449 @@ export function insertVideosBatch(videos: VideoInsertData[]): Video[] {
 export function getVideoById(id: string): Video | null {
   const db = getDatabase();
   const row = db.prepare('SELECT * FROM videos WHERE id = ?').get(id) as VideoRow | undefined;
-  return row ? rowToVideo(row) : null;
+  return row ? toVideo(row) : null;
 }
 
+// Exact match on the absolute path, in either stored form. file_path is UNIQUE, so SQLite's
+// automatic index serves this lookup and no separate index is needed
 export function getVideoByPath(filePath: string): Video | null {
   const db = getDatabase();
-  const row = db.prepare('SELECT * FROM videos WHERE file_path = ?').get(filePath) as VideoRow | undefined;
-  return row ? rowToVideo(row) : null;
+  const forms = getStoredPathForms(filePath);
+  const row = db
+    .prepare(`SELECT * FROM videos WHERE file_path IN (${forms.map(() => '?').join(', ')})`)
+    .get(...forms) as VideoRow | undefined;
+  return row ? toVideo(row) : null;
 }
 
-export function getVideosByDirectory(directory: string, sortBy: SortOption = 'date-desc'): Video[] {
+export function getVideoByFileHash(fileHash: string): Video | null {
   const db = getDatabase();
+  const row = db.prepare('SELECT * FROM videos WHERE file_hash = ? LIMIT 1').get(fileHash) as VideoRow | undefined;
+  return row ? toVideo(row) : null;
+}
+
+export function getVideoByNameAndSize(fileName: string, fileSize: number): Video | null {
+  const db = getDatabase();
+  const row = db.prepare('SELECT * FROM videos WHERE file_name = ? AND file_size = ? LIMIT 1').get(fileName, fileSize) as VideoRow | undefined;
+  return row ? toVideo(row) : null;
+}
 
-  let orderClause: string;
+// SQL ORDER BY clause for a sort option; table is the videos table name or alias
+function getOrderClause(sortBy: SortOption, table: string = 'videos'): string {
+  const watchedAt = `(SELECT watched_at FROM selections WHERE video_id = ${table}.id)`;
+  const bestDate = `COALESCE(NULLIF(${table}.media_date, ''), ${table}.name_date, ${table}.created_at)`;
   switch (sortBy) {
     case 'date-asc':
-      orderClause = 'created_at ASC';
-      break;
+      return `${table}.created_at ASC`;
     case 'date-desc':
-      orderClause = 'created_at DESC';
-      break;
+      return `${table}.created_at DESC`;
+    case 'best-date-asc':
+      return `${bestDate} ASC`;
+    case 'best-date-desc':
+      return `${bestDate} DESC`;
+    case 'scanned-asc':
+      // Rows from before scan times were recorded go last either way
+      return `${table}.scanned_at IS NULL, ${table}.scanned_at ASC`;
+    case 'scanned-desc':
+      return `${table}.scanned_at IS NULL, ${table}.scanned_at DESC`;
+    case 'bitrate-asc':
+    case 'bitrate-desc': {
+      // Same fallback as getBitrate: size over duration when the container has no bit rate
+      const bitrate = `COALESCE(NULLIF(${table}.bit_rate, 0), ${table}.file_size * 8.0 / NULLIF(${table}.duration, 0))`;
+      return `${bitrate} IS NULL, ${bitrate} ${sortBy === 'bitrate-asc' ? 'ASC' : 'DESC'}`;
+    }
     case 'duration-asc':
-      orderClause = 'duration ASC';
-      break;
+      return `${table}.duration ASC`;
     case 'duration-desc':
-      orderClause = 'duration DESC';
-      break;
+      return `${table}.duration DESC`;
     case 'name-asc':
-      orderClause = 'file_name ASC';
-      break;
+      return `${table}.file_name ASC`;
     case 'name-desc':
-      orderClause = 'file_name DESC';
-      break;
+      return `${table}.file_name DESC`;
+    case 'watched-asc':
+      // Longest-ago watched first; unwatched last
+      return `${watchedAt} IS NULL, ${watchedAt} ASC`;
+    case 'unwatched-first':
+      return `${watchedAt} IS NOT NULL, ${table}.created_at DESC`;
     default:
-      orderClause = 'created_at DESC';
+      return `${table}.created_at DESC`;
   }
+}
+
+export function getVideosByDirectory(directory: string, sortBy: SortOption = 'date-desc'): Video[] {
+  const db = getDatabase();
+
+  const orderClause = getOrderClause(sortBy);
 
   const rows = db.prepare(`
     SELECT * FROM videos
-    WHERE directory LIKE ?
+    WHERE (${getStoredPathForms(directory).map(() => 'directory LIKE ?').join(' OR ')}) AND removed_at IS NULL
     ORDER BY ${orderClause}
-  `).all(`${directory}%`) as VideoRow[];
+  `).all(...getStoredPathForms(directory).map((form) => `${form}%`)) as VideoRow[];
 
-  return rows.map(rowToVideo);
+  return rows.map(toVideo);
+}
+
+// Number of videos and their combined size
+export function getLibraryTotals(): { videoCount: number; totalBytes: number } {
+  const db = getDatabase();
+  return db.prepare('SELECT COUNT(*) AS videoCount, COALESCE(SUM(file_size), 0) AS totalBytes FROM videos WHERE removed_at IS NULL').get() as {
+    videoCount: number;
+    totalBytes: number;
+  };
 }
 
 export function getAllVideos(sortBy: SortOption = 'date-desc'): Video[] {
   const db = getDatabase();
 
-  let orderClause: string;
-  switch (sortBy) {
-    case 'date-asc':
-      orderClause = 'created_at ASC';
-      break;
-    case 'date-desc':
-      orderClause = 'created_at DESC';
-      break;
-    case 'duration-asc':
-      orderClause = 'duration ASC';
-      break;
-    case 'duration-desc':
-      orderClause = 'duration DESC';
-      break;
-    case 'name-asc':
-      orderClause = 'file_name ASC';
-      break;
-    case 'name-desc':
-      orderClause = 'file_name DESC';
-      break;
-    default:
-      orderClause = 'created_at DESC';
+  const orderClause = getOrderClause(sortBy);
+
+  const rows = db.prepare(`SELECT * FROM videos WHERE removed_at IS NULL ORDER BY ${orderClause}`).all() as VideoRow[];
+  return rows.map(toVideo);
+}
+
+// Videos whose bytes can be read without a download, for bulk jobs that open every file.
+// Reading an online-only file makes the cloud provider download all of it.
+export function getReadableVideos(): { videos: Video[]; onlineOnly: number } {
+  const videos = getAllVideos();
+  const readable = videos.filter((video) => !video.onlineOnly);
+  return { videos: readable, onlineOnly: videos.length - readable.length };
+}
+
+// Filters for searchVideos; unset fields don't filter
+export interface VideoQuery {
+  search?: string; // Substring of the file name (case-insensitive)
+  minDuration?: number;
+  maxDuration?: number;
+  minSize?: number;
+  maxSize?: number;
+  directory?: string; // Directory prefix
+  isFavorite?: boolean;
+  includeRemoved?: boolean; // Also videos in Recently Removed
+  sort?: SortOption;
+  limit?: number;
+  offset?: number;
+}
+
+function hasVideoColumn(database: Database.Database, name: string): boolean {
+  const columns = database.prepare('PRAGMA table_info(videos)').all() as { name: string }[];
+  return columns.some((c) => c.name === name);
+}
+
+// Escape LIKE wildcards so user input matches literally
+function escapeLike(value: string): string {
+  return value.replace(/[\\%_]/g, (c) => `\\${c}`);
+}
+
+// Filter, sort and page videos in SQL; total is the match count before paging.
+// Pass a database and its root to search a library other than the open one.
+export function searchVideos(
+  query: VideoQuery,
+  db: Database.Database = getDatabase(),
+  rootPath: string | null = currentRootPath
+): { videos: Video[]; total: number } {
+  const conditions: string[] = [];
+  const params: (string | number)[] = [];
+
+  // Catalogs of other libraries may predate Recently Removed and are opened read-only,
+  // so they can't have been migrated
+  if (!query.includeRemoved && (isOpenDatabase(db) || hasVideoColumn(db, 'removed_at'))) {
+    conditions.push('v.removed_at IS NULL');
+  }
+  if (query.search) {
+    conditions.push("v.file_name LIKE ? ESCAPE '\\'");
+    params.push(`%${escapeLike(query.search)}%`);
+  }
+  if (query.minDuration !== undefined) {
+    conditions.push('v.duration >= ?');
+    params.push(query.minDuration);
   }
+  if (query.maxDuration !== undefined) {
+    conditions.push('v.duration <= ?');
+    params.push(query.maxDuration);
+  }
+  if (query.minSize !== undefined) {
+    conditions.push('v.file_size >= ?');
+    params.push(query.minSize);
+  }
+  if (query.maxSize !== undefined) {
+    conditions.push('v.file_size <= ?');
+    params.push(query.maxSize);
+  }
+  if (query.directory) {
+    // Match both stored forms; relative rows belong to the open library
+    const forms = isOpenDatabase(db) ? getStoredPathForms(query.directory) : [query.directory];
+    conditions.push(`(${forms.map(() => "v.directory LIKE ? ESCAPE '\\'").join(' OR ')})`);
+    params.push(...forms.map((form) => `${escapeLike(form)}%`));
+  }
+  if (query.isFavorite !== undefined) {
+    conditions.push('COALESCE(s.is_favorite, 0) = ?');
+    params.push(query.isFavorite ? 1 : 0);
+  }
+
+  const from = `
+    FROM videos v
+    LEFT JOIN selections s ON s.video_id = v.id
+    ${conditions.length > 0 ? `WHERE ${conditions.join(' AND ')}` : ''}
+  `;
+
+  const { total } = db.prepare(`SELECT COUNT(*) AS total ${from}`).get(...params) as { total: number };
+
+  // SQLite needs a LIMIT to use OFFSET; -1 means no limit
+  const rows = db.prepare(`
+    SELECT v.* ${from}
+    ORDER BY ${getOrderClause(query.sort ?? 'date-desc', 'v')}
+    LIMIT ? OFFSET ?
+  `).all(...params, query.limit ?? -1, query.offset ?? 0) as VideoRow[];
 
-  const rows = db.prepare(`SELECT * FROM videos ORDER BY ${orderClause}`).all() as VideoRow[];
-  return rows.map(rowToVideo);
+  const videos = rows.map(rowToVideo);
+  return { videos: rootPath ? videos.map((video) => resolveVideoPaths(video, rootPath)) : videos, total };
 }
 
-export function updateVideoProxy(id: string, proxyPath: string, spritePath: string, thumbnailPath: string): void {
+export function updateVideoProxy(
+  id: string,
+  proxyPath: string,
+  spritePath: string,
+  thumbnailPath: string,
+  spriteConfig: SpriteConfig
+): void {
   const db = getDatabase();
   db.prepare(`
     UPDATE videos
-    SET has_proxy = 1, has_sprite = 1, proxy_path = ?, sprite_path = ?, thumbnail_path = ?
+    SET has_proxy = 1, has_sprite = 1, proxy_path = ?, sprite_path = ?, thumbnail_path = ?,
+      sprite_columns = ?, sprite_rows = ?, sprite_frames = ?
     WHERE id = ?
-  `).run(proxyPath, spritePath, thumbnailPath, id);
+  `).run(
+    toStoredPath(proxyPath),
+    toStoredPath(spritePath),
+    toStoredPath(thumbnailPath),
+    spriteConfig.columns,
+    spriteConfig.rows,
+    spriteConfig.totalFrames,
+    id
+  );
 }
 
 export function updateVideoThumbnail(id: string, thumbnailPath: string): void {
   const db = getDatabase();
-  db.prepare('UPDATE videos SET thumbnail_path = ? WHERE id = ?').run(thumbnailPath, id);
+  db.prepare('UPDATE videos SET thumbnail_path = ? WHERE id = ?').run(toStoredPath(thumbnailPath), id);
 }
 
-export function updateVideoThumbnailAndSprite(id: string, thumbnailPath: string, spritePath: string): void {
+export function updateVideoThumbnailAndSprite(
+  id: string,
+  thumbnailPath: string,
+  spritePath: string,
+  spriteConfig: SpriteConfig
+): void {
   const db = getDatabase();
   db.prepare(`
     UPDATE videos
-    SET thumbnail_path = ?, sprite_path = ?, has_sprite = 1
+    SET thumbnail_path = ?, sprite_path = ?, has_sprite = 1, sprite_columns = ?, sprite_rows = ?, sprite_frames = ?
     WHERE id = ?
-  `).run(thumbnailPath, spritePath, id);
+  `).run(
+    toStoredPath(thumbnailPath),
+    toStoredPath(spritePath),
+    spriteConfig.columns,
+    spriteConfig.rows,
+    spriteConfig.totalFrames,
+    id
+  );
 }
 
-export function deleteVideosByDirectory(directory: string): void {
+export function updateVideoSpriteGrid(id: string, spriteConfig: SpriteConfig): void {
+  const db = getDatabase();
+  db.prepare('UPDATE videos SET sprite_columns = ?, sprite_rows = ?, sprite_frames = ? WHERE id = ?')
+    .run(spriteConfig.columns, spriteConfig.rows, spriteConfig.totalFrames, id);
+}
+
+export function updateVideoSprite(id: string, spritePath: string, spriteConfig: SpriteConfig): void {
+  const db = getDatabase();
+  db.prepare(`
+    UPDATE videos
+    SET sprite_path = ?, has_sprite = 1, sprite_columns = ?, sprite_rows = ?, sprite_frames = ?
+    WHERE id = ?
+  `).run(toStoredPath(spritePath), spriteConfig.columns, spriteConfig.rows, spriteConfig.totalFrames, id);
+}
+
+// Forget one video's sprite sheet after its file was deleted
+export function clearVideoSprite(id: string): void {
+  const db = getDatabase();
+  db.prepare(`
+    UPDATE videos
+    SET has_sprite = 0, sprite_path = NULL, sprite_columns = NULL, sprite_rows = NULL, sprite_frames = NULL
+    WHERE id = ?
+  `).run(id);
+}
+
+// Forget one kind of generated asset for every video after its files were deleted;
+// returns rows changed. Scans regenerate thumbnails and sprites that are missing.
+export function clearVideoAssets(kind: 'thumbnails' | 'sprites' | 'proxies'): number {
   const db = getDatabase();
-  db.prepare('DELETE FROM videos WHERE directory LIKE ?').run(`${directory}%`);
+  const assignments = {
+    thumbnails: 'thumbnail_path = NULL',
+    sprites: 'has_sprite = 0, sprite_path = NULL, sprite_columns = NULL, sprite_rows = NULL, sprite_frames = NULL',
+    proxies: 'has_proxy = 0, proxy_path = NULL',
+  }[kind];
+  return db.prepare(`UPDATE videos SET ${assignments}`).run().changes;
 }
 
-// Selection operations
-export function getSelectionByVideoId(videoId: string): Selection | null {
+// Backfill for videos indexed before file name dates were parsed
+export function updateVideoNameDate(id: string, nameDate: string): void {
   const db = getDatabase();
+  db.prepare('UPDATE videos SET name_date = ? WHERE id = ?').run(nameDate, id);
+}
+
+// Flag (or clear) a file that failed integrity verification
+export function setVideoCorrupt(id: string, corrupt: boolean): void {
+  const db = getDatabase();
+  db.prepare('UPDATE videos SET corrupt_at = ? WHERE id = ?').run(corrupt ? new Date().toISOString() : null, id);
+}
+
+export function updateVideoStreamInfo(
+  id: string,
+  info: Pick<FFmpegMetadata, 'codec' | 'videoStreamCount' | 'audioTrackCount' | 'colorSpace' | 'hdrFormat' | 'location' | 'latitude' | 'longitude' | 'bitRate' | 'creationTime'>
+): void {
+  const db = getDatabase();
+  db.prepare(`
+    UPDATE videos
+    SET codec = ?, video_stream_count = ?, audio_track_count = ?, color_space = ?, hdr_format = ?,
+      location = ?, latitude = ?, longitude = ?, bit_rate = ?, media_date = ?
+    WHERE id = ?
+  `).run(
+    info.codec, info.videoStreamCount, info.audioTrackCount, info.colorSpace, info.hdrFormat,
+    info.location, info.latitude, info.longitude, info.bitRate, parseCreationTime(info.creationTime) ?? '', id
+  );
+}
+
+export function updateVideoLoudness(id: string, lufs: number): void {
+  const db = getDatabase();
+  db.prepare('UPDATE videos SET loudness_lufs = ? WHERE id = ?').run(lufs, id);
+}
+
+// Store the subtitle files found next to a video (names only, so portable and relinked
+// libraries need no conversion)
+export function updateVideoSubtitles(id: string, subtitleFiles: string[]): void {
+  const db = getDatabase();
+  db.prepare('UPDATE videos SET subtitle_paths = ? WHERE id = ?')
+    .run(subtitleFiles.length > 0 ? JSON.stringify(subtitleFiles) : null, id);
+}
+
+export function updateVideoPosterTime(id: string, posterTime: number | null): void {
+  const db = getDatabase();
+  db.prepare('UPDATE videos SET poster_time = ? WHERE id = ?').run(posterTime, id);
+}
+
+// Flag an entry whose file a scan found only in the cloud, or clear it once downloaded
+export function setVideoOnlineOnly(id: string, onlineOnly: boolean): void {
+  const db = getDatabase();
+  db.prepare('UPDATE videos SET online_only = ? WHERE id = ?').run(onlineOnly ? 1 : 0, id);
+}
+
+// Store sidecar metadata (null clears it when the sidecar was removed)
+export function updateVideoSidecar(id: string, sidecar: SidecarMetadata | null): void {
+  const db = getDatabase();
+  db.prepare(`
+    UPDATE videos
+    SET sidecar_title = ?, sidecar_description = ?, sidecar_keywords = ?, sidecar_mtime = ?
+    WHERE id = ?
+  `).run(
+    sidecar?.title ?? null,
+    sidecar?.description ?? null,
+    sidecar && sidecar.keywords.length > 0 ? sidecar.keywords.join(', ') : null,
+    sidecar?.mtime ?? null,
+    id
+  );
+}
+
+// Videos in Recently Removed are left alone; only a purge deletes them
+export function deleteVideoByPath(filePath: string): boolean {
+  const db = getDatabase();
+  const forms = getStoredPathForms(filePath);
+  return db
+    .prepare(`DELETE FROM videos WHERE file_path IN (${forms.map(() => '?').join(', ')}) AND removed_at IS NULL`)
+    .run(...forms).changes > 0;
+}
+
+// Recently Removed operations. Removing only sets removed_at, so the row keeps its
+// selection, generated files and ID until it is restored or purged.
+export const REMOVED_RETENTION_DAYS = 30;
+
+export function getRemovedVideos(): Video[] {
+  const db = getDatabase();
+  const rows = db.prepare('SELECT * FROM videos WHERE removed_at IS NOT NULL AND excluded = 0 ORDER BY removed_at DESC').all() as VideoRow[];
+  return rows.map(toVideo);
+}
+
+// Videos hidden because an excluded folder pattern covers them. They are removed like
+// the ones in Recently Removed, but aren't listed there or purged, and come back once
+// the pattern is gone.
+export function getExcludedVideos(): Video[] {
+  const db = getDatabase();
+  const rows = db.prepare('SELECT * FROM videos WHERE excluded = 1').all() as VideoRow[];
+  return rows.map(toVideo);
+}
+
+// Hide videos under an exclusion, or show them again; returns rows changed
+export function setVideosExcluded(ids: string[], excluded: boolean): number {
+  const db = getDatabase();
+  const update = excluded
+    ? db.prepare('UPDATE videos SET removed_at = ?, excluded = 1 WHERE id = ? AND removed_at IS NULL')
+    : db.prepare('UPDATE videos SET removed_at = NULL, excluded = 0 WHERE id = ? AND excluded = 1');
+  const removedAt = new Date().toISOString();
+  return db.transaction(() => ids.reduce(
+    (changed, id) => changed + (excluded ? update.run(removedAt, id) : update.run(id)).changes,
+    0
+  ))();
+}
+
+// Move videos into Recently Removed, or back into the catalog; returns rows changed
+export function setVideosRemoved(ids: string[], removed: boolean): number {
+  const db = getDatabase();
+  const update = removed
+    ? db.prepare('UPDATE videos SET removed_at = ? WHERE id = ? AND removed_at IS NULL')
+    : db.prepare('UPDATE videos SET removed_at = NULL WHERE id = ? AND removed_at IS NOT NULL AND excluded = 0');
+  const removedAt = new Date().toISOString();
+  return db.transaction(() => ids.reduce(
+    (changed, id) => changed + (removed ? update.run(removedAt, id) : update.run(id)).changes,
+    0
+  ))();
+}
+
+// Delete removed videos for good: the row and its selection (through the foreign key
+// cascade). Returns the IDs purged; removed.ts deletes their generated files.
+export function purgeRemovedVideos(ids: string[]): string[] {
+  const db = getDatabase();
+  const remove = db.prepare('DELETE FROM videos WHERE id = ? AND removed_at IS NOT NULL AND excluded = 0');
+  return db.transaction(() => ids.filter((id) => remove.run(id).changes > 0))();
+}
+
+// IDs removed more than REMOVED_RETENTION_DAYS ago
+export function getExpiredRemovedVideoIds(): string[] {
+  const cutoff = new Date(Date.now() - REMOVED_RETENTION_DAYS * 24 * 60 * 60 * 1000).toISOString();
+  const rows = getDatabase()
+    .prepare('SELECT id FROM videos WHERE removed_at < ? AND excluded = 0')
+    .all(cutoff) as { id: string }[];
+  return rows.map((row) => row.id);
+}
+
+export function deleteVideosByDirectory(directory: string): void {
+  const db = getDatabase();
+  const forms = getStoredPathForms(directory);
+  db.prepare(`DELETE FROM videos WHERE ${forms.map(() => 'directory LIKE ?').join(' OR ')}`)
+    .run(...forms.map((form) => `${form}%`));
+}
+
+// Selection operations. Pass a database to use a library other than the open one.
+export function getSelectionByVideoId(videoId: string, db: Database.Database = getDatabase()): Selection | null {
   const row = db.prepare('SELECT * FROM selections WHERE video_id = ?').get(videoId) as SelectionRow | undefined;
   return row ? rowToSelection(row) : null;
 }
 
-export function upsertSelection(videoId: string, isFavorite: boolean, notes: string): Selection {
-  const db = getDatabase();
+export function upsertSelection(
+  videoId: string,
+  isFavorite: boolean,
+  notes: string,
+  db: Database.Database = getDatabase()
+): Selection {
   const id = generateId(`selection-${videoId}`);
   const createdAt = new Date().toISOString();
 
@@ -357,9 +1123,61 @@ export function upsertSelection(videoId: string, isFavorite: boolean, notes: str
     ON CONFLICT(video_id) DO UPDATE SET is_favorite = ?, notes = ?
   `).run(id, videoId, isFavorite ? 1 : 0, notes, createdAt, isFavorite ? 1 : 0, notes);
 
+  return getSelectionByVideoId(videoId, db)!;
+}
+
+// Mark a video watched now, or clear it; favorites and notes are untouched
+export function setVideoWatched(videoId: string, watched: boolean): Selection {
+  const db = getDatabase();
+  const id = generateId(`selection-${videoId}`);
+  const now = new Date().toISOString();
+  const watchedAt = watched ? now : null;
+
+  db.prepare(`
+    INSERT INTO selections (id, video_id, is_favorite, notes, created_at, watched_at)
+    VALUES (?, ?, 0, '', ?, ?)
+    ON CONFLICT(video_id) DO UPDATE SET watched_at = ?
+  `).run(id, videoId, now, watchedAt, watchedAt);
+
   return getSelectionByVideoId(videoId)!;
 }
 
+// Mark many videos at once (e.g. everything in the current view)
+export function setVideosWatched(videoIds: string[], watched: boolean): Selection[] {
+  const db = getDatabase();
+  return db.transaction(() => videoIds.map((videoId) => setVideoWatched(videoId, watched)))();
+}
+
+// Set or clear the color label of many videos at once; favorites, notes and watched are untouched
+export function setVideosColorLabel(videoIds: string[], label: ColorLabel | null): Selection[] {
+  const db = getDatabase();
+  const now = new Date().toISOString();
+  const upsert = db.prepare(`
+    INSERT INTO selections (id, video_id, is_favorite, notes, created_at, color_label)
+    VALUES (?, ?, 0, '', ?, ?)
+    ON CONFLICT(video_id) DO UPDATE SET color_label = ?
+  `);
+  return db.transaction(() => videoIds.map((videoId) => {
+    upsert.run(generateId(`selection-${videoId}`), videoId, now, label, label);
+    return getSelectionByVideoId(videoId)!;
+  }))();
+}
+
+// Rate many videos at once in one transaction (null clears the rating)
+export function setVideosRating(videoIds: string[], rating: number | null): Selection[] {
+  const db = getDatabase();
+  const now = new Date().toISOString();
+  const upsert = db.prepare(`
+    INSERT INTO selections (id, video_id, is_favorite, notes, created_at, rating)
+    VALUES (?, ?, 0, '', ?, ?)
+    ON CONFLICT(video_id) DO UPDATE SET rating = ?
+  `);
+  return db.transaction(() => videoIds.map((videoId) => {
+    upsert.run(generateId(`selection-${videoId}`), videoId, now, rating, rating);
+    return getSelectionByVideoId(videoId)!;
+  }))();
+}
+
 export function getFavorites(): Selection[] {
   const db = getDatabase();
   const rows = db.prepare('SELECT * FROM selections WHERE is_favorite = 1').all() as SelectionRow[];
@@ -429,6 +1247,38 @@ export function getNextQueuedJob(): ProxyJob | null {
   return row ? rowToProxyJob(row) : null;
 }
 
+// Shared file name operations
+// File names used by more than one video (every camera card starts at C0001.MP4), with
+// how many videos use each. Kept until another library is opened or the catalog changes
+// (scans, the watcher, removing and restoring).
+let duplicateFileNames: Map<string, number> | null = null;
+
+export function getDuplicateFileNames(): Map<string, number> {
+  if (!duplicateFileNames) {
+    const rows = getDatabase().prepare(`
+      SELECT file_name, COUNT(*) AS count
+      FROM videos
+      WHERE removed_at IS NULL
+      GROUP BY file_name
+      HAVING COUNT(*) > 1
+    `).all() as { file_name: string; count: number }[];
+    duplicateFileNames = new Map(rows.map((row): [string, number] => [row.file_name, row.count]));
+  }
+  return duplicateFileNames;
+}
+
+export function clearDuplicateFileNames(): void {
+  duplicateFileNames = null;
+}
+
+// Paths of every video with this file name, fetched when a card's badge is hovered
+export function getPathsWithFileName(fileName: string): string[] {
+  const rows = getDatabase()
+    .prepare('SELECT file_path FROM videos WHERE file_name = ? AND removed_at IS NULL ORDER BY file_path')
+    .all(fileName) as { file_path: string }[];
+  return rows.map((row) => (currentRootPath ? resolveLibraryPath(currentRootPath, row.file_path) : row.file_path));
+}
+
 // Settings operations
 export function getSetting(key: string): string | null {
   const db = getDatabase();
@@ -442,15 +1292,15 @@ export function setSetting(key: string, value: string): void {
 }
 
 // Scan session operations
-export function createScan(rootPath: string): string {
+export function createScan(rootPath: string, scanPath: string = rootPath): string {
   const db = getDatabase();
   const id = generateId(`scan-${rootPath}-${Date.now()}`);
   const startedAt = new Date().toISOString();
 
   db.prepare(`
-    INSERT INTO scans (id, root_path, status, videos_found, started_at)
-    VALUES (?, ?, 'scanning', 0, ?)
-  `).run(id, rootPath, startedAt);
+    INSERT INTO scans (id, root_path, status, videos_found, started_at, scan_path)
+    VALUES (?, ?, 'scanning', 0, ?, ?)
+  `).run(id, rootPath, startedAt, scanPath !== rootPath ? scanPath : null);
 
   return id;
 }
@@ -460,20 +1310,67 @@ export function updateScanProgress(id: string, videosFound: number): void {
   db.prepare('UPDATE scans SET videos_found = ? WHERE id = ?').run(videosFound, id);
 }
 
-export function completeScan(id: string, videosFound: number): void {
+// Counts gathered while a scan runs, saved when it finishes
+export interface ScanResultDetails {
+  counts: ScanCounts;
+  folders: Record<string, ScanCounts>;
+  errors: string[];
+}
+
+function finishScan(id: string, status: 'complete' | 'error', error: string | null, details?: ScanResultDetails): void {
   const db = getDatabase();
   db.prepare(`
-    UPDATE scans SET status = 'complete', videos_found = ?, completed_at = ?
+    UPDATE scans SET
+      status = ?, completed_at = ?, error = ?,
+      added_count = ?, updated_count = ?, skipped_count = ?, removed_count = ?, error_count = ?,
+      folder_stats = ?, errors = ?
     WHERE id = ?
-  `).run(videosFound, new Date().toISOString(), id);
+  `).run(
+    status,
+    new Date().toISOString(),
+    error,
+    details?.counts.added ?? 0,
+    details?.counts.updated ?? 0,
+    details?.counts.skipped ?? 0,
+    details?.counts.removed ?? 0,
+    details?.counts.errored ?? 0,
+    details ? JSON.stringify(details.folders) : null,
+    details ? JSON.stringify(details.errors) : null,
+    id
+  );
+}
+
+export function completeScan(id: string, videosFound: number, details: ScanResultDetails): void {
+  updateScanProgress(id, videosFound);
+  finishScan(id, 'complete', null, details);
+}
+
+export function failScan(id: string, error: string, details?: ScanResultDetails): void {
+  finishScan(id, 'error', error, details);
+}
+
+// Scans still marked as running. Only one scan runs per server, so when none is
+// active these are scans that died mid-way (process killed, drive dropped).
+export function getInterruptedScan(): ScanReport | null {
+  const db = getDatabase();
+  const row = db.prepare(`SELECT * FROM scans WHERE status = 'scanning' ORDER BY started_at DESC LIMIT 1`).get() as ScanRow | undefined;
+  return row ? rowToScanReport(row) : null;
 }
 
-export function failScan(id: string, error: string): void {
+// Close out interrupted scans so they show as failed in the scan history
+export function closeInterruptedScans(): void {
   const db = getDatabase();
   db.prepare(`
-    UPDATE scans SET status = 'error', completed_at = ?
-    WHERE id = ?
-  `).run(new Date().toISOString(), id);
+    UPDATE scans SET status = 'error', error = 'Interrupted', completed_at = ?
+    WHERE status = 'scanning'
+  `).run(new Date().toISOString());
+}
+
+// Most recent scans first
+export function getScanHistory(limit: number = 20): ScanReport[] {
+  const db = getDatabase();
+  const rows = db.prepare('SELECT * FROM scans ORDER BY started_at DESC LIMIT ?').all(limit) as ScanRow[];
+  return rows.map(rowToScanReport);
 }
 
 export function getScanStatus(id: string): { status: string; videosFound: number } | null {
@@ -481,3 +1378,140 @@ export function getScanStatus(id: string): { status: string; videosFound: number
   const row = db.prepare('SELECT status, videos_found FROM scans WHERE id = ?').get(id) as { status: string; videos_found: number } | undefined;
   return row ? { status: row.status, videosFound: row.videos_found } : null;
 }
+
+// Silence detection operations. Results only count while the file's fingerprint is unchanged.
+export function saveSilenceAnalysis(videoId: string, fileHash: string | null, hasAudio: boolean, ranges: SilenceRange[]): void {
+  const db = getDatabase();
+  db.transaction(() => {
+    db.prepare('DELETE FROM silence_analyses WHERE video_id = ?').run(videoId);
+    db.prepare('INSERT INTO silence_analyses (video_id, file_hash, has_audio, analyzed_at) VALUES (?, ?, ?, ?)')
+      .run(videoId, fileHash, hasAudio ? 1 : 0, new Date().toISOString());
+    const insertRange = db.prepare('INSERT INTO silence_ranges (video_id, start_time, end_time) VALUES (?, ?, ?)');
+    for (const range of ranges) {
+      insertRange.run(videoId, range.start, range.end);
+    }
+  })();
+}
+
+export function getSilenceReport(videoId: string): SilenceReport | null {
+  const db = getDatabase();
+  const row = db.prepare(`
+    SELECT a.has_audio, a.analyzed_at FROM silence_analyses a
+    JOIN videos v ON v.id = a.video_id
+    WHERE a.video_id = ? AND a.file_hash IS v.file_hash
+  `).get(videoId) as { has_audio: number; analyzed_at: string } | undefined;
+  if (!row) return null;
+
+  const ranges = db.prepare('SELECT start_time, end_time FROM silence_ranges WHERE video_id = ? ORDER BY start_time')
+    .all(videoId) as { start_time: number; end_time: number }[];
+  return {
+    hasAudio: row.has_audio === 1,
+    analyzedAt: row.analyzed_at,
+    ranges: ranges.map((range) => ({ start: range.start_time, end: range.end_time })),
+  };
+}
+
+// Longest silent range per analyzed video (0 if none), for the card badges
+export function getLongestSilences(): Map<string, number> {
+  const db = getDatabase();
+  const rows = db.prepare(`
+    SELECT a.video_id, COALESCE(MAX(r.end_time - r.start_time), 0) AS longest
+    FROM silence_analyses a
+    JOIN videos v ON v.id = a.video_id AND a.file_hash IS v.file_hash
+    LEFT JOIN silence_ranges r ON r.video_id = a.video_id
+    GROUP BY a.video_id
+  `).all() as { video_id: string; longest: number }[];
+  return new Map(rows.map((row) => [row.video_id, row.longest]));
+}
+
+// Directory stats operations
+export function replaceDirectoryStats(stats: DirectoryStats[]): void {
+  const db = getDatabase();
+  const insert = db.prepare('INSERT INTO directory_stats (directory, video_count, total_duration, total_size) VALUES (?, ?, ?, ?)');
+  db.transaction(() => {
+    db.prepare('DELETE FROM directory_stats').run();
+    for (const entry of stats) {
+      insert.run(entry.directory, entry.videoCount, entry.totalDuration, entry.totalSize);
+    }
+  })();
+}
+
+export function getDirectoryStats(): DirectoryStats[] {
+  const db = getDatabase();
+  const rows = db.prepare('SELECT * FROM directory_stats ORDER BY directory').all() as {
+    directory: string;
+    video_count: number;
+    total_duration: number;
+    total_size: number;
+  }[];
+  return rows.map((row) => ({
+    directory: row.directory,
+    videoCount: row.video_count,
+    totalDuration: row.total_duration,
+    totalSize: row.total_size,
+  }));
+}
+
+// Selection set operations
+export function getSelectionSets(): SelectionSet[] {
+  const db = getDatabase();
+  const sets = db.prepare('SELECT * FROM selection_sets ORDER BY created_at').all() as { id: string; name: string; created_at: string }[];
+  const members = db.prepare('SELECT set_id, video_id FROM selection_set_videos ORDER BY set_id, position').all() as { set_id: string; video_id: string }[];
+
+  const videoIds = new Map<string, string[]>();
+  for (const member of members) {
+    const ids = videoIds.get(member.set_id) ?? [];
+    ids.push(member.video_id);
+    videoIds.set(member.set_id, ids);
+  }
+  return sets.map((set) => ({ id: set.id, name: set.name, videoIds: videoIds.get(set.id) ?? [], createdAt: set.created_at }));
+}
+
+export function createSelectionSet(name: string, videoIds: string[]): SelectionSet {
+  const db = getDatabase();
+  const set: SelectionSet = { id: crypto.randomUUID(), name, videoIds: [...new Set(videoIds)], createdAt: new Date().toISOString() };
+  const insertMember = db.prepare('INSERT INTO selection_set_videos (set_id, video_id, position) VALUES (?, ?, ?)');
+  db.transaction(() => {
+    db.prepare('INSERT INTO selection_sets (id, name, created_at) VALUES (?, ?, ?)').run(set.id, set.name, set.createdAt);
+    set.videoIds.forEach((videoId, position) => insertMember.run(set.id, videoId, position));
+  })();
+  return set;
+}
+
+// Append videos to a set, skipping ones already in it; returns how many were added, or
+// null if the set doesn't exist
+export function addToSelectionSet(id: string, videoIds: string[]): number | null {
+  const db = getDatabase();
+  if (!db.prepare('SELECT 1 FROM selection_sets WHERE id = ?').get(id)) return null;
+
+  const insertMember = db.prepare('INSERT OR IGNORE INTO selection_set_videos (set_id, video_id, position) VALUES (?, ?, ?)');
+  return db.transaction(() => {
+    const { next } = db.prepare('SELECT COALESCE(MAX(position) + 1, 0) AS next FROM selection_set_videos WHERE set_id = ?')
+      .get(id) as { next: number };
+    let added = 0;
+    for (const videoId of new Set(videoIds)) {
+      added += insertMember.run(id, videoId, next + added).changes;
+    }
+    return added;
+  })();
+}
+
+export function renameSelectionSet(id: string, name: string): boolean {
+  const db = getDatabase();
+  return db.prepare('UPDATE selection_sets SET name = ? WHERE id = ?').run(name, id).changes > 0;
+}
+
+export function deleteSelectionSet(id: string): boolean {
+  const db = getDatabase();
+  return db.prepare('DELETE FROM selection_sets WHERE id = ?').run(id).changes > 0;
+}
+
+// Split a set's members into videos still in the catalog and the number that are gone
+export function resolveSelectionSet(id: string): { set: SelectionSet; videoIds: string[]; missing: number } | null {
+  const set = getSelectionSets().find((s) => s.id === id);
+  if (!set) return null;
+  const db = getDatabase();
+  const exists = db.prepare('SELECT 1 FROM videos WHERE id = ? AND removed_at IS NULL');
+  const videoIds = set.videoIds.filter((videoId) => exists.get(videoId));
+  return { set, videoIds, missing: set.videoIds.length - videoIds.length };
+}
@@ -0,0 +1,59 @@
+import path from 'path';
+import { DirectoryStats } from './types';
+import { getAllVideos, getSetting, isOutsideRoot, replaceDirectoryStats, setSetting } from './db';
+
+// Per-folder state for folder views (server-side only). Totals are cached in
+// directory_stats so opening a library doesn't add up every video again; they're
+// rebuilt at the end of each scan, so changes picked up by the watcher show after the
+// next one. Which folders are collapsed is a JSON map in the library's settings,
+// keyed like the stats (relative to the library root) so portable libraries keep it.
+
+const COLLAPSED_SETTING = 'collapsed_directories';
+
+export function getCollapsedDirectories(): Record<string, boolean> {
+  try {
+    const parsed = JSON.parse(getSetting(COLLAPSED_SETTING) ?? '{}');
+    return parsed && typeof parsed === 'object' && !Array.isArray(parsed) ? parsed : {};
+  } catch {
+    return {};
+  }
+}
+
+export function setDirectoryCollapsed(directory: string, collapsed: boolean): void {
+  const state = getCollapsedDirectories();
+  if (collapsed) {
+    state[directory] = true;
+  } else {
+    delete state[directory];
+  }
+  setSetting(COLLAPSED_SETTING, JSON.stringify(state));
+}
+
+// Recompute totals for every folder that holds videos (directly or below it), and drop
+// collapse state for folders that no longer do, e.g. after a rename
+export function refreshDirectoryStats(rootPath: string): void {
+  const totals = new Map<string, DirectoryStats>();
+
+  for (const video of getAllVideos()) {
+    let directory = path.relative(rootPath, video.directory);
+    // Count the video in its folder and each folder above it, up to the root ('')
+    for (;;) {
+      const entry = totals.get(directory) ?? { directory, videoCount: 0, totalDuration: 0, totalSize: 0 };
+      entry.videoCount++;
+      entry.totalDuration += video.duration;
+      entry.totalSize += video.fileSize;
+      totals.set(directory, entry);
+      if (directory === '' || isOutsideRoot(directory)) break;
+      const parent = path.dirname(directory);
+      directory = parent === '.' ? '' : parent;
+    }
+  }
+  replaceDirectoryStats([...totals.values()]);
+
+  const collapsed = getCollapsedDirectories();
+  const stale = Object.keys(collapsed).filter((directory) => !totals.has(directory));
+  if (stale.length > 0) {
+    stale.forEach((directory) => delete collapsed[directory]);
+    setSetting(COLLAPSED_SETTING, JSON.stringify(collapsed));
+  }
+}
@@ -0,0 +1,19 @@
+import path from 'path';
+import { getSetting, setSetting, isDatabaseInitialized } from './db';
+
+// Whether the card's "Copy filename" includes the parent folder for names that aren't
+// unique in the library (e.g. "CARD3/C0001.MP4"), so EDL notes stay unambiguous
+const COPY_WITH_FOLDER_SETTING = 'copy_duplicate_names_with_folder';
+
+export function isCopyWithFolderEnabled(): boolean {
+  return isDatabaseInitialized() && getSetting(COPY_WITH_FOLDER_SETTING) === 'true';
+}
+
+export function setCopyWithFolderEnabled(enabled: boolean): void {
+  setSetting(COPY_WITH_FOLDER_SETTING, enabled ? 'true' : 'false');
+}
+
+// Parent folder and name, with / whatever the platform
+export function getDisambiguatedName(filePath: string): string {
+  return `${path.basename(path.dirname(filePath))}/${path.basename(filePath)}`;
+}
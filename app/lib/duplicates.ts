@@ -0,0 +1,228 @@
+import fs from 'fs/promises';
+import { createReadStream } from 'fs';
+import os from 'os';
+import path from 'path';
+import crypto from 'crypto';
+import { getAllVideos, getReadableVideos, getSelectionByVideoId, getCurrentRootPath, deleteVideoByPath, clearDuplicateFileNames } from './db';
+import { deleteVideoAssets } from './ffmpeg';
+import { getAssetVersion } from './assetCache';
+import { removeVideos } from './removed';
+import { DuplicateGroup, DuplicateReport, Video, VideoWithSelection } from './types';
+
+// Duplicate detection and cleanup (server-side only)
+
+// Bytes hashed from each end of a file when comparing same-size candidates
+const SAMPLE_BYTES = 65536;
+
+// Hash of file size plus the first and last 64KB. Unlike the scan fingerprint
+// it ignores mtime, so copies of the same file match.
+async function getContentHash(filePath: string, size: number): Promise<string> {
+  const fd = await fs.open(filePath, 'r');
+  try {
+    const head = Buffer.alloc(Math.min(SAMPLE_BYTES, size));
+    const tail = Buffer.alloc(Math.min(SAMPLE_BYTES, size));
+    await fd.read(head, 0, head.length, 0);
+    await fd.read(tail, 0, tail.length, Math.max(0, size - tail.length));
+    return crypto.createHash('sha1').update(String(size)).update(head).update(tail).digest('hex');
+  } finally {
+    await fd.close();
+  }
+}
+
+// SHA-1 of the whole file, read as a stream. Only run before trashing a copy, where a
+// sampled match isn't enough.
+function getFullHash(filePath: string): Promise<string> {
+  return new Promise((resolve, reject) => {
+    const hash = crypto.createHash('sha1');
+    createReadStream(filePath)
+      .on('error', reject)
+      .on('data', (chunk) => hash.update(chunk))
+      .on('end', () => resolve(hash.digest('hex')));
+  });
+}
+
+// Whether a copy that stays in the catalog has exactly the same bytes as the one being
+// trashed. Copies are hashed lazily and at most once per removeDuplicates call.
+async function hasIdenticalKeptCopy(
+  video: Video,
+  keptCopies: Video[],
+  fullHashes: Map<string, Promise<string | null>>
+): Promise<boolean> {
+  const hashOf = (filePath: string) => {
+    if (!fullHashes.has(filePath)) {
+      fullHashes.set(filePath, getFullHash(filePath).catch(() => null));
+    }
+    return fullHashes.get(filePath)!;
+  };
+
+  const hash = await hashOf(video.filePath);
+  if (hash === null) return false;
+  for (const kept of keptCopies) {
+    if (kept.fileSize === video.fileSize && (await hashOf(kept.filePath)) === hash) return true;
+  }
+  return false;
+}
+
+function withSelection(video: Video): VideoWithSelection {
+  return {
+    ...video,
+    selection: getSelectionByVideoId(video.id) || undefined,
+    thumbnailVersion: getAssetVersion(video.thumbnailPath),
+    spriteVersion: getAssetVersion(video.spritePath),
+  };
+}
+
+function groupBy<T>(items: T[], key: (item: T) => string | null): T[][] {
+  const groups = new Map<string, T[]>();
+  for (const item of items) {
+    const k = key(item);
+    if (k === null) continue;
+    groups.set(k, [...(groups.get(k) || []), item]);
+  }
+  return [...groups.values()].filter((group) => group.length > 1);
+}
+
+// Oldest copy first; everything after it is reclaimable
+function toDuplicateGroup(key: string, videos: Video[]): DuplicateGroup {
+  const sorted = [...videos].sort((a, b) => a.createdAt.localeCompare(b.createdAt));
+  const wastedBytes = sorted.slice(1).reduce((sum, v) => sum + v.fileSize, 0);
+  return { key, videos: sorted.map(withSelection), wastedBytes };
+}
+
+// Exact duplicates share size and sampled content; possible duplicates share only duration and resolution
+export async function findDuplicates(): Promise<DuplicateReport> {
+  const { videos, onlineOnly } = getReadableVideos();
+
+  const exact: DuplicateGroup[] = [];
+  const exactIds = new Set<string>();
+  for (const sameSize of groupBy(videos, (v) => String(v.fileSize))) {
+    const hashed = await Promise.all(
+      sameSize.map(async (video) => {
+        try {
+          return { video, hash: await getContentHash(video.filePath, video.fileSize) };
+        } catch {
+          // Missing or unreadable file
+          return { video, hash: null };
+        }
+      })
+    );
+
+    for (const group of groupBy(hashed, (h) => h.hash)) {
+      exact.push(toDuplicateGroup(group[0].hash!, group.map((h) => h.video)));
+      group.forEach((h) => exactIds.add(h.video.id));
+    }
+  }
+
+  const possible = groupBy(
+    videos.filter((v) => !exactIds.has(v.id) && v.duration > 0),
+    (v) => `${Math.round(v.duration)}:${v.width}x${v.height}`
+  ).map((group) => toDuplicateGroup(`${Math.round(group[0].duration)}:${group[0].width}x${group[0].height}`, group));
+
+  const byWaste = (a: DuplicateGroup, b: DuplicateGroup) => b.wastedBytes - a.wastedBytes;
+  exact.sort(byWaste);
+  possible.sort(byWaste);
+
+  return {
+    exact,
+    possible,
+    reclaimableBytes: exact.reduce((sum, group) => sum + group.wastedBytes, 0),
+    skippedOnlineOnly: onlineOnly,
+  };
+}
+
+// Pick a name in dir that doesn't collide with an existing file
+async function getFreeName(dir: string, name: string): Promise<string> {
+  const ext = path.extname(name);
+  const stem = path.basename(name, ext);
+  for (let i = 0; ; i++) {
+    const candidate = i === 0 ? name : `${stem} ${i}${ext}`;
+    try {
+      await fs.access(path.join(dir, candidate));
+    } catch {
+      return candidate;
+    }
+  }
+}
+
+// Move a file to the user's trash (macOS ~/.Trash, freedesktop trash on Linux)
+export async function moveToTrash(filePath: string): Promise<void> {
+  if (process.platform === 'darwin') {
+    const trashDir = path.join(os.homedir(), '.Trash');
+    const name = await getFreeName(trashDir, path.basename(filePath));
+    await fs.rename(filePath, path.join(trashDir, name));
+    return;
+  }
+
+  if (process.platform === 'linux') {
+    const trashDir = path.join(process.env.XDG_DATA_HOME || path.join(os.homedir(), '.local', 'share'), 'Trash');
+    await fs.mkdir(path.join(trashDir, 'files'), { recursive: true });
+    await fs.mkdir(path.join(trashDir, 'info'), { recursive: true });
+    const name = await getFreeName(path.join(trashDir, 'files'), path.basename(filePath));
+    const info = `[Trash Info]\nPath=${encodeURI(filePath)}\nDeletionDate=${new Date().toISOString().slice(0, 19)}\n`;
+    const infoPath = path.join(trashDir, 'info', `${name}.trashinfo`);
+    await fs.writeFile(infoPath, info);
+    try {
+      await fs.rename(filePath, path.join(trashDir, 'files', name));
+    } catch (error) {
+      // Without the file, the info would list a trashed file that doesn't exist
+      await fs.rm(infoPath, { force: true });
+      throw error;
+    }
+    return;
+  }
+
+  throw new Error(`Moving to trash is not supported on ${process.platform}`);
+}
+
+// Remove videos from the catalog into Recently Removed, or move their files to the trash
+// and drop them for good
+export async function removeDuplicates(
+  videoIds: string[],
+  trashFiles: boolean
+): Promise<{ removed: number; errors: string[] }> {
+  const videos = getAllVideos();
+  const byId = new Map(videos.map((v) => [v.id, v]));
+  const removeSet = new Set(videoIds);
+  // Hashing an online-only copy would download it, so it can't vouch for a trashed one
+  const keptCopies = videos.filter((v) => !removeSet.has(v.id) && !v.onlineOnly);
+  const fullHashes = new Map<string, Promise<string | null>>();
+  const errors: string[] = [];
+  let removed = 0;
+
+  for (const id of videoIds) {
+    const video = byId.get(id);
+    if (!video) continue;
+
+    if (!trashFiles) {
+      // Kept in Recently Removed, where it can be restored
+      removed += removeVideos([video.id]);
+      continue;
+    }
+
+    // The group was found by sampled bytes; never trash a file unless a copy we keep is
+    // byte-for-byte the same
+    if (!(await hasIdenticalKeptCopy(video, keptCopies, fullHashes))) {
+      errors.push(`${video.fileName}: no identical copy is being kept`);
+      continue;
+    }
+
+    try {
+      await moveToTrash(video.filePath);
+    } catch (error) {
+      // Keep the catalog entry when the file could not be moved
+      const reason = error instanceof Error && 'code' in error && error.code === 'EXDEV'
+        ? 'file is on a different drive than the trash'
+        : error instanceof Error ? error.message : String(error);
+      errors.push(`${video.fileName}: ${reason}`);
+      continue;
+    }
+
+    deleteVideoByPath(video.filePath);
+    const rootPath = getCurrentRootPath();
+    if (rootPath) await deleteVideoAssets(rootPath, video.id);
+    removed++;
+  }
+  if (trashFiles && removed > 0) clearDuplicateFileNames();
+
+  return { removed, errors };
+}
@@ -0,0 +1,173 @@
+import path from 'path';
+import { getSetting, isOutsideRoot, setSetting } from './db';
+import { withLibraryDatabase } from './globalSearch';
+import { reloadWatcherExclusions } from './watcher';
+
+// Per-library excluded folders (server-side only), kept as a JSON array of glob patterns
+// in the settings table. These are on top of the built-in skip rules (hidden and system folders).
+//
+// Patterns are matched against paths relative to the library root, with / separators:
+// - without a slash, against any single file or folder name: `Renders`, `*.tmp`
+// - with a slash, against the path from the root: `Archive/2019`, `Clients/*/Exports`
+// `*` and `?` stay within one name, `**` spans folders, `[abc]` and `{a,b}` work as usual.
+// Excluding a folder excludes everything under it.
+
+const EXCLUSIONS_SETTING = 'excluded_folders';
+
+// Returns whether a path inside the library is excluded
+export type ExclusionMatcher = (fullPath: string) => boolean;
+
+function parseExclusions(value: string | null | undefined): string[] {
+  if (!value) return [];
+  try {
+    const patterns = JSON.parse(value);
+    return Array.isArray(patterns) ? patterns.filter((p): p is string => typeof p === 'string') : [];
+  } catch {
+    return [];
+  }
+}
+
+// Trim, drop a leading ./ or / (patterns are always relative to the root) and a trailing /
+function normalizePattern(pattern: string): string {
+  return pattern.trim().replace(/\\/g, '/').replace(/^\.?\/+/, '').replace(/\/+$/, '');
+}
+
+// Translate one glob to an anchored RegExp; throws with the reason on invalid syntax
+function globToRegExp(glob: string): RegExp {
+  let source = '';
+  let braceDepth = 0;
+
+  for (let i = 0; i < glob.length; i++) {
+    const char = glob[i];
+    switch (char) {
+      case '*':
+        if (glob[i + 1] === '*') {
+          const atStart = i === 0 || glob[i - 1] === '/';
+          const atEnd = i + 2 === glob.length || glob[i + 2] === '/';
+          if (!atStart || !atEnd) {
+            throw new Error('"**" must be a whole path segment, e.g. "a/**/b"');
+          }
+          if (glob[i + 2] === '/') {
+            source += '(?:[^/]*/)*'; // Zero or more folders
+            i += 2;
+          } else {
+            source += '.*';
+            i += 1;
+          }
+        } else {
+          source += '[^/]*';
+        }
+        break;
+      case '?':
+        source += '[^/]';
+        break;
+      case '[': {
+        const close = glob.indexOf(']', i + 2); // A ] right after [ is literal
+        if (close === -1) throw new Error('unclosed "["');
+        let body = glob.slice(i + 1, close);
+        if (body.startsWith('!')) body = `^${body.slice(1)}`;
+        source += `[${body.replace(/\\/g, '\\\\')}]`;
+        i = close;
+        break;
+      }
+      case '{':
+        braceDepth++;
+        source += '(?:';
+        break;
+      case '}':
+        if (braceDepth === 0) throw new Error('unmatched "}"');
+        braceDepth--;
+        source += ')';
+        break;
+      case ',':
+        source += braceDepth > 0 ? '|' : ',';
+        break;
+      case '\\':
+        if (i + 1 === glob.length) throw new Error('trailing "\\"');
+        source += `\\${glob[++i]}`;
+        break;
+      default:
+        source += char.replace(/[.+^$()|]/g, '\\$&');
+    }
+  }
+
+  if (braceDepth > 0) throw new Error('unclosed "{"');
+  return new RegExp(`^${source}$`);
+}
+
+// Compile patterns for one scan, failing on the first invalid one
+export function compileExclusions(rootPath: string, patterns: string[]): ExclusionMatcher {
+  const nameRules: RegExp[] = [];
+  const pathRules: RegExp[] = [];
+
+  for (const raw of patterns) {
+    const pattern = normalizePattern(raw);
+    if (!pattern) continue;
+    try {
+      (pattern.includes('/') ? pathRules : nameRules).push(globToRegExp(pattern));
+    } catch (error) {
+      throw new Error(`Invalid exclusion pattern "${raw}": ${error instanceof Error ? error.message : error}`);
+    }
+  }
+
+  if (nameRules.length === 0 && pathRules.length === 0) {
+    return () => false;
+  }
+
+  return (fullPath: string) => {
+    const relative = path.relative(rootPath, fullPath);
+    if (!relative || isOutsideRoot(relative)) return false;
+    const segments = relative.split(path.sep);
+
+    if (segments.some((segment) => nameRules.some((rule) => rule.test(segment)))) {
+      return true;
+    }
+    // Test each ancestor so excluding a folder covers what's inside it
+    for (let i = 1; i <= segments.length; i++) {
+      const prefix = segments.slice(0, i).join('/');
+      if (pathRules.some((rule) => rule.test(prefix))) return true;
+    }
+    return false;
+  };
+}
+
+// Error message for the first invalid pattern, or null if all compile
+export function validateExclusions(patterns: string[]): string | null {
+  try {
+    compileExclusions('/', patterns);
+    return null;
+  } catch (error) {
+    return error instanceof Error ? error.message : String(error);
+  }
+}
+
+// Patterns for the open library
+export function getExclusions(): string[] {
+  return parseExclusions(getSetting(EXCLUSIONS_SETTING));
+}
+
+// Patterns for any known library, read without opening it as the current one
+export function getLibraryExclusions(rootPath: string): string[] {
+  return withLibraryDatabase(rootPath, (db) => {
+    const row = db.prepare('SELECT value FROM settings WHERE key = ?').get(EXCLUSIONS_SETTING) as { value: string } | undefined;
+    return parseExclusions(row?.value);
+  }) ?? [];
+}
+
+// Replace the open library's patterns (blank lines and duplicates dropped); throws on invalid syntax
+export function setExclusions(patterns: string[]): string[] {
+  const cleaned = Array.from(new Set(patterns.map((p) => p.trim()).filter(Boolean)));
+  const error = validateExclusions(cleaned);
+  if (error) throw new Error(error);
+  setSetting(EXCLUSIONS_SETTING, JSON.stringify(cleaned));
+  reloadWatcherExclusions();
+  return cleaned;
+}
+
+export function addExclusion(pattern: string): string[] {
+  return setExclusions([...getExclusions(), pattern]);
+}
+
+export function removeExclusion(pattern: string): string[] {
+  return setExclusions(getExclusions().filter((p) => p !== pattern.trim()));
+}
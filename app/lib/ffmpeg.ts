@@ -1,9 +1,26 @@
-import { spawn } from 'child_process';
+import { spawn as spawnProcess, ChildProcess, ChildProcessWithoutNullStreams, SpawnOptionsWithoutStdio } from 'child_process';
 import path from 'path';
 import fs from 'fs/promises';
 import { existsSync } from 'fs';
-import { FFmpegMetadata, SpriteConfig } from './types';
+import { Chapter, FFmpegMetadata, SilenceRange, SpriteConfig, SpriteDensity } from './types';
 import { getDataDir } from './db';
+import { invalidateAssetVersion } from './assetCache';
+
+// Windows paths of MAX_PATH (260) characters or more only work in the extended-length
+// \\?\ form. Node's own fs calls add it themselves; ffmpeg and ffprobe need it passed in.
+const MAX_PATH = 260;
+
+export function toExtendedLengthPath(filePath: string): string {
+  if (process.platform !== 'win32' || filePath.length < MAX_PATH || !path.win32.isAbsolute(filePath)) return filePath;
+  if (filePath.startsWith('\\\\?\\')) return filePath;
+  const resolved = path.win32.resolve(filePath); // The \\?\ form skips normalization
+  return resolved.startsWith('\\\\') ? `\\\\?\\UNC\\${resolved.slice(2)}` : `\\\\?\\${resolved}`;
+}
+
+// Every ffmpeg/ffprobe run goes through here so long path arguments are converted
+function spawn(command: string, args: string[], options: SpawnOptionsWithoutStdio = {}): ChildProcessWithoutNullStreams {
+  return spawnProcess(command, args.map(toExtendedLengthPath), options);
+}
 
 // Get centralized proxy directory path for a given root path
 export function getProxyDir(rootPath: string): string {
@@ -17,6 +34,18 @@ export async function ensureProxyDir(rootPath: string): Promise<string> {
   return proxyDir;
 }
 
+// Delete every generated file for a video: thumbnail, sprite, proxy, waveform and
+// extracted audio tracks (${id}_audio<n>.m4a) are all named ${id}_*
+export async function deleteVideoAssets(rootPath: string, videoId: string): Promise<void> {
+  const proxyDir = getProxyDir(rootPath);
+  const names = await fs.readdir(proxyDir).catch(() => [] as string[]);
+  await Promise.all(
+    names
+      .filter((name) => name.startsWith(`${videoId}_`))
+      .map((name) => fs.rm(path.join(proxyDir, name), { force: true }))
+  );
+}
+
 // Extract video metadata using ffprobe
 export async function getVideoMetadata(filePath: string): Promise<FFmpegMetadata> {
   return new Promise((resolve, reject) => {
@@ -47,20 +76,7 @@ export async function getVideoMetadata(filePath: string): Promise<FFmpegMetadata
       }
 
       try {
-        const data = JSON.parse(stdout);
-        const videoStream = data.streams?.find((s: { codec_type: string }) => s.codec_type === 'video');
-        const format = data.format || {};
-
-        const metadata: FFmpegMetadata = {
-          duration: parseFloat(format.duration) || 0,
-          width: videoStream?.width || 0,
-          height: videoStream?.height || 0,
-          codec: videoStream?.codec_name || 'unknown',
-          frameRate: parseFrameRate(videoStream?.r_frame_rate),
-          bitRate: parseInt(format.bit_rate) || 0,
-        };
-
-        resolve(metadata);
+        resolve(toVideoMetadata(JSON.parse(stdout)));
       } catch (error) {
         reject(new Error(`Failed to parse ffprobe output: ${error}`));
       }
@@ -72,6 +88,170 @@ export async function getVideoMetadata(filePath: string): Promise<FFmpegMetadata
   });
 }
 
+// The parts of ffprobe's -show_format -show_streams JSON that metadata is read from
+interface ProbeStream {
+  codec_type: string;
+  codec_name?: string;
+  width?: number;
+  height?: number;
+  duration?: string;
+  r_frame_rate?: string;
+  avg_frame_rate?: string;
+  field_order?: string;
+  color_space?: string;
+  color_transfer?: string;
+  color_primaries?: string;
+  side_data_list?: { side_data_type?: string }[];
+  disposition?: { attached_pic?: number };
+  tags?: Record<string, string>;
+}
+
+export interface ProbeOutput {
+  streams?: ProbeStream[];
+  format?: { duration?: string; bit_rate?: string; tags?: Record<string, string> };
+}
+
+// Video metadata from parsed ffprobe output
+export function toVideoMetadata(data: ProbeOutput): FFmpegMetadata {
+  const videoStream = data.streams?.find((s) => s.codec_type === 'video');
+  const format = data.format || {};
+
+  return {
+    // MXF often only reports duration on the stream, not the container
+    duration: parseFloat(format.duration ?? '') || parseFloat(videoStream?.duration ?? '') || 0,
+    width: videoStream?.width || 0,
+    height: videoStream?.height || 0,
+    codec: videoStream?.codec_name || 'unknown',
+    frameRate: getStreamFrameRate(videoStream),
+    bitRate: parseInt(format.bit_rate ?? '') || 0,
+    creationTime: format.tags?.creation_time || videoStream?.tags?.creation_time || null,
+    videoStreamCount: countStreams(data.streams || [], 'video'),
+    audioTrackCount: countStreams(data.streams || [], 'audio'),
+    ...getStreamColor(videoStream),
+    ...getLocation({ ...videoStream?.tags, ...format.tags }),
+  };
+}
+
+// Chapter markers in start order; empty for files without any
+export async function getChapters(filePath: string): Promise<Chapter[]> {
+  return new Promise((resolve, reject) => {
+    const args = [
+      '-v', 'quiet',
+      '-print_format', 'json',
+      '-show_chapters',
+      filePath
+    ];
+
+    const ffprobe = spawn('ffprobe', args);
+    // Decoded once at the end: titles aren't always valid UTF-8, and decoding per chunk
+    // would also split multi-byte characters. Invalid bytes become U+FFFD.
+    const chunks: Buffer[] = [];
+    let stderr = '';
+
+    ffprobe.stdout.on('data', (data: Buffer) => {
+      chunks.push(data);
+    });
+
+    ffprobe.stderr.on('data', (data) => {
+      stderr += data.toString();
+    });
+
+    ffprobe.on('close', (code) => {
+      if (code !== 0) {
+        reject(new Error(`ffprobe exited with code ${code}: ${stderr}`));
+        return;
+      }
+
+      try {
+        const data = JSON.parse(Buffer.concat(chunks).toString('utf-8'));
+        const chapters: Chapter[] = (data.chapters || [])
+          .map((chapter: { start_time?: string; end_time?: string; tags?: { title?: string } }) => ({
+            start: parseFloat(chapter.start_time ?? '') || 0,
+            end: parseFloat(chapter.end_time ?? '') || 0,
+            title: chapter.tags?.title?.trim() || null,
+          }))
+          .sort((a: Chapter, b: Chapter) => a.start - b.start);
+        resolve(chapters);
+      } catch (error) {
+        reject(new Error(`Failed to parse ffprobe output: ${error}`));
+      }
+    });
+
+    ffprobe.on('error', (error) => {
+      reject(new Error(`Failed to start ffprobe: ${error.message}`));
+    });
+  });
+}
+
+// Number of streams of a type (cover art is reported as a video stream, so skip attached pictures)
+function countStreams(streams: { codec_type: string; disposition?: { attached_pic?: number } }[], type: 'video' | 'audio'): number {
+  return streams.filter((s) => s.codec_type === type && !s.disposition?.attached_pic).length;
+}
+
+// Display names for the ffprobe color values we see most; others are stored as reported
+const COLOR_SPACE_NAMES: Record<string, string> = {
+  bt709: 'Rec. 709',
+  bt2020nc: 'Rec. 2020',
+  bt2020c: 'Rec. 2020',
+  bt2020: 'Rec. 2020',
+  smpte170m: 'Rec. 601',
+  bt470bg: 'Rec. 601',
+};
+
+const HDR_TRANSFERS: Record<string, string> = {
+  smpte2084: 'HDR10',
+  'arib-std-b67': 'HLG',
+};
+
+// Log transfers ffprobe can name; most cameras tag log footage as Rec. 709, so this
+// only catches files that say so
+const LOG_TRANSFERS = ['log100', 'log316'];
+
+// Color space and HDR format of a video stream. Dolby Vision is only visible in the
+// stream's side data. Untagged streams get 'unknown', so they aren't probed again.
+function getStreamColor(stream: {
+  color_space?: string;
+  color_transfer?: string;
+  color_primaries?: string;
+  side_data_list?: { side_data_type?: string }[];
+} | undefined): { colorSpace: string; hdrFormat: string | null } {
+  const transfer = stream?.color_transfer ?? '';
+  const isDolbyVision = !!stream?.side_data_list?.some((d) => d.side_data_type?.includes('DOVI'));
+  const hdrFormat = isDolbyVision ? 'Dolby Vision' : HDR_TRANSFERS[transfer] ?? null;
+  if (LOG_TRANSFERS.includes(transfer)) return { colorSpace: 'Log', hdrFormat };
+
+  const space = [stream?.color_primaries, stream?.color_space]
+    .find((value) => value && value !== 'unknown' && value !== 'reserved');
+  return { colorSpace: space ? COLOR_SPACE_NAMES[space] ?? space : 'unknown', hdrFormat };
+}
+
+// GPS position from the container tags: Apple's ISO 6709 key, or the plain `location`
+// tag Android phones and drones write (e.g. "+37.7749-122.4194+012.000/"). The tag is
+// kept as written ('' when there is none, so the file isn't probed again); only decimal
+// degrees are understood.
+function getLocation(tags: Record<string, string>): { location: string; latitude: number | null; longitude: number | null } {
+  const location = (tags['com.apple.quicktime.location.ISO6709'] || tags.location || tags['location-eng'] || '').trim();
+  const match = location.match(/^([+-]\d+(?:\.\d+)?)([+-]\d+(?:\.\d+)?)/);
+  const latitude = match ? Number(match[1]) : NaN;
+  const longitude = match ? Number(match[2]) : NaN;
+  if (!(Math.abs(latitude) <= 90 && Math.abs(longitude) <= 180)) {
+    return { location, latitude: null, longitude: null };
+  }
+  return { location, latitude, longitude };
+}
+
+// Frame rate of a video stream; interlaced footage reports field rate in r_frame_rate
+function getStreamFrameRate(stream: { r_frame_rate?: string; avg_frame_rate?: string; field_order?: string } | undefined): number {
+  const rFrameRate = parseFrameRate(stream?.r_frame_rate);
+  const isInterlaced = ['tt', 'bb', 'tb', 'bt'].includes(stream?.field_order || '');
+  if (!isInterlaced || !stream?.avg_frame_rate || stream.avg_frame_rate === '0/0') {
+    return rFrameRate;
+  }
+
+  const avgFrameRate = parseFrameRate(stream.avg_frame_rate);
+  return Math.abs(rFrameRate - avgFrameRate * 2) < 0.01 ? avgFrameRate : rFrameRate;
+}
+
 // Parse frame rate from ffprobe format (e.g., "30000/1001")
 function parseFrameRate(frameRate: string | undefined): number {
   if (!frameRate) return 30;
@@ -84,6 +264,11 @@ function parseFrameRate(frameRate: string | undefined): number {
   return parseFloat(frameRate) || 30;
 }
 
+// Default thumbnail timestamp: 10% into video or 5 seconds, whichever is earlier
+export function getAutoThumbnailTime(duration: number): number {
+  return Math.min(duration * 0.1, 5);
+}
+
 // Generate a single thumbnail from video
 export async function generateThumbnail(
   inputPath: string,
@@ -95,6 +280,7 @@ export async function generateThumbnail(
       '-y',
       '-ss', String(timestamp),
       '-i', inputPath,
+      '-map', '0:v:0',
       '-vframes', '1',
       '-vf', 'scale=384:-1',
       '-q:v', '5',
@@ -118,6 +304,7 @@ export async function generateThumbnail(
         reject(new Error(`Thumbnail file not created at ${outputPath}`));
         return;
       }
+      invalidateAssetVersion(outputPath);
       resolve();
     });
 
@@ -127,12 +314,82 @@ export async function generateThumbnail(
   });
 }
 
-// Generate sprite sheet for hover scrubbing
-export async function generateSpriteSheet(
+// Decode a single frame to an in-memory JPEG (no file written). With keyframeOnly the
+// decoder skips every non-keyframe and returns the first keyframe at or after the
+// timestamp, which is far cheaper on long-GOP high-bitrate files than decoding up to it.
+export async function extractFrame(
   inputPath: string,
-  outputPath: string,
-  duration: number
-): Promise<SpriteConfig> {
+  timestamp: number,
+  width: number = 640,
+  keyframeOnly: boolean = false
+): Promise<Buffer> {
+  return new Promise((resolve, reject) => {
+    const args = [
+      ...(keyframeOnly ? ['-skip_frame', 'nokey'] : []),
+      '-ss', String(timestamp),
+      '-i', inputPath,
+      '-map', '0:v:0',
+      '-vframes', '1',
+      '-vf', `scale=${width}:-2`,
+      '-q:v', '3',
+      '-f', 'image2pipe',
+      '-vcodec', 'mjpeg',
+      'pipe:1'
+    ];
+
+    const ffmpeg = spawn('ffmpeg', args);
+    const chunks: Buffer[] = [];
+    let stderr = '';
+
+    ffmpeg.stdout.on('data', (data: Buffer) => {
+      chunks.push(data);
+    });
+
+    ffmpeg.stderr.on('data', (data) => {
+      stderr += data.toString();
+    });
+
+    ffmpeg.on('close', (code) => {
+      const frame = Buffer.concat(chunks);
+      if (code !== 0 || frame.length === 0) {
+        reject(new Error(`ffmpeg frame extraction exited with code ${code}: ${stderr}`));
+        return;
+      }
+      resolve(frame);
+    });
+
+    ffmpeg.on('error', (error) => {
+      reject(new Error(`Failed to start ffmpeg: ${error.message}`));
+    });
+  });
+}
+
+// Clips shorter than this get no sprite sheet; a few tiles add nothing over seeking the clip
+export const MIN_SPRITE_DURATION = 10;
+
+// Sprite sheet grid for a video of this length. Without a custom density the layout is
+// picked by duration, which is also used to backfill the grid of sprites generated
+// before it was stored.
+export function getSpriteLayout(duration: number, density?: SpriteDensity | null): { fps: number; config: SpriteConfig } {
+  if (density) {
+    // The target tile count spread over the whole clip, at most one frame per second
+    const totalFrames = Math.max(1, Math.min(density.targetTiles, Math.floor(duration)));
+    const columns = Math.min(density.columns, totalFrames);
+    // ffmpeg's scale and pad need even sizes
+    const width = Math.round(density.tileWidth / 2) * 2;
+    return {
+      fps: totalFrames / duration,
+      config: {
+        width,
+        height: Math.round((width * 9) / 16 / 2) * 2,
+        columns,
+        rows: Math.ceil(totalFrames / columns),
+        interval: duration / totalFrames,
+        totalFrames,
+      },
+    };
+  }
+
   // Calculate sprite configuration based on video duration
   let fps: number;
   let columns: number;
@@ -161,16 +418,36 @@ export async function generateSpriteSheet(
   }
 
   const totalFrames = Math.min(Math.ceil(duration * fps), columns * rows);
-  const interval = duration / totalFrames;
 
-  // Smaller sprite thumbnails for faster loading
-  const thumbWidth = 160;
-  const thumbHeight = 90; // 16:9 aspect ratio
+  return {
+    fps,
+    config: {
+      // Smaller sprite thumbnails for faster loading
+      width: 160,
+      height: 90, // 16:9 aspect ratio
+      columns,
+      rows,
+      interval: duration / totalFrames,
+      totalFrames,
+    },
+  };
+}
+
+// Generate sprite sheet for hover scrubbing
+export async function generateSpriteSheet(
+  inputPath: string,
+  outputPath: string,
+  duration: number,
+  density?: SpriteDensity | null
+): Promise<SpriteConfig> {
+  const { fps, config } = getSpriteLayout(duration, density);
+  const { width: thumbWidth, height: thumbHeight, columns, rows } = config;
 
   return new Promise((resolve, reject) => {
     const args = [
       '-y',
       '-i', inputPath,
+      '-map', '0:v:0',
       '-vf', `fps=${fps},scale=${thumbWidth}:${thumbHeight}:force_original_aspect_ratio=decrease,pad=${thumbWidth}:${thumbHeight}:(ow-iw)/2:(oh-ih)/2,tile=${columns}x${rows}`,
       '-frames:v', '1',
       '-q:v', '5',
@@ -195,16 +472,7 @@ export async function generateSpriteSheet(
         reject(new Error(`Sprite sheet file not created at ${outputPath}`));
         return;
       }
-
-      const config: SpriteConfig = {
-        width: thumbWidth,
-        height: thumbHeight,
-        columns,
-        rows,
-        interval,
-        totalFrames,
-      };
-
+      invalidateAssetVersion(outputPath);
       resolve(config);
     });
 
@@ -228,6 +496,8 @@ export async function generateProxy(
     const args = [
       '-y',
       '-i', inputPath,
+      '-map', '0:v:0',
+      '-map', '0:a:0?',
       '-vf', 'scale=-2:360,fps=10',  // 360p @ 10fps for smooth scrubbing
       '-c:v', 'libx265',      // H.265/HEVC for better compression
       '-crf', '28',           // More aggressive compression for RAW files
@@ -278,13 +548,87 @@ export async function generateProxy(
   });
 }
 
+// Lines of ffmpeg's stderr kept when a transcode fails (the end is where the reason is)
+const STDERR_TAIL_LINES = 12;
+
+// Error from a failed transcode; `stderrTail` is shown per item in the task manager
+export class TranscodeError extends Error {
+  constructor(message: string, public stderrTail: string) {
+    super(message);
+  }
+}
+
+// Transcode with preset arguments (between input and output). Never overwrites: the
+// caller picks a free output path. Aborting the signal kills ffmpeg.
+export async function transcodeVideo(
+  inputPath: string,
+  outputPath: string,
+  presetArgs: string[],
+  duration: number,
+  onProgress?: (progress: number) => void,
+  signal?: AbortSignal,
+  inputArgs: string[] = [] // Placed before -i, e.g. -ss/-to to read only part of the input
+): Promise<void> {
+  return new Promise((resolve, reject) => {
+    const args = [
+      '-hide_banner',
+      '-nostdin',
+      '-n',
+      ...inputArgs,
+      '-i', inputPath,
+      ...presetArgs,
+      '-progress', 'pipe:1',
+      outputPath
+    ];
+
+    const ffmpeg = spawn('ffmpeg', args, { signal });
+    let stderr = '';
+    const stderrTail = () => stderr.trimEnd().split(/\r?\n/).slice(-STDERR_TAIL_LINES).join('\n');
+
+    ffmpeg.stdout.on('data', (data) => {
+      const timeMatch = data.toString().match(/out_time_ms=(\d+)/);
+      if (timeMatch && duration > 0 && onProgress) {
+        const currentTime = parseInt(timeMatch[1]) / 1000000;
+        onProgress(Math.round(Math.min((currentTime / duration) * 100, 100)));
+      }
+    });
+
+    ffmpeg.stderr.on('data', (data) => {
+      // Only the tail is reported, so don't let a long encode's log grow unbounded
+      stderr = (stderr + data.toString()).slice(-16384);
+    });
+
+    ffmpeg.on('close', (code) => {
+      if (signal?.aborted) return; // Rejected by the 'error' event
+      if (code !== 0) {
+        reject(new TranscodeError(`ffmpeg transcode exited with code ${code}`, stderrTail()));
+        return;
+      }
+      if (!existsSync(outputPath)) {
+        reject(new TranscodeError(`Output file not created at ${outputPath}`, stderrTail()));
+        return;
+      }
+      resolve();
+    });
+
+    ffmpeg.on('error', (error) => {
+      if (signal?.aborted) {
+        reject(new TranscodeError('Cancelled', ''));
+        return;
+      }
+      reject(new TranscodeError(`Failed to start ffmpeg: ${error.message}`, ''));
+    });
+  });
+}
+
 // Generate all proxy assets (proxy video, sprite sheet, thumbnail) - runs in parallel
 export async function generateAllProxyAssets(
   videoId: string,
   inputPath: string,
   rootPath: string,
   duration: number,
-  onProgress?: (stage: string, progress: number) => void
+  onProgress?: (stage: string, progress: number) => void,
+  posterTime?: number | null
 ): Promise<{ proxyPath: string; spritePath: string; thumbnailPath: string; spriteConfig: SpriteConfig }> {
   const proxyDir = await ensureProxyDir(rootPath);
 
@@ -292,7 +636,7 @@ export async function generateAllProxyAssets(
   const spritePath = path.join(proxyDir, `${videoId}_sprite.jpg`);
   const thumbnailPath = path.join(proxyDir, `${videoId}_thumb.jpg`);
 
-  const thumbnailTime = Math.min(duration * 0.1, 5); // 10% into video or 5 seconds
+  const thumbnailTime = posterTime ?? getAutoThumbnailTime(duration);
 
   // Run thumbnail, sprite, and proxy generation in PARALLEL for better performance
   onProgress?.('all', 0);
@@ -323,11 +667,12 @@ export async function generateThumbnailOnly(
   videoId: string,
   inputPath: string,
   rootPath: string,
-  duration: number
+  duration: number,
+  posterTime?: number | null
 ): Promise<string> {
   const proxyDir = await ensureProxyDir(rootPath);
   const thumbnailPath = path.join(proxyDir, `${videoId}_thumb.jpg`);
-  const thumbnailTime = Math.min(duration * 0.1, 5);
+  const thumbnailTime = posterTime ?? getAutoThumbnailTime(duration);
   await generateThumbnail(inputPath, thumbnailPath, thumbnailTime);
   return thumbnailPath;
 }
@@ -337,14 +682,343 @@ export async function generateSpriteSheetOnly(
   videoId: string,
   inputPath: string,
   rootPath: string,
-  duration: number
+  duration: number,
+  density?: SpriteDensity | null
 ): Promise<{ spritePath: string; spriteConfig: SpriteConfig }> {
   const proxyDir = await ensureProxyDir(rootPath);
   const spritePath = path.join(proxyDir, `${videoId}_sprite.jpg`);
-  const spriteConfig = await generateSpriteSheet(inputPath, spritePath, duration);
+  const spriteConfig = await generateSpriteSheet(inputPath, spritePath, duration, density);
   return { spritePath, spriteConfig };
 }
 
+// Extractions in progress by output path. Switching tracks fires overlapping requests
+// for the same file, and two ffmpegs writing one temp file would corrupt it.
+const audioExtractions = new Map<string, Promise<string>>();
+
+// Extract one audio track to AAC for the player's track selector (cached next to the proxy)
+export async function extractAudioTrack(
+  videoId: string,
+  inputPath: string,
+  rootPath: string,
+  trackIndex: number
+): Promise<string> {
+  const proxyDir = await ensureProxyDir(rootPath);
+  const outputPath = path.join(proxyDir, `${videoId}_audio${trackIndex}.m4a`);
+  if (existsSync(outputPath)) {
+    return outputPath;
+  }
+
+  let extraction = audioExtractions.get(outputPath);
+  if (!extraction) {
+    extraction = runAudioExtraction(inputPath, outputPath, trackIndex)
+      .finally(() => audioExtractions.delete(outputPath));
+    audioExtractions.set(outputPath, extraction);
+  }
+  return extraction;
+}
+
+async function runAudioExtraction(inputPath: string, outputPath: string, trackIndex: number): Promise<string> {
+  // Write to a temp name so an interrupted extraction is never served
+  const tempPath = `${outputPath}.partial.m4a`;
+  await new Promise<void>((resolve, reject) => {
+    const args = [
+      '-y',
+      '-v', 'error',
+      '-i', inputPath,
+      '-map', `0:a:${trackIndex}`,
+      '-vn',
+      '-c:a', 'aac',
+      '-b:a', '192k',
+      '-movflags', '+faststart',
+      tempPath
+    ];
+
+    const ffmpeg = spawn('ffmpeg', args);
+    let stderr = '';
+
+    ffmpeg.stderr.on('data', (data) => {
+      stderr += data.toString();
+    });
+
+    ffmpeg.on('close', (code) => {
+      if (code !== 0) {
+        reject(new Error(`ffmpeg audio extract exited with code ${code}: ${stderr}`));
+        return;
+      }
+      resolve();
+    });
+
+    ffmpeg.on('error', (error) => {
+      reject(new Error(`Failed to start ffmpeg: ${error.message}`));
+    });
+  });
+
+  await fs.rename(tempPath, outputPath);
+  return outputPath;
+}
+
+// Sample rate the audio is decoded at for waveform peaks (plenty for ~1000 buckets)
+const PEAK_SAMPLE_RATE = 4000;
+
+// Decode the first audio stream to mono PCM and report each bucket's peak level (0-255)
+// as soon as it is complete, in order. Resolves false if the file has no audio stream.
+export async function extractAudioPeaks(
+  inputPath: string,
+  duration: number,
+  bucketCount: number,
+  onPeak: (index: number, peak: number) => void
+): Promise<boolean> {
+  const samplesPerBucket = Math.max(1, Math.ceil((duration * PEAK_SAMPLE_RATE) / bucketCount));
+
+  return new Promise((resolve, reject) => {
+    const args = [
+      '-v', 'error',
+      '-nostdin',
+      '-i', inputPath,
+      '-map', '0:a:0',
+      '-vn',
+      '-ac', '1',
+      '-ar', String(PEAK_SAMPLE_RATE),
+      '-f', 's16le',
+      'pipe:1'
+    ];
+
+    const ffmpeg = spawn('ffmpeg', args);
+    let stderr = '';
+    let leftover: Buffer | null = null; // Half a sample split across chunks
+    let index = 0;
+    let count = 0;
+    let peak = 0;
+
+    ffmpeg.stdout.on('data', (chunk: Buffer) => {
+      const data: Buffer = leftover ? Buffer.concat([leftover, chunk]) : chunk;
+      const end = data.length - (data.length % 2);
+      leftover = end < data.length ? data.subarray(end) : null;
+
+      for (let offset = 0; offset < end; offset += 2) {
+        peak = Math.max(peak, Math.abs(data.readInt16LE(offset)));
+        // Audio running past the catalogued duration piles into the last bucket
+        if (++count === samplesPerBucket && index < bucketCount - 1) {
+          onPeak(index++, Math.min(255, Math.round((peak / 32768) * 255)));
+          count = 0;
+          peak = 0;
+        }
+      }
+    });
+
+    ffmpeg.stderr.on('data', (data) => {
+      stderr += data.toString();
+    });
+
+    ffmpeg.on('close', (code) => {
+      if (code !== 0) {
+        if (/matches no streams/.test(stderr)) {
+          resolve(false);
+          return;
+        }
+        reject(new Error(`ffmpeg audio peaks exited with code ${code}: ${stderr}`));
+        return;
+      }
+      if (count > 0) {
+        onPeak(index, Math.min(255, Math.round((peak / 32768) * 255)));
+      }
+      resolve(true);
+    });
+
+    ffmpeg.on('error', (error) => {
+      reject(new Error(`Failed to start ffmpeg: ${error.message}`));
+    });
+  });
+}
+
+// Find silent stretches in the first audio stream with ffmpeg's silencedetect filter.
+// Resolves hasAudio false if the file has no audio stream. Aborting the signal kills ffmpeg.
+export async function detectSilence(
+  inputPath: string,
+  duration: number,
+  noiseDb: number,
+  minSeconds: number,
+  onProgress?: (progress: number) => void,
+  signal?: AbortSignal
+): Promise<{ hasAudio: boolean; ranges: SilenceRange[] }> {
+  return new Promise((resolve, reject) => {
+    const args = [
+      '-hide_banner',
+      '-nostdin',
+      '-i', inputPath,
+      '-map', '0:a:0',
+      '-af', `silencedetect=noise=${noiseDb}dB:d=${minSeconds}`,
+      '-f', 'null',
+      '-progress', 'pipe:1',
+      '-'
+    ];
+
+    const ffmpeg = spawn('ffmpeg', args, { signal });
+    let stderr = '';
+
+    ffmpeg.stdout.on('data', (data) => {
+      const timeMatch = data.toString().match(/out_time_ms=(\d+)/);
+      if (timeMatch && duration > 0 && onProgress) {
+        const currentTime = parseInt(timeMatch[1]) / 1000000;
+        onProgress(Math.round(Math.min((currentTime / duration) * 100, 100)));
+      }
+    });
+
+    ffmpeg.stderr.on('data', (data) => {
+      stderr += data.toString();
+    });
+
+    ffmpeg.on('close', (code) => {
+      if (signal?.aborted) return; // Rejected by the 'error' event
+      if (code !== 0) {
+        if (/matches no streams/.test(stderr)) {
+          resolve({ hasAudio: false, ranges: [] });
+          return;
+        }
+        reject(new Error(`ffmpeg silencedetect exited with code ${code}: ${stderr}`));
+        return;
+      }
+
+      const ranges: SilenceRange[] = [];
+      let start: number | null = null;
+      for (const match of stderr.matchAll(/silence_(start|end): (-?[\d.]+)/g)) {
+        const time = Math.max(0, parseFloat(match[2]));
+        if (match[1] === 'start') {
+          start = time;
+        } else if (start !== null) {
+          ranges.push({ start, end: time });
+          start = null;
+        }
+      }
+      // Silence running to the end of the file has no silence_end line
+      if (start !== null && duration > start) {
+        ranges.push({ start, end: duration });
+      }
+      resolve({ hasAudio: true, ranges });
+    });
+
+    ffmpeg.on('error', (error) => {
+      reject(signal?.aborted ? new Error('Cancelled') : new Error(`Failed to start ffmpeg: ${error.message}`));
+    });
+  });
+}
+
+// Integrated loudness (LUFS) of the first audio track, from loudnorm's measurement pass.
+// Its input_i is the file as it is (output_i would be after normalizing). Null for files
+// without audio; digital silence reports -inf and is stored as loudnorm's -70 floor.
+export async function measureLoudness(inputPath: string): Promise<number | null> {
+  return new Promise((resolve, reject) => {
+    const args = [
+      '-hide_banner',
+      '-nostdin',
+      '-i', inputPath,
+      '-map', '0:a:0',
+      '-af', 'loudnorm=print_format=json',
+      '-f', 'null',
+      '-'
+    ];
+
+    const ffmpeg = spawn('ffmpeg', args);
+    let stderr = '';
+
+    ffmpeg.stderr.on('data', (data) => {
+      stderr += data.toString();
+    });
+
+    ffmpeg.on('close', (code) => {
+      if (code !== 0) {
+        if (/matches no streams/.test(stderr)) {
+          resolve(null);
+          return;
+        }
+        reject(new Error(`ffmpeg loudnorm exited with code ${code}: ${stderr}`));
+        return;
+      }
+
+      // The JSON block is the last thing loudnorm prints
+      const json = stderr.slice(stderr.lastIndexOf('{'));
+      try {
+        const value = JSON.parse(json).input_i;
+        const lufs = parseFloat(value);
+        resolve(Number.isFinite(lufs) ? lufs : -70);
+      } catch {
+        reject(new Error(`Failed to parse loudnorm output: ${json}`));
+      }
+    });
+
+    ffmpeg.on('error', (error) => {
+      reject(new Error(`Failed to start ffmpeg: ${error.message}`));
+    });
+  });
+}
+
+// Convert an .srt/.ass subtitle file to WebVTT, the only format <track> accepts
+export async function convertSubtitlesToVtt(inputPath: string): Promise<string> {
+  return new Promise((resolve, reject) => {
+    const args = [
+      '-v', 'error',
+      '-i', inputPath,
+      '-f', 'webvtt',
+      'pipe:1'
+    ];
+
+    const ffmpeg = spawn('ffmpeg', args);
+    let stdout = '';
+    let stderr = '';
+
+    ffmpeg.stdout.on('data', (data) => {
+      stdout += data.toString();
+    });
+
+    ffmpeg.stderr.on('data', (data) => {
+      stderr += data.toString();
+    });
+
+    ffmpeg.on('close', (code) => {
+      if (code !== 0) {
+        reject(new Error(`ffmpeg subtitle conversion exited with code ${code}: ${stderr}`));
+        return;
+      }
+      resolve(stdout);
+    });
+
+    ffmpeg.on('error', (error) => {
+      reject(new Error(`Failed to start ffmpeg: ${error.message}`));
+    });
+  });
+}
+
+// Protocols accepted for network streams (RTSP cameras, HLS/DASH over HTTP, RTMP)
+const NETWORK_STREAM_PROTOCOLS = ['rtsp:', 'rtsps:', 'rtmp:', 'http:', 'https:'];
+
+// Check if a string is a playable network stream URL
+export function isNetworkStreamUrl(url: string): boolean {
+  try {
+    return NETWORK_STREAM_PROTOCOLS.includes(new URL(url).protocol);
+  } catch {
+    return false;
+  }
+}
+
+// Open a network stream and transcode it to fragmented MP4 on stdout for the browser
+export function openNetworkStream(url: string): ChildProcess {
+  const args = [
+    '-v', 'error',
+    ...(url.startsWith('rtsp') ? ['-rtsp_transport', 'tcp'] : []),
+    '-i', url,
+    '-c:v', 'libx264',
+    '-preset', 'veryfast',
+    '-tune', 'zerolatency',
+    '-c:a', 'aac',
+    '-b:a', '128k',
+    '-movflags', 'frag_keyframe+empty_moov+default_base_moof',
+    '-f', 'mp4',
+    'pipe:1'
+  ];
+
+  return spawn('ffmpeg', args);
+}
+
 // Check if FFmpeg is available
 export async function checkFFmpegAvailable(): Promise<boolean> {
   return new Promise((resolve) => {
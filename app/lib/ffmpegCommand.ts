@@ -0,0 +1,73 @@
+// Ready-to-run ffmpeg commands for a clip, filled in from user-editable templates (client-safe).
+//
+// Templates are split on spaces. A word containing {input} or {stem} is quoted as a whole
+// after substitution, so `{stem}_proxy.mp4` becomes one safely quoted path. Other words are
+// copied as written; they can't contain spaces. Placeholders:
+// - {input}: full path of the clip
+// - {stem}: full path without the extension, for naming outputs next to the clip
+// - {start}, {end}: seconds (in/out points, or the current frame for {start})
+
+export interface CommandTemplate {
+  label: string;
+  template: string;
+}
+
+export type ShellKind = 'posix' | 'powershell';
+
+export const DEFAULT_COMMAND_TEMPLATES: CommandTemplate[] = [
+  {
+    label: 'Make H.264 proxy',
+    template: 'ffmpeg -i {input} -c:v libx264 -preset fast -crf 23 -vf scale=-2:720 -c:a aac -b:a 128k {stem}_proxy.mp4',
+  },
+  {
+    label: 'Extract audio to WAV',
+    template: 'ffmpeg -i {input} -vn -c:a pcm_s16le {stem}.wav',
+  },
+  {
+    label: 'Trim in–out to a new file',
+    template: 'ffmpeg -ss {start} -to {end} -i {input} -c copy {stem}_trim.mp4',
+  },
+  {
+    label: 'Extract frame as PNG',
+    template: 'ffmpeg -ss {start} -i {input} -frames:v 1 {stem}_frame.png',
+  },
+];
+
+const PATH_PLACEHOLDER = /\{(input|stem)\}/;
+
+// Single quotes are literal in POSIX shells; a quote inside is closed, escaped and reopened
+export function quotePosix(value: string): string {
+  return `'${value.replace(/'/g, `'\\''`)}'`;
+}
+
+// PowerShell single-quoted strings are literal too; quotes are escaped by doubling.
+// PowerShell also treats the typographic single quotes as quote characters.
+export function quotePowerShell(value: string): string {
+  return `'${value.replace(/['‘’‚‛]/g, '$&$&')}'`;
+}
+
+// Templates that mention {start} or {end} need in/out points
+export function templateUsesTimes(template: string): boolean {
+  return /\{(start|end)\}/.test(template);
+}
+
+export function buildFfmpegCommand(
+  template: string,
+  values: { input: string; start: number; end: number },
+  shell: ShellKind
+): string {
+  const quote = shell === 'powershell' ? quotePowerShell : quotePosix;
+  const stem = values.input.replace(/\.[^./\\]+$/, '');
+  // Function replacers, so $& or $1 in a file name is inserted as written
+  const substitute = (word: string) => word
+    .replace(/\{input\}/g, () => values.input)
+    .replace(/\{stem\}/g, () => stem)
+    .replace(/\{start\}/g, () => values.start.toFixed(3))
+    .replace(/\{end\}/g, () => values.end.toFixed(3));
+
+  return template
+    .trim()
+    .split(/\s+/)
+    .map((word) => (PATH_PLACEHOLDER.test(word) ? quote(substitute(word)) : substitute(word)))
+    .join(' ');
+}
@@ -0,0 +1,37 @@
+// Dates embedded in file names, for footage whose filesystem dates were lost when
+// it was copied. Client-safe (no Node imports).
+
+// 2021-06-14, 2021_06_14, 2021.06.14, optionally followed by a time (12-34-56, 12.34.56, 123456)
+const SEPARATED_DATE = /(?<!\d)((?:19|20)\d{2})[-_.](\d{2})[-_.](\d{2})(?:[ T_-](\d{2})[-_.:]?(\d{2})[-_.:]?(\d{2}))?(?!\d)/;
+
+// 20210614, IMG_20210614_123456, PXL_20210614_123456789 (sub-second digits ignored)
+const COMPACT_DATE = /(?<!\d)((?:19|20)\d{2})(\d{2})(\d{2})(?:[_-]?(\d{2})(\d{2})(\d{2})\d*)?(?!\d)/;
+
+const EARLIEST_YEAR = 1990;
+
+// Local time, as camera clocks are; null unless every part is a real calendar value
+function toIsoDate(parts: string[]): string | null {
+  const [year, month, day, hours = 0, minutes = 0, seconds = 0] = parts
+    .map((part) => (part === undefined ? undefined : parseInt(part, 10)));
+  if (year === undefined || month === undefined || day === undefined) return null;
+  if (year < EARLIEST_YEAR || year > new Date().getFullYear() + 1) return null;
+  if (hours > 23 || minutes > 59 || seconds > 59) return null;
+
+  const date = new Date(year, month - 1, day, hours, minutes, seconds);
+  // Rejects month 13, February 30 and the like, which Date would roll over
+  if (date.getMonth() !== month - 1 || date.getDate() !== day) return null;
+  return date.toISOString();
+}
+
+// Recording date from a file name, or null. Separated dates win over compact runs of
+// digits, which are likelier to be something else (clip numbers, timecodes).
+export function parseFileNameDate(fileName: string): string | null {
+  const stem = fileName.replace(/\.[^.]+$/, '');
+  for (const pattern of [SEPARATED_DATE, COMPACT_DATE]) {
+    const match = stem.match(pattern);
+    if (!match) continue;
+    const date = toIsoDate(match.slice(1, match[4] === undefined ? 4 : 7));
+    if (date) return date;
+  }
+  return null;
+}
@@ -0,0 +1,112 @@
+import Database from 'better-sqlite3';
+import fs from 'fs';
+import path from 'path';
+import { getDatabase, getDatabasePath, getCurrentRootPath, searchVideos } from './db';
+import { getLibraryHistory } from './libraryHistory';
+import { GlobalSearchResult, LibraryVideo, Selection, SelectionRow, Video, rowToSelection } from './types';
+
+// Search every library in the history, not just the open one (server-side only)
+
+// Per-library cap so one huge library can't crowd out the rest
+const RESULTS_PER_LIBRARY = 200;
+
+// Exact name beats prefix beats substring
+function getRelevance(video: Video, query: string): number {
+  const name = video.fileName.toLowerCase();
+  const stem = name.replace(/\.[^.]+$/, '');
+  if (name === query || stem === query) return 3;
+  if (name.startsWith(query)) return 2;
+  return 1;
+}
+
+// Run fn against a library's catalog: the open connection for the current library,
+// otherwise a short-lived connection (read-only unless writable is set)
+export function withLibraryDatabase<T>(
+  rootPath: string,
+  fn: (db: Database.Database) => T,
+  writable: boolean = false
+): T | null {
+  if (rootPath === getCurrentRootPath()) {
+    return fn(getDatabase());
+  }
+
+  const dbPath = getDatabasePath(rootPath);
+  if (!fs.existsSync(dbPath)) return null;
+
+  const db = new Database(dbPath, { readonly: !writable, fileMustExist: true });
+  try {
+    return fn(db);
+  } finally {
+    db.close();
+  }
+}
+
+export function isKnownLibrary(rootPath: string): boolean {
+  return getLibraryHistory().some((entry) => entry.path === rootPath);
+}
+
+export function getLibraryName(rootPath: string): string {
+  return path.basename(rootPath) || rootPath;
+}
+
+// Every video in one library with its selection, for the all-libraries view.
+// Null when the catalog can't be found (volume not mounted).
+export function getLibraryVideos(libraryPath: string): LibraryVideo[] | null {
+  return withLibraryDatabase(libraryPath, (db) => {
+    const { videos } = searchVideos({}, db, libraryPath);
+    const selections = new Map(
+      (db.prepare('SELECT * FROM selections').all() as SelectionRow[])
+        .map((row): [string, Selection] => [row.video_id, rowToSelection(row)])
+    );
+
+    // One directory listing per folder rather than a stat per video
+    const listings = new Map<string, Set<string> | null>();
+    const listFolder = (directory: string) => {
+      if (!listings.has(directory)) {
+        try {
+          listings.set(directory, new Set(fs.readdirSync(directory)));
+        } catch {
+          listings.set(directory, null); // Folder gone
+        }
+      }
+      return listings.get(directory)!;
+    };
+
+    return videos.map((video) => ({
+      ...video,
+      selection: selections.get(video.id),
+      libraryPath,
+      libraryName: getLibraryName(libraryPath),
+      isMissing: !(listFolder(path.dirname(video.filePath))?.has(path.basename(video.filePath)) ?? false),
+    }));
+  });
+}
+
+export function searchAllLibraries(query: string, limit: number): GlobalSearchResult[] {
+  const needle = query.trim().toLowerCase();
+  if (!needle) return [];
+
+  const results: GlobalSearchResult[] = [];
+  for (const { path: libraryPath } of getLibraryHistory()) {
+    try {
+      const videos = withLibraryDatabase(libraryPath, (db) =>
+        searchVideos({ search: needle, limit: RESULTS_PER_LIBRARY }, db, libraryPath).videos
+      );
+      for (const video of videos ?? []) {
+        results.push({
+          libraryPath,
+          libraryName: getLibraryName(libraryPath),
+          relevance: getRelevance(video, needle),
+          video,
+        });
+      }
+    } catch (error) {
+      // Drive unplugged, old schema, locked file... skip that library
+      console.warn(`Global search skipped ${libraryPath}:`, error);
+    }
+  }
+
+  return results
+    .sort((a, b) => b.relevance - a.relevance || b.video.createdAt.localeCompare(a.video.createdAt))
+    .slice(0, limit);
+}
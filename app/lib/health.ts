@@ -0,0 +1,51 @@
+import fs from 'fs/promises';
+import { getAllVideos } from './db';
+import { MIN_SPRITE_DURATION } from './ffmpeg';
+import { CodecCount, LibraryHealth } from './types';
+
+// Library health score for the toolbar (server-side only). Everything but the on-disk
+// check comes from the catalog; files are checked a batch at a time so a slow drive
+// isn't flooded with requests.
+
+const STAT_BATCH_SIZE = 64;
+
+function countCodecs(codecs: (string | null)[]): CodecCount[] {
+  const counts = new Map<string | null, number>();
+  for (const codec of codecs) {
+    counts.set(codec, (counts.get(codec) ?? 0) + 1);
+  }
+  return Array.from(counts, ([codec, count]) => ({ codec, count })).sort((a, b) => b.count - a.count);
+}
+
+export async function computeLibraryHealth(): Promise<LibraryHealth> {
+  const videos = getAllVideos();
+  const total = videos.length;
+
+  let onDisk = 0;
+  for (let i = 0; i < total; i += STAT_BATCH_SIZE) {
+    const results = await Promise.all(
+      videos.slice(i, i + STAT_BATCH_SIZE).map((video) => fs.access(video.filePath).then(() => true, () => false))
+    );
+    onDisk += results.filter(Boolean).length;
+  }
+
+  // An empty library has nothing wrong with it
+  const fraction = (count: number) => (total === 0 ? 1 : count / total);
+  const components = {
+    thumbnails: fraction(videos.filter((v) => v.thumbnailPath).length),
+    // Short clips never get a sprite, so they count as covered
+    sprites: fraction(videos.filter((v) => v.hasSprite || v.duration < MIN_SPRITE_DURATION).length),
+    metadata: fraction(videos.filter((v) => v.width && v.height).length),
+    onDisk: fraction(onDisk),
+  };
+
+  return {
+    score: (components.thumbnails + components.sprites + components.metadata + components.onDisk) / 4,
+    total,
+    ...components,
+    totalDuration: videos.reduce((sum, v) => sum + v.duration, 0),
+    totalSize: videos.reduce((sum, v) => sum + v.fileSize, 0),
+    codecs: countCodecs(videos.map((v) => v.codec)),
+    computedAt: new Date().toISOString(),
+  };
+}
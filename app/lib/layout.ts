@@ -0,0 +1,152 @@
+// Client-safe grid layout helpers
+
+import { SortOption, VideoWithSelection } from './types';
+import { getBaseName, getBestDate } from './utils';
+
+export interface LayoutRect {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+}
+
+export interface SmartGridItem {
+  video: VideoWithSelection;
+  rect: LayoutRect;
+}
+
+// A row of cards; its height is the tallest card on it
+export interface SmartGridShelf {
+  top: number;
+  height: number;
+  items: SmartGridItem[];
+}
+
+// Slot size for a video: 1×1 up to 3×3, larger for higher resolutions
+export function getSmartGridWeight(video: VideoWithSelection): number {
+  const width = video.width || 1920;
+  return Math.min(3, Math.max(1, Math.floor(width / 960)));
+}
+
+// Shelf-pack videos left to right into rows of cardBaseSize-wide slots.
+// Rects are relative to the top-left of the grid.
+export function computeSmartGridLayout(
+  videos: VideoWithSelection[],
+  containerWidth: number,
+  cardBaseSize: number,
+  gap: number,
+  cardAspectRatio: number
+): SmartGridShelf[] {
+  const columns = Math.max(1, Math.floor((containerWidth + gap) / (cardBaseSize + gap)));
+  const slotWidth = (containerWidth - gap * (columns - 1)) / columns;
+  const slotHeight = slotWidth * cardAspectRatio;
+
+  const shelves: SmartGridShelf[] = [];
+  let shelf: SmartGridShelf = { top: 0, height: 0, items: [] };
+  let column = 0;
+
+  for (const video of videos) {
+    // Never wider than the grid itself
+    const weight = Math.min(getSmartGridWeight(video), columns);
+
+    // Start a new shelf when the card doesn't fit on this one
+    if (column + weight > columns) {
+      shelves.push(shelf);
+      shelf = { top: shelf.top + shelf.height + gap, height: 0, items: [] };
+      column = 0;
+    }
+
+    const rect: LayoutRect = {
+      x: column * (slotWidth + gap),
+      y: shelf.top,
+      width: weight * slotWidth + (weight - 1) * gap,
+      height: weight * slotHeight + (weight - 1) * gap,
+    };
+    shelf.items.push({ video, rect });
+    shelf.height = Math.max(shelf.height, rect.height);
+    column += weight;
+  }
+
+  if (shelf.items.length > 0) {
+    shelves.push(shelf);
+  }
+
+  return shelves;
+}
+
+// Videos created on one calendar day (local time)
+export interface DateGroup {
+  key: string; // YYYY-MM-DD
+  label: string; // e.g. "March 15, 2024"
+  videos: VideoWithSelection[];
+}
+
+// Group videos by creation day (best available date when sorting by it), keeping the
+// current sort within each day. Days run newest first, or oldest first when sorting by oldest date.
+export function groupVideosByDate(videos: VideoWithSelection[], sortBy: SortOption): DateGroup[] {
+  const useBestDate = sortBy === 'best-date-asc' || sortBy === 'best-date-desc';
+  const useScanDate = sortBy === 'scanned-asc' || sortBy === 'scanned-desc';
+  const dateOf = (video: VideoWithSelection) => {
+    if (useBestDate) return getBestDate(video).date;
+    if (useScanDate) return video.scannedAt ?? video.createdAt;
+    return video.createdAt;
+  };
+  const groups = new Map<string, VideoWithSelection[]>();
+  for (const video of videos) {
+    const date = new Date(dateOf(video));
+    const key = `${date.getFullYear()}-${String(date.getMonth() + 1).padStart(2, '0')}-${String(date.getDate()).padStart(2, '0')}`;
+    const group = groups.get(key);
+    if (group) {
+      group.push(video);
+    } else {
+      groups.set(key, [video]);
+    }
+  }
+
+  return Array.from(groups)
+    .sort(([a], [b]) => (sortBy === 'date-asc' || sortBy === 'best-date-asc' || sortBy === 'scanned-asc' ? a.localeCompare(b) : b.localeCompare(a)))
+    .map(([key, groupVideos]) => ({
+      key,
+      label: new Date(dateOf(groupVideos[0])).toLocaleDateString(undefined, { year: 'numeric', month: 'long', day: 'numeric' }),
+      videos: groupVideos,
+    }));
+}
+
+// Videos directly inside one folder
+export interface FolderGroup {
+  key: string; // Relative to the library root, like directory_stats; '' is the root itself
+  label: string; // e.g. "Trips/Day 2", or the library name for the root
+  videos: VideoWithSelection[];
+}
+
+// A folder's path relative to the library root, split like path.relative would on the
+// server (either separator), or null when it isn't inside the root
+export function getRelativeDirectory(rootPath: string, directory: string): string | null {
+  const root = rootPath.replace(/[\\/]+$/, '');
+  if (directory === root) return '';
+  if (!directory.startsWith(root) || !/[\\/]/.test(directory.charAt(root.length))) return null;
+  return directory.slice(root.length + 1);
+}
+
+// Group videos by the folder they're in, keeping the current sort within each folder.
+// Folders run in path order, so subfolders follow their parent.
+export function groupVideosByFolder(videos: VideoWithSelection[], rootPath: string): FolderGroup[] {
+  const groups = new Map<string, VideoWithSelection[]>();
+  for (const video of videos) {
+    const key = getRelativeDirectory(rootPath, video.directory) ?? video.directory;
+    const group = groups.get(key);
+    if (group) {
+      group.push(video);
+    } else {
+      groups.set(key, [video]);
+    }
+  }
+
+  return Array.from(groups)
+    .sort(([a], [b]) => a.localeCompare(b, undefined, { numeric: true }))
+    .map(([key, groupVideos]) => ({
+      key,
+      label: key === '' ? getBaseName(rootPath) || rootPath : key.replace(/\\/g, '/'),
+      videos: groupVideos,
+    }));
+}
@@ -0,0 +1,115 @@
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+
+// Libraries opened on this machine, most recent first. Each library keeps its own
+// catalog.db, so this list lives in the user's home directory (server-side only).
+
+export interface LibraryHistoryEntry {
+  path: string;
+  lastOpenedAt: string;
+  // Recorded when a scan completes; missing for libraries not scanned since they were added
+  lastScannedAt?: string;
+  videoCount?: number;
+  totalBytes?: number;
+}
+
+const HISTORY_PATH = path.join(os.homedir(), '.video-catalog-browser', 'libraries.json');
+const MAX_LIBRARIES = 50;
+
+// The file is read once and kept in memory; the home directory can be on a slow network
+// share, and sync IO there would stall every request. Writes update the copy in memory at
+// once and reach the file in order in the background.
+let cachedHistory: LibraryHistoryEntry[] | null = null;
+let pendingWrite: Promise<void> = Promise.resolve();
+
+function parseLibraryHistory(text: string): LibraryHistoryEntry[] {
+  try {
+    const entries = JSON.parse(text);
+    return Array.isArray(entries) ? entries.filter((e) => typeof e?.path === 'string') : [];
+  } catch {
+    return [];
+  }
+}
+
+// Read the file without blocking (run at server startup, and by the recent libraries list)
+export async function loadLibraryHistory(): Promise<LibraryHistoryEntry[]> {
+  if (!cachedHistory) {
+    const text = await fs.promises.readFile(HISTORY_PATH, 'utf-8').catch(() => '[]');
+    // A write made while reading is newer than the file
+    cachedHistory ??= parseLibraryHistory(text);
+  }
+  return [...cachedHistory];
+}
+
+export function getLibraryHistory(): LibraryHistoryEntry[] {
+  if (!cachedHistory) {
+    // Only before loadLibraryHistory has finished
+    let text = '[]';
+    try {
+      text = fs.readFileSync(HISTORY_PATH, 'utf-8');
+    } catch {
+      // No history yet
+    }
+    cachedHistory = parseLibraryHistory(text);
+  }
+  return [...cachedHistory];
+}
+
+export function recordLibraryOpened(rootPath: string): void {
+  const history = getLibraryHistory();
+  const entry = history.find((e) => e.path === rootPath);
+  writeLibraryHistory([
+    { ...entry, path: rootPath, lastOpenedAt: new Date().toISOString() },
+    ...history.filter((e) => e.path !== rootPath),
+  ]);
+}
+
+// Remember when a library was last scanned and how big it was then, for the recent list
+export function recordLibraryScanned(rootPath: string, totals: { videoCount: number; totalBytes: number }): void {
+  const history = getLibraryHistory();
+  if (!history.some((e) => e.path === rootPath)) return;
+  writeLibraryHistory(history.map((e) => (
+    e.path === rootPath ? { ...e, lastScannedAt: new Date().toISOString(), ...totals } : e
+  )));
+}
+
+// Replace a library's old location with the one it was relinked to
+export function replaceLibraryPath(oldPath: string, newPath: string): void {
+  const history = getLibraryHistory();
+  const entry = history.find((e) => e.path === oldPath);
+  writeLibraryHistory([
+    { ...entry, path: newPath, lastOpenedAt: new Date().toISOString() },
+    ...history.filter((e) => e.path !== oldPath && e.path !== newPath),
+  ]);
+}
+
+// Add entries for libraries not already listed (imported from another machine); returns how many were added
+export function mergeLibraryHistory(entries: LibraryHistoryEntry[]): number {
+  const history = getLibraryHistory();
+  const known = new Set(history.map((e) => e.path));
+  const added: LibraryHistoryEntry[] = [];
+  for (const entry of entries) {
+    if (known.has(entry.path)) continue;
+    known.add(entry.path);
+    added.push(entry);
+  }
+
+  writeLibraryHistory(
+    [...history, ...added].sort((a, b) => b.lastOpenedAt.localeCompare(a.lastOpenedAt))
+  );
+  return added.length;
+}
+
+function writeLibraryHistory(history: LibraryHistoryEntry[]): void {
+  const entries = history.slice(0, MAX_LIBRARIES);
+  cachedHistory = entries;
+  pendingWrite = pendingWrite.then(async () => {
+    try {
+      await fs.promises.mkdir(path.dirname(HISTORY_PATH), { recursive: true });
+      await fs.promises.writeFile(HISTORY_PATH, JSON.stringify(entries, null, 2));
+    } catch (error) {
+      console.error('Failed to update library history:', error);
+    }
+  });
+}
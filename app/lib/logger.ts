@@ -0,0 +1,88 @@
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import util from 'util';
+import { LogEntry, LogLevel } from './types';
+
+// Server console output mirrored to a daily log file, so failures can be looked up
+// after the terminal is gone (server-side only). Shared by every library.
+
+export const LOG_DIR = path.join(os.homedir(), '.video-catalog-browser', 'logs');
+const LOG_FILE_PATTERN = /^vcb-\d{4}-\d{2}-\d{2}\.log$/;
+const MAX_LOG_FILES = 5;
+
+// One line per entry start: "<ISO time> <LEVEL> <message>"; continuation lines (stack traces) follow
+const ENTRY_PATTERN = /^(\d{4}-\d{2}-\d{2}T\S+Z) (DEBUG|INFO|WARN|ERROR) (.*)$/;
+
+const CONSOLE_LEVELS: Record<'debug' | 'log' | 'info' | 'warn' | 'error', LogLevel> = {
+  debug: 'debug',
+  log: 'info',
+  info: 'info',
+  warn: 'warn',
+  error: 'error',
+};
+
+let currentLogPath: string | null = null;
+let isInstalled = false;
+
+export function getLogPath(date: Date = new Date()): string {
+  return path.join(LOG_DIR, `vcb-${date.toISOString().slice(0, 10)}.log`);
+}
+
+// Keep the newest files; the date in the name sorts chronologically
+function pruneOldLogs(): void {
+  const files = fs.readdirSync(LOG_DIR).filter((name) => LOG_FILE_PATTERN.test(name)).sort();
+  for (const name of files.slice(0, -MAX_LOG_FILES)) {
+    fs.rmSync(path.join(LOG_DIR, name), { force: true });
+  }
+}
+
+function writeEntry(level: LogLevel, args: unknown[]): void {
+  const logPath = getLogPath();
+  if (logPath !== currentLogPath) {
+    // New day: start a new file and drop the oldest
+    fs.mkdirSync(LOG_DIR, { recursive: true });
+    currentLogPath = logPath;
+    pruneOldLogs();
+  }
+  fs.appendFileSync(logPath, `${new Date().toISOString()} ${level.toUpperCase()} ${util.format(...args)}\n`);
+}
+
+// Mirror console output to the log file. Called once from instrumentation.ts.
+export function installFileLogging(): void {
+  if (isInstalled) return;
+  isInstalled = true;
+
+  for (const method of Object.keys(CONSOLE_LEVELS) as (keyof typeof CONSOLE_LEVELS)[]) {
+    const original = console[method].bind(console);
+    console[method] = (...args: unknown[]) => {
+      original(...args);
+      try {
+        writeEntry(CONSOLE_LEVELS[method], args);
+      } catch {
+        // Logging must never break the caller (disk full, permissions...)
+      }
+    };
+  }
+}
+
+// Last entries of today's log, oldest first
+export function readRecentLogs(limit: number): LogEntry[] {
+  let content: string;
+  try {
+    content = fs.readFileSync(getLogPath(), 'utf-8');
+  } catch {
+    return [];
+  }
+
+  const entries: LogEntry[] = [];
+  for (const line of content.split('\n')) {
+    const match = line.match(ENTRY_PATTERN);
+    if (match) {
+      entries.push({ time: match[1], level: match[2].toLowerCase() as LogLevel, message: match[3] });
+    } else if (line && entries.length > 0) {
+      entries[entries.length - 1].message += `\n${line}`;
+    }
+  }
+  return entries.slice(-limit);
+}
@@ -0,0 +1,60 @@
+// Player modal size and position (client-side). Kept in localStorage rather than a
+// library's settings: it depends on this machine's monitors, not on the footage.
+
+export interface PlayerGeometry {
+  width: number;
+  height: number;
+  x: number | null; // null until moved: stays centered
+  y: number | null;
+  maximized: boolean; // Fills the window; toggled by double-clicking the video
+}
+
+const STORAGE_KEY = 'vcb.playerGeometry';
+
+export const MIN_PLAYER_WIDTH = 480;
+export const MIN_PLAYER_HEIGHT = 360;
+// Gap kept between the player and the window edge
+export const PLAYER_SCREEN_MARGIN = 16;
+
+export function loadPlayerGeometry(): PlayerGeometry | null {
+  try {
+    const saved = JSON.parse(localStorage.getItem(STORAGE_KEY) || 'null');
+    if (typeof saved?.width !== 'number' || typeof saved?.height !== 'number') return null;
+    return {
+      width: saved.width,
+      height: saved.height,
+      x: typeof saved.x === 'number' ? saved.x : null,
+      y: typeof saved.y === 'number' ? saved.y : null,
+      maximized: saved.maximized === true,
+    };
+  } catch {
+    return null;
+  }
+}
+
+export function savePlayerGeometry(geometry: PlayerGeometry): void {
+  try {
+    localStorage.setItem(STORAGE_KEY, JSON.stringify(geometry));
+  } catch {
+    // Storage full or disabled: the player just opens at its default size next time
+  }
+}
+
+// Fit saved geometry to the current window, e.g. after moving from a larger monitor
+export function clampPlayerGeometry(geometry: PlayerGeometry, viewportWidth: number, viewportHeight: number): PlayerGeometry {
+  const maxWidth = Math.max(MIN_PLAYER_WIDTH, viewportWidth - PLAYER_SCREEN_MARGIN * 2);
+  const maxHeight = Math.max(MIN_PLAYER_HEIGHT, viewportHeight - PLAYER_SCREEN_MARGIN * 2);
+  const width = Math.min(Math.max(geometry.width, MIN_PLAYER_WIDTH), maxWidth);
+  const height = Math.min(Math.max(geometry.height, MIN_PLAYER_HEIGHT), maxHeight);
+  const clampPosition = (value: number | null, size: number, viewport: number) => (value === null
+    ? null
+    : Math.min(Math.max(value, PLAYER_SCREEN_MARGIN), Math.max(PLAYER_SCREEN_MARGIN, viewport - size - PLAYER_SCREEN_MARGIN)));
+
+  return {
+    width,
+    height,
+    x: clampPosition(geometry.x, width, viewportWidth),
+    y: clampPosition(geometry.y, height, viewportHeight),
+    maximized: geometry.maximized,
+  };
+}
@@ -0,0 +1,44 @@
+// Client-safe M3U/M3U8 playlist helpers
+
+export interface PlaylistEntry {
+  filePath: string;
+  fileName: string;
+  duration: number;
+}
+
+// Make a path relative to rootPath when it lives inside it (either separator, as the
+// library may be on Windows)
+function toRelativePath(filePath: string, rootPath: string): string {
+  const root = rootPath.replace(/[\\/]+$/, '');
+  if (!filePath.startsWith(root) || !/[\\/]/.test(filePath.charAt(root.length))) return filePath;
+
+  const relative = filePath.slice(root.length + 1);
+  // A leading '#' would turn the line into a comment
+  return relative.startsWith('#') ? `./${relative}` : relative;
+}
+
+// Build an extended M3U8 playlist; pass relativeTo to write paths relative to the library root
+export function buildM3u8(entries: PlaylistEntry[], relativeTo?: string): string {
+  const lines = ['#EXTM3U'];
+
+  for (const entry of entries) {
+    // Titles run to end of line, so commas are fine but line breaks are not
+    const title = entry.fileName.replace(/[\r\n]+/g, ' ');
+    const duration = entry.duration > 0 ? Math.round(entry.duration) : -1;
+    const location = relativeTo ? toRelativePath(entry.filePath, relativeTo) : entry.filePath;
+
+    lines.push(`#EXTINF:${duration},${title}`);
+    lines.push(location);
+  }
+
+  return lines.join('\n') + '\n';
+}
+
+// Extract media locations from an M3U/M3U8 playlist (comments and directives skipped)
+export function parseM3u(content: string): string[] {
+  return content
+    .replace(/^\uFEFF/, '')
+    .split(/\r?\n/)
+    .map((line) => line.trim())
+    .filter((line) => line && !line.startsWith('#'));
+}
@@ -0,0 +1,82 @@
+import fs from 'fs';
+import path from 'path';
+import {
+  initDatabase,
+  getDatabasePath,
+  getAllVideos,
+  relinkVideoPaths,
+} from './db';
+import { getFileFingerprint } from './scanner';
+import { withLibraryDatabase } from './globalSearch';
+import { replaceLibraryPath } from './libraryHistory';
+import { RelinkResult } from './types';
+
+// Point a moved library at its new root without rescanning (server-side only)
+
+// Files fingerprinted to confirm the new folder holds the same library
+const SAMPLE_SIZE = 8;
+
+// Missing files listed in the result (the count is always complete)
+const MAX_MISSING_LISTED = 100;
+
+// Evenly spaced picks so one folder can't make up the whole sample
+function pickSample<T>(items: T[], count: number): T[] {
+  if (items.length <= count) return items;
+  const step = items.length / count;
+  return Array.from({ length: count }, (_, i) => items[Math.floor(i * step)]);
+}
+
+export async function relinkLibrary(oldPath: string, newPath: string): Promise<RelinkResult> {
+  // The catalog lives in the library folder, so it moved along with the videos
+  if (!fs.existsSync(getDatabasePath(newPath))) {
+    throw new Error('No catalog found in that folder. Pick the folder that contains .vcb-data.');
+  }
+
+  // Verify against the moved catalog read-only: opening it switches the app to that
+  // library and records it in the history, which a failed relink must not do
+  const catalog = withLibraryDatabase(newPath, (db) => {
+    const lastScan = db.prepare('SELECT root_path FROM scans ORDER BY started_at DESC LIMIT 1')
+      .get() as { root_path: string } | undefined;
+    const videos = db.prepare('SELECT file_path, file_hash FROM videos')
+      .all() as { file_path: string; file_hash: string | null }[];
+    return { oldRoot: lastScan?.root_path ?? oldPath, videos };
+  });
+  if (!catalog) {
+    throw new Error('No catalog found in that folder. Pick the folder that contains .vcb-data.');
+  }
+  const { oldRoot } = catalog;
+
+  // Videos still recorded under the old root (portable libraries store relative paths, so have none)
+  const stale = catalog.videos.filter((v) => v.file_path.startsWith(oldRoot + path.sep));
+  const sample = pickSample(stale, SAMPLE_SIZE);
+
+  let verified = 0;
+  for (const video of sample) {
+    const candidate = path.join(newPath, path.relative(oldRoot, video.file_path));
+    try {
+      if (await getFileFingerprint(candidate) === video.file_hash) {
+        verified++;
+      }
+    } catch {
+      // Missing at the new location
+    }
+  }
+
+  if (sample.length > 0 && verified === 0) {
+    throw new Error(`None of ${sample.length} sampled videos were found under ${newPath}`);
+  }
+
+  initDatabase(newPath);
+  const relinked = oldRoot === newPath ? 0 : relinkVideoPaths(oldRoot, newPath);
+  replaceLibraryPath(oldPath, newPath);
+
+  const missing = getAllVideos().filter((v) => !fs.existsSync(v.filePath)).map((v) => v.filePath);
+
+  return {
+    sampled: sample.length,
+    verified,
+    relinked,
+    missingCount: missing.length,
+    missing: missing.slice(0, MAX_MISSING_LISTED),
+  };
+}
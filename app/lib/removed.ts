@@ -0,0 +1,46 @@
+import { clearDuplicateFileNames, getCurrentRootPath, getExpiredRemovedVideoIds, purgeRemovedVideos, setVideosRemoved } from './db';
+import { refreshDirectoryStats } from './directories';
+import { deleteVideoAssets } from './ffmpeg';
+import { refreshLibraryCacheSize } from './storage';
+
+// Recently Removed (server-side only). Removing a video from the catalog hides it for
+// REMOVED_RETENTION_DAYS, then the next library open purges it. Folder totals and the
+// shared file names are recounted on every change, since both skip removed videos.
+
+function refreshCounts(): void {
+  const rootPath = getCurrentRootPath();
+  if (rootPath) refreshDirectoryStats(rootPath);
+  clearDuplicateFileNames();
+}
+
+export function removeVideos(ids: string[]): number {
+  const removed = setVideosRemoved(ids, true);
+  if (removed > 0) refreshCounts();
+  return removed;
+}
+
+export function restoreVideos(ids: string[]): number {
+  const restored = setVideosRemoved(ids, false);
+  if (restored > 0) refreshCounts();
+  return restored;
+}
+
+// Purging deletes each video's generated files, so the cached .vcb-data size is measured again
+export async function purgeVideos(ids: string[]): Promise<number> {
+  const rootPath = getCurrentRootPath();
+  const purged = purgeRemovedVideos(ids);
+  if (purged.length > 0 && rootPath) {
+    await Promise.all(purged.map((id) => deleteVideoAssets(rootPath, id)));
+    await refreshLibraryCacheSize(rootPath).catch((error) => console.error('Failed to compute cache size:', error));
+  }
+  return purged.length;
+}
+
+// Runs when a library is opened
+export async function purgeExpiredVideos(): Promise<void> {
+  try {
+    await purgeVideos(getExpiredRemovedVideoIds());
+  } catch (error) {
+    console.warn('Could not purge expired removed videos:', error);
+  }
+}
@@ -9,25 +9,62 @@ import {
   generateId,
   createScan,
   updateScanProgress,
+  closeInterruptedScans,
   completeScan,
   failScan,
   updateVideoThumbnail,
   updateVideoThumbnailAndSprite,
   getVideoByPath,
+  setVideoOnlineOnly,
+  getAllVideos,
+  getReadableVideos,
+  deleteVideoByPath,
+  updateVideoSidecar,
+  updateVideoSubtitles,
+  updateVideoSpriteGrid,
+  updateVideoSprite,
+  updateVideoStreamInfo,
+  updateVideoLoudness,
+  updateVideoNameDate,
   initDatabase,
-  VideoInsertData
+  getSetting,
+  setSetting,
+  getLibraryTotals,
+  clearDuplicateFileNames,
+  searchVideos,
+  getExcludedVideos,
+  setVideosExcluded,
+  isOutsideRoot,
+  VideoInsertData,
+  ScanResultDetails
 } from './db';
-import { getVideoMetadata, generateThumbnailOnly, generateSpriteSheetOnly, ensureProxyDir } from './ffmpeg';
-import { Video } from './types';
-
-// Video file extensions to search for
-const VIDEO_EXTENSIONS = ['.mov', '.mp4', '.m4v', '.avi', '.mkv', '.webm'];
+import { getVideoMetadata, generateThumbnailOnly, generateSpriteSheetOnly, getSpriteLayout, ensureProxyDir, checkFFmpegAvailable, measureLoudness, MIN_SPRITE_DURATION } from './ffmpeg';
+import { getSpriteDensity } from './sprites';
+import { setThumbnailStatus } from './thumbnailStatus';
+import { refreshLibraryCacheSize } from './storage';
+import { prefetchThumbnails } from './assetCache';
+import { createPlaceholderCheck, getCloudPlaceholderMode, getICloudStubTarget, PlaceholderCheck } from './cloudPlaceholders';
+import { findSidecar, readSidecar } from './sidecar';
+import { findSubtitles } from './subtitles';
+import { getLibraryHistory, recordLibraryScanned } from './libraryHistory';
+import { withLibraryDatabase } from './globalSearch';
+import { parseFileNameDate } from './fileNameDate';
+import { parseCreationTime } from './utils';
+import { compileExclusions, getExclusions, getLibraryExclusions, ExclusionMatcher } from './exclusions';
+import { refreshDirectoryStats } from './directories';
+import { ScanCounts, ScanPreview, ScanPreviewCounts, Video } from './types';
+
+// Video file extensions to search for (includes pro camera containers: MXF, AVCHD, 3GP)
+const VIDEO_EXTENSIONS = ['.mov', '.mp4', '.m4v', '.avi', '.mkv', '.webm', '.mxf', '.mts', '.m2ts', '.3gp'];
+
+// AVCHD files report unreliable filesystem creation times
+const AVCHD_EXTENSIONS = ['.mts', '.m2ts'];
 
 // Concurrency limit for parallel operations
 const METADATA_CONCURRENCY = 4;
 
 // Check if a file is a video based on extension
-function isVideoFile(filePath: string): boolean {
+export function isVideoFile(filePath: string): boolean {
   const ext = path.extname(filePath).toLowerCase();
   return VIDEO_EXTENSIONS.includes(ext);
 }
@@ -45,15 +82,92 @@ function shouldSkipPath(name: string): boolean {
   return false;
 }
 
+// Check whether a path inside rootPath falls under a skipped or user-excluded file or folder
+export function isSkippedPath(rootPath: string, filePath: string, isExcluded?: ExclusionMatcher): boolean {
+  const relative = path.relative(rootPath, filePath);
+  if (isOutsideRoot(relative)) {
+    return true;
+  }
+  return relative.split(path.sep).some(shouldSkipPath) || (isExcluded?.(filePath) ?? false);
+}
+
+// Per-scan options for walking a library
+export interface ScanOptions {
+  isExcluded?: ExclusionMatcher; // The library's excluded folder patterns, compiled
+  isPlaceholder?: PlaceholderCheck; // Online-only cloud files, which reading would download
+  onPlaceholder?: (filePath: string) => void;
+  onError?: (message: string) => void; // Folders that can't be read and names that can't be cataloged
+  minFileSize?: number; // Bytes; smaller new video files (camera preview clips) are left out
+  isCataloged?: (filePath: string) => boolean; // Already indexed, so kept whatever the minimum size
+  onTooSmall?: (filePath: string) => void;
+  measureLoudness?: boolean; // Measure LUFS of new and not yet measured videos (a full decode of the audio)
+}
+
+// Cameras write small preview .mp4s next to the real clips; anything under this is skipped
+const MIN_FILE_SIZE_SETTING = 'min_file_size_bytes';
+export const DEFAULT_MIN_FILE_SIZE = 1024 * 1024;
+
+// Minimum video size for any known library, read without opening it as the current one; 0 keeps every file
+export function getMinFileSize(rootPath: string): number {
+  const value = withLibraryDatabase(rootPath, (db) => {
+    const row = db.prepare('SELECT value FROM settings WHERE key = ?').get(MIN_FILE_SIZE_SETTING) as { value: string } | undefined;
+    return row ? Number(row.value) : null;
+  });
+  return value !== null && Number.isFinite(value) && value >= 0 ? value : DEFAULT_MIN_FILE_SIZE;
+}
+
+export function setMinFileSize(bytes: number): void {
+  setSetting(MIN_FILE_SIZE_SETTING, String(Math.round(bytes)));
+}
+
+// Loudness measurement decodes all of the audio, so it is off unless the library turns it on
+const MEASURE_LOUDNESS_SETTING = 'measure_loudness';
+
+export function getMeasureLoudness(): boolean {
+  return getSetting(MEASURE_LOUDNESS_SETTING) === 'true';
+}
+
+export function setMeasureLoudness(enabled: boolean): void {
+  setSetting(MEASURE_LOUDNESS_SETTING, enabled ? 'true' : 'false');
+}
+
+// Whether a video file is under the minimum size. Files that can't be read are kept, so
+// processing reports them instead of them silently dropping out of the catalog.
+async function isTooSmall(filePath: string, minFileSize: number | undefined): Promise<boolean> {
+  if (!minFileSize) return false;
+  return fs.stat(filePath).then(({ size }) => size < minFileSize, () => false);
+}
+
+// Node decodes names that aren't valid UTF-8 with U+FFFD, and the decoded path no longer
+// leads to the file. Such names can't be stored in the catalog (paths are TEXT), so they
+// are reported instead of indexed under a path that playback can't open.
+async function isUndecodableName(fullPath: string, name: string): Promise<boolean> {
+  if (!name.includes('\uFFFD')) return false;
+  return fs.lstat(fullPath).then(() => false, () => true);
+}
+
+// Bytes from the start of the file included in the fingerprint
+const FINGERPRINT_BYTES = 65536; // 64KB
+
+// Fingerprints from the last scan preview, reused by the full scan that follows it
+const previewFingerprints = new Map<string, { fingerprint: string; size: number; mtimeMs: number }>();
+
 // Generate quick file fingerprint without reading entire file
 export async function getFileFingerprint(filePath: string): Promise<string> {
   const stats = await fs.stat(filePath);
 
-  // Read first 64KB of file for partial hash
-  const fd = await fs.open(filePath, 'r');
-  const buffer = Buffer.alloc(65536); // 64KB
-  const { bytesRead } = await fd.read(buffer, 0, 65536, 0);
-  await fd.close();
+  // Read first 64KB of file for partial hash (the whole file if smaller). The buffer
+  // is sized to what will be read and left unzeroed, since every byte hashed is overwritten.
+  const buffer = Buffer.allocUnsafe(Math.min(stats.size, FINGERPRINT_BYTES));
+  let bytesRead = 0;
+  if (buffer.length > 0) {
+    const fd = await fs.open(filePath, 'r');
+    try {
+      ({ bytesRead } = await fd.read(buffer, 0, buffer.length, 0));
+    } finally {
+      await fd.close();
+    }
+  }
 
   // Create hash combining: first 64KB content + file size + mtime
   const hash = crypto.createHash('md5')
@@ -65,27 +179,102 @@ export async function getFileFingerprint(filePath: string): Promise<string> {
   return hash;
 }
 
+// Thumbnails smaller than this are treated as failed writes
+const MIN_THUMBNAIL_BYTES = 1024;
+
+// Check that a thumbnail exists, isn't tiny, and has JPEG start/end markers (catches truncation)
+async function isThumbnailValid(thumbnailPath: string): Promise<boolean> {
+  try {
+    const { size } = await fs.stat(thumbnailPath);
+    if (size <= MIN_THUMBNAIL_BYTES) return false;
+
+    const fd = await fs.open(thumbnailPath, 'r');
+    try {
+      const head = Buffer.alloc(2);
+      const tail = Buffer.alloc(2);
+      await fd.read(head, 0, 2, 0);
+      await fd.read(tail, 0, 2, size - 2);
+      return head[0] === 0xff && head[1] === 0xd8 && tail[0] === 0xff && tail[1] === 0xd9;
+    } finally {
+      await fd.close();
+    }
+  } catch {
+    return false;
+  }
+}
+
+// Find zero-byte or corrupted thumbnails under scanPath and regenerate them; returns the
+// affected video IDs
+export async function validateThumbnails(rootPath: string, scanPath: string = rootPath): Promise<string[]> {
+  const limit = pLimit(METADATA_CONCURRENCY);
+  const broken: string[] = [];
+
+  // Online-only files are left alone, since ffmpeg would download them
+  await Promise.all(
+    getReadableVideos().videos
+      .filter((video) => video.thumbnailPath && video.duration > 0 && isInsidePath(scanPath, video.filePath))
+      .map((video) => limit(async () => {
+        if (await isThumbnailValid(video.thumbnailPath!)) return;
+
+        broken.push(video.id);
+        try {
+          const thumbnailPath = await generateThumbnailOnly(video.id, video.filePath, rootPath, video.duration, video.posterTime);
+          updateVideoThumbnail(video.id, thumbnailPath);
+        } catch (error) {
+          console.warn(`Failed to regenerate thumbnail for ${video.filePath}:`, error);
+        }
+      }))
+  );
+
+  return broken;
+}
+
 // Recursively scan directory for video files
-export async function* scanDirectory(rootPath: string): AsyncGenerator<string> {
+export async function* scanDirectory(rootPath: string, options: ScanOptions = {}): AsyncGenerator<string> {
   try {
     const entries = await fs.readdir(rootPath, { withFileTypes: true });
 
     for (const entry of entries) {
+      // A hidden iCloud stub stands in for a video whose data is only in the cloud
+      const stubTarget = getICloudStubTarget(entry.name);
+      if (stubTarget && entry.isFile() && isVideoFile(stubTarget) && !shouldSkipPath(stubTarget)) {
+        const stubPath = path.join(rootPath, stubTarget);
+        if (!options.isExcluded?.(stubPath)) {
+          options.onPlaceholder?.(stubPath);
+        }
+        continue;
+      }
       if (shouldSkipPath(entry.name)) {
         continue;
       }
 
       const fullPath = path.join(rootPath, entry.name);
+      if (options.isExcluded?.(fullPath)) {
+        continue;
+      }
+      if ((entry.isDirectory() || isVideoFile(entry.name)) && await isUndecodableName(fullPath, entry.name)) {
+        options.onError?.(`Skipped ${fullPath}: the name isn't valid UTF-8, so it can't be stored in the catalog. Rename it to index it`);
+        continue;
+      }
 
       if (entry.isDirectory()) {
         // Recursively scan subdirectories
-        yield* scanDirectory(fullPath);
+        yield* scanDirectory(fullPath, options);
       } else if (entry.isFile() && isVideoFile(entry.name)) {
+        if (!options.isCataloged?.(fullPath) && await isTooSmall(fullPath, options.minFileSize)) {
+          options.onTooSmall?.(fullPath);
+          continue;
+        }
+        if (await options.isPlaceholder?.(fullPath)) {
+          options.onPlaceholder?.(fullPath);
+          continue;
+        }
         yield fullPath;
       }
     }
   } catch (error) {
     console.error(`Error scanning directory ${rootPath}:`, error);
+    options.onError?.(`Could not read folder ${rootPath}: ${error instanceof Error ? error.message : error}`);
   }
 }
 
@@ -106,31 +295,124 @@ export interface ScanProgressCallback {
     processed: number;
     skipped: number;
     currentFile: string;
+    totalBytes: number; // Size of every video found so far
+    bytesProcessed: number;
+    tooSmall: number; // Left out for being under the library's minimum file size
+    placeholders: number; // Online-only cloud files, not downloaded (left out, or cataloged from the name)
   }): void;
 }
 
+// Import sidecar metadata when the sidecar appeared, changed, or was removed.
+// Returns an error message for malformed sidecars instead of throwing.
+async function syncSidecar(video: Video): Promise<string | null> {
+  const sidecar = await findSidecar(video.filePath);
+  if ((sidecar?.mtime ?? null) === video.sidecarMtime) {
+    return null;
+  }
+
+  if (!sidecar) {
+    updateVideoSidecar(video.id, null);
+    return null;
+  }
+
+  try {
+    updateVideoSidecar(video.id, await readSidecar(sidecar.path, sidecar.mtime));
+    return null;
+  } catch (error) {
+    return `Malformed sidecar ${sidecar.path}: ${error instanceof Error ? error.message : error}`;
+  }
+}
+
+// Record subtitle files added or removed next to the video since the last scan
+async function syncSubtitles(video: Video): Promise<void> {
+  const subtitleFiles = await findSubtitles(video.filePath);
+  if (subtitleFiles.join('\n') !== video.subtitleFiles.join('\n')) {
+    updateVideoSubtitles(video.id, subtitleFiles);
+  }
+}
+
 // Process a single video file with fingerprint check
 async function processVideoFile(
   filePath: string,
   rootPath: string,
-  generateThumbs: boolean = true
-): Promise<{ video: Video | null; skipped: boolean }> {
+  generateThumbs: boolean = true,
+  measureAudio: boolean = false
+): Promise<{ video: Video | null; skipped: boolean; isNew?: boolean; error?: string }> {
   try {
-    // Get file fingerprint
-    const fingerprint = await getFileFingerprint(filePath);
+    // A video in Recently Removed stays out of the catalog, file or not, until it is
+    // restored or purged
+    const existing = getVideoByPath(filePath);
+    if (existing?.removedAt) {
+      previewFingerprints.delete(filePath);
+      return { video: null, skipped: true };
+    }
+
+    // Get file fingerprint (from the preview if the file hasn't changed since)
     const stats = await fs.stat(filePath);
     const fileMtime = stats.mtime.toISOString();
+    const previewed = previewFingerprints.get(filePath);
+    previewFingerprints.delete(filePath);
+    const fingerprint = previewed && previewed.size === stats.size && previewed.mtimeMs === stats.mtimeMs
+      ? previewed.fingerprint
+      : await getFileFingerprint(filePath);
 
     // Check if video already exists with same fingerprint (skip reprocessing)
-    const existing = getVideoByPath(filePath);
     if (existing && existing.fileHash === fingerprint) {
-      // File unchanged, skip processing
-      return { video: existing, skipped: true };
+      // File unchanged, skip processing (sidecars have their own fingerprint)
+      if (existing.onlineOnly) {
+        // Downloaded again since a scan found it only in the cloud
+        setVideoOnlineOnly(existing.id, false);
+      }
+      if (existing.codec === null || existing.audioTrackCount === null || existing.colorSpace === null || existing.location === null || existing.bitRate === null || existing.mediaDate === null) {
+        // Backfill stream info for videos indexed before it was recorded
+        updateVideoStreamInfo(existing.id, await getVideoMetadata(filePath));
+      }
+      if (existing.nameDate === null) {
+        // Cheap enough to retry on every scan; stays null for names without a date
+        const nameDate = parseFileNameDate(existing.fileName);
+        if (nameDate) updateVideoNameDate(existing.id, nameDate);
+      }
+      if (existing.hasSprite && existing.spriteColumns === null && existing.duration > 0) {
+        // Sprites made before the grid was stored used the same duration-based layout
+        updateVideoSpriteGrid(existing.id, getSpriteLayout(existing.duration).config);
+      }
+      const needsSprite = !existing.hasSprite && existing.duration >= MIN_SPRITE_DURATION;
+      if (generateThumbs && existing.duration > 0 && (!existing.thumbnailPath || needsSprite)) {
+        // Regenerate only what a partial Clear Cache removed (or an earlier attempt failed to make)
+        let thumbnailDone = !!existing.thumbnailPath;
+        try {
+          if (!thumbnailDone) {
+            setThumbnailStatus(rootPath, existing.id, 'generating');
+            const thumbnailPath = await generateThumbnailOnly(existing.id, filePath, rootPath, existing.duration, existing.posterTime);
+            updateVideoThumbnail(existing.id, thumbnailPath);
+            setThumbnailStatus(rootPath, existing.id, null);
+            thumbnailDone = true;
+          }
+          if (needsSprite) {
+            const { spritePath, spriteConfig } = await generateSpriteSheetOnly(existing.id, filePath, rootPath, existing.duration, getSpriteDensity());
+            updateVideoSprite(existing.id, spritePath, spriteConfig);
+          }
+        } catch (thumbError) {
+          if (!thumbnailDone) setThumbnailStatus(rootPath, existing.id, 'failed');
+          console.warn(`Failed to regenerate thumbnail/sprite for ${filePath}:`, thumbError);
+        }
+      }
+      if (measureAudio && existing.loudnessLufs === null && existing.audioTrackCount !== 0) {
+        await recordLoudness(existing.id, filePath);
+      }
+      await syncSubtitles(existing);
+      const sidecarError = await syncSidecar(existing);
+      return { video: existing, skipped: true, error: sidecarError ?? undefined };
     }
 
     // Get video metadata using ffprobe
     const metadata = await getVideoMetadata(filePath);
 
+    // Prefer the container's recorded time for AVCHD, falling back to mtime
+    const createdAt = AVCHD_EXTENSIONS.includes(path.extname(filePath).toLowerCase())
+      ? (parseCreationTime(metadata.creationTime) ?? fileMtime)
+      : stats.birthtime.toISOString();
+
     // Prepare video data for insertion
     const videoData: VideoInsertData = {
       filePath,
@@ -139,55 +421,182 @@ async function processVideoFile(
       duration: metadata.duration,
       width: metadata.width,
       height: metadata.height,
-      createdAt: stats.birthtime.toISOString(),
+      createdAt,
       directory: path.dirname(filePath),
       fileHash: fingerprint,
       fileMtime: fileMtime,
+      frameRate: metadata.frameRate,
+      codec: metadata.codec,
+      videoStreamCount: metadata.videoStreamCount,
+      audioTrackCount: metadata.audioTrackCount,
+      colorSpace: metadata.colorSpace,
+      hdrFormat: metadata.hdrFormat,
+      location: metadata.location,
+      latitude: metadata.latitude,
+      longitude: metadata.longitude,
+      bitRate: metadata.bitRate,
+      mediaDate: parseCreationTime(metadata.creationTime) ?? '',
+      nameDate: parseFileNameDate(path.basename(filePath)),
+      // Keep a user-chosen poster frame across re-processing
+      posterTime: existing?.posterTime ?? null,
     };
 
     // Insert video record
     const video = insertVideo(videoData);
 
     // Generate thumbnail AND sprite in parallel (for immediate hover scrubbing)
-    if (generateThumbs && metadata.duration > 0) {
+    if (generateThumbs && metadata.duration > 0 && metadata.duration < MIN_SPRITE_DURATION) {
+      setThumbnailStatus(rootPath, video.id, 'generating');
+      try {
+        updateVideoThumbnail(video.id, await generateThumbnailOnly(video.id, filePath, rootPath, metadata.duration, video.posterTime));
+        setThumbnailStatus(rootPath, video.id, null);
+      } catch (thumbError) {
+        setThumbnailStatus(rootPath, video.id, 'failed');
+        console.warn(`Failed to generate thumbnail for ${filePath}:`, thumbError);
+      }
+    } else if (generateThumbs && metadata.duration > 0) {
+      setThumbnailStatus(rootPath, video.id, 'generating');
       try {
         const [thumbnailPath, spriteResult] = await Promise.all([
-          generateThumbnailOnly(video.id, filePath, rootPath, metadata.duration),
-          generateSpriteSheetOnly(video.id, filePath, rootPath, metadata.duration)
+          generateThumbnailOnly(video.id, filePath, rootPath, metadata.duration, video.posterTime),
+          generateSpriteSheetOnly(video.id, filePath, rootPath, metadata.duration, getSpriteDensity())
         ]);
-        updateVideoThumbnailAndSprite(video.id, thumbnailPath, spriteResult.spritePath);
+        updateVideoThumbnailAndSprite(video.id, thumbnailPath, spriteResult.spritePath, spriteResult.spriteConfig);
+        setThumbnailStatus(rootPath, video.id, null);
       } catch (thumbError) {
-        console.error(`Failed to generate thumbnail/sprite for ${filePath}:`, thumbError);
+        setThumbnailStatus(rootPath, video.id, 'failed');
+        console.warn(`Failed to generate thumbnail/sprite for ${filePath}:`, thumbError);
       }
     }
 
-    return { video, skipped: false };
+    if (measureAudio && metadata.audioTrackCount > 0) {
+      await recordLoudness(video.id, filePath);
+    }
+
+    await syncSubtitles(video);
+    const sidecarError = await syncSidecar(video);
+    return { video, skipped: false, isNew: !existing, error: sidecarError ?? undefined };
   } catch (error) {
-    console.error(`Error processing video ${filePath}:`, error);
-    return { video: null, skipped: false };
+    console.warn(`Error processing video ${filePath}:`, error);
+    return {
+      video: null,
+      skipped: false,
+      error: `Failed to index ${filePath}: ${error instanceof Error ? error.message : error}`,
+    };
   }
 }
 
-// Scan a directory and process all video files with parallel processing
+// Record an online-only file without downloading it. A file already in the catalog
+// (indexed before it was evicted, or in Recently Removed) keeps its entry, flagged so
+// previews don't read it. A new one is cataloged, when catalogNew is set, from what the
+// folder listing tells: name, size, times and a date in the name. Once the file is
+// downloaded, its missing fingerprint makes the next scan index it properly.
+async function recordOnlineOnly(
+  filePath: string,
+  catalogNew: boolean
+): Promise<{ video: Video | null; isNew: boolean; error?: string }> {
+  const existing = getVideoByPath(filePath);
+  if (existing) {
+    if (!existing.onlineOnly) setVideoOnlineOnly(existing.id, true);
+    return { video: existing.removedAt ? null : existing, isNew: false };
+  }
+  if (!catalogNew) {
+    return { video: null, isNew: false };
+  }
+
+  try {
+    // An iCloud stub leaves nothing at the path, so its size isn't known
+    const stats = await fs.stat(filePath).catch(() => null);
+    const video = insertVideo({
+      filePath,
+      fileName: path.basename(filePath),
+      fileSize: stats?.size ?? 0,
+      duration: 0,
+      width: null,
+      height: null,
+      createdAt: (stats?.birthtime ?? new Date()).toISOString(),
+      directory: path.dirname(filePath),
+      fileMtime: stats?.mtime.toISOString(),
+      nameDate: parseFileNameDate(path.basename(filePath)),
+      onlineOnly: true,
+    });
+    return { video, isNew: true };
+  } catch (error) {
+    return {
+      video: null,
+      isNew: false,
+      error: `Failed to catalog online-only file ${filePath}: ${error instanceof Error ? error.message : error}`,
+    };
+  }
+}
+
+// Measure and store a video's loudness; failures are logged and retried next scan
+async function recordLoudness(videoId: string, filePath: string): Promise<void> {
+  try {
+    const lufs = await measureLoudness(filePath);
+    if (lufs !== null) updateVideoLoudness(videoId, lufs);
+  } catch (error) {
+    console.warn(`Failed to measure loudness of ${filePath}:`, error);
+  }
+}
+
+// Index one file outside a full scan (used by the folder watcher); true if the catalog changed
+export async function processSingleVideo(filePath: string, rootPath: string): Promise<boolean> {
+  // Reading an online-only file would download it; the next scan decides what to do with it
+  if (await createPlaceholderCheck()(filePath)) return false;
+
+  const result = await processVideoFile(filePath, rootPath, true, getMeasureLoudness());
+  if (result.error) {
+    console.warn(result.error);
+  }
+  return result.video !== null && !result.skipped;
+}
+
+// Scan a directory and process all video files with parallel processing. With scanPath,
+// only that folder inside the library is walked, and only its videos can be removed.
 export async function scanAndProcessDirectory(
   rootPath: string,
-  onProgress?: ScanProgressCallback
-): Promise<{ scanId: string; videosFound: number; videosProcessed: number; videosSkipped: number }> {
+  onProgress?: ScanProgressCallback,
+  isCancelled?: () => boolean,
+  scanPath: string = rootPath
+): Promise<{ scanId: string; videosFound: number; videosProcessed: number; videosSkipped: number; videosTooSmall: number; videosOnlineOnly: number; errors: string[] }> {
   // Verify directory exists
   try {
-    const stats = await fs.stat(rootPath);
+    const stats = await fs.stat(scanPath);
     if (!stats.isDirectory()) {
       throw new Error('Path is not a directory');
     }
   } catch {
-    throw new Error(`Invalid directory path: ${rootPath}`);
+    throw new Error(`Invalid directory path: ${scanPath}`);
+  }
+  if (!isInsidePath(rootPath, scanPath)) {
+    throw new Error(`${scanPath} is not inside the library ${rootPath}`);
+  }
+
+  // Fail loudly instead of indexing nothing when FFmpeg is missing
+  if (!(await checkFFmpegAvailable())) {
+    throw new Error('FFmpeg was not found on PATH. Install it (e.g. brew install ffmpeg) and scan again.');
   }
 
   // Initialize database for this root path (stored on source drive)
   initDatabase(rootPath);
 
-  // Create scan record
-  const scanId = createScan(rootPath);
+  // Fail before touching the catalog if a saved pattern doesn't compile
+  const isExcluded = compileExclusions(rootPath, getExclusions());
+  if (scanPath !== rootPath && isSkippedPath(rootPath, scanPath, isExcluded)) {
+    throw new Error(`${scanPath} is excluded from the library`);
+  }
+
+  // Create scan record. Videos are inserted as each one finishes, so an interrupted
+  // scan's work is kept and this scan skips it by fingerprint.
+  closeInterruptedScans();
+  const scanId = createScan(rootPath, scanPath);
+
+  // Bring back videos hidden by a pattern that has since been removed, so the walk
+  // below treats them like any other catalog entry (and drops them if they're gone)
+  const unexcluded = getExcludedVideos()
+    .filter((video) => isInsidePath(scanPath, video.filePath) && !isExcluded(video.filePath));
+  setVideosExcluded(unexcluded.map((video) => video.id), false);
 
   // Phase 1: Quick count of all videos
   onProgress?.({
@@ -196,42 +605,110 @@ export async function scanAndProcessDirectory(
     processed: 0,
     skipped: 0,
     currentFile: 'Counting videos...',
+    totalBytes: 0,
+    bytesProcessed: 0,
+    tooSmall: 0,
+    placeholders: 0,
   });
 
   const videoPaths: string[] = [];
-  for await (const videoPath of scanDirectory(rootPath)) {
+  const fileSizes = new Map<string, number>();
+  let totalBytes = 0;
+  const errors: string[] = [];
+  const onWalkError = (message: string) => {
+    console.warn(message);
+    errors.push(message);
+  };
+  const tooSmallPaths = new Set<string>();
+  const placeholderPaths = new Set<string>();
+  // The minimum size only keeps new files out; videos indexed before it was set or
+  // raised stay in the catalog
+  const catalogedPaths = new Set(getAllVideos().map((video) => video.filePath));
+  const walkOptions: ScanOptions = {
+    isExcluded,
+    onError: onWalkError,
+    minFileSize: getMinFileSize(rootPath),
+    isCataloged: (filePath) => catalogedPaths.has(filePath),
+    onTooSmall: (filePath) => tooSmallPaths.add(filePath),
+    isPlaceholder: createPlaceholderCheck(),
+    onPlaceholder: (filePath) => placeholderPaths.add(filePath),
+    measureLoudness: getMeasureLoudness(),
+  };
+  for await (const videoPath of scanDirectory(scanPath, walkOptions)) {
+    if (isCancelled?.()) break;
     videoPaths.push(videoPath);
+    try {
+      const { size } = await fs.stat(videoPath);
+      fileSizes.set(videoPath, size);
+      totalBytes += size;
+    } catch {
+      // Unreadable files are reported when processed
+    }
     onProgress?.({
       phase: 'counting',
       totalVideos: videoPaths.length,
       processed: 0,
       skipped: 0,
       currentFile: videoPath,
+      totalBytes,
+      bytesProcessed: 0,
+      tooSmall: tooSmallPaths.size,
+      placeholders: placeholderPaths.size,
     });
   }
 
   const totalVideos = videoPaths.length;
+  let bytesProcessed = 0;
 
   // Phase 2: Process videos with parallel metadata extraction
   let videosProcessed = 0;
   let videosSkipped = 0;
   let videosFound = 0;
 
+  // Totals and per-folder counts for the scan history
+  const newCounts = (): ScanCounts => ({ added: 0, updated: 0, skipped: 0, removed: 0, errored: 0 });
+  const counts = newCounts();
+  const folders: Record<string, ScanCounts> = {};
+  const countFile = (filePath: string, key: keyof ScanCounts) => {
+    const folder = path.relative(rootPath, path.dirname(filePath)) || '.';
+    if (!folders[folder]) folders[folder] = newCounts();
+    folders[folder][key]++;
+    counts[key]++;
+  };
+  const getDetails = (): ScanResultDetails => ({ counts, folders, errors });
+
   // Use p-limit for bounded concurrency
   const limit = pLimit(METADATA_CONCURRENCY);
 
   // Process in batches for progress updates
   const processVideo = async (videoPath: string) => {
-    const result = await processVideoFile(videoPath, rootPath, true);
+    // Drain the remaining queue quickly once cancelled
+    if (isCancelled?.()) return null;
+
+    const result = await processVideoFile(videoPath, rootPath, true, walkOptions.measureLoudness);
+
+    if (result.error) {
+      console.warn(result.error);
+      errors.push(result.error);
+    }
 
     if (result.video) {
       videosFound++;
       if (result.skipped) {
         videosSkipped++;
+        countFile(videoPath, 'skipped');
       } else {
         videosProcessed++;
+        countFile(videoPath, result.isNew ? 'added' : 'updated');
       }
+    } else if (result.skipped) {
+      // In Recently Removed
+      videosSkipped++;
+      countFile(videoPath, 'skipped');
+    } else {
+      countFile(videoPath, 'errored');
     }
+    bytesProcessed += fileSizes.get(videoPath) ?? 0;
 
     onProgress?.({
       phase: 'processing',
@@ -239,6 +716,10 @@ export async function scanAndProcessDirectory(
       processed: videosProcessed,
       skipped: videosSkipped,
       currentFile: videoPath,
+      totalBytes,
+      bytesProcessed,
+      tooSmall: tooSmallPaths.size,
+      placeholders: placeholderPaths.size,
     });
 
     updateScanProgress(scanId, videosFound);
@@ -251,10 +732,185 @@ export async function scanAndProcessDirectory(
     videoPaths.map(videoPath => limit(() => processVideo(videoPath)))
   );
 
+  // Online-only files already in the catalog are flagged; new ones are only cataloged
+  // when the library asks for it
+  const catalogPlaceholders = getCloudPlaceholderMode() === 'catalog';
+  for (const placeholderPath of placeholderPaths) {
+    if (isCancelled?.()) break;
+    const result = await recordOnlineOnly(placeholderPath, catalogPlaceholders);
+    if (result.error) {
+      console.warn(result.error);
+      errors.push(result.error);
+      countFile(placeholderPath, 'errored');
+    } else if (result.video) {
+      videosFound++;
+      countFile(placeholderPath, result.isNew ? 'added' : 'skipped');
+    }
+  }
+  updateScanProgress(scanId, videosFound);
+
+  if (isCancelled?.()) {
+    failScan(scanId, 'Cancelled', getDetails());
+    throw new Error('Scan cancelled');
+  }
+
+  // Drop catalog entries whose file was deleted or is now skipped. Excluded ones are only
+  // hidden, keeping favorites and notes in case the pattern is removed again. Folders
+  // outside a subfolder scan weren't walked, so their videos are left alone, and
+  // online-only files keep what was indexed before they were evicted.
+  const foundPaths = new Set(videoPaths);
+  const newlyExcluded: string[] = [];
+  for (const video of getAllVideos()) {
+    if (foundPaths.has(video.filePath) || placeholderPaths.has(video.filePath) || !isInsidePath(scanPath, video.filePath)) continue;
+    if (isExcluded(video.filePath)) {
+      newlyExcluded.push(video.id);
+      countFile(video.filePath, 'removed');
+      continue;
+    }
+    if (!isSkippedPath(rootPath, video.filePath) && fsSync.existsSync(video.filePath)) continue;
+    deleteVideoByPath(video.filePath);
+    countFile(video.filePath, 'removed');
+  }
+  setVideosExcluded(newlyExcluded, true);
+
+  // Folder totals for folder views, and pruning of state for folders that are gone
+  refreshDirectoryStats(rootPath);
+
+  // Repair thumbnails that failed silently (zero-byte or truncated)
+  const brokenThumbnails = await validateThumbnails(rootPath, scanPath);
+  if (brokenThumbnails.length > 0) {
+    console.warn(`Regenerated ${brokenThumbnails.length} broken thumbnail(s)`);
+  }
+
   // Mark scan as complete
-  completeScan(scanId, videosFound);
+  completeScan(scanId, videosFound, getDetails());
+  recordLibraryScanned(rootPath, getLibraryTotals());
+  clearDuplicateFileNames();
+  previewFingerprints.clear();
+
+  // Read the top of the grid (default sort) into memory while the UI reloads the list
+  prefetchThumbnails(getAllVideos().map((video) => video.thumbnailPath)).catch((error) => {
+    console.warn('Failed to prefetch thumbnails:', error);
+  });
+
+  // Record how much space the generated assets now take
+  try {
+    await refreshLibraryCacheSize(rootPath);
+  } catch (error) {
+    console.error('Failed to compute cache size:', error);
+  }
+
+  return { scanId, videosFound, videosProcessed, videosSkipped, videosTooSmall: tooSmallPaths.size, videosOnlineOnly: placeholderPaths.size, errors };
+}
+
+// Whether target is dir itself or somewhere below it
+function isInsidePath(dir: string, target: string): boolean {
+  const relative = path.relative(dir, target);
+  return relative !== '..' && !relative.startsWith(`..${path.sep}`) && !path.isAbsolute(relative);
+}
+
+// Walk like scanDirectory, also collecting what the skip rules, exclusions, minimum size and
+// online-only check leave out (the preview never downloads, whatever the library does with them)
+async function walkForPreview(dir: string, videoPaths: string[], excluded: string[], options: ScanOptions): Promise<void> {
+  const entries = await fs.readdir(dir, { withFileTypes: true });
+  for (const entry of entries) {
+    const fullPath = path.join(dir, entry.name);
+    if (shouldSkipPath(entry.name)) {
+      // Our own data folder isn't something the user excluded
+      if (entry.name !== '.vcb-data') excluded.push(fullPath);
+    } else if (options.isExcluded?.(fullPath)) {
+      excluded.push(fullPath);
+    } else if (entry.isDirectory()) {
+      await walkForPreview(fullPath, videoPaths, excluded, options);
+    } else if (entry.isFile() && isVideoFile(entry.name)) {
+      const isTooSmallNew = !options.isCataloged?.(fullPath) && await isTooSmall(fullPath, options.minFileSize);
+      if (isTooSmallNew || await options.isPlaceholder?.(fullPath)) {
+        excluded.push(fullPath);
+      } else {
+        videoPaths.push(fullPath);
+      }
+    }
+  }
+}
+
+// Dry run: walk, fingerprint and look each file up in the catalog, without ffprobe,
+// thumbnails or inserts. The catalog is opened read-only and not created if missing.
+export async function previewScan(
+  rootPath: string,
+  onProgress?: (done: number, total: number, currentFile: string) => void,
+  isCancelled?: () => boolean
+): Promise<ScanPreview> {
+  const videoPaths: string[] = [];
+  const excluded: string[] = [];
+  const isExcluded = compileExclusions(rootPath, getLibraryExclusions(rootPath));
+  const catalog = new Map(
+    (withLibraryDatabase(rootPath, (db) => searchVideos({ includeRemoved: true }, db, rootPath).videos) ?? [])
+      .map((video): [string, Video] => [video.filePath, video])
+  );
+  await walkForPreview(rootPath, videoPaths, excluded, {
+    isExcluded,
+    minFileSize: getMinFileSize(rootPath),
+    isCataloged: (filePath) => catalog.has(filePath),
+    isPlaceholder: createPlaceholderCheck(),
+  });
+
+  const newCounts = (): ScanPreviewCounts => ({ new: 0, changed: 0, cached: 0, bytes: 0 });
+  const preview: ScanPreview = {
+    ...newCounts(),
+    rootPath,
+    excluded: excluded.length,
+    errors: [],
+    cachedBytes: 0,
+    cachedDuration: 0,
+    estimatedDuration: null,
+    folders: {},
+  };
+
+  previewFingerprints.clear();
+  const limit = pLimit(METADATA_CONCURRENCY);
+  let done = 0;
+
+  await Promise.all(videoPaths.map((filePath) => limit(async () => {
+    if (isCancelled?.()) return;
+
+    try {
+      const stats = await fs.stat(filePath);
+      const fingerprint = await getFileFingerprint(filePath);
+      previewFingerprints.set(filePath, { fingerprint, size: stats.size, mtimeMs: stats.mtimeMs });
+
+      const existing = catalog.get(filePath);
+      // Files in Recently Removed are skipped by the scan, like unchanged ones
+      const key = !existing ? 'new' : existing.removedAt || existing.fileHash === fingerprint ? 'cached' : 'changed';
+      const folder = path.relative(rootPath, filePath).split(path.sep)[0];
+      const topLevel = folder === path.basename(filePath) ? '.' : folder;
+      if (!preview.folders[topLevel]) preview.folders[topLevel] = newCounts();
+
+      for (const counts of [preview, preview.folders[topLevel]]) {
+        counts[key]++;
+        if (key !== 'cached') counts.bytes += stats.size;
+      }
+      if (key === 'cached') {
+        preview.cachedBytes += stats.size;
+        preview.cachedDuration += existing!.duration;
+      }
+    } catch (error) {
+      preview.errors.push(`Failed to read ${filePath}: ${error instanceof Error ? error.message : error}`);
+    }
+
+    onProgress?.(++done, videoPaths.length, filePath);
+  })));
+
+  if (isCancelled?.()) {
+    previewFingerprints.clear();
+    throw new Error('Preview cancelled');
+  }
+
+  // New footage is assumed to have the same bitrate as what's already indexed
+  if (preview.cachedBytes > 0) {
+    preview.estimatedDuration = preview.bytes * (preview.cachedDuration / preview.cachedBytes);
+  }
 
-  return { scanId, videosFound, videosProcessed, videosSkipped };
+  return preview;
 }
 
 // Quick scan - just find video files without processing metadata
@@ -306,9 +962,7 @@ export async function validatePath(dirPath: string): Promise<{ valid: boolean; e
   }
 }
 
-// Get recently used directories from settings
+// Get recently opened libraries (most recent first)
 export function getRecentDirectories(): string[] {
-  // This would be stored in the settings table
-  // For now, return empty array
-  return [];
+  return getLibraryHistory().map((entry) => entry.path);
 }
@@ -0,0 +1,39 @@
+// Client-safe pool of hover scrub videos kept open between hovers. Re-hovering a card
+// whose video is still open skips re-opening the file, but every open <video> holds a
+// decoder and buffered data, so only the most recently hovered few stay mounted.
+
+export const MAX_OPEN_SCRUB_VIDEOS = 6;
+
+export interface ScrubVideoPool {
+  // Mark a card's video as just used; the least recently used beyond the limit are released
+  retain(owner: object, release: () => void): void;
+  // Drop a card that unmounted (its video is gone with it)
+  forget(owner: object): void;
+  size(): number;
+}
+
+export function createScrubVideoPool(maxOpen: number = MAX_OPEN_SCRUB_VIDEOS): ScrubVideoPool {
+  // Insertion order is recency order: the first entry is the least recently hovered
+  const open = new Map<object, () => void>();
+
+  return {
+    retain(owner, release) {
+      open.delete(owner);
+      open.set(owner, release);
+      while (open.size > maxOpen) {
+        const [oldest, releaseOldest] = open.entries().next().value!;
+        open.delete(oldest);
+        releaseOldest();
+      }
+    },
+    forget(owner) {
+      open.delete(owner);
+    },
+    size() {
+      return open.size;
+    },
+  };
+}
+
+// Shared by every card in the page
+export const scrubVideoPool = createScrubVideoPool();
@@ -0,0 +1,120 @@
+import fs from 'fs/promises';
+import path from 'path';
+
+// Per-clip sidecar metadata (.xmp from Resolve/Adobe, .json from DIT tools)
+
+export interface SidecarMetadata {
+  title: string | null;
+  description: string | null;
+  keywords: string[];
+  mtime: string;
+}
+
+// Sidecar file names checked for a video, in priority order
+function getSidecarCandidates(videoPath: string): string[] {
+  const dir = path.dirname(videoPath);
+  const stem = path.basename(videoPath, path.extname(videoPath));
+  return [
+    path.join(dir, `${stem}.xmp`),
+    `${videoPath}.xmp`,
+    path.join(dir, `${stem}.json`),
+  ];
+}
+
+// Find the sidecar for a video (null if it has none)
+export async function findSidecar(videoPath: string): Promise<{ path: string; mtime: string } | null> {
+  for (const candidate of getSidecarCandidates(videoPath)) {
+    try {
+      const stats = await fs.stat(candidate);
+      if (stats.isFile()) {
+        return { path: candidate, mtime: stats.mtime.toISOString() };
+      }
+    } catch {
+      // Not present, try the next candidate
+    }
+  }
+  return null;
+}
+
+function decodeXmlEntities(value: string): string {
+  return value
+    .replace(/&lt;/g, '<')
+    .replace(/&gt;/g, '>')
+    .replace(/&quot;/g, '"')
+    .replace(/&apos;/g, "'")
+    .replace(/&amp;/g, '&')
+    .trim();
+}
+
+// Text of an XMP property, either as an element (optionally wrapping rdf:li items) or an attribute
+function getXmpValues(xml: string, property: string): string[] {
+  const element = xml.match(new RegExp(`<${property}[^>]*>([\\s\\S]*?)</${property}>`));
+  if (element) {
+    const items = [...element[1].matchAll(/<rdf:li[^>]*>([\s\S]*?)<\/rdf:li>/g)].map((m) => m[1]);
+    return (items.length > 0 ? items : [element[1]])
+      .map(decodeXmlEntities)
+      .filter(Boolean);
+  }
+
+  const attribute = xml.match(new RegExp(`${property}="([^"]*)"`));
+  return attribute ? [decodeXmlEntities(attribute[1])].filter(Boolean) : [];
+}
+
+// Lenient XMP parser: pulls title, description, keywords, scene and take
+function parseXmp(xml: string): Omit<SidecarMetadata, 'mtime'> {
+  if (!xml.includes('x:xmpmeta') && !xml.includes('rdf:RDF')) {
+    throw new Error('Not an XMP document');
+  }
+
+  const scene = getXmpValues(xml, 'xmpDM:scene')[0];
+  const take = getXmpValues(xml, 'xmpDM:takeNumber')[0];
+
+  return {
+    title: getXmpValues(xml, 'dc:title')[0] || null,
+    description: getXmpValues(xml, 'dc:description')[0] || null,
+    keywords: [
+      ...getXmpValues(xml, 'dc:subject'),
+      ...(scene ? [`Scene ${scene}`] : []),
+      ...(take ? [`Take ${take}`] : []),
+    ],
+  };
+}
+
+// Lenient JSON parser: accepts common key spellings, keywords as array or comma string
+function parseJsonSidecar(text: string): Omit<SidecarMetadata, 'mtime'> {
+  const data = JSON.parse(text);
+  if (!data || typeof data !== 'object' || Array.isArray(data)) {
+    throw new Error('Expected a JSON object');
+  }
+
+  const lookup = (...keys: string[]): unknown => {
+    const entry = Object.entries(data).find(([key]) => keys.includes(key.toLowerCase()));
+    return entry?.[1];
+  };
+  const asString = (value: unknown) => (value === undefined || value === null || value === '' ? null : String(value));
+  const asList = (value: unknown): string[] => {
+    if (Array.isArray(value)) return value.map(String).map((v) => v.trim()).filter(Boolean);
+    if (typeof value === 'string') return value.split(',').map((v) => v.trim()).filter(Boolean);
+    return [];
+  };
+
+  const scene = asString(lookup('scene'));
+  const take = asString(lookup('take', 'takenumber'));
+
+  return {
+    title: asString(lookup('title', 'name')),
+    description: asString(lookup('description', 'comment', 'notes')),
+    keywords: [
+      ...asList(lookup('keywords', 'tags', 'subject')),
+      ...(scene ? [`Scene ${scene}`] : []),
+      ...(take ? [`Take ${take}`] : []),
+    ],
+  };
+}
+
+// Read and parse a sidecar file; throws on malformed content
+export async function readSidecar(sidecarPath: string, mtime: string): Promise<SidecarMetadata> {
+  const text = await fs.readFile(sidecarPath, 'utf-8');
+  const parsed = sidecarPath.endsWith('.json') ? parseJsonSidecar(text) : parseXmp(text);
+  return { ...parsed, mtime };
+}
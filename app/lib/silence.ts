@@ -0,0 +1,98 @@
+import { SilenceReport, Video } from './types';
+import { getCurrentRootPath, getSetting, getSilenceReport, saveSilenceAnalysis, setSetting } from './db';
+import { detectSilence } from './ffmpeg';
+import { startTask } from './tasks';
+
+// Silence detection for catching audio dropouts (server-side only). It is a separate
+// pass, never part of a scan. Every silent stretch of at least SILENCE_MIN_SECONDS is
+// stored, so changing the warning threshold doesn't need a new analysis. A clip is
+// analyzed again only when its fingerprint changes.
+
+// Anything quieter than this counts as silence (a dropped lav sits near the noise floor)
+const SILENCE_NOISE_DB = -50;
+// Shortest stretch stored; ordinary pauses in speech are shorter
+const SILENCE_MIN_SECONDS = 1;
+
+const THRESHOLD_SETTING = 'silence_threshold';
+export const DEFAULT_SILENCE_THRESHOLD = 5;
+
+// Seconds of continuous silence that flag a card
+export function getSilenceThreshold(): number {
+  const value = Number(getSetting(THRESHOLD_SETTING));
+  return Number.isFinite(value) && value > 0 ? value : DEFAULT_SILENCE_THRESHOLD;
+}
+
+export function setSilenceThreshold(seconds: number): void {
+  setSetting(THRESHOLD_SETTING, String(seconds));
+}
+
+// Stored report for an unchanged file, or a new analysis
+export async function analyzeSilence(
+  video: Video,
+  onProgress?: (progress: number) => void,
+  signal?: AbortSignal
+): Promise<SilenceReport> {
+  const existing = getSilenceReport(video.id);
+  if (existing) return existing;
+
+  const { hasAudio, ranges } = await detectSilence(
+    video.filePath,
+    video.duration,
+    SILENCE_NOISE_DB,
+    SILENCE_MIN_SECONDS,
+    onProgress,
+    signal
+  );
+  saveSilenceAnalysis(video.id, video.fileHash, hasAudio, ranges);
+  return getSilenceReport(video.id)!;
+}
+
+// Analyze clips one at a time as a background task; already analyzed ones are skipped
+export function startSilenceBatch(videos: Video[]): string {
+  const pending = videos.filter((video) => !getSilenceReport(video.id));
+  const task = startTask('silence', `Checking ${pending.length} clip${pending.length !== 1 ? 's' : ''} for silence`);
+
+  const rootPath = getCurrentRootPath();
+  const run = async () => {
+    let failed = 0;
+    for (const [i, video] of pending.entries()) {
+      if (task.isCancelled()) break;
+      if (getCurrentRootPath() !== rootPath) {
+        task.finish('error', 'Another library was opened');
+        return;
+      }
+      task.setProgress(i, pending.length, video.fileName);
+
+      const controller = new AbortController();
+      const cancelCheck = setInterval(() => {
+        if (task.isCancelled()) controller.abort();
+      }, 500);
+      try {
+        await analyzeSilence(video, undefined, controller.signal);
+      } catch (error) {
+        if (!controller.signal.aborted) {
+          failed++;
+          console.warn(`Silence detection failed for ${video.filePath}:`, error);
+        }
+      } finally {
+        clearInterval(cancelCheck);
+      }
+    }
+
+    if (task.isCancelled()) {
+      task.finish('cancelled');
+    } else if (failed > 0) {
+      task.finish('error', `${failed} of ${pending.length} clips failed`);
+    } else {
+      task.setProgress(pending.length, pending.length);
+      task.finish();
+    }
+  };
+
+  run().catch((error) => {
+    console.error('Silence batch failed:', error);
+    task.finish('error', error instanceof Error ? error.message : String(error));
+  });
+
+  return task.id;
+}
@@ -0,0 +1,69 @@
+import crypto from 'crypto';
+import { getSetting, setSetting } from './db';
+import { SearchMode, SmartView, SmartViewCriteria, SortOption, WatchedFilter } from './types';
+
+// Saved searches for the open library (server-side only), kept as JSON in the settings table
+
+const SMART_VIEWS_SETTING = 'smart_views';
+
+const SORT_OPTIONS: SortOption[] = [
+  'date-asc', 'date-desc', 'duration-asc', 'duration-desc', 'name-asc', 'name-desc', 'watched-asc', 'unwatched-first',
+  'best-date-asc', 'best-date-desc', 'scanned-asc', 'scanned-desc',
+  'bitrate-asc', 'bitrate-desc',
+];
+const SEARCH_MODES: SearchMode[] = ['name', 'all'];
+const WATCHED_FILTERS: WatchedFilter[] = ['any', 'watched', 'unwatched'];
+
+export function getSmartViews(): SmartView[] {
+  const value = getSetting(SMART_VIEWS_SETTING);
+  if (!value) return [];
+  try {
+    const views = JSON.parse(value);
+    // Normalize so views saved before a criterion existed still compare equal
+    return Array.isArray(views)
+      ? views.map((view: SmartView) => ({ id: view.id, name: view.name, ...parseSmartViewCriteria(view) }))
+      : [];
+  } catch {
+    return [];
+  }
+}
+
+function writeSmartViews(views: SmartView[]): void {
+  setSetting(SMART_VIEWS_SETTING, JSON.stringify(views));
+}
+
+// Normalize criteria from a request body, falling back to defaults for unknown values
+export function parseSmartViewCriteria(body: Partial<SmartViewCriteria>): SmartViewCriteria {
+  return {
+    query: typeof body.query === 'string' ? body.query.trim() : '',
+    searchMode: SEARCH_MODES.includes(body.searchMode as SearchMode) ? body.searchMode as SearchMode : 'name',
+    favoritesOnly: body.favoritesOnly === true,
+    watchedFilter: WATCHED_FILTERS.includes(body.watchedFilter as WatchedFilter) ? body.watchedFilter as WatchedFilter : 'any',
+    inefficientOnly: body.inefficientOnly === true,
+    sortBy: SORT_OPTIONS.includes(body.sortBy as SortOption) ? body.sortBy as SortOption : 'date-desc',
+  };
+}
+
+export function createSmartView(name: string, criteria: SmartViewCriteria): SmartView {
+  const view: SmartView = { id: crypto.randomUUID(), name, ...criteria };
+  writeSmartViews([...getSmartViews(), view]);
+  return view;
+}
+
+// Returns the updated view, or null if it doesn't exist
+export function renameSmartView(id: string, name: string): SmartView | null {
+  const views = getSmartViews();
+  const view = views.find((v) => v.id === id);
+  if (!view) return null;
+  view.name = name;
+  writeSmartViews(views);
+  return view;
+}
+
+export function deleteSmartView(id: string): boolean {
+  const views = getSmartViews();
+  const remaining = views.filter((v) => v.id !== id);
+  if (remaining.length === views.length) return false;
+  writeSmartViews(remaining);
+  return true;
+}
@@ -0,0 +1,96 @@
+import { promises as fs } from 'fs';
+import { SpriteDensity } from './types';
+import { clearVideoSprite, getAllVideos, getCurrentRootPath, getSetting, setSetting, updateVideoSprite } from './db';
+import { generateSpriteSheetOnly, MIN_SPRITE_DURATION } from './ffmpeg';
+import { startTask } from './tasks';
+
+// Sprite sheet density for hover scrubbing (server-side only). A library either keeps
+// the duration-based layout or sets its own tile size, columns and tile count. Existing
+// sprites keep the layout they were made with until they are regenerated.
+
+const DENSITY_SETTING = 'sprite_density';
+export const DEFAULT_SPRITE_DENSITY: SpriteDensity = { tileWidth: 160, columns: 10, targetTiles: 100 };
+
+// Limits that keep a sprite sheet a reasonable single JPEG
+export const SPRITE_LIMITS = {
+  tileWidth: { min: 64, max: 480 },
+  columns: { min: 1, max: 30 },
+  targetTiles: { min: 1, max: 600 },
+};
+
+export function isValidSpriteDensity(value: unknown): value is SpriteDensity {
+  if (!value || typeof value !== 'object') return false;
+  const density = value as Record<string, unknown>;
+  return (Object.keys(SPRITE_LIMITS) as (keyof SpriteDensity)[]).every((key) => {
+    const n = density[key];
+    return typeof n === 'number' && Number.isInteger(n) && n >= SPRITE_LIMITS[key].min && n <= SPRITE_LIMITS[key].max;
+  });
+}
+
+// The open library's custom density, or null for the duration-based layout
+export function getSpriteDensity(): SpriteDensity | null {
+  const value = getSetting(DENSITY_SETTING);
+  if (!value) return null;
+  try {
+    const density = JSON.parse(value);
+    return isValidSpriteDensity(density) ? density : null;
+  } catch {
+    return null;
+  }
+}
+
+export function setSpriteDensity(density: SpriteDensity | null): void {
+  setSetting(DENSITY_SETTING, density ? JSON.stringify(density) : '');
+}
+
+// Remake every sprite with the current density as a background task, one video at a
+// time. Clips under MIN_SPRITE_DURATION lose their sprite instead.
+export function startSpriteRegeneration(): string {
+  const videos = getAllVideos().filter((video) => video.duration > 0);
+  const task = startTask('sprites', `Regenerating ${videos.length} sprite${videos.length !== 1 ? 's' : ''}`);
+
+  const rootPath = getCurrentRootPath();
+  const density = getSpriteDensity();
+  const run = async () => {
+    let failed = 0;
+    for (const [i, video] of videos.entries()) {
+      if (task.isCancelled()) break;
+      if (!rootPath || getCurrentRootPath() !== rootPath) {
+        task.finish('error', 'Another library was opened');
+        return;
+      }
+      task.setProgress(i, videos.length, video.fileName);
+
+      try {
+        if (video.duration < MIN_SPRITE_DURATION) {
+          if (video.spritePath) {
+            await fs.unlink(video.spritePath).catch(() => {});
+            clearVideoSprite(video.id);
+          }
+          continue;
+        }
+        const { spritePath, spriteConfig } = await generateSpriteSheetOnly(video.id, video.filePath, rootPath, video.duration, density);
+        updateVideoSprite(video.id, spritePath, spriteConfig);
+      } catch (error) {
+        failed++;
+        console.warn(`Sprite regeneration failed for ${video.filePath}:`, error);
+      }
+    }
+
+    if (task.isCancelled()) {
+      task.finish('cancelled');
+    } else if (failed > 0) {
+      task.finish('error', `${failed} of ${videos.length} sprites failed`);
+    } else {
+      task.setProgress(videos.length, videos.length);
+      task.finish();
+    }
+  };
+
+  run().catch((error) => {
+    console.error('Sprite regeneration failed:', error);
+    task.finish('error', error instanceof Error ? error.message : String(error));
+  });
+
+  return task.id;
+}
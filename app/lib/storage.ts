@@ -0,0 +1,109 @@
+import fs from 'fs/promises';
+import path from 'path';
+import { clearVideoAssets, getDataDir, getSetting, setSetting } from './db';
+import { clearAssetVersions } from './assetCache';
+import { CacheClearScope, CacheSizeBreakdown } from './types';
+
+const CACHE_SIZE_SETTING = 'cache_size';
+
+// Classify a .vcb-data file into a cache category
+function categorize(fileName: string): keyof Omit<CacheSizeBreakdown, 'total' | 'computedAt'> {
+  if (fileName.startsWith('catalog.db')) return 'database'; // includes -wal/-shm
+  if (fileName.endsWith('_thumb.jpg') || fileName.endsWith('_thumb_prev.jpg')) return 'thumbnails';
+  if (fileName.endsWith('_sprite.jpg')) return 'sprites';
+  if (fileName.endsWith('_proxy.mp4')) return 'proxies';
+  return 'other';
+}
+
+// Walk a library's .vcb-data folder and sum file sizes by category
+export async function computeLibraryCacheSize(rootPath: string): Promise<CacheSizeBreakdown> {
+  const breakdown: CacheSizeBreakdown = {
+    total: 0,
+    database: 0,
+    thumbnails: 0,
+    sprites: 0,
+    proxies: 0,
+    other: 0,
+    computedAt: new Date().toISOString(),
+  };
+
+  const walk = async (dir: string) => {
+    let entries;
+    try {
+      entries = await fs.readdir(dir, { withFileTypes: true });
+    } catch {
+      return;
+    }
+
+    for (const entry of entries) {
+      const fullPath = path.join(dir, entry.name);
+      if (entry.isDirectory()) {
+        await walk(fullPath);
+      } else if (entry.isFile()) {
+        const { size } = await fs.stat(fullPath);
+        breakdown[categorize(entry.name)] += size;
+        breakdown.total += size;
+      }
+    }
+  };
+
+  await walk(getDataDir(rootPath));
+  return breakdown;
+}
+
+// Recompute and store the cache size for the open library
+export async function refreshLibraryCacheSize(rootPath: string): Promise<CacheSizeBreakdown> {
+  const breakdown = await computeLibraryCacheSize(rootPath);
+  setSetting(CACHE_SIZE_SETTING, JSON.stringify(breakdown));
+  return breakdown;
+}
+
+// Get the last stored cache size (null if never computed)
+export function getStoredLibraryCacheSize(): CacheSizeBreakdown | null {
+  const value = getSetting(CACHE_SIZE_SETTING);
+  if (!value) return null;
+  try {
+    return JSON.parse(value) as CacheSizeBreakdown;
+  } catch {
+    return null;
+  }
+}
+
+// Delete one category of generated files from the open library's .vcb-data and forget
+// them in the catalog, leaving everything else in place. Returns the bytes freed.
+export async function clearLibraryCacheCategory(
+  rootPath: string,
+  category: Exclude<CacheClearScope, 'all'>
+): Promise<number> {
+  let freed = 0;
+
+  const walk = async (dir: string) => {
+    let entries;
+    try {
+      entries = await fs.readdir(dir, { withFileTypes: true });
+    } catch {
+      return;
+    }
+
+    for (const entry of entries) {
+      const fullPath = path.join(dir, entry.name);
+      if (entry.isDirectory()) {
+        await walk(fullPath);
+      } else if (entry.isFile() && categorize(entry.name) === category) {
+        try {
+          const { size } = await fs.stat(fullPath);
+          await fs.rm(fullPath, { force: true });
+          freed += size;
+        } catch (error) {
+          console.warn(`Could not delete ${fullPath}:`, error);
+        }
+      }
+    }
+  };
+
+  await walk(getDataDir(rootPath));
+  clearVideoAssets(category);
+  clearAssetVersions();
+  await refreshLibraryCacheSize(rootPath);
+  return freed;
+}
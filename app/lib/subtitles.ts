@@ -0,0 +1,85 @@
+import fs from 'fs/promises';
+import path from 'path';
+
+// Subtitle files that sit next to a video (server-side only)
+
+export const SUBTITLE_EXTENSIONS = ['.srt', '.vtt', '.ass'];
+
+// Subtitle files for a video: <stem>.srt, <stem>.en.srt, <stem>.forced.vtt, ...
+// Returns file names, sorted so the plain <stem>.<ext> file comes first.
+export async function findSubtitles(videoPath: string): Promise<string[]> {
+  const dir = path.dirname(videoPath);
+  const stem = path.basename(videoPath, path.extname(videoPath));
+
+  let entries: string[];
+  try {
+    entries = await fs.readdir(dir);
+  } catch {
+    return [];
+  }
+
+  return entries
+    .filter((name) =>
+      name.startsWith(`${stem}.`)
+      && SUBTITLE_EXTENSIONS.includes(path.extname(name).toLowerCase())
+    )
+    .sort((a, b) => a.length - b.length || a.localeCompare(b));
+}
+
+export interface SubtitleCue {
+  start: number; // Seconds
+  end: number;
+  text: string;
+}
+
+// 00:01:02,345 (also tolerates . for the comma and a missing hours field)
+const SRT_TIME = /(?:(\d+):)?(\d{1,2}):(\d{1,2})[,.](\d{1,3})/;
+const SRT_TIMING = new RegExp(`${SRT_TIME.source}\\s*-->\\s*${SRT_TIME.source}`);
+
+function toSeconds(hours: string | undefined, minutes: string, seconds: string, millis: string): number {
+  return (parseInt(hours || '0', 10) * 3600) + (parseInt(minutes, 10) * 60) + parseInt(seconds, 10)
+    + parseInt(millis.padEnd(3, '0'), 10) / 1000;
+}
+
+// Parse .srt text: blocks separated by blank lines, each an optional counter line, a
+// timing line and the cue text. Malformed blocks are skipped rather than failing the file.
+export function parseSrt(text: string): SubtitleCue[] {
+  const cues: SubtitleCue[] = [];
+  const blocks = text.replace(/^\uFEFF/, '').replace(/\r\n?/g, '\n').split(/\n\s*\n/);
+
+  for (const block of blocks) {
+    const lines = block.split('\n').filter((line) => line.trim() !== '');
+    const timingIndex = lines.findIndex((line) => SRT_TIMING.test(line));
+    if (timingIndex === -1 || timingIndex > 1) continue;
+
+    const match = lines[timingIndex].match(SRT_TIMING)!;
+    const start = toSeconds(match[1], match[2], match[3], match[4]);
+    const end = toSeconds(match[5], match[6], match[7], match[8]);
+    // ASS-style positioning tags ({\an8}) that some .srt files carry aren't valid WebVTT
+    const cueText = lines.slice(timingIndex + 1).join('\n').replace(/\{\\[^}]*\}/g, '').trim();
+    if (end > start && cueText) {
+      cues.push({ start, end, text: cueText });
+    }
+  }
+
+  return cues;
+}
+
+function formatVttTime(seconds: number): string {
+  const totalMillis = Math.round(seconds * 1000);
+  const hours = Math.floor(totalMillis / 3600000);
+  const minutes = Math.floor((totalMillis % 3600000) / 60000);
+  const secs = Math.floor((totalMillis % 60000) / 1000);
+  const millis = totalMillis % 1000;
+  return `${String(hours).padStart(2, '0')}:${String(minutes).padStart(2, '0')}:${String(secs).padStart(2, '0')}.${String(millis).padStart(3, '0')}`;
+}
+
+export function cuesToVtt(cues: SubtitleCue[]): string {
+  return `WEBVTT\n\n${cues.map((cue) => `${formatVttTime(cue.start)} --> ${formatVttTime(cue.end)}\n${cue.text}\n`).join('\n')}`;
+}
+
+// .srt files predate UTF-8 being the norm; fall back to Latin-1 when the bytes aren't valid UTF-8
+export function decodeSubtitleFile(data: Buffer): string {
+  const utf8 = data.toString('utf-8');
+  return utf8.includes('\uFFFD') ? data.toString('latin1') : utf8;
+}
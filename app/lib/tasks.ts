@@ -0,0 +1,104 @@
+import { TaskInfo, TaskItem, TaskKind, TaskStatus } from './types';
+
+// Registry of background work (scans, thumbnail repair, proxies, watcher updates) for the task popover
+
+// Passed to the code doing the work; progress is written straight into the shared TaskInfo
+export interface TaskHandle {
+  id: string;
+  setProgress: (done: number, total: number, detail?: string) => void;
+  isCancelled: () => boolean;
+  isPaused: () => boolean;
+  setItems: (items: TaskItem[]) => void; // Shared array; update entries in place
+  finish: (status?: Exclude<TaskStatus, 'running'>, error?: string) => void;
+}
+
+// Finished tasks stay listed briefly so the popover can show the outcome
+const FINISHED_TASK_TTL_MS = 10000;
+// Batches with failed items stay longer, so the captured errors can be read
+const FAILED_ITEMS_TTL_MS = 30 * 60 * 1000;
+
+// Module-level registry
+const tasks = new Map<string, { info: TaskInfo; cancelRequested: boolean }>();
+let nextTaskId = 1;
+
+function pruneFinishedTasks(): void {
+  const now = Date.now();
+  for (const [id, task] of tasks) {
+    const ttl = task.info.items?.some((item) => item.status === 'error') ? FAILED_ITEMS_TTL_MS : FINISHED_TASK_TTL_MS;
+    if (task.info.finishedAt && new Date(task.info.finishedAt).getTime() < now - ttl) {
+      tasks.delete(id);
+    }
+  }
+}
+
+// Pausable tasks check isPaused() between units of work
+export function startTask(kind: TaskKind, label: string, options: { pausable?: boolean } = {}): TaskHandle {
+  pruneFinishedTasks();
+
+  const id = `${kind}-${nextTaskId++}`;
+  const task: { info: TaskInfo; cancelRequested: boolean } = {
+    info: {
+      id,
+      kind,
+      label,
+      status: 'running',
+      done: 0,
+      total: 0,
+      detail: '',
+      error: null,
+      startedAt: new Date().toISOString(),
+      finishedAt: null,
+      pausable: options.pausable ?? false,
+      paused: false,
+    },
+    cancelRequested: false,
+  };
+  tasks.set(id, task);
+
+  return {
+    id,
+    setProgress: (done, total, detail) => {
+      task.info.done = done;
+      task.info.total = total;
+      if (detail !== undefined) task.info.detail = detail;
+    },
+    isCancelled: () => task.cancelRequested,
+    isPaused: () => task.info.paused,
+    setItems: (items) => {
+      task.info.items = items;
+    },
+    finish: (status = 'complete', error) => {
+      if (task.info.status !== 'running') return;
+      task.info.paused = false;
+      task.info.status = task.cancelRequested && status !== 'complete' ? 'cancelled' : status;
+      task.info.error = error ?? null;
+      task.info.finishedAt = new Date().toISOString();
+    },
+  };
+}
+
+// Ask a running task to stop; the task decides when it is safe to do so
+export function cancelTask(id: string): boolean {
+  const task = tasks.get(id);
+  if (!task || task.info.status !== 'running') return false;
+  task.cancelRequested = true;
+  return true;
+}
+
+// Pause or resume a running task that supports it
+export function pauseTask(id: string, paused: boolean): boolean {
+  const task = tasks.get(id);
+  if (!task || task.info.status !== 'running' || !task.info.pausable) return false;
+  task.info.paused = paused;
+  return true;
+}
+
+export function getTask(id: string): TaskInfo | null {
+  const task = tasks.get(id);
+  return task ? { ...task.info } : null;
+}
+
+export function getTasks(): TaskInfo[] {
+  pruneFinishedTasks();
+  return [...tasks.values()].map((task) => ({ ...task.info }));
+}
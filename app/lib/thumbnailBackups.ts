@@ -0,0 +1,112 @@
+import fs from 'fs/promises';
+import path from 'path';
+import { getProxyDir } from './ffmpeg';
+import { getLibraryHistory } from './libraryHistory';
+import { invalidateAssetVersion } from './assetCache';
+import { updateVideoPosterTime, updateVideoThumbnail } from './db';
+
+// Previous thumbnails kept while regenerating (server-side only), so this session's
+// changes can be undone. The first regeneration of a video in a session renames its
+// thumbnail to `<id>_thumb_prev.jpg`; later ones overwrite freely, so undo goes back
+// to how the thumbnail was when the session started. Backups older than a day are
+// deleted at server startup.
+
+const BACKUP_SUFFIX = '_thumb_prev.jpg';
+const BACKUP_MAX_AGE_MS = 24 * 60 * 60 * 1000;
+
+interface ThumbnailBackup {
+  rootPath: string;
+  thumbnailPath: string;
+  backupPath: string;
+  posterTime: number | null;
+}
+
+// videoId -> backup, for this server session
+const backups = new Map<string, ThumbnailBackup>();
+
+// Move the current thumbnail aside before it's regenerated. Returns true if a backup
+// was made now, false if there's nothing to keep or one exists from earlier this session.
+export async function backupThumbnail(
+  videoId: string,
+  rootPath: string,
+  thumbnailPath: string | null,
+  posterTime: number | null
+): Promise<boolean> {
+  if (!thumbnailPath || backups.get(videoId)?.rootPath === rootPath) return false;
+
+  const backupPath = path.join(getProxyDir(rootPath), `${videoId}${BACKUP_SUFFIX}`);
+  try {
+    await fs.rename(thumbnailPath, backupPath);
+  } catch {
+    return false; // Thumbnail missing; nothing to undo to
+  }
+  const now = new Date();
+  await fs.utimes(backupPath, now, now).catch(() => {}); // Date the backup, not the old thumbnail
+  backups.set(videoId, { rootPath, thumbnailPath, backupPath, posterTime });
+  return true;
+}
+
+// Put a backup made by backupThumbnail back, e.g. after the regeneration failed
+export async function restoreThumbnail(videoId: string): Promise<void> {
+  const backup = backups.get(videoId);
+  if (!backup) return;
+  await fs.rename(backup.backupPath, backup.thumbnailPath);
+  invalidateAssetVersion(backup.thumbnailPath);
+  backups.delete(videoId);
+}
+
+// Videos in the open library with a thumbnail that can be restored
+export function getUndoableThumbnailCount(rootPath: string): number {
+  let count = 0;
+  for (const backup of backups.values()) {
+    if (backup.rootPath === rootPath) count++;
+  }
+  return count;
+}
+
+// Restore every backed-up thumbnail (and its poster time) in the open library
+export async function undoThumbnailRegeneration(rootPath: string): Promise<number> {
+  let restored = 0;
+  for (const [videoId, backup] of Array.from(backups)) {
+    if (backup.rootPath !== rootPath) continue;
+    try {
+      await fs.rename(backup.backupPath, backup.thumbnailPath);
+      invalidateAssetVersion(backup.thumbnailPath);
+      updateVideoThumbnail(videoId, backup.thumbnailPath);
+      updateVideoPosterTime(videoId, backup.posterTime);
+      restored++;
+    } catch (error) {
+      console.warn(`Could not restore thumbnail for ${videoId}:`, error);
+    }
+    backups.delete(videoId);
+  }
+  return restored;
+}
+
+// Delete day-old backups in every known library (run at server startup)
+export async function pruneThumbnailBackups(): Promise<void> {
+  const cutoff = Date.now() - BACKUP_MAX_AGE_MS;
+
+  for (const { path: rootPath } of getLibraryHistory()) {
+    const proxyDir = getProxyDir(rootPath);
+    let entries: string[];
+    try {
+      entries = await fs.readdir(proxyDir);
+    } catch {
+      continue; // Drive not mounted or library never scanned
+    }
+
+    for (const entry of entries) {
+      if (!entry.endsWith(BACKUP_SUFFIX)) continue;
+      const filePath = path.join(proxyDir, entry);
+      try {
+        const stats = await fs.stat(filePath);
+        if (stats.mtimeMs < cutoff) {
+          await fs.rm(filePath, { force: true });
+        }
+      } catch {
+        // Removed meanwhile
+      }
+    }
+  }
+}
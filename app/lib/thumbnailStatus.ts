@@ -0,0 +1,96 @@
+import { ThumbnailStatus, Video } from './types';
+import { getCurrentRootPath, updateVideoThumbnail } from './db';
+import { generateThumbnailOnly } from './ffmpeg';
+import { startTask } from './tasks';
+
+// Thumbnail generation state per video (server-side only), so cards can tell "wait a
+// second" from "broken". Only videos being worked on or that failed are kept; a video
+// with a thumbnail has no entry. Every change bumps a version, which lets the client
+// poll cheaply and re-render only when something moved. Lost on restart, after which a
+// video without a thumbnail falls back to the browser decoding a frame.
+
+const statuses = new Map<string, { rootPath: string; status: ThumbnailStatus }>();
+let version = 0;
+
+// Record a video's state; null clears it (thumbnail made, or nothing pending anymore)
+export function setThumbnailStatus(rootPath: string, videoId: string, status: ThumbnailStatus | null): void {
+  const current = statuses.get(videoId);
+  if (status === null) {
+    if (!current) return;
+    statuses.delete(videoId);
+  } else {
+    if (current?.rootPath === rootPath && current.status === status) return;
+    statuses.set(videoId, { rootPath, status });
+  }
+  version++;
+}
+
+export function getThumbnailStatuses(rootPath: string): { version: number; statuses: Record<string, ThumbnailStatus> } {
+  const result: Record<string, ThumbnailStatus> = {};
+  for (const [videoId, entry] of statuses) {
+    if (entry.rootPath === rootPath) result[videoId] = entry.status;
+  }
+  return { version, statuses: result };
+}
+
+export function getThumbnailStatusVersion(): number {
+  return version;
+}
+
+// Generate thumbnails for these videos as a background task, one at a time. All of
+// them show as pending until their turn.
+export function startThumbnailTask(videos: Video[]): string {
+  const task = startTask('thumbnails', `Generating ${videos.length} thumbnail${videos.length !== 1 ? 's' : ''}`);
+
+  const rootPath = getCurrentRootPath();
+  if (rootPath) {
+    for (const video of videos) setThumbnailStatus(rootPath, video.id, 'pending');
+  }
+
+  const run = async () => {
+    if (!rootPath) {
+      task.finish('error', 'No video library loaded');
+      return;
+    }
+
+    let failed = 0;
+    for (const [i, video] of videos.entries()) {
+      if (task.isCancelled() || getCurrentRootPath() !== rootPath) break;
+      task.setProgress(i, videos.length, video.fileName);
+
+      setThumbnailStatus(rootPath, video.id, 'generating');
+      try {
+        const thumbnailPath = await generateThumbnailOnly(video.id, video.filePath, rootPath, video.duration, video.posterTime);
+        updateVideoThumbnail(video.id, thumbnailPath);
+        setThumbnailStatus(rootPath, video.id, null);
+      } catch (error) {
+        failed++;
+        setThumbnailStatus(rootPath, video.id, 'failed');
+        console.warn(`Thumbnail generation failed for ${video.filePath}:`, error);
+      }
+    }
+
+    // Videos that never got their turn go back to having no status
+    for (const video of videos) {
+      if (statuses.get(video.id)?.status === 'pending') setThumbnailStatus(rootPath, video.id, null);
+    }
+
+    if (getCurrentRootPath() !== rootPath) {
+      task.finish('error', 'Another library was opened');
+    } else if (task.isCancelled()) {
+      task.finish('cancelled');
+    } else if (failed > 0) {
+      task.finish('error', `${failed} of ${videos.length} thumbnails failed`);
+    } else {
+      task.setProgress(videos.length, videos.length);
+      task.finish();
+    }
+  };
+
+  run().catch((error) => {
+    console.error('Thumbnail task failed:', error);
+    task.finish('error', error instanceof Error ? error.message : String(error));
+  });
+
+  return task.id;
+}
@@ -0,0 +1,151 @@
+import fs from 'fs/promises';
+import path from 'path';
+import { TaskItem, TranscodePreset, Video } from './types';
+import { startTask, TaskHandle } from './tasks';
+import { transcodeVideo, TranscodeError } from './ffmpeg';
+import { splitPresetArgs } from './transcodePresets';
+import { getCurrentRootPath } from './db';
+import { isSkippedPath, isVideoFile, processSingleVideo } from './scanner';
+import { compileExclusions, getExclusions } from './exclusions';
+
+// Batch transcodes to a preset (server-side only), reported as one pausable task with
+// an item per clip. Clips are encoded one at a time, and batches queue behind each
+// other so two of them never compete for the CPU. Pausing waits for the current clip
+// to finish; cancelling stops it and deletes the partial output.
+
+export interface TranscodeBatchOptions {
+  videos: Video[];
+  preset: TranscodePreset;
+  destination: string; // Absolute folder, already checked to exist
+  rootPath: string; // Library the videos belong to
+  addToCatalog: boolean; // Index outputs that land inside the library
+}
+
+const PAUSE_POLL_MS = 500;
+
+// Tail of the batch queue; each batch starts when the previous one settles
+let queueTail: Promise<void> = Promise.resolve();
+
+// `<name>.<ext>`, or `<name>_1.<ext>`, `<name>_2.<ext>`… if that's taken on disk or
+// already claimed by an earlier clip in the batch
+export async function pickOutputPath(destination: string, baseName: string, extension: string, claimed: Set<string>): Promise<string> {
+  for (let n = 0; ; n++) {
+    const candidate = path.join(destination, `${baseName}${n === 0 ? '' : `_${n}`}.${extension}`);
+    if (claimed.has(candidate)) continue;
+    try {
+      await fs.access(candidate);
+    } catch {
+      claimed.add(candidate);
+      return candidate;
+    }
+  }
+}
+
+// Add a finished output to the catalog if it's a video inside the (still open) library
+export async function addOutputToCatalog(outputPath: string, rootPath: string): Promise<boolean> {
+  if (getCurrentRootPath() !== rootPath || !isVideoFile(outputPath)) return false;
+  if (isSkippedPath(rootPath, outputPath, compileExclusions(rootPath, getExclusions()))) return false;
+  return processSingleVideo(outputPath, rootPath);
+}
+
+async function runBatch(options: TranscodeBatchOptions, task: TaskHandle, items: TaskItem[]): Promise<void> {
+  const { videos, preset, destination, rootPath, addToCatalog } = options;
+  const presetArgs = splitPresetArgs(preset.args);
+  const claimed = new Set<string>();
+  let completed = 0;
+  let failed = 0;
+  let added = 0;
+
+  for (const [i, video] of videos.entries()) {
+    const item = items[i];
+
+    while (task.isPaused() && !task.isCancelled()) {
+      task.setProgress(i, videos.length, 'Paused');
+      await new Promise((resolve) => setTimeout(resolve, PAUSE_POLL_MS));
+    }
+    if (task.isCancelled()) {
+      items.slice(i).forEach((rest) => (rest.status = 'cancelled'));
+      break;
+    }
+
+    item.status = 'running';
+    task.setProgress(i, videos.length, video.fileName);
+
+    const outputPath = await pickOutputPath(destination, path.parse(video.fileName).name, preset.extension, claimed);
+    item.detail = path.basename(outputPath);
+
+    // Cancelling kills ffmpeg mid-clip
+    const controller = new AbortController();
+    const cancelCheck = setInterval(() => {
+      if (task.isCancelled()) controller.abort();
+    }, PAUSE_POLL_MS);
+
+    try {
+      await transcodeVideo(video.filePath, outputPath, presetArgs, video.duration, (progress) => {
+        item.progress = progress;
+      }, controller.signal);
+
+      item.status = 'complete';
+      item.progress = 100;
+      completed++;
+
+      // A failure here mustn't count as a failed transcode (the output would be deleted)
+      const wasAdded = addToCatalog && await addOutputToCatalog(outputPath, rootPath).catch((error) => {
+        console.warn(`Could not add ${outputPath} to the catalog:`, error);
+        return false;
+      });
+      if (wasAdded) added++;
+    } catch (error) {
+      await fs.rm(outputPath, { force: true }).catch(() => {});
+      if (controller.signal.aborted) {
+        item.status = 'cancelled';
+      } else {
+        const tail = error instanceof TranscodeError ? error.stderrTail : '';
+        const message = error instanceof Error ? error.message : String(error);
+        item.status = 'error';
+        item.error = tail ? `${message}\n${tail}` : message;
+        failed++;
+        console.warn(`Transcode failed for ${video.filePath}: ${item.error}`);
+      }
+    } finally {
+      clearInterval(cancelCheck);
+    }
+  }
+
+  const summary = [
+    `${completed} transcoded`,
+    failed > 0 ? `${failed} failed` : '',
+    added > 0 ? `${added} added to catalog` : '',
+  ].filter(Boolean).join(', ');
+  task.setProgress(completed + failed, videos.length, summary);
+  if (failed > 0) {
+    task.finish('error', `${failed} of ${videos.length} clips failed`);
+  } else {
+    task.finish(task.isCancelled() ? 'cancelled' : 'complete');
+  }
+}
+
+// Queue a batch and return its task id; progress is read from the task registry
+export function startTranscodeBatch(options: TranscodeBatchOptions): string {
+  const task = startTask(
+    'transcode',
+    `Transcoding ${options.videos.length} clip${options.videos.length !== 1 ? 's' : ''} to ${options.preset.label}`,
+    { pausable: true }
+  );
+  const items: TaskItem[] = options.videos.map((video) => ({
+    label: video.fileName,
+    status: 'queued',
+    progress: 0,
+    detail: '',
+    error: null,
+  }));
+  task.setItems(items);
+  task.setProgress(0, options.videos.length, 'Waiting for the previous batch');
+
+  queueTail = queueTail.then(() => runBatch(options, task, items)).catch((error) => {
+    console.error('Transcode batch failed:', error);
+    task.finish('error', error instanceof Error ? error.message : String(error));
+  });
+
+  return task.id;
+}
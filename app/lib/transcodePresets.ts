@@ -0,0 +1,77 @@
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+import { TranscodePreset } from './types';
+
+// Batch transcode presets (server-side only). App-wide like the ffmpeg command
+// templates, stored next to them in the user's home directory.
+
+const PRESETS_PATH = path.join(os.homedir(), '.video-catalog-browser', 'transcode-presets.json');
+
+export const DEFAULT_TRANSCODE_PRESETS: TranscodePreset[] = [
+  {
+    label: 'H.264 1080p',
+    args: '-map 0:v:0 -map 0:a? -c:v libx264 -preset medium -crf 20 -vf scale=-2:1080 -pix_fmt yuv420p -c:a aac -b:a 192k -movflags +faststart',
+    extension: 'mp4',
+  },
+  {
+    label: 'H.265 4K',
+    args: '-map 0:v:0 -map 0:a? -c:v libx265 -preset medium -crf 22 -vf scale=-2:2160 -tag:v hvc1 -c:a aac -b:a 192k -movflags +faststart',
+    extension: 'mp4',
+  },
+  {
+    label: 'DNxHR LB',
+    args: '-map 0:v:0 -map 0:a? -c:v dnxhd -profile:v dnxhr_lb -pix_fmt yuv422p -c:a pcm_s16le',
+    extension: 'mov',
+  },
+];
+
+// Split preset arguments into words for spawn (no shell, so no quoting)
+export function splitPresetArgs(args: string): string[] {
+  return args.trim().split(/\s+/).filter(Boolean);
+}
+
+// Check presets from a request or an import; the error names the first bad entry
+export function validateTranscodePresets(data: unknown): { presets: TranscodePreset[] } | { error: string } {
+  if (!Array.isArray(data)) {
+    return { error: '"presets" must be an array' };
+  }
+  for (const [i, entry] of data.entries()) {
+    if (typeof entry?.label !== 'string' || !entry.label.trim()) {
+      return { error: `"presets[${i}].label" must be a non-empty string` };
+    }
+    if (typeof entry.args !== 'string' || !entry.args.trim()) {
+      return { error: `"presets[${i}].args" must be a non-empty string` };
+    }
+    if (typeof entry.extension !== 'string' || !/^[a-z0-9]{1,8}$/i.test(entry.extension.trim().replace(/^\./, ''))) {
+      return { error: `"presets[${i}].extension" must be a file extension like "mp4"` };
+    }
+  }
+  return {
+    presets: data.map((entry: TranscodePreset) => ({
+      label: entry.label.trim(),
+      args: entry.args.trim(),
+      extension: entry.extension.trim().replace(/^\./, '').toLowerCase(),
+    })),
+  };
+}
+
+// Saved presets, or the defaults if none were saved (or the file is unreadable)
+export function getTranscodePresets(): TranscodePreset[] {
+  try {
+    const validation = validateTranscodePresets(JSON.parse(fs.readFileSync(PRESETS_PATH, 'utf-8')));
+    return 'presets' in validation ? validation.presets : DEFAULT_TRANSCODE_PRESETS;
+  } catch {
+    return DEFAULT_TRANSCODE_PRESETS;
+  }
+}
+
+export function setTranscodePresets(presets: TranscodePreset[]): void {
+  fs.mkdirSync(path.dirname(PRESETS_PATH), { recursive: true });
+  fs.writeFileSync(PRESETS_PATH, JSON.stringify(presets, null, 2));
+}
+
+// Back to the built-in presets
+export function resetTranscodePresets(): void {
+  fs.rmSync(PRESETS_PATH, { force: true });
+}
@@ -9,6 +9,7 @@ export interface Video {
   height: number | null;
   createdAt: string;
   directory: string;
+  onlineOnly: boolean; // Cataloged from the name while its data was only in the cloud
   hasProxy: boolean;
   hasSprite: boolean;
   proxyPath: string | null;
@@ -18,6 +19,32 @@ export interface Video {
   fileHash: string | null;
   fileMtime: string | null;
   scannedAt: string | null;
+  frameRate: number | null;
+  posterTime: number | null; // User-chosen thumbnail timestamp (null = automatic)
+  // Metadata imported from .xmp/.json sidecar files
+  sidecarTitle: string | null;
+  sidecarDescription: string | null;
+  sidecarKeywords: string | null; // Comma-separated
+  sidecarMtime: string | null;
+  codec: string | null; // Video stream codec (e.g. h264, prores)
+  videoStreamCount: number | null;
+  audioTrackCount: number | null;
+  subtitleFiles: string[]; // .srt/.vtt/.ass file names next to the video
+  // Sprite sheet grid, for picking frames without hovering (null for older sprites)
+  spriteColumns: number | null;
+  spriteRows: number | null;
+  spriteFrames: number | null;
+  corruptAt: string | null; // Marked corrupt from a verify report
+  mediaDate: string | null; // Recording time from the container metadata; '' when it has none, null until probed
+  nameDate: string | null; // Date parsed from the file name
+  colorSpace: string | null; // e.g. 'Rec. 709', 'Rec. 2020', 'Log'; 'unknown' when untagged, null before it was probed
+  hdrFormat: string | null; // 'HDR10', 'HLG' or 'Dolby Vision'; null for SDR
+  location: string | null; // GPS tag as written (ISO 6709); '' when untagged, null before it was probed
+  latitude: number | null; // Decimal degrees parsed from the tag
+  longitude: number | null;
+  loudnessLufs: number | null; // Integrated loudness, when the library measures it
+  bitRate: number | null; // Container bit rate in bits/s; 0 when not reported, null before it was probed
+  removedAt: string | null; // In Recently Removed since then; hidden from the catalog
 }
 
 // Database row type (snake_case from SQLite)
@@ -31,6 +58,7 @@ export interface VideoRow {
   height: number | null;
   created_at: string;
   directory: string;
+  online_only: number | null;
   has_proxy: number;
   has_sprite: number;
   proxy_path: string | null;
@@ -40,8 +68,37 @@ export interface VideoRow {
   file_hash: string | null;
   file_mtime: string | null;
   scanned_at: string | null;
+  frame_rate: number | null;
+  poster_time: number | null;
+  sidecar_title: string | null;
+  sidecar_description: string | null;
+  sidecar_keywords: string | null;
+  sidecar_mtime: string | null;
+  codec: string | null;
+  video_stream_count: number | null;
+  audio_track_count: number | null;
+  subtitle_paths: string | null; // JSON array of file names
+  sprite_columns: number | null;
+  sprite_rows: number | null;
+  sprite_frames: number | null;
+  corrupt_at: string | null;
+  media_date: string | null;
+  name_date: string | null;
+  color_space: string | null;
+  hdr_format: string | null;
+  location: string | null;
+  latitude: number | null;
+  longitude: number | null;
+  loudness_lufs: number | null;
+  bit_rate: number | null;
+  removed_at: string | null;
+  excluded: number; // 1 while hidden by an excluded folder pattern (removed_at is set too)
 }
 
+// Finder-style color labels, shown as an edge on the card
+export type ColorLabel = 'red' | 'orange' | 'green' | 'blue' | 'purple';
+export const COLOR_LABELS: ColorLabel[] = ['red', 'orange', 'green', 'blue', 'purple'];
+
 // Selection/favorites type
 export interface Selection {
   id: string;
@@ -49,13 +106,22 @@ export interface Selection {
   isFavorite: boolean;
   notes: string;
   createdAt: string;
+  watchedAt: string | null; // Set by "Mark Watched"; independent of favorites
+  colorLabel: ColorLabel | null;
+  rating: number | null; // 1-5 stars
 }
 
+// Star ratings run from 1 to 5; null is unrated
+export const MAX_RATING = 5;
+
 export interface SelectionRow {
   id: string;
   video_id: string;
   is_favorite: number;
   notes: string | null;
+  watched_at: string | null;
+  color_label: string | null;
+  rating: number | null;
   created_at: string;
 }
 
@@ -88,6 +154,41 @@ export interface ScanStatus {
   completedAt?: string;
 }
 
+// File counts recorded for a scan
+export interface ScanCounts {
+  added: number;
+  updated: number; // Re-indexed because the file changed
+  skipped: number; // Unchanged since the last scan
+  removed: number; // Catalog entries whose file no longer exists
+  errored: number;
+}
+
+// Scan history entry, broken down by folder
+export interface ScanReport extends ScanStatus, ScanCounts {
+  error: string | null; // Why the scan failed or was cancelled
+  folders: Record<string, ScanCounts>; // Keyed by folder relative to the library root
+  errors: string[];
+  scanPath: string | null; // Folder scanned inside the library; null for the whole library
+}
+
+export interface ScanRow {
+  id: string;
+  root_path: string;
+  status: string;
+  videos_found: number;
+  started_at: string;
+  completed_at: string | null;
+  added_count: number | null;
+  updated_count: number | null;
+  skipped_count: number | null;
+  removed_count: number | null;
+  error_count: number | null;
+  folder_stats: string | null;
+  errors: string | null;
+  error: string | null;
+  scan_path: string | null;
+}
+
 // Extended scan progress for enhanced loading screen
 export interface ExtendedScanProgress {
   status: 'idle' | 'counting' | 'scanning' | 'complete' | 'error';
@@ -99,6 +200,9 @@ export interface ExtendedScanProgress {
   message: string;
 }
 
+// What scans do with online-only cloud files: leave them out, or catalog them from the name
+export type CloudPlaceholderMode = 'skip' | 'catalog';
+
 // Proxy generation status
 export interface ProxyStatus {
   isProcessing: boolean;
@@ -118,8 +222,246 @@ export interface SpriteConfig {
   totalFrames: number; // Total number of frames
 }
 
+// Thumbnail generation in progress or failed; videos with a thumbnail have no status
+export type ThumbnailStatus = 'pending' | 'generating' | 'failed';
+
+// A library's custom sprite sheet layout (none means picked by duration)
+export interface SpriteDensity {
+  tileWidth: number;   // Pixels; height follows at 16:9
+  columns: number;     // Tiles per row
+  targetTiles: number; // Tiles per sprite, fewer for clips shorter than that many seconds
+}
+
+// Disk usage of a library's .vcb-data folder, in bytes
+export interface CacheSizeBreakdown {
+  total: number;
+  database: number;
+  thumbnails: number;
+  sprites: number;
+  proxies: number;
+  other: number;
+  computedAt: string;
+}
+
+// What Clear Cache removes: one category of generated files, or all of .vcb-data
+export type CacheClearScope = 'thumbnails' | 'sprites' | 'proxies' | 'all';
+
+// Open library's catalog.db size, and whether free pages make compacting worthwhile
+export interface DatabaseStats {
+  fileSize: number; // catalog.db plus its write-ahead log
+  compactable: boolean;
+}
+
+// Totals for a folder and everything under it, refreshed at the end of each scan
+export interface DirectoryStats {
+  directory: string; // Relative to the library root; '' is the root itself
+  videoCount: number;
+  totalDuration: number; // Seconds
+  totalSize: number; // Bytes
+}
+
 // Sort options
-export type SortOption = 'date-asc' | 'date-desc' | 'duration-asc' | 'duration-desc' | 'name-asc' | 'name-desc';
+export type SortOption =
+  | 'date-asc' | 'date-desc' | 'duration-asc' | 'duration-desc' | 'name-asc' | 'name-desc'
+  | 'watched-asc' | 'unwatched-first' | 'best-date-asc' | 'best-date-desc' | 'scanned-asc' | 'scanned-desc'
+  | 'bitrate-asc' | 'bitrate-desc';
+
+// Watched state filter chip
+export type WatchedFilter = 'any' | 'watched' | 'unwatched';
+
+// Toolbar color filter: HDR of any format, tagged log footage, or everything else
+export type ColorFilter = 'any' | 'hdr' | 'log' | 'sdr';
+
+// Toolbar loudness filter (measured clips only): above -18 LUFS or below -28 LUFS
+export type LoudnessFilter = 'any' | 'loud' | 'quiet';
+
+// Resolution classes for the "Inefficient clips" view, by shorter side
+export type BitrateClass = 'sd' | '720p' | '1080p' | '4k';
+
+// Per-class bit rate above which a clip counts as inefficient, in Mbps
+export type BitrateThresholds = Record<BitrateClass, number>;
+
+// Search scope: file name only, or name plus path, codec, notes and keywords
+export type SearchMode = 'name' | 'all';
+
+// Grid layouts: uniform cards, or cards sized by resolution
+export type ViewLayout = 'grid' | 'smart' | 'strip' | 'date' | 'folder';
+
+// Criteria a smart view re-applies; all of them must match (favorites narrows the search)
+export interface SmartViewCriteria {
+  query: string;
+  searchMode: SearchMode;
+  favoritesOnly: boolean;
+  watchedFilter: WatchedFilter;
+  inefficientOnly: boolean; // Bit rate above the library's threshold for the resolution
+  sortBy: SortOption;
+}
+
+// Saved search, stored per library in the settings table
+export interface SmartView extends SmartViewCriteria {
+  id: string;
+  name: string;
+}
+
+// Named snapshot of selected video ids, stored per library. IDs of videos removed since
+// are kept, so restoring can report them.
+export interface SelectionSet {
+  id: string;
+  name: string;
+  videoIds: string[];
+  createdAt: string;
+}
+
+// Copies of the same clip; videos are oldest first
+export interface DuplicateGroup {
+  key: string;
+  videos: VideoWithSelection[];
+  wastedBytes: number; // Size of every copy except one
+}
+
+export interface DuplicateReport {
+  exact: DuplicateGroup[]; // Same size and sampled content
+  possible: DuplicateGroup[]; // Same duration and resolution only
+  reclaimableBytes: number;
+  skippedOnlineOnly: number; // Not sampled, so the cloud provider doesn't download them
+}
+
+// Library from the history, for the recent libraries list
+export interface RecentLibrary {
+  path: string;
+  lastOpenedAt: string;
+  lastScannedAt?: string; // Set once the library has been scanned since this was recorded
+  videoCount?: number;
+  totalBytes?: number;
+  exists: boolean; // False when the folder moved or the drive isn't mounted
+}
+
+// Outcome of pointing a moved library at its new root
+export interface RelinkResult {
+  sampled: number; // Videos fingerprinted at the new location
+  verified: number;
+  relinked: number;
+  missingCount: number;
+  missing: string[]; // First few paths still not found
+}
+
+// A match from searching every known library
+export interface GlobalSearchResult {
+  libraryPath: string;
+  libraryName: string;
+  relevance: number; // Higher is better
+  video: Video;
+}
+
+// A video in the all-libraries view, tagged with the library it came from
+export interface LibraryVideo extends VideoWithSelection {
+  libraryPath: string;
+  libraryName: string;
+  isMissing: boolean; // File not found, e.g. its volume isn't mounted
+}
+
+// Server log entries shown in the log viewer
+export type LogLevel = 'debug' | 'info' | 'warn' | 'error';
+
+export interface LogEntry {
+  time: string; // ISO timestamp
+  level: LogLevel;
+  message: string; // May span several lines (stack traces)
+}
+
+// Dry-run scan results: what a full scan would do, without probing or inserting anything
+export interface ScanPreviewCounts {
+  new: number; // Not in the catalog yet
+  changed: number; // In the catalog, but the fingerprint differs (re-indexed)
+  cached: number; // Unchanged, skipped by the full scan
+  bytes: number; // Size of new and changed files
+}
+
+export interface ScanPreview extends ScanPreviewCounts {
+  rootPath: string;
+  excluded: number; // Files/folders left out by the skip rules or the library's exclusions
+  errors: string[]; // Files that couldn't be read
+  cachedBytes: number;
+  cachedDuration: number; // Seconds, from the catalog
+  estimatedDuration: number | null; // Seconds for new and changed files, from the cached videos' bytes per second
+  folders: Record<string, ScanPreviewCounts>; // Keyed by top-level folder ("." for files in the root)
+}
+
+// File integrity check against the stored scan fingerprints
+export type VerifyBucket = 'changed' | 'mismatched' | 'missing';
+
+export interface VerifyEntry {
+  videoId: string;
+  filePath: string;
+  fileName: string;
+  bucket: VerifyBucket;
+  storedSize: number;
+  storedMtime: string | null;
+  currentSize: number | null; // Null when the file is missing
+  currentMtime: string | null;
+  isCorrupt: boolean;
+}
+
+export interface VerifyReport {
+  rootPath: string;
+  startedAt: string;
+  completedAt: string | null; // Null while running
+  cancelled: boolean;
+  done: number;
+  total: number;
+  unchanged: number;
+  skippedOnlineOnly: number; // Not read, so the cloud provider doesn't download them
+  entries: VerifyEntry[]; // Everything that isn't unchanged
+}
+
+// Background tasks shown in the task popover
+export type TaskKind = 'scan' | 'thumbnails' | 'proxy' | 'hashing' | 'watcher' | 'transcode' | 'silence' | 'clip' | 'sprites';
+
+export type TaskStatus = 'running' | 'complete' | 'cancelled' | 'error';
+
+export interface TaskInfo {
+  id: string;
+  kind: TaskKind;
+  label: string;
+  status: TaskStatus;
+  done: number;
+  total: number; // 0 while the total is still unknown
+  detail: string;
+  error: string | null;
+  startedAt: string;
+  finishedAt: string | null;
+  pausable: boolean;
+  paused: boolean;
+  items?: TaskItem[]; // Per-item progress for batch tasks
+}
+
+// One entry of a batch task (e.g. a clip being transcoded)
+export interface TaskItem {
+  label: string;
+  status: 'queued' | 'running' | 'complete' | 'error' | 'cancelled';
+  progress: number; // 0-100
+  detail: string;
+  error: string | null; // For ffmpeg failures, the tail of its stderr
+}
+
+// Argument template for batch transcodes, placed between the input and the output file
+export interface TranscodePreset {
+  label: string;
+  args: string; // Split on spaces; words can't contain spaces
+  extension: string; // Output container, without the dot
+}
+
+// Subclip export from the player's A–B markers
+export type ClipContainer = 'mp4' | 'mov' | 'webm';
+export type ClipVideoCodec = 'copy' | 'h264' | 'hevc';
+export type ClipAudioCodec = 'copy' | 'aac';
+
+export interface ClipExportOptions {
+  container: ClipContainer;
+  videoCodec: ClipVideoCodec;
+  audioCodec: ClipAudioCodec;
+  crf: number; // Quality when re-encoding video (0-51, lower is better)
+}
 
 // API response types
 export interface ScanResponse {
@@ -139,9 +481,73 @@ export interface ProxyGenerateResponse {
   error?: string;
 }
 
+// Local catalog API responses (stable shape for external tools)
+export interface CatalogVideo {
+  id: string;
+  filePath: string;
+  fileName: string;
+  directory: string;
+  fileSize: number;
+  duration: number;
+  width: number | null;
+  height: number | null;
+  frameRate: number | null;
+  colorSpace: string | null;
+  hdrFormat: string | null;
+  createdAt: string;
+  isFavorite: boolean;
+  notes: string;
+  watchedAt: string | null;
+  colorLabel: ColorLabel | null;
+  rating: number | null;
+  hasProxy: boolean;
+  thumbnailUrl: string | null;
+}
+
+export interface CatalogVideosResponse {
+  videos: CatalogVideo[];
+  total: number;
+}
+
+export interface CatalogStats {
+  rootPath: string;
+  totalVideos: number;
+  totalDuration: number;
+  totalSize: number;
+  favorites: number;
+  withProxy: number;
+}
+
+// Share of the catalog with generated assets, metadata and reachable files (0-1 each).
+// The score is their average.
+export interface LibraryHealth {
+  score: number;
+  total: number;
+  thumbnails: number;
+  sprites: number;
+  metadata: number; // Width and height known
+  onDisk: number;
+  totalDuration: number;
+  totalSize: number;
+  codecs: CodecCount[]; // Most common first
+  computedAt: string;
+}
+
+// Videos per video codec; null for videos whose codec isn't known yet
+export interface CodecCount {
+  codec: string | null;
+  count: number;
+}
+
 // Video with selection data
 export interface VideoWithSelection extends Video {
   selection?: Selection;
+  // Asset file versions used to cache-bust thumbnail/sprite URLs
+  thumbnailVersion?: number | null;
+  spriteVersion?: number | null;
+  silenceWarning?: number; // Longest silent stretch (seconds), set when it reaches the library's threshold
+  sameNameCount?: number; // Videos in the library with this file name, when it's more than one
+  copyName?: string; // Copied instead of the file name: parent folder + name when the name isn't unique
 }
 
 // FFmpeg metadata from probe
@@ -152,6 +558,33 @@ export interface FFmpegMetadata {
   codec: string;
   frameRate: number;
   bitRate: number;
+  creationTime: string | null; // Container creation_time tag, if present
+  videoStreamCount: number; // More than one for multi-angle files
+  audioTrackCount: number;
+  colorSpace: string;
+  hdrFormat: string | null;
+  location: string; // '' when untagged
+  latitude: number | null;
+  longitude: number | null;
+}
+
+// Chapter marker from the container (OBS, Resolve and Premiere exports write these)
+export interface Chapter {
+  start: number; // Seconds
+  end: number;
+  title: string | null;
+}
+
+// Silent stretch of a clip's audio, from ffmpeg's silencedetect
+export interface SilenceRange {
+  start: number; // Seconds
+  end: number;
+}
+
+export interface SilenceReport {
+  hasAudio: boolean;
+  analyzedAt: string;
+  ranges: SilenceRange[];
 }
 
 // Convert database row to Video object
@@ -166,6 +599,7 @@ export function rowToVideo(row: VideoRow): Video {
     height: row.height,
     createdAt: row.created_at,
     directory: row.directory,
+    onlineOnly: row.online_only === 1,
     hasProxy: row.has_proxy === 1,
     hasSprite: row.has_sprite === 1,
     proxyPath: row.proxy_path,
@@ -174,6 +608,30 @@ export function rowToVideo(row: VideoRow): Video {
     fileHash: row.file_hash,
     fileMtime: row.file_mtime,
     scannedAt: row.scanned_at,
+    frameRate: row.frame_rate,
+    posterTime: row.poster_time,
+    sidecarTitle: row.sidecar_title,
+    sidecarDescription: row.sidecar_description,
+    sidecarKeywords: row.sidecar_keywords,
+    sidecarMtime: row.sidecar_mtime,
+    codec: row.codec,
+    videoStreamCount: row.video_stream_count,
+    audioTrackCount: row.audio_track_count,
+    subtitleFiles: row.subtitle_paths ? JSON.parse(row.subtitle_paths) : [],
+    spriteColumns: row.sprite_columns,
+    spriteRows: row.sprite_rows,
+    spriteFrames: row.sprite_frames,
+    corruptAt: row.corrupt_at,
+    mediaDate: row.media_date,
+    nameDate: row.name_date,
+    colorSpace: row.color_space,
+    hdrFormat: row.hdr_format,
+    location: row.location,
+    latitude: row.latitude,
+    longitude: row.longitude,
+    loudnessLufs: row.loudness_lufs,
+    bitRate: row.bit_rate,
+    removedAt: row.removed_at,
   };
 }
 
@@ -185,6 +643,9 @@ export function rowToSelection(row: SelectionRow): Selection {
     isFavorite: row.is_favorite === 1,
     notes: row.notes || '',
     createdAt: row.created_at,
+    watchedAt: row.watched_at ?? null,
+    colorLabel: (row.color_label as ColorLabel | null) ?? null,
+    rating: row.rating ?? null,
   };
 }
 
@@ -199,3 +660,24 @@ export function rowToProxyJob(row: ProxyJobRow): ProxyJob {
     error: row.error || undefined,
   };
 }
+
+// Convert database row to ScanReport object
+export function rowToScanReport(row: ScanRow): ScanReport {
+  return {
+    id: row.id,
+    status: row.status as ScanReport['status'],
+    rootPath: row.root_path,
+    videosFound: row.videos_found,
+    startedAt: row.started_at,
+    completedAt: row.completed_at || undefined,
+    added: row.added_count ?? 0,
+    updated: row.updated_count ?? 0,
+    skipped: row.skipped_count ?? 0,
+    removed: row.removed_count ?? 0,
+    errored: row.error_count ?? 0,
+    error: row.error,
+    folders: row.folder_stats ? JSON.parse(row.folder_stats) : {},
+    errors: row.errors ? JSON.parse(row.errors) : [],
+    scanPath: row.scan_path,
+  };
+}
@@ -1,5 +1,7 @@
 // Client-safe utility functions
 
+import { BitrateClass, BitrateThresholds, ColorFilter, ColorLabel, LoudnessFilter, SearchMode, SortOption, Video, VideoWithSelection } from './types';
+
 // Format duration in HH:MM:SS or MM:SS
 export function formatDuration(seconds: number): string {
   const hours = Math.floor(seconds / 3600);
@@ -26,3 +28,331 @@ export function formatFileSize(bytes: number): string {
 
   return `${size.toFixed(1)} ${units[unitIndex]}`;
 }
+
+// Frame rate assumed for videos indexed before frame rates were recorded
+export const DEFAULT_FRAME_RATE = 24;
+
+// Swatch class per color label; keys 1-5 follow this order, like Finder
+export const COLOR_LABEL_CLASSES: Record<ColorLabel, string> = {
+  red: 'bg-red-500',
+  orange: 'bg-orange-500',
+  green: 'bg-green-500',
+  blue: 'bg-blue-500',
+  purple: 'bg-purple-500',
+};
+
+// Display names for the ffprobe codec names we see most; others are upper-cased
+const CODEC_NAMES: Record<string, string> = {
+  h264: 'H.264',
+  hevc: 'HEVC',
+  prores: 'ProRes',
+  mpeg4: 'MPEG-4',
+  mpeg2video: 'MPEG-2',
+  vp9: 'VP9',
+  av1: 'AV1',
+  dnxhd: 'DNxHD',
+  mjpeg: 'Motion JPEG',
+};
+
+export function formatCodecName(codec: string | null): string {
+  if (!codec) return 'Unknown';
+  return CODEC_NAMES[codec] ?? codec.toUpperCase();
+}
+
+// Drag data type for cards dragged onto a selection set (JSON array of video ids)
+export const VIDEO_DRAG_TYPE = 'application/x-vcb-video-ids';
+
+// Rough age of a timestamp, e.g. "3 days ago"
+export function formatTimeAgo(iso: string, now: number = Date.now()): string {
+  const minutes = Math.max(0, Math.floor((now - new Date(iso).getTime()) / 60000));
+  const units: [string, number][] = [['year', 525600], ['month', 43200], ['day', 1440], ['hour', 60], ['minute', 1]];
+  for (const [unit, size] of units) {
+    const count = Math.floor(minutes / size);
+    if (count >= 1) return `${count} ${unit}${count === 1 ? '' : 's'} ago`;
+  }
+  return 'just now';
+}
+
+// Format seconds as an HH:MM:SS:FF timecode (non-drop-frame)
+export function formatTimecode(seconds: number, frameRate: number): string {
+  const fps = Math.max(1, Math.round(frameRate));
+  const totalFrames = Math.floor(Math.max(0, seconds) * frameRate);
+  const frames = totalFrames % fps;
+  const totalSeconds = Math.floor(totalFrames / fps);
+  const hours = Math.floor(totalSeconds / 3600);
+  const minutes = Math.floor((totalSeconds % 3600) / 60);
+  const secs = totalSeconds % 60;
+
+  return [hours, minutes, secs, frames].map((n) => n.toString().padStart(2, '0')).join(':');
+}
+
+// Load an image URL and re-encode it as PNG (the clipboard only accepts PNG images)
+export async function imageUrlToPngBlob(url: string): Promise<Blob> {
+  const res = await fetch(url);
+  if (!res.ok) {
+    throw new Error(`Failed to load image (${res.status})`);
+  }
+
+  const bitmap = await createImageBitmap(await res.blob());
+  const canvas = document.createElement('canvas');
+  canvas.width = bitmap.width;
+  canvas.height = bitmap.height;
+  canvas.getContext('2d')?.drawImage(bitmap, 0, 0);
+  bitmap.close();
+
+  return canvasToPngBlob(canvas);
+}
+
+function canvasToPngBlob(canvas: HTMLCanvasElement): Promise<Blob> {
+  return new Promise((resolve, reject) => {
+    canvas.toBlob((blob) => {
+      if (blob) {
+        resolve(blob);
+      } else {
+        reject(new Error('Failed to encode image'));
+      }
+    }, 'image/png');
+  });
+}
+
+// The player's current frame at the decoded stream's size, not the on-screen size
+// (proxy playback gives a proxy-sized frame). Throws before the first frame has loaded.
+export function captureVideoFrame(video: HTMLVideoElement): ImageData {
+  if (video.videoWidth === 0 || video.videoHeight === 0) {
+    throw new Error('No video frame loaded yet');
+  }
+
+  const canvas = document.createElement('canvas');
+  canvas.width = video.videoWidth;
+  canvas.height = video.videoHeight;
+  const context = canvas.getContext('2d');
+  if (!context) {
+    throw new Error('Canvas is not available');
+  }
+  context.drawImage(video, 0, 0);
+  return context.getImageData(0, 0, canvas.width, canvas.height);
+}
+
+export function imageDataToPngBlob(image: ImageData): Promise<Blob> {
+  const canvas = document.createElement('canvas');
+  canvas.width = image.width;
+  canvas.height = image.height;
+  canvas.getContext('2d')?.putImageData(image, 0, 0);
+  return canvasToPngBlob(canvas);
+}
+
+// Common name for a resolution (e.g. '4k', '1080p'), matched by height
+export function getResolutionLabel(width: number | null, height: number | null): string | null {
+  if (!width || !height) return null;
+  const lines = Math.min(width, height);
+  if (lines >= 4320) return '8k';
+  if (lines >= 2160) return '4k';
+  if (lines >= 1440) return '1440p';
+  if (lines >= 1080) return '1080p';
+  if (lines >= 720) return '720p';
+  return 'sd';
+}
+
+// Card badge for footage that isn't plain Rec. 709; null for Rec. 709 and untagged video
+export function getColorBadge(video: Video): { label: string; title: string; className: string } | null {
+  if (video.hdrFormat) {
+    return { label: 'HDR', title: video.hdrFormat, className: 'bg-error/80 text-white' };
+  }
+  if (video.colorSpace === 'Log') {
+    return { label: 'LOG', title: 'Log footage', className: 'bg-success/80 text-white' };
+  }
+  if (!video.colorSpace || video.colorSpace === 'unknown' || video.colorSpace === 'Rec. 709') return null;
+  return { label: video.colorSpace, title: `Color space: ${video.colorSpace}`, className: 'bg-black/70 text-white' };
+}
+
+// Whether a video passes the toolbar color filter; untagged video counts as SDR
+export function matchesColorFilter(video: Video, filter: ColorFilter): boolean {
+  switch (filter) {
+    case 'hdr':
+      return !!video.hdrFormat;
+    case 'log':
+      return video.colorSpace === 'Log';
+    case 'sdr':
+      return !video.hdrFormat && video.colorSpace !== 'Log';
+    default:
+      return true;
+  }
+}
+
+// Loudness filter bounds: broadcast delivery sits near -23 LUFS, phones and web video near -14
+export const LOUD_LUFS = -18;
+export const QUIET_LUFS = -28;
+
+// e.g. "−23.2 LUFS", with a real minus sign
+export function formatLoudness(lufs: number): string {
+  return `${lufs.toFixed(1).replace('-', '−')} LUFS`;
+}
+
+// Whether a video passes the toolbar loudness filter; unmeasured clips match neither
+export function matchesLoudnessFilter(video: Video, filter: LoudnessFilter): boolean {
+  if (filter === 'any') return true;
+  if (video.loudnessLufs === null) return false;
+  return filter === 'loud' ? video.loudnessLufs > LOUD_LUFS : video.loudnessLufs < QUIET_LUFS;
+}
+
+// Bit rate in bits/s: the container's, or file size over duration when it doesn't say.
+// Null without a duration.
+export function getBitrate(video: Video): number | null {
+  if (video.bitRate) return video.bitRate;
+  return video.duration > 0 ? (video.fileSize * 8) / video.duration : null;
+}
+
+// e.g. "24.3 Mbps"
+export function formatBitrate(bitsPerSecond: number): string {
+  const mbps = bitsPerSecond / 1_000_000;
+  return `${mbps < 10 ? mbps.toFixed(1) : Math.round(mbps)} Mbps`;
+}
+
+// Storage cost per minute of footage, e.g. "182 MB/min"
+export function formatMbPerMinute(bitsPerSecond: number): string {
+  const mbPerMinute = (bitsPerSecond * 60) / 8 / (1024 * 1024);
+  return `${mbPerMinute < 10 ? mbPerMinute.toFixed(1) : Math.round(mbPerMinute)} MB/min`;
+}
+
+// Threshold class for a video's resolution; null when the size is unknown
+export function getBitrateClass(video: Video): BitrateClass | null {
+  switch (getResolutionLabel(video.width, video.height)) {
+    case null:
+      return null;
+    case '8k':
+    case '4k':
+      return '4k';
+    case '1440p':
+    case '1080p':
+      return '1080p';
+    case '720p':
+      return '720p';
+    default:
+      return 'sd';
+  }
+}
+
+// Whether a clip's bit rate is above the threshold for its resolution class
+export function isInefficient(video: Video, thresholds: BitrateThresholds): boolean {
+  const bitrateClass = getBitrateClass(video);
+  const bitrate = getBitrate(video);
+  return bitrateClass !== null && bitrate !== null && bitrate > thresholds[bitrateClass] * 1_000_000;
+}
+
+// GPS position for display, e.g. "37.77490, -122.41940"
+export function formatCoordinates(latitude: number, longitude: number): string {
+  return `${latitude.toFixed(5)}, ${longitude.toFixed(5)}`;
+}
+
+// Where a video was shot, rounded to two decimals (about 1 km) so the clips of one spot
+// share a key; null without GPS
+export function getPlaceKey(video: Video): string | null {
+  if (video.latitude === null || video.longitude === null) return null;
+  return `${video.latitude.toFixed(2)}, ${video.longitude.toFixed(2)}`;
+}
+
+// Map search for a position; phones hand it to their Maps app
+export function getMapsUrl(latitude: number, longitude: number): string {
+  return `https://www.google.com/maps/search/?api=1&query=${latitude},${longitude}`;
+}
+
+// Menu label for a subtitle file: "clip.en.srt" next to "clip.mp4" becomes "en (SRT)"
+export function getSubtitleLabel(subtitleFile: string, videoFileName: string): string {
+  const stem = videoFileName.replace(/\.[^.]+$/, '');
+  const extension = subtitleFile.split('.').pop()!.toUpperCase();
+  const tag = subtitleFile.slice(stem.length + 1, -(extension.length + 1));
+  return tag ? `${tag} (${extension})` : extension;
+}
+
+// Folder containing a file (handles both / and \\ separators)
+export function getParentDirectory(filePath: string): string {
+  return filePath.replace(/[\\/][^\\/]*$/, '');
+}
+
+// Last component of a path, like path.basename on either platform (trailing separators ignored)
+export function getBaseName(filePath: string): string {
+  return filePath.split(/[\\/]/).filter(Boolean).pop() || '';
+}
+
+// A container's creation_time as an ISO string, or null when missing or unparseable
+// (cameras write '0000-00-00 00:00:00' and other junk, which toISOString throws on)
+export function parseCreationTime(creationTime: string | null | undefined): string | null {
+  if (!creationTime) return null;
+  const date = new Date(creationTime);
+  return Number.isNaN(date.getTime()) ? null : date.toISOString();
+}
+
+// Match a search query against a video; every whitespace-separated token must match
+export function matchesSearch(video: VideoWithSelection, query: string, mode: SearchMode): boolean {
+  const tokens = query.toLowerCase().split(/\s+/).filter(Boolean);
+  if (tokens.length === 0) return true;
+
+  const fields = mode === 'name'
+    ? [video.fileName]
+    : [
+        video.filePath,
+        video.directory,
+        video.codec,
+        video.selection?.notes,
+        video.sidecarTitle,
+        video.sidecarDescription,
+        video.sidecarKeywords,
+        getResolutionLabel(video.width, video.height),
+        video.hdrFormat,
+      ];
+  const haystack = fields.filter(Boolean).join('\n').toLowerCase();
+
+  return tokens.every((token) => haystack.includes(token));
+}
+
+export type DateSource = 'media' | 'name' | 'file';
+
+// Most trustworthy recording date: container metadata, then the file name, then the
+// filesystem date (which copies and syncs often reset). Mirrors the SQL best-date sort.
+export function getBestDate(video: Video): { date: string; source: DateSource } {
+  if (video.mediaDate) return { date: video.mediaDate, source: 'media' };
+  if (video.nameDate) return { date: video.nameDate, source: 'name' };
+  return { date: video.createdAt, source: 'file' };
+}
+
+// Client-side counterpart of the SQL sort orders, for videos merged from several libraries
+export function compareVideos(a: VideoWithSelection, b: VideoWithSelection, sortBy: SortOption): number {
+  const aWatched = a.selection?.watchedAt ?? null;
+  const bWatched = b.selection?.watchedAt ?? null;
+  switch (sortBy) {
+    case 'date-asc':
+      return a.createdAt.localeCompare(b.createdAt);
+    case 'duration-asc':
+      return a.duration - b.duration;
+    case 'duration-desc':
+      return b.duration - a.duration;
+    case 'name-asc':
+      return a.fileName.localeCompare(b.fileName);
+    case 'name-desc':
+      return b.fileName.localeCompare(a.fileName);
+    case 'watched-asc':
+      // Longest-ago watched first; unwatched last
+      if (!aWatched || !bWatched) return Number(!aWatched) - Number(!bWatched);
+      return aWatched.localeCompare(bWatched);
+    case 'best-date-asc':
+      return getBestDate(a).date.localeCompare(getBestDate(b).date);
+    case 'best-date-desc':
+      return getBestDate(b).date.localeCompare(getBestDate(a).date);
+    case 'scanned-asc':
+    case 'scanned-desc':
+      if (!a.scannedAt || !b.scannedAt) return Number(!a.scannedAt) - Number(!b.scannedAt);
+      return sortBy === 'scanned-asc' ? a.scannedAt.localeCompare(b.scannedAt) : b.scannedAt.localeCompare(a.scannedAt);
+    case 'bitrate-asc':
+    case 'bitrate-desc': {
+      // Clips without a duration go last either way
+      const aRate = getBitrate(a);
+      const bRate = getBitrate(b);
+      if (aRate === null || bRate === null) return Number(aRate === null) - Number(bRate === null);
+      return sortBy === 'bitrate-asc' ? aRate - bRate : bRate - aRate;
+    }
+    case 'unwatched-first':
+      return Number(!!aWatched) - Number(!!bWatched) || b.createdAt.localeCompare(a.createdAt);
+    default:
+      return b.createdAt.localeCompare(a.createdAt);
+  }
+}
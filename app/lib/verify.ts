@@ -0,0 +1,131 @@
+import fs from 'fs/promises';
+import path from 'path';
+import pLimit from 'p-limit';
+import { getCurrentRootPath, getReadableVideos } from './db';
+import { getFileFingerprint } from './scanner';
+import { startTask } from './tasks';
+import { Video, VerifyBucket, VerifyEntry, VerifyReport } from './types';
+
+// Integrity check of catalogued files against their scan fingerprints (server-side only).
+// The fingerprint covers the first 64KB, size and mtime; no full-file hash is stored,
+// so damage past the first 64KB of a file with an untouched mtime isn't caught.
+
+const VERIFY_CONCURRENCY = 4;
+
+// Last run, kept so the report can be reopened until the next verify
+let lastReport: VerifyReport | null = null;
+let activeTaskId: string | null = null;
+
+async function verifyVideo(video: Video): Promise<VerifyBucket | null> {
+  let stats;
+  try {
+    stats = await fs.stat(video.filePath);
+  } catch {
+    return 'missing';
+  }
+
+  if (await getFileFingerprint(video.filePath) === video.fileHash) {
+    return null;
+  }
+
+  // Size or mtime moved: most likely re-exported or edited on purpose.
+  // Same size and mtime with different content is what bit rot and bad copies look like.
+  const sameFile = stats.size === video.fileSize && stats.mtime.toISOString() === video.fileMtime;
+  return sameFile ? 'mismatched' : 'changed';
+}
+
+function toEntry(video: Video, bucket: VerifyBucket, stats: { size: number; mtime: Date } | null): VerifyEntry {
+  return {
+    videoId: video.id,
+    filePath: video.filePath,
+    fileName: video.fileName,
+    bucket,
+    storedSize: video.fileSize,
+    storedMtime: video.fileMtime,
+    currentSize: stats?.size ?? null,
+    currentMtime: stats?.mtime.toISOString() ?? null,
+    isCorrupt: video.corruptAt !== null,
+  };
+}
+
+// Report for the open library, if one was run since it was opened
+export function getVerifyReport(): VerifyReport | null {
+  return lastReport?.rootPath === getCurrentRootPath() ? lastReport : null;
+}
+
+// Task ID of the running verify, for cancelling it; null when idle
+export function getVerifyTaskId(): string | null {
+  return activeTaskId;
+}
+
+// Start verifying every catalogued file in the background; returns the task ID
+export function startVerify(): string {
+  const rootPath = getCurrentRootPath()!;
+  const { videos, onlineOnly } = getReadableVideos();
+  const task = startTask('hashing', `Verifying ${path.basename(rootPath) || rootPath}`);
+  activeTaskId = task.id;
+
+  const report: VerifyReport = {
+    rootPath,
+    startedAt: new Date().toISOString(),
+    completedAt: null,
+    cancelled: false,
+    done: 0,
+    total: videos.length,
+    unchanged: 0,
+    skippedOnlineOnly: onlineOnly,
+    entries: [],
+  };
+  lastReport = report;
+
+  const limit = pLimit(VERIFY_CONCURRENCY);
+  const run = async () => {
+    await Promise.all(videos.map((video) => limit(async () => {
+      if (task.isCancelled()) return;
+
+      try {
+        const bucket = await verifyVideo(video);
+        if (bucket === null) {
+          report.unchanged++;
+        } else {
+          const stats = bucket === 'missing' ? null : await fs.stat(video.filePath);
+          report.entries.push(toEntry(video, bucket, stats));
+          if (bucket === 'mismatched') {
+            console.warn(`Fingerprint mismatch with unchanged size and mtime: ${video.filePath}`);
+          }
+        }
+      } catch (error) {
+        // Unreadable (permissions, I/O error) counts as suspicious
+        console.warn(`Failed to verify ${video.filePath}:`, error);
+        report.entries.push(toEntry(video, 'mismatched', null));
+      }
+
+      report.done++;
+      task.setProgress(report.done, report.total, video.filePath);
+    })));
+  };
+
+  run()
+    .then(() => {
+      report.cancelled = task.isCancelled();
+      task.finish(report.cancelled ? 'cancelled' : 'complete');
+    })
+    .catch((error) => {
+      console.error('Verify error:', error);
+      task.finish('error', error instanceof Error ? error.message : String(error));
+    })
+    .finally(() => {
+      report.completedAt = new Date().toISOString();
+      activeTaskId = null;
+    });
+
+  return task.id;
+}
+
+// Keep the report in step with per-file actions taken from it
+export function updateVerifyEntry(videoId: string, update: Partial<VerifyEntry> | null): void {
+  if (!lastReport) return;
+  lastReport.entries = update === null
+    ? lastReport.entries.filter((entry) => entry.videoId !== videoId)
+    : lastReport.entries.map((entry) => (entry.videoId === videoId ? { ...entry, ...update } : entry));
+}
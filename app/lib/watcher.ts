@@ -0,0 +1,177 @@
+import fs from 'fs';
+import fsPromises from 'fs/promises';
+import path from 'path';
+import { getSetting, setSetting, getCurrentRootPath, isDatabaseInitialized, deleteVideoByPath, clearDuplicateFileNames, getVideoByPath } from './db';
+import { getMinFileSize, isVideoFile, isSkippedPath, processSingleVideo } from './scanner';
+import { compileExclusions, getExclusions, ExclusionMatcher } from './exclusions';
+
+// Library folder watcher: re-indexes created/modified/removed videos while browsing (server-side only)
+
+const WATCH_SETTING = 'watch_enabled';
+
+// Quiet period after the last filesystem event before processing
+const DEBOUNCE_MS = 2000;
+
+export interface WatcherStatus {
+  enabled: boolean;
+  active: boolean;
+  pending: number;
+  changeCount: number; // Increments whenever the catalog changes; clients refetch on change
+  lastChangeAt: string | null;
+  error: string | null;
+}
+
+// Module-level state (one library is open at a time)
+let watcher: {
+  rootPath: string;
+  handle: fs.FSWatcher;
+  isExcluded: ExclusionMatcher; // Compiled once per change to the excluded folders
+  pending: Set<string>;
+  lastSizes: Map<string, number>; // Size seen at the previous flush, for growing-file detection
+  timer: NodeJS.Timeout | null;
+  isFlushing: boolean;
+} | null = null;
+let changeCount = 0;
+let lastChangeAt: string | null = null;
+let watchError: string | null = null;
+
+export function isWatchEnabled(): boolean {
+  return isDatabaseInitialized() && getSetting(WATCH_SETTING) === 'true';
+}
+
+export function setWatchEnabled(enabled: boolean): void {
+  setSetting(WATCH_SETTING, enabled ? 'true' : 'false');
+  syncWatcher();
+}
+
+function recordChange(): void {
+  // Added, renamed or deleted files change which names are shared
+  clearDuplicateFileNames();
+  changeCount++;
+  lastChangeAt = new Date().toISOString();
+}
+
+function scheduleFlush(): void {
+  if (!watcher) return;
+  if (watcher.timer) clearTimeout(watcher.timer);
+  watcher.timer = setTimeout(() => {
+    flushPending().catch((error) => console.error('Error processing watched files:', error));
+  }, DEBOUNCE_MS);
+}
+
+// Process queued paths; files still growing (e.g. renders in progress) wait for the next flush
+async function flushPending(): Promise<void> {
+  const current = watcher;
+  if (!current || current.isFlushing) return;
+  current.isFlushing = true;
+  current.timer = null;
+
+  try {
+    const paths = [...current.pending];
+    current.pending.clear();
+
+    for (const filePath of paths) {
+      let size: number;
+      try {
+        size = (await fsPromises.stat(filePath)).size;
+      } catch {
+        // File is gone
+        current.lastSizes.delete(filePath);
+        if (deleteVideoByPath(filePath)) {
+          recordChange();
+        }
+        continue;
+      }
+
+      // Hold back until the size is unchanged between two flushes
+      if (current.lastSizes.get(filePath) !== size) {
+        current.lastSizes.set(filePath, size);
+        current.pending.add(filePath);
+        continue;
+      }
+
+      current.lastSizes.delete(filePath);
+      // New camera preview clips are left out, as a scan would; indexed ones are kept
+      if (size < getMinFileSize(current.rootPath) && !getVideoByPath(filePath)) continue;
+      if (await processSingleVideo(filePath, current.rootPath)) {
+        recordChange();
+      }
+    }
+  } finally {
+    current.isFlushing = false;
+    if (watcher === current && current.pending.size > 0) {
+      scheduleFlush();
+    }
+  }
+}
+
+function startWatcher(rootPath: string): void {
+  try {
+    const handle = fs.watch(rootPath, { recursive: true }, (_event, fileName) => {
+      if (!watcher || !fileName) return;
+      const filePath = path.join(rootPath, fileName.toString());
+      if (!isVideoFile(filePath)) return;
+      if (isSkippedPath(rootPath, filePath, watcher.isExcluded)) return;
+
+      watcher.pending.add(filePath);
+      scheduleFlush();
+    });
+
+    handle.on('error', (error) => {
+      console.error('Folder watcher error:', error);
+      watchError = error.message;
+      stopWatcher();
+    });
+
+    watcher = {
+      rootPath,
+      handle,
+      isExcluded: compileExclusions(rootPath, getExclusions()),
+      pending: new Set(),
+      lastSizes: new Map(),
+      timer: null,
+      isFlushing: false,
+    };
+    watchError = null;
+  } catch (error) {
+    console.error('Failed to start folder watcher:', error);
+    watchError = error instanceof Error ? error.message : 'Failed to start watcher';
+  }
+}
+
+function stopWatcher(): void {
+  if (!watcher) return;
+  if (watcher.timer) clearTimeout(watcher.timer);
+  watcher.handle.close();
+  watcher = null;
+}
+
+// Start, stop, or move the watcher to match the open library and its setting
+export function syncWatcher(): void {
+  const rootPath = getCurrentRootPath();
+  const shouldWatch = rootPath !== null && isWatchEnabled();
+
+  if (watcher && (!shouldWatch || watcher.rootPath !== rootPath)) {
+    stopWatcher();
+  }
+  if (shouldWatch && rootPath && !watcher) {
+    startWatcher(rootPath);
+  }
+}
+
+// Pick up edited exclusion patterns without restarting the watcher
+export function reloadWatcherExclusions(): void {
+  if (!watcher) return;
+  watcher.isExcluded = compileExclusions(watcher.rootPath, getExclusions());
+}
+
+export function getWatcherStatus(): WatcherStatus {
+  return {
+    enabled: isWatchEnabled(),
+    active: watcher !== null,
+    pending: watcher?.pending.size ?? 0,
+    changeCount,
+    lastChangeAt,
+    error: watchError,
+  };
+}
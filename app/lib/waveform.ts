@@ -0,0 +1,111 @@
+import fs from 'fs/promises';
+import path from 'path';
+import { Video } from './types';
+import { ensureProxyDir, extractAudioPeaks, getProxyDir } from './ffmpeg';
+
+// Audio waveforms for the player (server-side only): one peak byte (0-255) per bucket,
+// cached as `<id>_wave.bin` next to the proxies. While a waveform is being decoded,
+// readers get the buckets finished so far and then the rest as they arrive, and
+// several readers of one video share a single ffmpeg run.
+
+export const WAVEFORM_BUCKETS = 1000;
+
+interface WaveformJob {
+  peaks: Uint8Array;
+  filled: number; // Buckets done, always a prefix
+  done: boolean;
+  listeners: Set<() => void>;
+}
+
+// Waveform path -> job in progress
+const jobs = new Map<string, WaveformJob>();
+
+export function getWaveformPath(rootPath: string, videoId: string): string {
+  return path.join(getProxyDir(rootPath), `${videoId}_wave.bin`);
+}
+
+// A cached waveform, unless it's missing, truncated or older than the video file
+async function readCachedWaveform(wavePath: string, video: Video): Promise<Buffer | null> {
+  try {
+    const [waveStats, videoStats] = await Promise.all([fs.stat(wavePath), fs.stat(video.filePath)]);
+    if (waveStats.size !== WAVEFORM_BUCKETS || waveStats.mtimeMs < videoStats.mtimeMs) return null;
+    return await fs.readFile(wavePath);
+  } catch {
+    return null;
+  }
+}
+
+function notify(job: WaveformJob): void {
+  for (const listener of job.listeners) listener();
+}
+
+function startJob(video: Video, rootPath: string, wavePath: string): WaveformJob {
+  const job: WaveformJob = {
+    peaks: new Uint8Array(WAVEFORM_BUCKETS),
+    filled: 0,
+    done: false,
+    listeners: new Set(),
+  };
+  jobs.set(wavePath, job);
+
+  const run = async () => {
+    // Known silent clips skip ffmpeg and cache a flat line
+    if (video.audioTrackCount !== 0) {
+      await extractAudioPeaks(video.filePath, video.duration, WAVEFORM_BUCKETS, (index, peak) => {
+        job.peaks[index] = peak;
+        job.filled = index + 1;
+        notify(job);
+      });
+    }
+    await ensureProxyDir(rootPath);
+    await fs.writeFile(wavePath, job.peaks);
+  };
+
+  run()
+    .catch((error) => console.warn(`Waveform failed for ${video.filePath}:`, error))
+    .finally(() => {
+      job.filled = WAVEFORM_BUCKETS;
+      job.done = true;
+      jobs.delete(wavePath);
+      notify(job);
+    });
+
+  return job;
+}
+
+// Peak bytes for a video: the cached file, or a stream that fills in as decoding goes.
+// Decoding continues if the reader goes away, so the next open finds the cache.
+export async function getWaveform(video: Video, rootPath: string): Promise<Uint8Array | ReadableStream<Uint8Array>> {
+  const wavePath = getWaveformPath(rootPath, video.id);
+  if (!jobs.has(wavePath)) {
+    const cached = await readCachedWaveform(wavePath, video);
+    if (cached) return new Uint8Array(cached);
+  }
+
+  // Checked again: another request may have started decoding during the read
+  const current = jobs.get(wavePath) ?? startJob(video, rootPath, wavePath);
+  let sent = 0;
+
+  // Resolves on the next batch of buckets (or the end); the listener removes itself
+  const nextPeaks = () => new Promise<void>((resolve) => {
+    const listener = () => {
+      current.listeners.delete(listener);
+      resolve();
+    };
+    current.listeners.add(listener);
+  });
+
+  return new ReadableStream<Uint8Array>({
+    async pull(controller) {
+      while (sent >= current.filled && !current.done) {
+        await nextPeaks();
+      }
+      if (sent < current.filled) {
+        controller.enqueue(current.peaks.slice(sent, current.filled));
+        sent = current.filled;
+      } else {
+        controller.close();
+      }
+    },
+  });
+}
@@ -1,16 +1,41 @@
 'use client';
 
-import { useState, useEffect, useCallback } from 'react';
+import { useState, useEffect, useCallback, useMemo, useRef } from 'react';
 import DropZone from './components/DropZone';
 import VideoGrid from './components/VideoGrid';
 import SortControls from './components/SortControls';
 import ProxyProgress from './components/ProxyProgress';
 import VideoModal from './components/VideoModal';
 import ScanProgress from './components/ScanProgress';
-import { VideoWithSelection, SortOption } from './lib/types';
+import Toast, { ToastMessage, ToastType } from './components/Toast';
+import StreamModal from './components/StreamModal';
+import PlaylistExport from './components/PlaylistExport';
+import CatalogImport from './components/CatalogImport';
+import SettingsPanel from './components/SettingsPanel';
+import WatchIndicator from './components/WatchIndicator';
+import SearchBar from './components/SearchBar';
+import TasksPanel from './components/TasksPanel';
+import ThumbnailWarmup from './components/ThumbnailWarmup';
+import ScanPreviewModal from './components/ScanPreviewModal';
+import DuplicatesView from './components/DuplicatesView';
+import RecentlyRemovedView from './components/RecentlyRemovedView';
+import ScanHistory from './components/ScanHistory';
+import VerifyView from './components/VerifyView';
+import TranscodeModal from './components/TranscodeModal';
+import LibraryHealth from './components/LibraryHealth';
+import GlobalSearch from './components/GlobalSearch';
+import SmartViews from './components/SmartViews';
+import SelectionSets from './components/SelectionSets';
+import RecentLibraries from './components/RecentLibraries';
+import AllLibrariesView from './components/AllLibrariesView';
+import { VideoWithSelection, Selection, SortOption, ViewLayout, SearchMode, GlobalSearchResult, SmartViewCriteria, WatchedFilter, ColorFilter, LoudnessFilter, ScanPreview, TaskInfo, ColorLabel, COLOR_LABELS, SelectionSet, MAX_RATING, ThumbnailStatus, BitrateThresholds, DirectoryStats } from './lib/types';
+import { formatCodecName, getBaseName, getParentDirectory, getPlaceKey, isInefficient, matchesColorFilter, matchesLoudnessFilter, matchesSearch, LOUD_LUFS, QUIET_LUFS } from './lib/utils';
 
 type ViewMode = 'all' | 'favorites';
 
+// Random picks remembered (and skipped) so repeated presses show variety
+const RANDOM_PICK_MEMORY = 10;
+
 // Extended scan progress state
 interface ScanState {
   status: 'idle' | 'counting' | 'scanning' | 'complete' | 'error';
@@ -18,8 +43,15 @@ interface ScanState {
   totalVideos: number;
   videosProcessed: number;
   videosSkipped: number;
+  videosTooSmall?: number; // Under the library's minimum file size
+  totalBytes: number;
+  bytesProcessed: number;
+  videosOnlineOnly?: number; // Online-only cloud files, not downloaded
   currentFile: string;
   message: string;
+  errors: string[];
+  folder?: string | null; // Set when only a folder inside the library is being scanned
+  incremental?: boolean; // Rescan of the open library; the grid stays visible meanwhile
 }
 
 export default function Home() {
@@ -35,14 +67,60 @@ export default function Home() {
     videosSkipped: 0,
     currentFile: '',
     message: '',
+    errors: [],
   });
   const [sortBy, setSortBy] = useState<SortOption>('date-desc');
   const [viewMode, setViewMode] = useState<ViewMode>('all');
+  const [layout, setLayout] = useState<ViewLayout>('grid');
+  const [stripFrames, setStripFrames] = useState(5);
+  const [searchQuery, setSearchQuery] = useState('');
+  const [searchMode, setSearchMode] = useState<SearchMode>('name');
+  const [watchedFilter, setWatchedFilter] = useState<WatchedFilter>('any');
+  const [labelFilter, setLabelFilter] = useState<ColorLabel | 'any'>('any');
+  const [colorFilter, setColorFilter] = useState<ColorFilter>('any');
+  const [loudnessFilter, setLoudnessFilter] = useState<LoudnessFilter>('any');
+  const [locationFilter, setLocationFilter] = useState<string | null>(null); // 'located', or a place key (~1 km)
+  const [inefficientOnly, setInefficientOnly] = useState(false); // "Inefficient clips" smart view
+  const [bitrateThresholds, setBitrateThresholds] = useState<BitrateThresholds | null>(null); // Per library
+  const [selectedDirectory, setSelectedDirectory] = useState<string | null>(null); // "Show Siblings" folder filter
   const [selectedVideo, setSelectedVideo] = useState<VideoWithSelection | null>(null);
+  const [streamUrl, setStreamUrl] = useState<string | null>(null);
+  const [showDuplicates, setShowDuplicates] = useState(false);
+  const [showRecentlyRemoved, setShowRecentlyRemoved] = useState(false);
+  const [showScanHistory, setShowScanHistory] = useState(false);
+  const [showVerify, setShowVerify] = useState(false);
+  const [showTranscode, setShowTranscode] = useState(false);
+  const [selectedIds, setSelectedIds] = useState<Set<string>>(new Set()); // Batch selection (Ctrl+A)
+  const [codecFilter, setCodecFilter] = useState<string | null>(null); // From the stats panel; '' is unknown
+  const [nameFilter, setNameFilter] = useState<string | null>(null); // A file name several videos share
+  const [setFilter, setSetFilter] = useState<{ name: string; videoIds: Set<string> } | null>(null); // Restored selection set
+  const [showGlobalSearch, setShowGlobalSearch] = useState(false);
+  const [showAllLibraries, setShowAllLibraries] = useState(false);
+  const [highlightedVideoId, setHighlightedVideoId] = useState<string | null>(null);
+  const recentPicks = useRef<string[]>([]); // Last random picks, not repeated
+  const [pendingVideoId, setPendingVideoId] = useState<string | null>(null); // Opened once its library loads
   const [error, setError] = useState<string | null>(null);
+  const [scanPreview, setScanPreview] = useState<ScanPreview | null>(null);
+  const [isPreviewing, setIsPreviewing] = useState(false);
+  const [interruptedScan, setInterruptedScan] = useState<{ startedAt: string; videosFound: number; folder: string | null } | null>(null);
+  const [toast, setToast] = useState<ToastMessage | null>(null);
+  const [undoableThumbnails, setUndoableThumbnails] = useState(0); // Regenerated this session, restorable
+  const [healthKey, setHealthKey] = useState(0); // Bumped to recompute the library health score
+  const [directoryStats, setDirectoryStats] = useState<Map<string, DirectoryStats>>(new Map()); // Folder layout totals
+  const [collapsedDirectories, setCollapsedDirectories] = useState<Record<string, boolean>>({});
+  // Thumbnails pending, generating or failed, by video id
+  const [thumbnailStatuses, setThumbnailStatuses] = useState<Record<string, ThumbnailStatus>>({});
+  const thumbnailStatusVersion = useRef<number | null>(null);
 
   const isScanning = scanState.status === 'scanning' || scanState.status === 'counting';
 
+  // Show a transient notification
+  const showToast = useCallback((message: string, type: ToastType = 'success') => {
+    setToast({ id: Date.now(), message, type });
+  }, []);
+
+  const dismissToast = useCallback(() => setToast(null), []);
+
   // Fetch videos from API
   const fetchVideos = useCallback(async () => {
     if (!currentPath) return;
@@ -80,6 +158,7 @@ export default function Home() {
     // If complete, fetch videos once and exit
     if (scanState.status === 'complete') {
       fetchVideos();
+      setHealthKey((key) => key + 1);
       return;
     }
 
@@ -89,15 +168,22 @@ export default function Home() {
         const data = await res.json();
 
         if (data.success) {
-          setScanState({
+          setScanState((prev) => ({
             status: data.status,
             phase: data.phase || 'done',
             totalVideos: data.totalVideos || 0,
             videosProcessed: data.videosProcessed || 0,
             videosSkipped: data.videosSkipped || 0,
+            videosTooSmall: data.videosTooSmall || 0,
+            totalBytes: data.totalBytes || 0,
+            bytesProcessed: data.bytesProcessed || 0,
+            videosOnlineOnly: data.videosOnlineOnly || 0,
             currentFile: data.currentFile || '',
             message: data.message || '',
-          });
+            errors: data.errors || [],
+            folder: data.folder,
+            incremental: prev.incremental,
+          }));
 
           if (data.status === 'complete') {
             // Update path if we got it from the scan
@@ -116,6 +202,42 @@ export default function Home() {
     return () => clearInterval(interval);
   }, [isScanning, scanState.status, fetchVideos, currentPath]);
 
+  // Folder totals are rebuilt at the end of each scan, which also bumps healthKey
+  useEffect(() => {
+    if (!currentPath) return;
+    let cancelled = false;
+    fetch('/api/directories')
+      .then((res) => res.json())
+      .then((data) => {
+        if (cancelled || !data.success) return;
+        setDirectoryStats(new Map((data.directories as DirectoryStats[]).map((entry) => [entry.directory, entry])));
+        setCollapsedDirectories(data.collapsed);
+      })
+      .catch((err) => console.error('Error fetching folder state:', err));
+    return () => {
+      cancelled = true;
+    };
+  }, [currentPath, healthKey]);
+
+  // Collapse state is saved per library, so the same folders are open next time
+  const handleToggleDirectory = useCallback(async (directory: string, collapsed: boolean) => {
+    setCollapsedDirectories((prev) => {
+      const next = { ...prev };
+      if (collapsed) next[directory] = true;
+      else delete next[directory];
+      return next;
+    });
+    try {
+      await fetch('/api/directories', {
+        method: 'PUT',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ directory, collapsed }),
+      });
+    } catch (err) {
+      console.error('Error saving folder state:', err);
+    }
+  }, []);
+
   // Fetch videos when path or sort changes
   useEffect(() => {
     if (currentPath && !isScanning && scanState.status !== 'complete') {
@@ -123,6 +245,41 @@ export default function Home() {
     }
   }, [currentPath, sortBy, viewMode, isScanning, scanState.status, fetchVideos]);
 
+  // Thumbnail states belong to the open library
+  useEffect(() => {
+    setThumbnailStatuses({});
+  }, [currentPath]);
+
+  // Poll thumbnail states while browsing, including during a rescan of the open library.
+  // Unchanged state comes back without statuses, so the grid only re-renders when a card's
+  // thumbnail actually moved on.
+  const pollThumbnails = !!currentPath && (!isScanning || !!scanState.incremental);
+  useEffect(() => {
+    thumbnailStatusVersion.current = null;
+    if (!pollThumbnails) return;
+
+    let tracked: Record<string, ThumbnailStatus> = {};
+    const poll = async () => {
+      try {
+        const since = thumbnailStatusVersion.current;
+        const res = await fetch(`/api/thumbnails${since !== null ? `?since=${since}` : ''}`);
+        const data = await res.json();
+        if (!data.success || data.unchanged) return;
+        thumbnailStatusVersion.current = data.version;
+        // A video that left the list got its thumbnail, so the list is fetched for the new path
+        if (Object.keys(tracked).some((id) => !(id in data.statuses))) fetchVideos();
+        tracked = data.statuses;
+        setThumbnailStatuses(data.statuses);
+      } catch (err) {
+        console.error('Error polling thumbnail status:', err);
+      }
+    };
+
+    poll();
+    const interval = setInterval(poll, 2000);
+    return () => clearInterval(interval);
+  }, [pollThumbnails, currentPath, fetchVideos]);
+
   // Check for last directory on mount
   useEffect(() => {
     const checkLastDirectory = async () => {
@@ -131,6 +288,7 @@ export default function Home() {
         const data = await res.json();
         if (data.success && data.lastDirectory) {
           setCurrentPath(data.lastDirectory);
+          setInterruptedScan(data.interruptedScan);
         }
       } catch (err) {
         console.error('Error checking last directory:', err);
@@ -140,10 +298,18 @@ export default function Home() {
     checkLastDirectory();
   }, []);
 
-  // Handle directory selection
-  const handleDirectorySelected = useCallback(async (path: string) => {
+  // A relinked library is already open on the server. Skip the rescan so files
+  // still missing after the move stay in the catalog instead of being removed.
+  const handleLibraryRelinked = useCallback((path: string) => {
+    setError(null);
+    setCurrentPath(path);
+  }, []);
+
+  // Scan a library, or with folder, just that folder of it into the existing catalog
+  const startScan = useCallback(async (path: string, folder?: string) => {
     setError(null);
     setCurrentPath(path);
+    setInterruptedScan(null);
 
     // Reset scan state
     setScanState({
@@ -152,15 +318,20 @@ export default function Home() {
       totalVideos: 0,
       videosProcessed: 0,
       videosSkipped: 0,
+      totalBytes: 0,
+      bytesProcessed: 0,
       currentFile: '',
       message: 'Starting scan...',
+      errors: [],
+      folder: folder ?? null,
+      incremental: path === currentPath && videos.length > 0,
     });
 
     try {
       const res = await fetch('/api/scan', {
         method: 'POST',
         headers: { 'Content-Type': 'application/json' },
-        body: JSON.stringify({ path }),
+        body: JSON.stringify({ path, folder }),
       });
 
       const data = await res.json();
@@ -174,6 +345,58 @@ export default function Home() {
       setScanState(prev => ({ ...prev, status: 'error', message: 'Failed to start scan' }));
       console.error('Error starting scan:', err);
     }
+  }, [currentPath, videos.length]);
+
+  // Handle directory selection
+  const handleDirectorySelected = useCallback((path: string) => startScan(path), [startScan]);
+
+  // Pick up new footage in one folder without walking the whole library
+  const handleScanFolder = useCallback((folder?: string) => {
+    if (!currentPath) return;
+    if (folder === undefined) {
+      const current = selectedDirectory?.startsWith(currentPath)
+        ? selectedDirectory.slice(currentPath.length).replace(/^[\\/]/, '')
+        : '';
+      const answer = window.prompt('Folder to scan into this library (relative to it, or a full path):', current);
+      if (!answer?.trim()) return;
+      folder = answer.trim();
+    }
+    startScan(currentPath, folder);
+  }, [currentPath, selectedDirectory, startScan]);
+
+  // Dry-run scan; its fingerprints are reused if the full scan follows
+  const handlePreviewScan = useCallback(async (path: string) => {
+    setIsPreviewing(true);
+    setError(null);
+    try {
+      const res = await fetch('/api/scan/preview', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ path }),
+      });
+      const data = await res.json();
+
+      if (data.success) {
+        setScanPreview(data.preview);
+      } else {
+        setError(data.error || 'Failed to preview scan');
+      }
+    } catch (err) {
+      setError('Failed to preview scan');
+      console.error('Error previewing scan:', err);
+    } finally {
+      setIsPreviewing(false);
+    }
+  }, []);
+
+  // Leave the interrupted scan as it is; what it indexed stays in the catalog
+  const handleDismissInterruptedScan = useCallback(async () => {
+    setInterruptedScan(null);
+    try {
+      await fetch('/api/scan', { method: 'DELETE' });
+    } catch (err) {
+      console.error('Error dismissing interrupted scan:', err);
+    }
   }, []);
 
   // Handle scan complete
@@ -190,6 +413,39 @@ export default function Home() {
     setSelectedVideo(video);
   }, []);
 
+  // Handle watched toggle
+  const handleToggleWatched = useCallback(async (videoId: string, watched: boolean) => {
+    try {
+      const res = await fetch('/api/selections/watched', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ videoId, watched }),
+      });
+
+      const data = await res.json();
+
+      if (data.success) {
+        // Update local state
+        setVideos((prev) =>
+          prev.map((v) =>
+            v.id === videoId
+              ? { ...v, selection: { ...v.selection, ...data.selection } }
+              : v
+          )
+        );
+
+        // Update selected video if open
+        setSelectedVideo((prev) =>
+          prev?.id === videoId ? { ...prev, selection: { ...prev.selection, ...data.selection } } : prev
+        );
+      } else {
+        showToast(data.error || 'Failed to update watched state', 'error');
+      }
+    } catch (err) {
+      console.error('Error toggling watched:', err);
+    }
+  }, [showToast]);
+
   // Handle favorite toggle
   const handleToggleFavorite = useCallback(async (videoId: string, isFavorite: boolean) => {
     try {
@@ -261,19 +517,474 @@ export default function Home() {
     }
   }, [videos, selectedVideo?.id]);
 
+  const refreshUndoableThumbnails = useCallback(async () => {
+    try {
+      const res = await fetch('/api/thumbnails/undo');
+      const data = await res.json();
+      if (data.success) setUndoableThumbnails(data.count);
+    } catch (err) {
+      console.error('Error checking thumbnail backups:', err);
+    }
+  }, []);
+
+  useEffect(() => {
+    if (currentPath) refreshUndoableThumbnails();
+  }, [currentPath, refreshUndoableThumbnails]);
+
+  // Replace a video after a server-side update (e.g. new poster frame)
+  const handleVideoUpdated = useCallback((updated: VideoWithSelection) => {
+    setVideos((prev) => prev.map((v) => (v.id === updated.id ? updated : v)));
+    setSelectedVideo((prev) => (prev?.id === updated.id ? updated : prev));
+    refreshUndoableThumbnails();
+    setHealthKey((key) => key + 1);
+  }, [refreshUndoableThumbnails]);
+
+  // Put back the thumbnails as they were before this session's regenerations
+  const handleUndoThumbnails = useCallback(async () => {
+    try {
+      const res = await fetch('/api/thumbnails/undo', { method: 'POST' });
+      const data = await res.json();
+      if (data.success) {
+        showToast(`Restored ${data.restored} thumbnail${data.restored !== 1 ? 's' : ''}`, 'success');
+        fetchVideos();
+        setHealthKey((key) => key + 1);
+      } else {
+        showToast(data.error || 'Failed to restore thumbnails', 'error');
+      }
+    } catch (err) {
+      showToast('Failed to restore thumbnails', 'error');
+      console.error('Error undoing thumbnail regeneration:', err);
+    }
+    refreshUndoableThumbnails();
+  }, [fetchVideos, showToast, refreshUndoableThumbnails]);
+
+  // Check the clips in view for silent stretches; cards get dropout badges when it finishes
+  const handleCheckSilence = useCallback(async (videoIds: string[]) => {
+    try {
+      const res = await fetch('/api/silence', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ videoIds }),
+      });
+      const data = await res.json();
+      if (data.success) {
+        showToast('Checking for silence; progress is under Background tasks', 'success');
+      } else {
+        showToast(data.error || 'Failed to start silence detection', 'error');
+      }
+    } catch (err) {
+      showToast('Failed to start silence detection', 'error');
+      console.error('Error starting silence detection:', err);
+    }
+  }, [showToast]);
+
+  // Refresh for tasks that change what the grid shows (new dropout badges, exported clips, new sprites)
+  const handleTaskFinished = useCallback((task: TaskInfo) => {
+    if (task.kind === 'silence' || task.kind === 'clip' || task.kind === 'sprites') fetchVideos();
+  }, [fetchVideos]);
+
+  // Open a global search result, switching libraries first if needed
+  const handleOpenSearchResult = useCallback((result: GlobalSearchResult) => {
+    setShowGlobalSearch(false);
+    setShowAllLibraries(false);
+    setSearchQuery('');
+    setViewMode('all');
+
+    if (result.libraryPath === currentPath) {
+      const video = videos.find((v) => v.id === result.video.id);
+      if (video) setSelectedVideo(video);
+      return;
+    }
+
+    setPendingVideoId(result.video.id);
+    handleDirectorySelected(result.libraryPath);
+  }, [currentPath, videos, handleDirectorySelected]);
+
+  // Open the pending search result once its library's videos have loaded
+  useEffect(() => {
+    if (!pendingVideoId || isScanning) return;
+    const video = videos.find((v) => v.id === pendingVideoId);
+    if (video) {
+      setSelectedVideo(video);
+      setPendingVideoId(null);
+    }
+  }, [pendingVideoId, videos, isScanning]);
+
+  // "/" opens global search (unless typing in a field)
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      const target = e.target as HTMLElement;
+      if (e.key !== '/' || target.isContentEditable || ['INPUT', 'TEXTAREA', 'SELECT'].includes(target.tagName)) {
+        return;
+      }
+      e.preventDefault();
+      setShowGlobalSearch(true);
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, []);
+
   // Handle generate all proxies
   const handleGenerateAllProxies = useCallback(async () => {
     try {
-      await fetch('/api/proxy/generate', {
+      const res = await fetch('/api/proxy/generate', {
         method: 'POST',
         headers: { 'Content-Type': 'application/json' },
         body: JSON.stringify({ all: true }),
       });
+      const data = await res.json();
+      if (data.success && data.skippedOnlineOnly > 0) {
+        showToast(`Skipped ${data.skippedOnlineOnly} online-only video${data.skippedOnlineOnly !== 1 ? 's' : ''}; download them to make proxies`);
+      }
     } catch (err) {
       console.error('Error generating proxies:', err);
     }
+  }, [showToast]);
+
+  // The folder filter belongs to the library it was set in
+  useEffect(() => {
+    setSelectedDirectory(null);
+  }, [currentPath]);
+
+  const handleShowSiblings = useCallback((video: VideoWithSelection) => {
+    setSelectedDirectory(getParentDirectory(video.filePath));
+  }, []);
+
+  const handleShowSameName = useCallback((video: VideoWithSelection) => {
+    setNameFilter(video.fileName);
   }, []);
 
+  // Shows as pending right away; the poll picks up the rest
+  const handleRetryThumbnail = useCallback(async (video: VideoWithSelection) => {
+    setThumbnailStatuses((previous) => ({ ...previous, [video.id]: 'pending' }));
+    try {
+      const res = await fetch('/api/thumbnails', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ videoIds: [video.id] }),
+      });
+      const data = await res.json();
+      if (!data.success) {
+        showToast(data.error || 'Failed to retry thumbnail', 'error');
+      }
+    } catch (err) {
+      showToast('Failed to retry thumbnail', 'error');
+      console.error('Error retrying thumbnail:', err);
+    }
+  }, [showToast]);
+
+  const handleRescanFolder = useCallback((video: VideoWithSelection) => {
+    handleScanFolder(getParentDirectory(video.filePath));
+  }, [handleScanFolder]);
+
+  // The loudness filter only appears once the library has measured clips
+  const hasLoudness = useMemo(() => videos.some((v) => v.loudnessLufs !== null), [videos]);
+
+  // Places with GPS footage in the library, most clips first
+  const places = useMemo(() => {
+    const counts = new Map<string, number>();
+    for (const video of videos) {
+      const key = getPlaceKey(video);
+      if (key) counts.set(key, (counts.get(key) ?? 0) + 1);
+    }
+    return Array.from(counts).sort((a, b) => b[1] - a[1]);
+  }, [videos]);
+
+  // Videos matching the search box (all tokens must match) and the folder filter
+  const visibleVideos = useMemo(
+    () => videos.filter((v) =>
+      matchesSearch(v, searchQuery, searchMode)
+      && (watchedFilter === 'any' || (watchedFilter === 'watched') === !!v.selection?.watchedAt)
+      && (labelFilter === 'any' || v.selection?.colorLabel === labelFilter)
+      && matchesColorFilter(v, colorFilter)
+      && matchesLoudnessFilter(v, loudnessFilter)
+      && (locationFilter === null
+        || (locationFilter === 'located' ? v.latitude !== null : getPlaceKey(v) === locationFilter))
+      && (!inefficientOnly || (!!bitrateThresholds && isInefficient(v, bitrateThresholds)))
+      && (!selectedDirectory || getParentDirectory(v.filePath) === selectedDirectory)
+      && (!setFilter || setFilter.videoIds.has(v.id))
+      && (codecFilter === null || (v.codec ?? '') === codecFilter)
+      && (!nameFilter || v.fileName === nameFilter)
+    ),
+    [videos, searchQuery, searchMode, watchedFilter, labelFilter, colorFilter, loudnessFilter, locationFilter, inefficientOnly, bitrateThresholds, selectedDirectory, setFilter, codecFilter, nameFilter]
+  );
+
+  // Batch buttons act on the selected videos in view, or on the whole view when none are
+  const selectedVisible = useMemo(
+    () => visibleVideos.filter((v) => selectedIds.has(v.id)),
+    [visibleVideos, selectedIds]
+  );
+  const batchVideos = selectedVisible.length > 0 ? selectedVisible : visibleVideos;
+  const batchScope = selectedVisible.length > 0 ? 'selected' : 'in this view';
+
+  // Set or clear the color label of several videos
+  const applyColorLabel = useCallback(async (videoIds: string[], label: ColorLabel | null) => {
+    if (videoIds.length === 0) return;
+    try {
+      const res = await fetch('/api/selections/label', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ videoIds, label }),
+      });
+      const data = await res.json();
+
+      if (data.success) {
+        const selections = new Map<string, Selection>(
+          data.selections.map((selection: Selection): [string, Selection] => [selection.videoId, selection])
+        );
+        setVideos((prev) =>
+          prev.map((v) => {
+            const selection = selections.get(v.id);
+            return selection ? { ...v, selection } : v;
+          })
+        );
+      } else {
+        showToast(data.error || 'Failed to update label', 'error');
+      }
+    } catch (err) {
+      console.error('Error setting color label:', err);
+    }
+  }, [showToast]);
+
+  // Rate several videos at once; the server applies it in a single transaction
+  const applyRating = useCallback(async (videoIds: string[], rating: number | null) => {
+    if (videoIds.length === 0) return;
+    try {
+      const res = await fetch('/api/selections/rating', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ videoIds, rating }),
+      });
+      const data = await res.json();
+
+      if (data.success) {
+        const selections = new Map<string, Selection>(
+          data.selections.map((selection: Selection): [string, Selection] => [selection.videoId, selection])
+        );
+        setVideos((prev) =>
+          prev.map((v) => {
+            const selection = selections.get(v.id);
+            return selection ? { ...v, selection } : v;
+          })
+        );
+        const count = `${videoIds.length} video${videoIds.length === 1 ? '' : 's'}`;
+        showToast(rating ? `Rated ${count} ${'★'.repeat(rating)}` : `Cleared the rating of ${count}`, 'success');
+      } else {
+        showToast(data.error || 'Failed to update rating', 'error');
+      }
+    } catch (err) {
+      showToast('Failed to update rating', 'error');
+      console.error('Error setting rating:', err);
+    }
+  }, [showToast]);
+
+  // From a card's menu: remove the whole selection if the card is part of it. Removed
+  // videos wait in Recently Removed, so there is no confirmation.
+  const handleRemoveFromCatalog = useCallback(async (video: VideoWithSelection) => {
+    const videoIds = selectedIds.has(video.id) ? selectedVisible.map((v) => v.id) : [video.id];
+    try {
+      const res = await fetch('/api/removed', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ videoIds }),
+      });
+      const data = await res.json();
+
+      if (data.success) {
+        const removedIds = new Set(videoIds);
+        setVideos((prev) => prev.filter((v) => !removedIds.has(v.id)));
+        setSelectedIds((prev) => new Set(Array.from(prev).filter((id) => !removedIds.has(id))));
+        showToast(`Moved ${data.removed} video${data.removed !== 1 ? 's' : ''} to Recently Removed`, 'success');
+      } else {
+        showToast(data.error || 'Failed to remove from catalog', 'error');
+      }
+    } catch (err) {
+      showToast('Failed to remove from catalog', 'error');
+      console.error('Error removing videos:', err);
+    }
+  }, [selectedIds, selectedVisible, showToast]);
+
+  // From a card's menu: label the whole selection if the card is part of it
+  const handleSetColorLabel = useCallback((video: VideoWithSelection, label: ColorLabel | null) => {
+    const videoIds = selectedIds.has(video.id) ? selectedVisible.map((v) => v.id) : [video.id];
+    applyColorLabel(videoIds, label);
+  }, [selectedIds, selectedVisible, applyColorLabel]);
+
+  // Ctrl+A (Cmd+A) selects every video in view, or clears the selection if they all are;
+  // Escape clears it; 1-5 label the selection and 0 clears its labels, like Finder.
+  // Ignored while typing or when a modal is open.
+  const hasModalOpen = !!selectedVideo || showTranscode || showDuplicates || showRecentlyRemoved || showVerify || showGlobalSearch || showAllLibraries;
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      const target = e.target as HTMLElement;
+      if (hasModalOpen || target.isContentEditable || ['INPUT', 'TEXTAREA', 'SELECT'].includes(target.tagName)) {
+        return;
+      }
+      if (e.key === 'a' && (e.ctrlKey || e.metaKey)) {
+        e.preventDefault();
+        setSelectedIds(selectedVisible.length === visibleVideos.length
+          ? new Set()
+          : new Set(visibleVideos.map((v) => v.id)));
+      } else if (e.key === 'Escape') {
+        setSelectedIds(new Set());
+      } else if (/^[0-5]$/.test(e.key) && !e.ctrlKey && !e.metaKey && !e.altKey && selectedVisible.length > 0) {
+        const index = Number(e.key);
+        applyColorLabel(selectedVisible.map((v) => v.id), index === 0 ? null : COLOR_LABELS[index - 1]);
+      }
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [hasModalOpen, visibleVideos, selectedVisible, applyColorLabel]);
+
+  // A new library starts with nothing selected
+  useEffect(() => {
+    setSelectedIds(new Set());
+    setSetFilter(null);
+    setCodecFilter(null);
+    setNameFilter(null);
+    setLocationFilter(null);
+  }, [currentPath]);
+
+  // Thresholds for the "Inefficient clips" view are stored per library
+  useEffect(() => {
+    if (!currentPath) return;
+    const fetchBitrateThresholds = async () => {
+      try {
+        const res = await fetch('/api/bitrate');
+        const data = await res.json();
+        setBitrateThresholds(data.success ? data.thresholds : null);
+      } catch (error) {
+        console.error('Error fetching bitrate thresholds:', error);
+      }
+    };
+
+    fetchBitrateThresholds();
+  }, [currentPath]);
+
+  const selectedVisibleIds = useMemo(() => selectedVisible.map((v) => v.id), [selectedVisible]);
+
+  // A dragged card carries the whole selection when it's part of it
+  const getDragIds = useCallback(
+    (video: VideoWithSelection) => (selectedIds.has(video.id) ? selectedVisibleIds : [video.id]),
+    [selectedIds, selectedVisibleIds]
+  );
+
+  const handleRestoreSelectionSet = useCallback((set: SelectionSet, videoIds: string[], filter: boolean) => {
+    setSelectedIds(new Set(videoIds));
+    setSetFilter(filter ? { name: set.name, videoIds: new Set(videoIds) } : null);
+  }, []);
+
+  // Watched progress for the current view
+  const watchStats = useMemo(() => {
+    const unwatched = visibleVideos.filter((v) => !v.selection?.watchedAt);
+    return {
+      watched: visibleVideos.length - unwatched.length,
+      total: visibleVideos.length,
+      remainingSeconds: unwatched.reduce((sum, v) => sum + v.duration, 0),
+    };
+  }, [visibleVideos]);
+
+  // Batch-mark the current view, e.g. after reviewing a folder outside the app
+  const handleMarkVisibleWatched = useCallback(async () => {
+    const videoIds = batchVideos.filter((v) => !v.selection?.watchedAt).map((v) => v.id);
+    if (videoIds.length === 0) return;
+    if (!window.confirm(`Mark ${videoIds.length} video${videoIds.length !== 1 ? 's' : ''} as watched?`)) return;
+
+    try {
+      const res = await fetch('/api/selections/watched', {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({ videoIds, watched: true }),
+      });
+      const data = await res.json();
+
+      if (data.success) {
+        const selections = new Map<string, Selection>(
+          data.selections.map((selection: Selection): [string, Selection] => [selection.videoId, selection])
+        );
+        setVideos((prev) =>
+          prev.map((v) => {
+            const selection = selections.get(v.id);
+            return selection ? { ...v, selection } : v;
+          })
+        );
+        showToast(`Marked ${selections.size} video${selections.size !== 1 ? 's' : ''} as watched`, 'success');
+      } else {
+        showToast(data.error || 'Failed to update watched state', 'error');
+      }
+    } catch (err) {
+      console.error('Error marking videos watched:', err);
+    }
+  }, [batchVideos, showToast]);
+
+  // Pick a random video from the current view, skipping recent picks
+  const handleRandomPick = useCallback((openInPlayer: boolean) => {
+    if (visibleVideos.length === 0) return;
+
+    const memory = Math.min(RANDOM_PICK_MEMORY, visibleVideos.length - 1);
+    const recent = recentPicks.current.slice(-memory);
+    const candidates = memory > 0
+      ? visibleVideos.filter((v) => !recent.includes(v.id))
+      : visibleVideos;
+    const pick = candidates[Math.floor(Math.random() * candidates.length)];
+
+    recentPicks.current = [...recent, pick.id];
+    setHighlightedVideoId(pick.id);
+    if (openInPlayer) {
+      setSelectedVideo(pick);
+    }
+  }, [visibleVideos]);
+
+  // Ctrl+Shift+Space (Cmd+Shift+Space) picks a random video and opens it in the player
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (hasModalOpen || e.code !== 'Space' || !e.shiftKey || !(e.ctrlKey || e.metaKey)) return;
+      e.preventDefault();
+      handleRandomPick(true);
+    };
+
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [hasModalOpen, handleRandomPick]);
+
+  // Current criteria, compared against saved smart views
+  const smartViewCriteria = useMemo<SmartViewCriteria>(
+    () => ({ query: searchQuery, searchMode, favoritesOnly: viewMode === 'favorites', watchedFilter, inefficientOnly, sortBy }),
+    [searchQuery, searchMode, viewMode, watchedFilter, inefficientOnly, sortBy]
+  );
+
+  // Live match count for each view. Only known while every video is loaded,
+  // not in the Favorites view.
+  const countSmartViewMatches = useCallback((criteria: SmartViewCriteria) => {
+    if (viewMode !== 'all') return null;
+    return videos.filter((v) =>
+      matchesSearch(v, criteria.query, criteria.searchMode)
+      && (!criteria.favoritesOnly || !!v.selection?.isFavorite)
+      && (criteria.watchedFilter === 'any' || (criteria.watchedFilter === 'watched') === !!v.selection?.watchedAt)
+      && (!criteria.inefficientOnly || (!!bitrateThresholds && isInefficient(v, bitrateThresholds)))
+    ).length;
+  }, [videos, viewMode, bitrateThresholds]);
+
+  // A smart view replaces every criterion, including the Favorites toggle
+  const handleApplySmartView = useCallback((criteria: SmartViewCriteria) => {
+    setSearchQuery(criteria.query);
+    setSearchMode(criteria.searchMode);
+    setViewMode(criteria.favoritesOnly ? 'favorites' : 'all');
+    setWatchedFilter(criteria.watchedFilter);
+    setInefficientOnly(criteria.inefficientOnly);
+    setSortBy(criteria.sortBy);
+  }, []);
+
+  // Re-encode a whole view from the Smart Views menu; clears the selection so the
+  // transcode covers every match
+  const handleTranscodeSmartView = useCallback((criteria: SmartViewCriteria) => {
+    handleApplySmartView(criteria);
+    setSelectedIds(new Set());
+    setShowTranscode(true);
+  }, [handleApplySmartView]);
+
   // Count videos without proxies
   const videosWithoutProxy = videos.filter((v) => !v.hasProxy).length;
 
@@ -288,30 +999,95 @@ export default function Home() {
               <p className="text-sm text-muted">Quick preview of your entire video catalog</p>
             </div>
 
-            {currentPath && !isScanning && videos.length > 0 && (
-              <div className="flex items-center gap-2">
-                <button
-                  onClick={() => setViewMode('all')}
-                  className={`px-4 py-2 text-sm rounded-lg transition-colors ${
-                    viewMode === 'all'
-                      ? 'bg-accent text-white'
-                      : 'bg-card-border text-muted hover:text-foreground'
-                  }`}
-                >
-                  All Videos
-                </button>
-                <button
-                  onClick={() => setViewMode('favorites')}
-                  className={`px-4 py-2 text-sm rounded-lg transition-colors ${
-                    viewMode === 'favorites'
-                      ? 'bg-accent text-white'
-                      : 'bg-card-border text-muted hover:text-foreground'
-                  }`}
-                >
-                  Favorites
-                </button>
-              </div>
-            )}
+            <div className="flex items-center gap-2">
+              {currentPath && !isScanning && videos.length > 0 && (
+                <>
+                  <button
+                    onClick={() => setViewMode('all')}
+                    className={`px-4 py-2 text-sm rounded-lg transition-colors ${
+                      viewMode === 'all'
+                        ? 'bg-accent text-white'
+                        : 'bg-card-border text-muted hover:text-foreground'
+                    }`}
+                  >
+                    All Videos
+                  </button>
+                  <button
+                    onClick={() => setViewMode('favorites')}
+                    className={`px-4 py-2 text-sm rounded-lg transition-colors ${
+                      viewMode === 'favorites'
+                        ? 'bg-accent text-white'
+                        : 'bg-card-border text-muted hover:text-foreground'
+                    }`}
+                  >
+                    Favorites
+                  </button>
+                  <SmartViews
+                    currentPath={currentPath}
+                    criteria={smartViewCriteria}
+                    onApply={handleApplySmartView}
+                    countMatches={countSmartViewMatches}
+                    onTranscode={handleTranscodeSmartView}
+                    onToast={showToast}
+                  />
+                  <button
+                    onClick={() => setShowDuplicates(true)}
+                    className="px-4 py-2 text-sm rounded-lg transition-colors bg-card-border text-muted hover:text-foreground"
+                  >
+                    Duplicates
+                  </button>
+                  <button
+                    onClick={() => setShowRecentlyRemoved(true)}
+                    className="px-4 py-2 text-sm rounded-lg transition-colors bg-card-border text-muted hover:text-foreground"
+                    title="Videos removed from the catalog in the last 30 days"
+                  >
+                    Recently Removed
+                  </button>
+                  <button
+                    onClick={() => setShowScanHistory(true)}
+                    className="px-4 py-2 text-sm rounded-lg transition-colors bg-card-border text-muted hover:text-foreground"
+                  >
+                    Scan history
+                  </button>
+                  <button
+                    onClick={() => setShowVerify(true)}
+                    className="px-4 py-2 text-sm rounded-lg transition-colors bg-card-border text-muted hover:text-foreground"
+                    title="Re-check files against their scan fingerprints"
+                  >
+                    Verify files
+                  </button>
+                  {undoableThumbnails > 0 && (
+                    <button
+                      onClick={handleUndoThumbnails}
+                      className="px-4 py-2 text-sm rounded-lg transition-colors bg-card-border text-muted hover:text-foreground"
+                      title="Restore the thumbnails replaced since the server started"
+                    >
+                      Undo thumbnail changes ({undoableThumbnails})
+                    </button>
+                  )}
+                  <LibraryHealth
+                    key={currentPath}
+                    refreshKey={healthKey}
+                    onVerify={() => setShowVerify(true)}
+                    codecFilter={codecFilter}
+                    onFilterCodec={setCodecFilter}
+                  />
+                  <WatchIndicator onCatalogChanged={fetchVideos} />
+                  <SettingsPanel onToast={showToast} onVideosChanged={fetchVideos} onBitrateThresholdsChange={setBitrateThresholds} />
+                </>
+              )}
+              <button
+                onClick={() => setShowGlobalSearch(true)}
+                className="h-9 px-3 rounded-lg flex items-center gap-2 bg-card-border text-muted hover:text-foreground transition-colors text-sm"
+                title="Search all libraries (/)"
+              >
+                <svg className="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                  <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z" />
+                </svg>
+                All libraries
+              </button>
+              <TasksPanel onTaskFinished={handleTaskFinished} />
+            </div>
           </div>
         </div>
       </header>
@@ -324,8 +1100,17 @@ export default function Home() {
             <div className="w-full max-w-2xl">
               <DropZone
                 onDirectorySelected={handleDirectorySelected}
+                onPreview={handlePreviewScan}
+                onStreamUrlSelected={setStreamUrl}
                 currentPath={currentPath}
                 isScanning={isScanning}
+                isPreviewing={isPreviewing}
+              />
+              <RecentLibraries
+                onOpen={handleDirectorySelected}
+                onRelinked={handleLibraryRelinked}
+                onShowAll={() => setShowAllLibraries(true)}
+                onToast={showToast}
               />
             </div>
           </div>
@@ -333,15 +1118,22 @@ export default function Home() {
 
         {/* Enhanced scanning progress */}
         {(isScanning || scanState.status === 'complete') && (
-          <div className="flex-1 flex items-center justify-center">
+          <div className={scanState.incremental ? 'mx-4 mt-4 bg-card border border-card-border rounded-lg' : 'flex-1 flex items-center justify-center'}>
             <ScanProgress
               status={scanState.status}
               phase={scanState.phase}
               totalVideos={scanState.totalVideos}
               videosProcessed={scanState.videosProcessed}
               videosSkipped={scanState.videosSkipped}
+              videosTooSmall={scanState.videosTooSmall}
+              totalBytes={scanState.totalBytes}
+              bytesProcessed={scanState.bytesProcessed}
+              videosOnlineOnly={scanState.videosOnlineOnly}
               currentFile={scanState.currentFile}
               message={scanState.message}
+              errors={scanState.errors}
+              folder={scanState.folder}
+              compact={scanState.incremental}
               onComplete={handleScanComplete}
             />
           </div>
@@ -354,8 +1146,30 @@ export default function Home() {
           </div>
         )}
 
+        {/* Offer to finish a scan that died mid-way; indexed videos are skipped */}
+        {interruptedScan && currentPath && !isScanning && (
+          <div className="mx-4 mt-4 p-4 bg-warning/10 border border-warning/30 rounded-lg flex items-center gap-4 text-sm">
+            <p className="flex-1">
+              The scan{interruptedScan.folder && ` of ${getBaseName(interruptedScan.folder)}`} started {new Date(interruptedScan.startedAt).toLocaleString()} was interrupted
+              after {interruptedScan.videosFound} video{interruptedScan.videosFound !== 1 ? 's' : ''}. Resume previous scan?
+            </p>
+            <button
+              onClick={() => startScan(currentPath, interruptedScan.folder ?? undefined)}
+              className="px-3 py-1.5 text-xs rounded-lg bg-accent text-white hover:bg-accent-hover"
+            >
+              Resume
+            </button>
+            <button
+              onClick={handleDismissInterruptedScan}
+              className="px-3 py-1.5 text-xs rounded-lg bg-card-border text-muted hover:text-foreground"
+            >
+              Dismiss
+            </button>
+          </div>
+        )}
+
         {/* Video grid */}
-        {currentPath && videos.length > 0 && !isScanning && scanState.status !== 'complete' && (
+        {currentPath && videos.length > 0 && ((!isScanning && scanState.status !== 'complete') || scanState.incremental) && (
           <div className="flex-1 flex flex-col">
             {/* Sort controls & folder selector */}
             <div className="flex items-center justify-between px-4 py-3 border-b border-card-border">
@@ -375,25 +1189,299 @@ export default function Home() {
                 <span className="text-sm text-muted truncate max-w-md" title={currentPath}>
                   {currentPath}
                 </span>
+                <SearchBar
+                  query={searchQuery}
+                  onQueryChange={setSearchQuery}
+                  mode={searchMode}
+                  onModeChange={setSearchMode}
+                />
+                {/* Watched filter chip: any -> unwatched -> watched */}
+                <button
+                  onClick={() => setWatchedFilter((prev) => (prev === 'any' ? 'unwatched' : prev === 'unwatched' ? 'watched' : 'any'))}
+                  className={`
+                    px-3 py-1.5 text-sm rounded-full transition-colors border
+                    ${watchedFilter === 'any'
+                      ? 'bg-card border-card-border text-muted hover:text-foreground'
+                      : 'bg-success/20 border-success/50 text-success'
+                    }
+                  `}
+                  title="Filter by watched state"
+                >
+                  {watchedFilter === 'watched' ? '✓ Watched' : watchedFilter === 'unwatched' ? 'Unwatched' : '✓ Watched: any'}
+                </button>
+                <select
+                  value={labelFilter}
+                  onChange={(e) => setLabelFilter(e.target.value as ColorLabel | 'any')}
+                  className={`
+                    px-3 py-1.5 text-sm rounded-full border bg-card
+                    ${labelFilter === 'any' ? 'border-card-border text-muted' : 'border-accent text-foreground'}
+                  `}
+                  title="Filter by color label"
+                >
+                  <option value="any">Label: any</option>
+                  {COLOR_LABELS.map((label) => (
+                    <option key={label} value={label}>{label[0].toUpperCase()}{label.slice(1)}</option>
+                  ))}
+                </select>
+                <select
+                  value={colorFilter}
+                  onChange={(e) => setColorFilter(e.target.value as ColorFilter)}
+                  className={`
+                    px-3 py-1.5 text-sm rounded-full border bg-card
+                    ${colorFilter === 'any' ? 'border-card-border text-muted' : 'border-accent text-foreground'}
+                  `}
+                  title="Filter by color space (HDR10, HLG and Dolby Vision count as HDR)"
+                >
+                  <option value="any">Color: any</option>
+                  <option value="hdr">HDR</option>
+                  <option value="log">Log</option>
+                  <option value="sdr">SDR</option>
+                </select>
+                {hasLoudness && (
+                  <select
+                    value={loudnessFilter}
+                    onChange={(e) => setLoudnessFilter(e.target.value as LoudnessFilter)}
+                    className={`
+                      px-3 py-1.5 text-sm rounded-full border bg-card
+                      ${loudnessFilter === 'any' ? 'border-card-border text-muted' : 'border-accent text-foreground'}
+                    `}
+                    title="Filter by measured loudness; clips that weren't measured only show with any"
+                  >
+                    <option value="any">Loudness: any</option>
+                    <option value="loud">Loud (&gt; {LOUD_LUFS} LUFS)</option>
+                    <option value="quiet">Quiet (&lt; {QUIET_LUFS} LUFS)</option>
+                  </select>
+                )}
+                {places.length > 0 && (
+                  <select
+                    value={locationFilter ?? ''}
+                    onChange={(e) => setLocationFilter(e.target.value || null)}
+                    className={`
+                      px-3 py-1.5 text-sm rounded-full border bg-card
+                      ${locationFilter === null ? 'border-card-border text-muted' : 'border-accent text-foreground'}
+                    `}
+                    title="Filter by GPS location; places group clips within about 1 km"
+                  >
+                    <option value="">Location: any</option>
+                    <option value="located">Has location</option>
+                    {places.map(([key, count]) => (
+                      <option key={key} value={key}>📍 {key} ({count})</option>
+                    ))}
+                  </select>
+                )}
+                {inefficientOnly && (
+                  <button
+                    onClick={() => setInefficientOnly(false)}
+                    className="px-3 py-1.5 text-sm rounded-full border border-accent bg-card text-foreground"
+                    title="Show clips of any bitrate"
+                  >
+                    Inefficient clips ✕
+                  </button>
+                )}
+                {codecFilter !== null && (
+                  <button
+                    onClick={() => setCodecFilter(null)}
+                    className="px-3 py-1.5 text-sm rounded-full border border-accent bg-card text-foreground"
+                    title="Show all codecs"
+                  >
+                    Codec: {formatCodecName(codecFilter || null)} ✕
+                  </button>
+                )}
+                {nameFilter && (
+                  <button
+                    onClick={() => setNameFilter(null)}
+                    className="px-3 py-1.5 text-sm rounded-full border border-accent bg-card text-foreground"
+                    title="Show all videos again"
+                  >
+                    Name: {nameFilter} ✕
+                  </button>
+                )}
+                {setFilter && (
+                  <button
+                    onClick={() => setSetFilter(null)}
+                    className="px-3 py-1.5 text-sm rounded-full border border-accent bg-card text-foreground"
+                    title="Show all videos again"
+                  >
+                    Set: {setFilter.name} ✕
+                  </button>
+                )}
+                <span className="flex items-center gap-2 text-sm text-accent">
+                  {selectedVisible.length > 0 && (
+                    <>
+                      {selectedVisible.length} selected
+                      <button
+                        onClick={() => setSelectedIds(new Set())}
+                        className="px-2 py-1 text-xs rounded-lg bg-card border border-card-border text-muted hover:text-foreground"
+                        title="Deselect all (Esc)"
+                      >
+                        Deselect all
+                      </button>
+                    </>
+                  )}
+                  {selectedVisible.length > 1 && (
+                    <span className="flex items-center gap-0.5 text-xs text-muted" title="Rate every selected video">
+                      Rate selected
+                      {Array.from({ length: MAX_RATING }, (_, i) => i + 1).map((rating) => (
+                        <button
+                          key={rating}
+                          onClick={() => applyRating(selectedVisibleIds, rating)}
+                          className="px-0.5 text-sm text-muted hover:text-yellow-400"
+                          title={`${rating} star${rating === 1 ? '' : 's'}`}
+                        >
+                          ★
+                        </button>
+                      ))}
+                      <button
+                        onClick={() => applyRating(selectedVisibleIds, null)}
+                        className="px-1 text-muted hover:text-foreground"
+                        title="Clear rating"
+                      >
+                        ✕
+                      </button>
+                    </span>
+                  )}
+                  <SelectionSets
+                    currentPath={currentPath}
+                    selectedIds={selectedVisibleIds}
+                    onRestore={handleRestoreSelectionSet}
+                    onToast={showToast}
+                  />
+                </span>
+                <button
+                  onClick={handleMarkVisibleWatched}
+                  disabled={batchVideos.every((v) => v.selection?.watchedAt)}
+                  className="
+                    px-3 py-1.5 text-sm rounded-lg transition-colors
+                    bg-card border border-card-border text-muted
+                    hover:text-foreground disabled:opacity-50
+                  "
+                  title={`Mark every video ${batchScope} as watched`}
+                >
+                  {selectedVisible.length > 0 ? 'Mark selected watched' : 'Mark visible watched'}
+                </button>
+                <button
+                  onClick={(e) => handleRandomPick(e.shiftKey || e.altKey || e.metaKey)}
+                  disabled={visibleVideos.length === 0}
+                  className="
+                    px-3 py-1.5 text-sm rounded-lg transition-colors
+                    bg-card border border-card-border text-muted
+                    hover:text-foreground disabled:opacity-50
+                  "
+                  title="Highlight a random video from this view (Shift-click or Ctrl+Shift+Space to play it)"
+                >
+                  🎲 Random
+                </button>
+                <button
+                  onClick={() => setShowTranscode(true)}
+                  disabled={visibleVideos.length === 0}
+                  className="
+                    px-3 py-1.5 text-sm rounded-lg transition-colors
+                    bg-card border border-card-border text-muted
+                    hover:text-foreground disabled:opacity-50
+                  "
+                  title={`Transcode videos ${batchScope} to a preset`}
+                >
+                  Transcode…
+                </button>
+                <button
+                  onClick={() => handleCheckSilence(batchVideos.map((v) => v.id))}
+                  disabled={visibleVideos.length === 0}
+                  className="
+                    px-3 py-1.5 text-sm rounded-lg transition-colors
+                    bg-card border border-card-border text-muted
+                    hover:text-foreground disabled:opacity-50
+                  "
+                  title={`Look for audio dropouts in videos ${batchScope}`}
+                >
+                  Check silence
+                </button>
+                <button
+                  onClick={() => handleScanFolder()}
+                  className="
+                    px-3 py-1.5 text-sm rounded-lg transition-colors
+                    bg-card border border-card-border text-muted
+                    hover:text-foreground
+                  "
+                  title="Scan one folder for new or changed videos without rescanning the whole library"
+                >
+                  Scan folder…
+                </button>
+                <PlaylistExport videos={batchVideos} rootPath={currentPath} />
+                <CatalogImport onImported={fetchVideos} onToast={showToast} />
               </div>
               <SortControls
                 value={sortBy}
                 onChange={setSortBy}
-                videoCount={videos.length}
+                layout={layout}
+                onLayoutChange={setLayout}
+                stripFrames={stripFrames}
+                onStripFramesChange={setStripFrames}
+                videoCount={visibleVideos.length}
+                watchStats={watchStats}
                 onClearCache={() => {
                   setCurrentPath(null);
                   setVideos([]);
                 }}
+                onAssetsCleared={fetchVideos}
               />
             </div>
 
+            {/* Active folder filter */}
+            {selectedDirectory && (
+              <div className="flex items-center gap-2 px-4 py-2 border-b border-card-border text-sm">
+                <span className="text-muted">📁</span>
+                <span className="font-mono truncate" title={selectedDirectory}>
+                  {selectedDirectory.startsWith(currentPath)
+                    ? selectedDirectory.slice(currentPath.length).replace(/^[\\/]/, '') || '.'
+                    : selectedDirectory}
+                </span>
+                <button
+                  onClick={() => handleScanFolder(selectedDirectory)}
+                  className="px-2 py-0.5 text-xs rounded-lg text-muted hover:text-foreground hover:bg-card-border"
+                  title="Scan this folder into the library"
+                >
+                  Rescan
+                </button>
+                <button
+                  onClick={() => setSelectedDirectory(null)}
+                  className="w-6 h-6 rounded-full text-muted hover:text-foreground hover:bg-card-border flex items-center justify-center"
+                  title="Show all folders"
+                >
+                  ×
+                </button>
+              </div>
+            )}
+
             {/* Grid */}
             <div className="flex-1 overflow-hidden relative">
+              <ThumbnailWarmup videos={visibleVideos} />
               <VideoGrid
-                videos={videos}
+                videos={visibleVideos}
                 isLoading={isLoading}
+                layout={layout}
+                sortBy={sortBy}
+                stripFrames={stripFrames}
+                emptyMessage={searchQuery || watchedFilter !== 'any' || labelFilter !== 'any' || colorFilter !== 'any' || loudnessFilter !== 'any' || locationFilter !== null || inefficientOnly || selectedDirectory || setFilter || codecFilter !== null || nameFilter ? 'No videos match your filters' : undefined}
+                highlightedVideoId={highlightedVideoId}
+                selectedIds={selectedIds}
+                onSelectionChange={setSelectedIds}
                 onSelectVideo={handleSelectVideo}
                 onToggleFavorite={handleToggleFavorite}
+                onToggleWatched={handleToggleWatched}
+                onShowSiblings={handleShowSiblings}
+                onRescanFolder={handleRescanFolder}
+                onShowSameName={handleShowSameName}
+                onSetColorLabel={handleSetColorLabel}
+                onRemoveFromCatalog={handleRemoveFromCatalog}
+                scanningFilePath={scanState.incremental && isScanning ? scanState.currentFile : null}
+                thumbnailStatuses={thumbnailStatuses}
+                onRetryThumbnail={handleRetryThumbnail}
+                getDragIds={getDragIds}
+                rootPath={currentPath}
+                directoryStats={directoryStats}
+                collapsedDirectories={collapsedDirectories}
+                onToggleDirectory={handleToggleDirectory}
+                onToast={showToast}
               />
             </div>
           </div>
@@ -406,13 +1494,94 @@ export default function Home() {
         videosWithoutProxy={videosWithoutProxy}
       />
 
-      {/* Video modal */}
+      {/* Toast notifications */}
+      <Toast toast={toast} onDismiss={dismissToast} />
+
+      {/* Video modal, remounted per video so A–B marks, audio track and subtitles don't carry over */}
       {selectedVideo && (
         <VideoModal
+          key={selectedVideo.id}
           video={selectedVideo}
           onClose={() => setSelectedVideo(null)}
           onToggleFavorite={handleToggleFavorite}
+          onToggleWatched={handleToggleWatched}
           onUpdateNotes={handleUpdateNotes}
+          onVideoUpdated={handleVideoUpdated}
+        />
+      )}
+
+      {/* Network stream player */}
+      {streamUrl && (
+        <StreamModal url={streamUrl} onClose={() => setStreamUrl(null)} />
+      )}
+
+      {/* Browse all libraries */}
+      {showAllLibraries && (
+        <AllLibrariesView
+          onOpenVideo={handleOpenSearchResult}
+          onClose={() => setShowAllLibraries(false)}
+          onToast={showToast}
+        />
+      )}
+
+      {/* Search across all libraries */}
+      {showGlobalSearch && (
+        <GlobalSearch
+          currentPath={currentPath}
+          onOpenResult={handleOpenSearchResult}
+          onClose={() => setShowGlobalSearch(false)}
+        />
+      )}
+
+      {/* Duplicate review */}
+      {showDuplicates && (
+        <DuplicatesView
+          onClose={() => setShowDuplicates(false)}
+          onCatalogChanged={fetchVideos}
+          onToast={showToast}
+        />
+      )}
+
+      {showRecentlyRemoved && (
+        <RecentlyRemovedView
+          onClose={() => setShowRecentlyRemoved(false)}
+          onCatalogChanged={fetchVideos}
+          onToast={showToast}
+        />
+      )}
+
+      {/* Batch transcode */}
+      {showTranscode && currentPath && (
+        <TranscodeModal
+          videos={batchVideos}
+          rootPath={currentPath}
+          onClose={() => setShowTranscode(false)}
+          onToast={showToast}
+        />
+      )}
+
+      {/* File integrity check */}
+      {showVerify && (
+        <VerifyView
+          onClose={() => setShowVerify(false)}
+          onCatalogChanged={fetchVideos}
+          onToast={showToast}
+        />
+      )}
+
+      {/* Scan history */}
+      {showScanHistory && (
+        <ScanHistory onClose={() => setShowScanHistory(false)} />
+      )}
+
+      {scanPreview && (
+        <ScanPreviewModal
+          preview={scanPreview}
+          onProceed={(path) => {
+            setScanPreview(null);
+            handleDirectorySelected(path);
+          }}
+          onClose={() => setScanPreview(null)}
         />
       )}
     </div>
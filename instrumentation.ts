@@ -0,0 +1,17 @@
+// Runs once when the Next.js server starts
+export async function register() {
+  // The log file needs Node's fs, which the edge runtime doesn't have
+  if (process.env.NEXT_RUNTIME === 'nodejs') {
+    const { installFileLogging } = await import('./app/lib/logger');
+    installFileLogging();
+
+    // Read the library history in the background, so a slow home directory doesn't hold
+    // up startup or the first requests. Thumbnail undo only lasts a session; old backups
+    // in those libraries are left over from earlier runs.
+    const { loadLibraryHistory } = await import('./app/lib/libraryHistory');
+    const { pruneThumbnailBackups } = await import('./app/lib/thumbnailBackups');
+    loadLibraryHistory()
+      .then(() => pruneThumbnailBackups())
+      .catch((error) => console.warn('Could not prune thumbnail backups:', error));
+  }
+}
@@ -1,6 +1,9 @@
 /** @type {import('next').NextConfig} */
 const nextConfig = {
-  /* config options here */
+  experimental: {
+    // Enables instrumentation.ts (file logging) on Next.js 14
+    instrumentationHook: true,
+  },
 };
 
 export default nextConfig;
@@ -0,0 +1,119 @@
+import { test, before, after } from 'node:test';
+import assert from 'node:assert/strict';
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+
+// The library history is written under HOME, so keep it out of the real one
+const tmp = fs.mkdtempSync(path.join(os.tmpdir(), 'vcb-catalog-'));
+process.env.HOME = tmp;
+
+let db: typeof import('../app/lib/db');
+let catalogApi: typeof import('../app/lib/catalogApi');
+let catalogServer: typeof import('../app/lib/catalogServer');
+let baseUrl = '';
+let videoId = '';
+
+before(async () => {
+  db = await import('../app/lib/db');
+  catalogApi = await import('../app/lib/catalogApi');
+  catalogServer = await import('../app/lib/catalogServer');
+
+  const root = path.join(tmp, 'Footage');
+  fs.mkdirSync(root);
+  db.initDatabase(root);
+  // Let the library's own (disabled) sync settle before starting on a free port
+  await catalogServer.syncCatalogServer();
+
+  const video = db.insertVideo({
+    filePath: path.join(root, 'beach.mp4'),
+    fileName: 'beach.mp4',
+    fileSize: 2048,
+    duration: 12,
+    width: 1920,
+    height: 1080,
+    createdAt: '2024-06-01T10:00:00.000Z',
+    directory: root,
+  });
+  db.insertVideo({
+    filePath: path.join(root, 'city.mov'),
+    fileName: 'city.mov',
+    fileSize: 4096,
+    duration: 30,
+    width: null,
+    height: null,
+    createdAt: '2024-06-02T10:00:00.000Z',
+    directory: root,
+  });
+  videoId = video.id;
+
+  const thumbnailPath = path.join(tmp, 'beach.jpg');
+  fs.writeFileSync(thumbnailPath, Buffer.from([0xff, 0xd8, 0xff, 0xd9]));
+  db.updateVideoThumbnail(videoId, thumbnailPath);
+
+  catalogApi.setCatalogApiEnabled(true);
+  const port = await catalogServer.startCatalogServer(0);
+  baseUrl = `http://127.0.0.1:${port}`;
+});
+
+after(async () => {
+  await catalogServer.stopCatalogServer();
+  fs.rmSync(tmp, { recursive: true, force: true });
+});
+
+test('lists and filters videos', async () => {
+  const res = await fetch(`${baseUrl}/videos?search=beach`);
+  assert.equal(res.status, 200);
+  const body = await res.json();
+  assert.equal(body.total, 1);
+  assert.equal(body.videos[0].fileName, 'beach.mp4');
+  assert.equal(body.videos[0].thumbnailUrl, `/videos/${videoId}/thumbnail`);
+});
+
+test('returns one video and 404s an unknown ID', async () => {
+  const res = await fetch(`${baseUrl}/videos/${videoId}`);
+  assert.equal(res.status, 200);
+  assert.equal((await res.json()).id, videoId);
+
+  const missing = await fetch(`${baseUrl}/videos/nope`);
+  assert.equal(missing.status, 404);
+  assert.equal((await missing.json()).success, false);
+});
+
+test('streams the thumbnail', async () => {
+  const res = await fetch(`${baseUrl}/videos/${videoId}/thumbnail`);
+  assert.equal(res.status, 200);
+  assert.equal(res.headers.get('content-type'), 'image/jpeg');
+  assert.deepEqual(Buffer.from(await res.arrayBuffer()), Buffer.from([0xff, 0xd8, 0xff, 0xd9]));
+});
+
+test('reports library totals', async () => {
+  const res = await fetch(`${baseUrl}/stats`);
+  const stats = await res.json();
+  assert.equal(stats.totalVideos, 2);
+  assert.equal(stats.totalDuration, 42);
+  assert.equal(stats.totalSize, 6144);
+});
+
+test('rejects writes and unknown paths', async () => {
+  assert.equal((await fetch(`${baseUrl}/videos`, { method: 'POST' })).status, 405);
+  assert.equal((await fetch(`${baseUrl}/api/catalog/videos`)).status, 404);
+});
+
+test('refuses requests once disabled', async () => {
+  catalogApi.setCatalogApiEnabled(false);
+  try {
+    const res = await fetch(`${baseUrl}/stats`);
+    assert.equal(res.status, 403);
+  } finally {
+    catalogApi.setCatalogApiEnabled(true);
+  }
+});
+
+test('only accepts valid unprivileged ports', () => {
+  assert.ok(catalogApi.isValidCatalogApiPort(3939));
+  assert.ok(!catalogApi.isValidCatalogApiPort(80));
+  assert.ok(!catalogApi.isValidCatalogApiPort(70000));
+  assert.ok(!catalogApi.isValidCatalogApiPort(3939.5));
+  assert.ok(!catalogApi.isValidCatalogApiPort('3939'));
+});
@@ -0,0 +1,145 @@
+import { test, before, after } from 'node:test';
+import assert from 'node:assert/strict';
+import { spawnSync } from 'child_process';
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+
+// The library history is written under HOME, so keep it out of the real one
+const tmp = fs.mkdtempSync(path.join(os.tmpdir(), 'vcb-containers-'));
+process.env.HOME = tmp;
+
+const hasFfmpeg = ['ffmpeg', 'ffprobe'].every((tool) => spawnSync(tool, ['-version']).status === 0);
+const skip = hasFfmpeg ? false : 'ffmpeg and ffprobe are not on PATH';
+
+let ffmpeg: typeof import('../app/lib/ffmpeg');
+
+before(async () => {
+  ffmpeg = await import('../app/lib/ffmpeg');
+});
+
+after(() => {
+  fs.rmSync(tmp, { recursive: true, force: true });
+});
+
+// Encode a 1-second 25 fps test pattern with lavfi
+function makeFixture(name: string, args: string[]): string {
+  const output = path.join(tmp, name);
+  const result = spawnSync('ffmpeg', [
+    '-y', '-v', 'error',
+    '-f', 'lavfi', '-i', 'testsrc=duration=1:size=320x240:rate=25',
+    ...args,
+    output,
+  ]);
+  assert.equal(result.status, 0, result.stderr?.toString());
+  return output;
+}
+
+function isJpeg(filePath: string): boolean {
+  const bytes = fs.readFileSync(filePath);
+  return bytes.length > 4 && bytes[0] === 0xff && bytes[1] === 0xd8;
+}
+
+test('MXF duration falls back to the stream when the container has none', () => {
+  const metadata = ffmpeg.toVideoMetadata({
+    streams: [
+      { codec_type: 'data' },
+      { codec_type: 'video', codec_name: 'mpeg2video', width: 1920, height: 1080, duration: '12.48', r_frame_rate: '25/1' },
+    ],
+    format: {},
+  });
+  assert.equal(metadata.duration, 12.48);
+  assert.equal(metadata.width, 1920);
+  assert.equal(metadata.codec, 'mpeg2video');
+});
+
+test('interlaced streams report frames, not fields', () => {
+  for (const fieldOrder of ['tt', 'bb', 'tb', 'bt']) {
+    const { frameRate } = ffmpeg.toVideoMetadata({
+      streams: [{ codec_type: 'video', r_frame_rate: '50/1', avg_frame_rate: '25/1', field_order: fieldOrder }],
+    });
+    assert.equal(frameRate, 25, fieldOrder);
+  }
+  // Progressive 50p keeps its rate
+  const progressive = ffmpeg.toVideoMetadata({
+    streams: [{ codec_type: 'video', r_frame_rate: '50/1', avg_frame_rate: '50/1', field_order: 'progressive' }],
+  });
+  assert.equal(progressive.frameRate, 50);
+});
+
+test('MXF with an audio track', { skip }, async () => {
+  const input = makeFixture('clip.mxf', [
+    '-f', 'lavfi', '-i', 'anullsrc=sample_rate=48000:channel_layout=mono', '-shortest',
+    '-c:v', 'mpeg2video', '-c:a', 'pcm_s16le', '-f', 'mxf',
+  ]);
+
+  const metadata = await ffmpeg.getVideoMetadata(input);
+  assert.ok(Math.abs(metadata.duration - 1) < 0.1, String(metadata.duration));
+  assert.equal(metadata.frameRate, 25);
+  assert.equal(metadata.videoStreamCount, 1);
+  assert.equal(metadata.audioTrackCount, 1);
+
+  const thumbnail = path.join(tmp, 'mxf_thumb.jpg');
+  await ffmpeg.generateThumbnail(input, thumbnail, 0.5);
+  assert.ok(isJpeg(thumbnail));
+
+  const sprite = path.join(tmp, 'mxf_sprite.jpg');
+  const config = await ffmpeg.generateSpriteSheet(input, sprite, metadata.duration);
+  assert.ok(isJpeg(sprite));
+  assert.ok(config.totalFrames > 0);
+});
+
+test('thumbnails and sprites pick the video when another stream comes first', { skip }, async () => {
+  // ffmpeg's MXF muxer insists on video first, so a transport stream stands in for camera
+  // files whose audio or data tracks lead
+  const input = makeFixture('audio_first.mts', [
+    '-f', 'lavfi', '-i', 'anullsrc=sample_rate=48000:channel_layout=stereo', '-shortest',
+    '-map', '1:a', '-map', '0:v', '-c:v', 'mpeg2video', '-c:a', 'mp2', '-f', 'mpegts',
+  ]);
+  const probe = spawnSync('ffprobe', ['-v', 'error', '-show_entries', 'stream=codec_type', '-of', 'csv=p=0', input]);
+  assert.equal(probe.stdout.toString().trim().split('\n')[0], 'audio');
+
+  const thumbnail = path.join(tmp, 'audio_first_thumb.jpg');
+  await ffmpeg.generateThumbnail(input, thumbnail, 0.5);
+  assert.ok(isJpeg(thumbnail));
+
+  const sprite = path.join(tmp, 'audio_first_sprite.jpg');
+  await ffmpeg.generateSpriteSheet(input, sprite, 1);
+  assert.ok(isJpeg(sprite));
+});
+
+test('AVCHD transport stream (.mts)', { skip }, async () => {
+  const input = makeFixture('00000.mts', ['-c:v', 'mpeg2video', '-f', 'mpegts']);
+
+  const metadata = await ffmpeg.getVideoMetadata(input);
+  assert.ok(Math.abs(metadata.duration - 1) < 0.1, String(metadata.duration));
+  assert.equal(metadata.frameRate, 25);
+  assert.equal(metadata.width, 320);
+
+  const thumbnail = path.join(tmp, 'mts_thumb.jpg');
+  await ffmpeg.generateThumbnail(input, thumbnail, 0.5);
+  assert.ok(isJpeg(thumbnail));
+});
+
+test('3GP phone video', { skip }, async () => {
+  const input = makeFixture('clip.3gp', ['-c:v', 'mpeg4', '-s', '176x144', '-f', '3gp']);
+
+  const metadata = await ffmpeg.getVideoMetadata(input);
+  assert.ok(Math.abs(metadata.duration - 1) < 0.1, String(metadata.duration));
+  assert.equal(metadata.frameRate, 25);
+  assert.equal(metadata.width, 176);
+
+  const thumbnail = path.join(tmp, '3gp_thumb.jpg');
+  await ffmpeg.generateThumbnail(input, thumbnail, 0.5);
+  assert.ok(isJpeg(thumbnail));
+});
+
+test('interlaced encode reports its frame rate', { skip }, async () => {
+  const input = makeFixture('interlaced.mts', [
+    '-c:v', 'mpeg2video', '-flags', '+ildct+ilme', '-top', '1', '-f', 'mpegts',
+  ]);
+
+  const metadata = await ffmpeg.getVideoMetadata(input);
+  assert.equal(metadata.frameRate, 25);
+  assert.ok(Math.abs(metadata.duration - 1) < 0.1, String(metadata.duration));
+});
@@ -0,0 +1,31 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { parseCreationTime } from '../app/lib/utils';
+
+// creation_time tags as ffprobe reports them from real containers
+const VALID: [string, string][] = [
+  ['2021-06-14T12:34:56.000000Z', '2021-06-14T12:34:56.000Z'], // MP4/MOV
+  ['2021-06-14T12:34:56+02:00', '2021-06-14T10:34:56.000Z'], // MXF with an offset
+];
+
+const INVALID = [
+  '0000-00-00 00:00:00', // AVCHD camcorders with an unset clock
+  '0000-00-00T00:00:00.000000Z',
+  '2021-13-45T00:00:00Z',
+  'unknown',
+  '',
+];
+
+test('valid creation times become ISO strings', () => {
+  for (const [tag, expected] of VALID) {
+    assert.equal(parseCreationTime(tag), expected);
+  }
+});
+
+test('junk creation times are null instead of throwing', () => {
+  for (const tag of INVALID) {
+    assert.equal(parseCreationTime(tag), null, JSON.stringify(tag));
+  }
+  assert.equal(parseCreationTime(null), null);
+  assert.equal(parseCreationTime(undefined), null);
+});
@@ -0,0 +1,41 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { execFileSync } from 'child_process';
+import { buildFfmpegCommand, quotePosix, quotePowerShell } from '../app/lib/ffmpegCommand';
+
+const values = (input: string) => ({ input, start: 1, end: 2.5 });
+
+// Names where a string replacement would expand $&, $1, $$ or $' instead of keeping them
+const DOLLAR_NAMES = ['/Footage/Price $&.mov', '/Footage/$1 take.mp4', '/Footage/$$ cash.mp4', "/Footage/a$'b.mkv"];
+
+test('dollar patterns in paths are inserted as written', () => {
+  for (const input of DOLLAR_NAMES) {
+    const command = buildFfmpegCommand('ffmpeg -i {input} {stem}_proxy.mp4', values(input), 'posix');
+    const stem = input.replace(/\.[^.]+$/, '');
+    assert.equal(command, `ffmpeg -i ${quotePosix(input)} ${quotePosix(`${stem}_proxy.mp4`)}`);
+  }
+});
+
+test('times are filled in with millisecond precision', () => {
+  assert.equal(
+    buildFfmpegCommand('ffmpeg -ss {start} -to {end} -i {input} -c copy {stem}_trim.mp4', values('/a/b.mp4'), 'posix'),
+    "ffmpeg -ss 1.000 -to 2.500 -i '/a/b.mp4' -c copy '/a/b_trim.mp4'"
+  );
+});
+
+test('POSIX quoting survives the shell', { skip: process.platform === 'win32' }, () => {
+  for (const input of [...DOLLAR_NAMES, "/Footage/it's \"quoted\".mov", '/Footage/`cmd` $(x) \\n.mp4']) {
+    const echoed = execFileSync('sh', ['-c', `printf '%s' ${quotePosix(input)}`], { encoding: 'utf8' });
+    assert.equal(echoed, input);
+  }
+});
+
+test('PowerShell quoting doubles every kind of single quote', () => {
+  assert.equal(quotePowerShell("C:\\it's.mov"), "'C:\\it''s.mov'");
+  assert.equal(quotePowerShell('C:\\it’s $&.mov'), "'C:\\it’’s $&.mov'");
+  assert.equal(quotePowerShell('C:\\"double".mov'), "'C:\\\"double\".mov'");
+  assert.equal(
+    buildFfmpegCommand('ffmpeg -i {input}', values('C:\\Price $& it’s.mov'), 'powershell'),
+    "ffmpeg -i 'C:\\Price $& it’’s.mov'"
+  );
+});
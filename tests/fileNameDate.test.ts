@@ -0,0 +1,52 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { parseFileNameDate } from '../app/lib/fileNameDate';
+
+// Dates in names are camera-local time, like the parser
+const local = (...parts: number[]) =>
+  new Date(parts[0], parts[1] - 1, parts[2], parts[3] ?? 0, parts[4] ?? 0, parts[5] ?? 0).toISOString();
+
+test('reads separated dates, with or without a time', () => {
+  assert.equal(parseFileNameDate('2021-06-14.mp4'), local(2021, 6, 14));
+  assert.equal(parseFileNameDate('2021_06_14.mov'), local(2021, 6, 14));
+  assert.equal(parseFileNameDate('Trip 2021.06.14 12.34.56.mp4'), local(2021, 6, 14, 12, 34, 56));
+  assert.equal(parseFileNameDate('2021-06-14T12-34-56.mkv'), local(2021, 6, 14, 12, 34, 56));
+});
+
+test('reads phone-style compact dates', () => {
+  assert.equal(parseFileNameDate('IMG_20210614_123456.MOV'), local(2021, 6, 14, 12, 34, 56));
+  assert.equal(parseFileNameDate('VID_20230102_030405.mp4'), local(2023, 1, 2, 3, 4, 5));
+  // Sub-second digits are ignored
+  assert.equal(parseFileNameDate('PXL_20230102_030405678.mp4'), local(2023, 1, 2, 3, 4, 5));
+  assert.equal(parseFileNameDate('20200101_4K60.mp4'), local(2020, 1, 1));
+});
+
+test('resolution and frame rate tags are not dates', () => {
+  for (const name of [
+    'clip_1080p.mp4',
+    'Trip_2021_1080p60.mp4',
+    '20191080p60.mp4',
+    '4K60_0012.mov',
+    'GX011080_4K60.mp4',
+    '2020_4K60_2020.mp4',
+    'Wedding 2019 4K60fps 1080.mov',
+    '1920x1080.mp4',
+    '19201080.mp4',
+    '20201080.mp4',
+  ]) {
+    assert.equal(parseFileNameDate(name), null, name);
+  }
+});
+
+test('rejects impossible and implausible dates', () => {
+  assert.equal(parseFileNameDate('2021-02-30.mp4'), null);
+  assert.equal(parseFileNameDate('2021-13-01.mp4'), null);
+  assert.equal(parseFileNameDate('1989-01-01.mp4'), null); // Before EARLIEST_YEAR
+  assert.equal(parseFileNameDate(`${new Date().getFullYear() + 2}-01-01.mp4`), null);
+  assert.equal(parseFileNameDate('2021-06-14 25.00.00.mp4'), null); // Hour 25
+});
+
+test('names without digits or with clip numbers have no date', () => {
+  assert.equal(parseFileNameDate('C0001.MXF'), null);
+  assert.equal(parseFileNameDate('beach.mp4'), null);
+});
@@ -0,0 +1,85 @@
+import { test, before, after } from 'node:test';
+import assert from 'node:assert/strict';
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+
+// The library history is written under HOME, so keep it out of the real one
+const tmp = fs.mkdtempSync(path.join(os.tmpdir(), 'vcb-paths-'));
+process.env.HOME = tmp;
+
+let toExtendedLengthPath: typeof import('../app/lib/ffmpeg').toExtendedLengthPath;
+let scanDirectory: typeof import('../app/lib/scanner').scanDirectory;
+
+before(async () => {
+  ({ toExtendedLengthPath } = await import('../app/lib/ffmpeg'));
+  ({ scanDirectory } = await import('../app/lib/scanner'));
+});
+
+after(() => {
+  fs.rmSync(tmp, { recursive: true, force: true });
+});
+
+const longName = `${'x'.repeat(250)}.mp4`;
+
+test('long Windows paths get the extended-length prefix', { skip: process.platform !== 'win32' }, () => {
+  assert.equal(toExtendedLengthPath(`D:\\Footage\\${longName}`), `\\\\?\\D:\\Footage\\${longName}`);
+  assert.equal(toExtendedLengthPath(`\\\\nas\\share\\${longName}`), `\\\\?\\UNC\\nas\\share\\${longName}`);
+  // Normalized first, since the prefixed form skips it
+  assert.equal(toExtendedLengthPath(`D:/Footage/./${longName}`), `\\\\?\\D:\\Footage\\${longName}`);
+  assert.equal(toExtendedLengthPath(`\\\\?\\D:\\Footage\\${longName}`), `\\\\?\\D:\\Footage\\${longName}`);
+});
+
+test('short and relative Windows paths are left alone', { skip: process.platform !== 'win32' }, () => {
+  assert.equal(toExtendedLengthPath('D:\\Footage\\clip.mp4'), 'D:\\Footage\\clip.mp4');
+  assert.equal(toExtendedLengthPath(longName), longName);
+  assert.equal(toExtendedLengthPath('-i'), '-i');
+});
+
+test('paths are never rewritten on other platforms', { skip: process.platform === 'win32' }, () => {
+  const longPath = `/Volumes/Footage/${'nested/'.repeat(40)}${longName}`;
+  assert.equal(toExtendedLengthPath(longPath), longPath);
+});
+
+async function walk(dir: string): Promise<{ found: string[]; errors: string[] }> {
+  const found: string[] = [];
+  const errors: string[] = [];
+  for await (const filePath of scanDirectory(dir, { onError: (message) => errors.push(message) })) {
+    found.push(filePath);
+  }
+  return { found, errors };
+}
+
+// Linux file systems take any bytes in a name; APFS and NTFS refuse invalid UTF-8
+test('names that aren\'t valid UTF-8 are reported, not indexed', { skip: process.platform !== 'linux' }, async () => {
+  const dir = path.join(tmp, 'latin1');
+  fs.mkdirSync(dir);
+  // "café.mp4" in Latin-1: 0xE9 on its own is invalid UTF-8
+  fs.writeFileSync(Buffer.concat([Buffer.from(`${dir}/caf`), Buffer.from([0xe9]), Buffer.from('.mp4')]), '');
+  fs.writeFileSync(path.join(dir, 'ok.mp4'), '');
+
+  const { found, errors } = await walk(dir);
+  assert.deepEqual(found, [path.join(dir, 'ok.mp4')]);
+  assert.equal(errors.length, 1);
+  assert.match(errors[0], /isn't valid UTF-8/);
+});
+
+test('a real U+FFFD in a name is indexed', { skip: process.platform === 'win32' }, async () => {
+  const dir = path.join(tmp, 'replacement');
+  fs.mkdirSync(dir);
+  fs.writeFileSync(path.join(dir, 'caf\uFFFD.mp4'), '');
+
+  const { found, errors } = await walk(dir);
+  assert.deepEqual(found, [path.join(dir, 'caf\uFFFD.mp4')]);
+  assert.deepEqual(errors, []);
+});
+
+test('long paths can be walked', { skip: process.platform === 'win32' }, async () => {
+  let dir = path.join(tmp, 'long');
+  for (let i = 0; i < 6; i++) dir = path.join(dir, 'y'.repeat(60));
+  fs.mkdirSync(dir, { recursive: true });
+  fs.writeFileSync(path.join(dir, longName), '');
+
+  const { found } = await walk(path.join(tmp, 'long'));
+  assert.deepEqual(found, [path.join(dir, longName)]);
+});
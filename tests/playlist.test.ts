@@ -0,0 +1,55 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import path from 'path';
+import { buildM3u8, parseM3u, PlaylistEntry } from '../app/lib/playlist';
+import { getBaseName } from '../app/lib/utils';
+
+const ROOT = '/Volumes/Footage';
+
+// Names with characters that mean something in M3U: '#' starts a comment, ',' ends the
+// EXTINF duration, line breaks end an entry
+const entries: PlaylistEntry[] = [
+  { filePath: `${ROOT}/#1 take.mov`, fileName: '#1 take.mov', duration: 12.4 },
+  { filePath: `${ROOT}/Day 2/clip, final.mp4`, fileName: 'clip, final.mp4', duration: 0 },
+  { filePath: `${ROOT}/Day 2/#sub/a#b.mkv`, fileName: 'a#b.mkv', duration: 3 },
+  { filePath: `${ROOT}/Ünïcødé/日本語 50%.mp4`, fileName: '日本語 50%.mp4', duration: 60 },
+  { filePath: '/elsewhere/outside root.mp4', fileName: 'line\nbreak.mp4', duration: 1 },
+];
+
+test('absolute paths survive a build and parse round trip', () => {
+  const locations = parseM3u(buildM3u8(entries));
+  assert.deepEqual(locations, entries.map((entry) => entry.filePath));
+});
+
+test('relative paths resolve back to the original files', () => {
+  const locations = parseM3u(buildM3u8(entries, ROOT));
+  assert.equal(locations.length, entries.length);
+  locations.forEach((location, i) => {
+    assert.ok(!location.startsWith('#'), `${location} would be read as a comment`);
+    assert.equal(path.posix.resolve(ROOT, location), entries[i].filePath);
+  });
+});
+
+test('line breaks in titles stay on the EXTINF line', () => {
+  const lines = buildM3u8(entries).trimEnd().split('\n');
+  assert.equal(lines.length, 1 + entries.length * 2);
+  assert.equal(lines[lines.length - 2], '#EXTINF:1,line break.mp4');
+});
+
+test('paths under a Windows library root are written relative', () => {
+  const playlist = buildM3u8(
+    [{ filePath: 'D:\\Footage\\#2\\clip.mp4', fileName: 'clip.mp4', duration: 5 }],
+    'D:\\Footage\\'
+  );
+  assert.deepEqual(parseM3u(playlist), ['./#2\\clip.mp4']);
+});
+
+test('a sibling folder sharing the root prefix stays absolute', () => {
+  const playlist = buildM3u8([{ filePath: `${ROOT}2/clip.mp4`, fileName: 'clip.mp4', duration: 5 }], ROOT);
+  assert.deepEqual(parseM3u(playlist), [`${ROOT}2/clip.mp4`]);
+});
+
+test('library names come from either separator', () => {
+  assert.equal(getBaseName('/Volumes/Footage/'), 'Footage');
+  assert.equal(getBaseName('D:\\Footage\\2024'), '2024');
+});
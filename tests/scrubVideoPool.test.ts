@@ -0,0 +1,32 @@
+import { test } from 'node:test';
+import assert from 'node:assert/strict';
+import { createScrubVideoPool } from '../app/lib/scrubVideoPool';
+
+test('the least recently hovered video is released past the limit', () => {
+  const pool = createScrubVideoPool(2);
+  const released: string[] = [];
+  const a = {};
+  const b = {};
+  const c = {};
+
+  pool.retain(a, () => released.push('a'));
+  pool.retain(b, () => released.push('b'));
+  pool.retain(a, () => released.push('a')); // Re-hovering a makes b the oldest
+  pool.retain(c, () => released.push('c'));
+
+  assert.deepEqual(released, ['b']);
+  assert.equal(pool.size(), 2);
+});
+
+test('forgotten cards are never released', () => {
+  const pool = createScrubVideoPool(1);
+  const released: string[] = [];
+  const a = {};
+
+  pool.retain(a, () => released.push('a'));
+  pool.forget(a);
+  pool.retain({}, () => released.push('b'));
+
+  assert.deepEqual(released, []);
+  assert.equal(pool.size(), 1);
+});
@@ -0,0 +1,59 @@
+import { test, before, after } from 'node:test';
+import assert from 'node:assert/strict';
+import fs from 'fs';
+import os from 'os';
+import path from 'path';
+
+// The library history is written under HOME, so keep it out of the real one
+const tmp = fs.mkdtempSync(path.join(os.tmpdir(), 'vcb-ids-'));
+process.env.HOME = tmp;
+
+let generateId: typeof import('../app/lib/db').generateId;
+let getFileFingerprint: typeof import('../app/lib/scanner').getFileFingerprint;
+
+before(async () => {
+  ({ generateId } = await import('../app/lib/db'));
+  ({ getFileFingerprint } = await import('../app/lib/scanner'));
+});
+
+after(() => {
+  fs.rmSync(tmp, { recursive: true, force: true });
+});
+
+test('10,000 library paths get distinct IDs', () => {
+  const ids = new Set<string>();
+  for (let i = 0; i < 10000; i++) {
+    ids.add(generateId(`/Volumes/Footage/Day ${i % 50}/clip_${String(i).padStart(5, '0')}.mp4`));
+  }
+  assert.equal(ids.size, 10000);
+});
+
+test('paths differing by "Aa" and "BB" share an ID', () => {
+  // Both pairs of characters add the same amount to the 32-bit hash
+  assert.equal(generateId('/x/Aa.mp4'), generateId('/x/BB.mp4'));
+});
+
+test('IDs are the same on every call', () => {
+  const filePath = '/Volumes/Footage/Trip/IMG_0001.MOV';
+  assert.equal(generateId(filePath), generateId(filePath));
+  assert.equal(generateId(filePath), '2z58uu');
+});
+
+test('fingerprints are the MD5 of the first bytes, size and mtime', async () => {
+  const filePath = path.join(tmp, 'known.mp4');
+  fs.writeFileSync(filePath, 'hello world');
+  const mtime = new Date('2024-01-01T00:00:00.000Z');
+  fs.utimesSync(filePath, mtime, mtime);
+
+  // md5('hello world' + '11' + '2024-01-01T00:00:00.000Z')
+  assert.equal(await getFileFingerprint(filePath), '0052b633c9e89f476a649052079e0a95');
+});
+
+test('empty files still get a fingerprint', async () => {
+  const filePath = path.join(tmp, 'empty.mp4');
+  fs.writeFileSync(filePath, '');
+  const mtime = new Date('2024-01-01T00:00:00.000Z');
+  fs.utimesSync(filePath, mtime, mtime);
+
+  assert.equal(await getFileFingerprint(filePath), 'ce0d2ef9bc0660825b4a67937881234d');
+});